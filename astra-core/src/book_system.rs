@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use astra_formats::Book;
 use astra_types::{
     AchievementBook, AiBook, AmiiboBook, AnimSetBook, AnimalBook, ArenaBook, AssetTableBook,
@@ -14,13 +14,87 @@ use astra_types::{
     LaterTalkBook, MapEditorBook, MapHistoryBook, MascotBook, MovieBook, MuscleExerciseDataBook,
     MusicBook, ParamsBook, PersonBook, PhotographSpotBook, ProfileCardBook, RangeBook, RelayBook,
     RelianceBook, RingBook, RingCleaningVoiceBook, ShopBook, SkillBook, SoundEventBook,
-    TerrainBook, TitleBook, TutorialBook, VibrationBook,
+    TerrainBook, TitleBook, TutorialBook, UnknownAttributes, VibrationBook,
 };
 use parking_lot::RwLock;
 use tracing::info;
 
 use crate::{BundlePersistFormat, CobaltFileSystemProxy};
 
+/// Run `$action` for every book owned by the [BookSystem] (excluding dispos),
+/// binding the field name and a reference to the book.
+macro_rules! for_each_book {
+    ($self:ident, |$name:ident, $book:ident| $action:expr) => {
+        for_each_book!(@books $self, $name, $book, $action, [
+            achieve,
+            ai,
+            amiibo,
+            anim_set,
+            animal,
+            arena,
+            asset_table,
+            calculator,
+            chapter,
+            chart,
+            cook,
+            dragon_ride_preset_param,
+            dragon_ride_prize_list,
+            dragon_ride_target_pattern,
+            effect,
+            encount,
+            end_roll,
+            fishing,
+            friend_list,
+            god,
+            ground_attribute,
+            hub_area,
+            hub_demo,
+            hub_dispos,
+            hub_fortune_telling,
+            hub_investment,
+            hub_map_icon,
+            hub_my_room,
+            hub_resource,
+            hub_talk,
+            item,
+            job,
+            jukebox,
+            key_help,
+            kill_bonus,
+            later_talk,
+            map_editor,
+            map_history,
+            mascot,
+            movie,
+            music,
+            muscle_exercise,
+            param,
+            person,
+            photograph,
+            profile_card,
+            range,
+            relay,
+            reliance,
+            ring,
+            ring_cleaning_voice,
+            shop,
+            skill,
+            sound_event,
+            terrain,
+            title,
+            tutorial,
+            vibration,
+        ])
+    };
+    (@books $self:ident, $name:ident, $book:ident, $action:expr, [$($field:ident),* $(,)?]) => {
+        $({
+            let $name = stringify!($field);
+            let $book = &$self.$field;
+            $action;
+        })*
+    };
+}
+
 pub struct BookSystem {
     file_system: Arc<CobaltFileSystemProxy>,
     dispos: HashMap<String, OpenBook<DisposBook>>,
@@ -244,75 +318,105 @@ impl BookSystem {
         }
     }
 
+    /// Copy the dispos `source` to a new dispos called `dispos_name`. The copy is written on the next save.
+    pub fn create_dispos(&mut self, dispos_name: &str, source: &str) -> Result<OpenBook<DisposBook>> {
+        let path = Path::new("dispos").join(dispos_name.to_lowercase());
+        let xml_name = dispos_name.to_uppercase();
+        if self.dispos.contains_key(dispos_name) || self.file_system.book_exists(&path, &xml_name)? {
+            bail!("Dispos '{}' already exists.", dispos_name);
+        }
+        // Copy from the open book so unsaved changes to the source are included.
+        let xml = self.open_dispos(source)?.to_xml()?;
+        let persist_format = self.file_system.copied_book_format(
+            Path::new("dispos").join(source.to_lowercase()),
+            &path,
+            &xml_name,
+        )?;
+        let book = OpenBook::new(DisposBook::try_from(Book::from_string(&xml)?)?, persist_format);
+        book.mark_dirty();
+        self.dispos.insert(dispos_name.to_string(), book.clone());
+        Ok(book)
+    }
+
+    /// Drop an open dispos. Unsaved changes are lost.
+    pub fn forget_dispos(&mut self, dispos_name: &str) {
+        self.dispos.remove(dispos_name);
+    }
+
     pub fn save(&self, backup_root: &Path) -> Result<()> {
-        self.achieve.save(&self.file_system, backup_root)?;
-        self.ai.save(&self.file_system, backup_root)?;
-        self.amiibo.save(&self.file_system, backup_root)?;
-        self.anim_set.save(&self.file_system, backup_root)?;
-        self.animal.save(&self.file_system, backup_root)?;
-        self.arena.save(&self.file_system, backup_root)?;
-        self.asset_table.save(&self.file_system, backup_root)?;
-        self.calculator.save(&self.file_system, backup_root)?;
-        self.chapter.save(&self.file_system, backup_root)?;
-        self.chart.save(&self.file_system, backup_root)?;
-        self.cook.save(&self.file_system, backup_root)?;
-        self.dragon_ride_preset_param
-            .save(&self.file_system, backup_root)?;
-        self.dragon_ride_prize_list
-            .save(&self.file_system, backup_root)?;
-        self.dragon_ride_target_pattern
-            .save(&self.file_system, backup_root)?;
-        self.effect.save(&self.file_system, backup_root)?;
-        self.encount.save(&self.file_system, backup_root)?;
-        self.end_roll.save(&self.file_system, backup_root)?;
-        self.fishing.save(&self.file_system, backup_root)?;
-        self.friend_list.save(&self.file_system, backup_root)?;
-        self.god.save(&self.file_system, backup_root)?;
-        self.ground_attribute.save(&self.file_system, backup_root)?;
-        self.hub_area.save(&self.file_system, backup_root)?;
-        self.hub_demo.save(&self.file_system, backup_root)?;
-        self.hub_dispos.save(&self.file_system, backup_root)?;
-        self.hub_fortune_telling
-            .save(&self.file_system, backup_root)?;
-        self.hub_investment.save(&self.file_system, backup_root)?;
-        self.hub_map_icon.save(&self.file_system, backup_root)?;
-        self.hub_my_room.save(&self.file_system, backup_root)?;
-        self.hub_resource.save(&self.file_system, backup_root)?;
-        self.hub_talk.save(&self.file_system, backup_root)?;
-        self.person.save(&self.file_system, backup_root)?;
-        self.item.save(&self.file_system, backup_root)?;
-        self.job.save(&self.file_system, backup_root)?;
-        self.jukebox.save(&self.file_system, backup_root)?;
-        self.key_help.save(&self.file_system, backup_root)?;
-        self.kill_bonus.save(&self.file_system, backup_root)?;
-        self.later_talk.save(&self.file_system, backup_root)?;
-        self.map_editor.save(&self.file_system, backup_root)?;
-        self.map_history.save(&self.file_system, backup_root)?;
-        self.mascot.save(&self.file_system, backup_root)?;
-        self.movie.save(&self.file_system, backup_root)?;
-        self.music.save(&self.file_system, backup_root)?;
-        self.muscle_exercise.save(&self.file_system, backup_root)?;
-        self.param.save(&self.file_system, backup_root)?;
-        self.photograph.save(&self.file_system, backup_root)?;
-        self.profile_card.save(&self.file_system, backup_root)?;
-        self.range.save(&self.file_system, backup_root)?;
-        self.relay.save(&self.file_system, backup_root)?;
-        self.reliance.save(&self.file_system, backup_root)?;
-        self.ring.save(&self.file_system, backup_root)?;
-        self.ring_cleaning_voice
-            .save(&self.file_system, backup_root)?;
-        self.shop.save(&self.file_system, backup_root)?;
-        self.skill.save(&self.file_system, backup_root)?;
-        self.sound_event.save(&self.file_system, backup_root)?;
-        self.terrain.save(&self.file_system, backup_root)?;
-        self.title.save(&self.file_system, backup_root)?;
-        self.tutorial.save(&self.file_system, backup_root)?;
-        self.vibration.save(&self.file_system, backup_root)?;
+        for_each_book!(self, |_name, book| {
+            book.save(&self.file_system, backup_root)?;
+        });
         for book in self.dispos.values() {
             book.save(&self.file_system, backup_root)?;
         }
         Ok(())
     }
+
+    pub fn is_dirty(&self) -> bool {
+        let mut dirty = self.dispos.values().any(|book| book.is_dirty());
+        for_each_book!(self, |_name, book| dirty |= book.is_dirty());
+        dirty
+    }
+
+    /// Write the XML for every modified book to `dir`. Dispos go in a `dispos` subfolder.
+    pub fn autosave(&self, dir: &Path) -> Result<()> {
+        let write = |path: PathBuf, xml: String| -> Result<()> {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&path, xml)
+                .with_context(|| format!("Failed to autosave book to {}", path.display()))
+        };
+        for_each_book!(self, |name, book| {
+            if book.is_dirty() {
+                write(dir.join(name).with_extension("xml"), book.to_xml()?)?;
+            }
+        });
+        for (name, book) in &self.dispos {
+            if book.is_dirty() {
+                write(
+                    dir.join("dispos").join(name).with_extension("xml"),
+                    book.to_xml()?,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace books with the autosaved copies in `dir`. Recovered books are marked dirty.
+    pub fn recover(&mut self, dir: &Path) -> Result<()> {
+        let read = |path: PathBuf| -> Result<Option<String>> {
+            if path.is_file() {
+                std::fs::read_to_string(&path)
+                    .map(Some)
+                    .with_context(|| format!("Failed to read autosaved book {}", path.display()))
+            } else {
+                Ok(None)
+            }
+        };
+        for_each_book!(self, |name, book| {
+            if let Some(xml) = read(dir.join(name).with_extension("xml"))? {
+                book.recover(&xml)
+                    .with_context(|| format!("Failed to recover book '{}'", name))?;
+            }
+        });
+        let dispos_dir = dir.join("dispos");
+        if dispos_dir.is_dir() {
+            for entry in std::fs::read_dir(&dispos_dir)? {
+                let path = entry?.path();
+                let Some(name) = path.file_stem().map(|s| s.to_string_lossy().into_owned()) else {
+                    continue;
+                };
+                if let Some(xml) = read(path)? {
+                    self.open_dispos(&name)?
+                        .recover(&xml)
+                        .with_context(|| format!("Failed to recover dispos '{}'", name))?;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 pub struct OpenBook<T>(Arc<RwLock<OpenBookInner<T>>>);
@@ -344,11 +448,16 @@ impl<T> OpenBook<T> {
     pub fn mark_dirty(&self) {
         self.0.write().dirty = true;
     }
+
+    /// Whether the book has been modified since it was loaded or last saved.
+    pub fn is_dirty(&self) -> bool {
+        self.0.read().dirty
+    }
 }
 
 impl<T> OpenBook<T>
 where
-    T: TryFrom<Book, Error = anyhow::Error>,
+    T: TryFrom<Book, Error = anyhow::Error> + UnknownAttributes,
     for<'a> &'a T: Into<Book>,
 {
     pub fn load(
@@ -363,6 +472,21 @@ where
     pub fn save(&self, file_system: &CobaltFileSystemProxy, backup_root: &Path) -> Result<()> {
         self.0.write().save(file_system, backup_root)
     }
+
+    /// Serialize the current contents of the book to XML.
+    pub fn to_xml(&self) -> Result<String> {
+        let book: Book = (&self.0.read().data).into();
+        book.serialize()
+    }
+
+    /// Replace the contents of the book with previously serialized XML and mark it dirty.
+    pub fn recover(&self, xml: &str) -> Result<()> {
+        let data = T::try_from(Book::from_string(xml)?)?;
+        let mut book = self.0.write();
+        book.data = data;
+        book.dirty = true;
+        Ok(())
+    }
 }
 
 struct OpenBookInner<T> {
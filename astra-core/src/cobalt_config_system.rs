@@ -2,6 +2,7 @@ use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{bail, Context, Result};
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
@@ -22,16 +23,23 @@ pub struct ModConfig {
 pub struct CobaltConfigSystem {
     file_system: Arc<CobaltFileSystemProxy>,
     config: Option<ModConfig>,
+    saved_config: Mutex<Option<String>>,
 }
 
 impl CobaltConfigSystem {
     pub fn load(file_system: Arc<CobaltFileSystemProxy>) -> Result<Self> {
+        let config: Option<ModConfig> = if let Some(raw_config) = file_system.read_cobalt_config() {
+            serde_yaml::from_str(&raw_config).context("Failed to parse Cobalt config.yaml")?
+        } else {
+            None
+        };
         Ok(Self {
-            config: if let Some(raw_config) = file_system.read_cobalt_config() {
-                serde_yaml::from_str(&raw_config).context("Failed to parse Cobalt config.yaml")?
-            } else {
-                None
-            },
+            saved_config: Mutex::new(
+                config
+                    .as_ref()
+                    .and_then(|config| serde_yaml::to_string(config).ok()),
+            ),
+            config,
             file_system,
         })
     }
@@ -39,13 +47,44 @@ impl CobaltConfigSystem {
     pub fn save(&self, backup_root: &Path) -> Result<()> {
         if let Some(config) = &self.config {
             info!("Saving Cobalt config...");
-            self.file_system.save_cobalt_config(&serde_yaml::to_string(&config)?, backup_root)?;
+            let raw_config = serde_yaml::to_string(&config)?;
+            self.file_system.save_cobalt_config(&raw_config, backup_root)?;
+            *self.saved_config.lock() = Some(raw_config);
         } else {
             info!("NOT saving Cobalt config since the project does not have one.")
         }
         Ok(())
     }
 
+    /// Whether the config differs from the last version written to disk.
+    pub fn is_dirty(&self) -> bool {
+        self.config
+            .as_ref()
+            .and_then(|config| serde_yaml::to_string(config).ok())
+            != *self.saved_config.lock()
+    }
+
+    /// Write the config to `dir` as `config.yaml` if it has unsaved changes.
+    pub fn autosave(&self, dir: &Path) -> Result<()> {
+        if let Some(config) = self.config.as_ref().filter(|_| self.is_dirty()) {
+            std::fs::write(dir.join("config.yaml"), serde_yaml::to_string(config)?)
+                .context("Failed to autosave Cobalt config")?;
+        }
+        Ok(())
+    }
+
+    /// Replace the config with the one written by [CobaltConfigSystem::autosave].
+    pub fn recover(&mut self, dir: &Path) -> Result<()> {
+        let path = dir.join("config.yaml");
+        if path.is_file() {
+            self.config = Some(
+                serde_yaml::from_str(&std::fs::read_to_string(path)?)
+                    .context("Failed to parse autosaved Cobalt config")?,
+            );
+        }
+        Ok(())
+    }
+
     pub fn create_config(&mut self) -> Result<()> {
         if self.config.is_some() {
             bail!("Cannot create a new config.yaml since one already exists");
@@ -9,6 +9,7 @@ use std::time::Duration;
 
 use anyhow::{anyhow, bail, Context, Result};
 use astra_formats::{Book, TextBundle};
+use astra_types::UnknownAttributes;
 use indexmap::IndexMap;
 use normpath::PathExt;
 use quick_xml::events::Event;
@@ -541,6 +542,15 @@ impl LocalizedFileSystem {
     }
 }
 
+/// A script copied by [CobaltFileSystemProxy::copy_script] that hasn't been written yet.
+pub struct ScriptCopy {
+    /// The script the copy was made from.
+    pub source: String,
+    /// Where the copy goes, relative to the Cobalt folder or the output folder.
+    path: PathBuf,
+    contents: Vec<u8>,
+}
+
 pub enum BundlePersistFormat {
     Cobalt {
         path: PathBuf,
@@ -697,6 +707,66 @@ impl CobaltFileSystemProxy {
         ))
     }
 
+    /// Copy the script `source` to a new script called `dest`, in the same format the source was read from.
+    /// Nothing is written yet. The copy is kept in memory until it's passed to [CobaltFileSystemProxy::write_script_copy].
+    pub fn copy_script(
+        &self,
+        source: &str,
+        dest: &str,
+    ) -> Result<(PathBuf, BundlePersistFormat, ScriptCopy)> {
+        let base_path = Path::new(r"StreamingAssets\aa\Switch\fe_assets_scripts").join(dest);
+        let script_path = base_path.with_extension("lua");
+        if self.list_scripts()?.contains(dest) || self.main_file_system.exists(&script_path, false)? {
+            bail!("Script '{}' already exists.", dest);
+        }
+
+        let (source_path, source_format) = self.read_script(source)?;
+        let contents = std::fs::read(source_path)?;
+        let copy = move |path| ScriptCopy {
+            source: source.to_string(),
+            path,
+            contents,
+        };
+        match (source_format, &self.cobalt_file_system) {
+            (BundlePersistFormat::Cobalt { .. }, Some(cobalt)) => {
+                let path_in_cobalt = Path::new("scripts").join(dest).with_extension("txt");
+                info!("Copying script '{}' to Cobalt folder at {}", source, path_in_cobalt.display());
+                Ok((
+                    cobalt.root.join(&path_in_cobalt),
+                    BundlePersistFormat::Cobalt {
+                        path: path_in_cobalt.clone(),
+                    },
+                    copy(path_in_cobalt),
+                ))
+            }
+            // The source's bundle is reused as the container for the copy.
+            (BundlePersistFormat::Vanilla { bundle, .. }, _) => {
+                info!("Copying script '{}' to {}", source, script_path.display());
+                Ok((
+                    self.main_file_system.root().join(&script_path),
+                    BundlePersistFormat::Vanilla {
+                        bundle_path: base_path.with_extension("txt.bundle"),
+                        bundle,
+                    },
+                    copy(script_path),
+                ))
+            }
+            _ => bail!("Cannot copy a Cobalt script because Cobalt's file system is not configured."),
+        }
+    }
+
+    /// Write a script made by [CobaltFileSystemProxy::copy_script] to disk.
+    pub fn write_script_copy(&self, copy: &ScriptCopy, persist_format: &BundlePersistFormat) -> Result<()> {
+        info!("Writing copy of script '{}' to {}", copy.source, copy.path.display());
+        match (persist_format, &self.cobalt_file_system) {
+            (BundlePersistFormat::Cobalt { .. }, Some(cobalt)) => cobalt.write(&copy.path, &copy.contents),
+            (BundlePersistFormat::Vanilla { .. }, _) => {
+                self.main_file_system.write(&copy.path, &copy.contents, false)
+            }
+            _ => bail!("Cannot write a Cobalt script because Cobalt's file system is not configured."),
+        }
+    }
+
     pub fn save_script<P: AsRef<Path>, P2: AsRef<Path>>(
         &self,
         absolute_script_path: P,
@@ -727,7 +797,7 @@ impl CobaltFileSystemProxy {
     ) -> Result<OpenBook<DataType>>
     where
         PathType: AsRef<Path>,
-        DataType: TryFrom<Book, Error = anyhow::Error>,
+        DataType: TryFrom<Book, Error = anyhow::Error> + UnknownAttributes,
     {
         // Try to read a Cobalt XML.
         if let Some(cobalt) = &self.cobalt_file_system {
@@ -742,6 +812,7 @@ impl CobaltFileSystemProxy {
                     .and_then(|raw| Book::from_string(&String::from_utf8_lossy(&raw)))
                     .and_then(|book| DataType::try_from(book))
                     .map(|data| {
+                        warn_unknown_attributes(&data, xml_name);
                         OpenBook::new(
                             data,
                             BundlePersistFormat::Cobalt {
@@ -762,6 +833,7 @@ impl CobaltFileSystemProxy {
         let mut bundle = TextBundle::from_slice(&raw)?;
         let book = Book::from_string(&bundle.take_string()?)?;
         let data = DataType::try_from(book)?;
+        warn_unknown_attributes(&data, xml_name);
         Ok(OpenBook::new(
             data,
             if self.cobalt_file_system.is_some() {
@@ -777,6 +849,47 @@ impl CobaltFileSystemProxy {
         ))
     }
 
+    /// Whether a book exists at `path`, either in the Cobalt folder or as a bundle.
+    pub fn book_exists<P: AsRef<Path>>(&self, path: P, xml_name: &str) -> Result<bool> {
+        if let Some(cobalt) = &self.cobalt_file_system {
+            if cobalt.exists(Self::format_cobalt_xml_path(&path, Some(xml_name)))? {
+                return Ok(true);
+            }
+        }
+        self.main_file_system
+            .exists(Self::bundled_book_path(&path), false)
+    }
+
+    /// Pick where a copy of the book at `source_path` will be saved when it's written to `dest_path`.
+    /// Vanilla projects reuse the source's bundle as the container for the copy.
+    pub fn copied_book_format<P: AsRef<Path>, P2: AsRef<Path>>(
+        &self,
+        source_path: P,
+        dest_path: P2,
+        dest_xml_name: &str,
+    ) -> Result<BundlePersistFormat> {
+        if self.cobalt_file_system.is_some() {
+            return Ok(BundlePersistFormat::Cobalt {
+                path: Self::format_cobalt_xml_path(&dest_path, Some(dest_xml_name)),
+            });
+        }
+        let raw = self
+            .main_file_system
+            .read(Self::bundled_book_path(&source_path), false)?;
+        let mut bundle = TextBundle::from_slice(&raw)?;
+        bundle.replace_raw(vec![])?;
+        Ok(BundlePersistFormat::Vanilla {
+            bundle_path: Self::bundled_book_path(&dest_path),
+            bundle,
+        })
+    }
+
+    fn bundled_book_path<P: AsRef<Path>>(path: P) -> PathBuf {
+        Path::new(r"StreamingAssets/aa/Switch/fe_assets_gamedata/")
+            .join(path)
+            .with_extension("xml.bundle")
+    }
+
     // TODO: Delete this.
     fn format_cobalt_xml_path<P: AsRef<Path>>(path: P, xml_name: Option<&str>) -> PathBuf {
         let path = Path::new("xml").join(path);
@@ -954,6 +1067,16 @@ impl CobaltFileSystemProxy {
     }
 }
 
+fn warn_unknown_attributes(data: &impl UnknownAttributes, xml_name: &str) {
+    let unknown = data.unknown_attributes();
+    if !unknown.is_empty() {
+        warn!(
+            "Book {} has attributes that Astra does not recognize. They will be preserved as-is: {:?}",
+            xml_name, unknown
+        );
+    }
+}
+
 // Borrowed from Raytwo
 fn prettify_xml(xml: &str) -> Result<String> {
     let mut reader = Reader::from_str(xml);
@@ -10,6 +10,7 @@ mod terrain_system;
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use anyhow::Result;
 
@@ -55,6 +56,7 @@ pub enum RomSource {
 #[derive(Debug)]
 pub struct AstraProject {
     pub backup_dir: PathBuf,
+    pub autosave_dir: PathBuf,
     pub rom_source: RomSource,
     pub output_dir: PathBuf,
     pub cobalt_dir: Option<PathBuf>,
@@ -116,6 +118,82 @@ impl Astra {
         self.script_system.save(backup_path.as_path())?;
         self.terrain_system.save(backup_path.as_path())?;
         self.config_system.save(backup_path.as_path())?;
+        self.discard_autosave()?;
+        Ok(())
+    }
+
+    /// Whether any book, message archive, script, terrain, or the Cobalt config has unsaved changes.
+    pub fn is_dirty(&self) -> bool {
+        self.book_system.is_dirty()
+            || self.message_system.is_dirty()
+            || self.script_system.is_dirty()
+            || self.terrain_system.is_dirty()
+            || self.config_system.is_dirty()
+    }
+
+    /// Write unsaved books, messages, terrain, script copies and the Cobalt config to the
+    /// project's autosave folder so they can be recovered if Astra exits without saving.
+    /// Other scripts are edited in place on disk so they don't need to be included.
+    pub fn autosave(&self) -> Result<()> {
+        let autosave_dir = &self.project.autosave_dir;
+        if !self.book_system.is_dirty()
+            && !self.message_system.is_dirty()
+            && !self.terrain_system.is_dirty()
+            && !self.config_system.is_dirty()
+            && !self.script_system.has_pending_copies()
+        {
+            return self.discard_autosave();
+        }
+
+        // Write to a staging folder first so a crash mid-write doesn't clobber the last autosave.
+        let staging_dir = autosave_dir.with_extension("tmp");
+        if staging_dir.exists() {
+            std::fs::remove_dir_all(&staging_dir)?;
+        }
+        std::fs::create_dir_all(&staging_dir)?;
+        self.book_system.autosave(&staging_dir)?;
+        self.message_system.autosave(&staging_dir)?;
+        self.terrain_system.autosave(&staging_dir)?;
+        self.config_system.autosave(&staging_dir)?;
+        self.script_system.autosave(&staging_dir)?;
+        self.discard_autosave()?;
+        std::fs::rename(&staging_dir, autosave_dir).context("Failed to move autosave into place")
+    }
+
+    /// The time of the last autosave, if one is waiting to be recovered.
+    pub fn autosave_time(&self) -> Option<SystemTime> {
+        std::fs::metadata(&self.project.autosave_dir)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+
+    /// Load the last autosave over the current data. Recovered data is marked dirty.
+    pub fn recover_autosave(&mut self) -> Result<()> {
+        let autosave_dir = self.project.autosave_dir.clone();
+        self.book_system
+            .recover(&autosave_dir)
+            .context("Failed to recover books")?;
+        self.message_system
+            .recover(&autosave_dir)
+            .context("Failed to recover messages")?;
+        self.terrain_system
+            .recover(&autosave_dir)
+            .context("Failed to recover terrain")?;
+        self.config_system
+            .recover(&autosave_dir)
+            .context("Failed to recover the Cobalt config")?;
+        self.script_system
+            .recover(&autosave_dir)
+            .context("Failed to recover scripts")?;
+        Ok(())
+    }
+
+    /// Delete the last autosave.
+    pub fn discard_autosave(&self) -> Result<()> {
+        let autosave_dir = &self.project.autosave_dir;
+        if autosave_dir.exists() {
+            std::fs::remove_dir_all(autosave_dir).context("Failed to delete autosave")?;
+        }
         Ok(())
     }
 
@@ -129,6 +207,11 @@ impl Astra {
             .open(script_name, editor_program, editor_args)
     }
 
+    /// Copy the script `source` to a new script called `script_name`. The copy is written on the next save.
+    pub fn create_script(&mut self, script_name: &str, source: &str) -> Result<()> {
+        self.script_system.create(script_name, source)
+    }
+
     pub fn forget_script(&mut self, script_name: &str) {
         self.script_system.forget(script_name)
     }
@@ -192,6 +275,34 @@ impl Astra {
         }
     }
 
+    /// Copy the terrain `source` to a new terrain. The copy is written on the next save.
+    pub fn create_chapter_terrain(
+        &mut self,
+        terrain_name: &str,
+        source: &str,
+    ) -> Result<OpenTerrain> {
+        self.terrain_system.create(terrain_name, source)
+    }
+
+    /// Copy the dispos `source` to a new dispos. The copy is written on the next save.
+    pub fn create_dispos(
+        &mut self,
+        dispos_name: &str,
+        source: &str,
+    ) -> Result<OpenBook<DisposBook>> {
+        self.book_system.create_dispos(dispos_name, source)
+    }
+
+    /// Drop an open terrain, discarding unsaved changes. Used to undo [Self::create_chapter_terrain].
+    pub fn forget_chapter_terrain(&mut self, terrain_name: &str) {
+        self.terrain_system.forget(terrain_name)
+    }
+
+    /// Drop an open dispos, discarding unsaved changes. Used to undo [Self::create_dispos].
+    pub fn forget_dispos(&mut self, dispos_name: &str) {
+        self.book_system.forget_dispos(dispos_name)
+    }
+
     pub fn get_achieve_book(&self) -> OpenBook<AchievementBook> {
         self.book_system.achieve.clone()
     }
@@ -29,6 +29,15 @@ impl OpenMessageScript {
         self.0.read().path.clone()
     }
 
+    pub fn read<R>(&self, consumer: impl FnOnce(&str) -> R) -> R {
+        consumer(&self.0.read().script)
+    }
+
+    /// Whether the script was changed since it was loaded or last saved.
+    pub fn is_dirty(&self) -> bool {
+        self.0.read().dirty
+    }
+
     pub fn access(&self, consumer: impl FnOnce(&mut String) -> bool) {
         let mut script = self.0.write();
         if consumer(&mut script.script) {
@@ -64,6 +73,7 @@ impl OpenMessageScriptInner {
             // Clear out the data after building the bundle to avoid a memory leak.
             self.bundle.replace_script("")?;
             file_system.write(&self.path, &raw_bundle, false)?;
+            self.dirty = false;
         }
         Ok(())
     }
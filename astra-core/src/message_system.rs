@@ -1,4 +1,4 @@
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::Path;
 use std::sync::Arc;
 
@@ -240,6 +240,68 @@ impl MessageSystem {
     pub fn get(&self, archive_id: &str) -> Option<&OpenMessageArchive> {
         self.archives.get(archive_id)
     }
+
+    pub fn is_dirty(&self) -> bool {
+        self.archives.values().any(|archive| archive.is_dirty())
+            || self.scripts.values().any(|script| script.is_dirty())
+    }
+
+    /// Write unsaved messages to `dir`. Archives only store altered entries.
+    pub fn autosave(&self, dir: &Path) -> Result<()> {
+        let mut archives = BTreeMap::new();
+        for (name, archive) in &self.archives {
+            if archive.is_dirty() {
+                archives.insert(name.clone(), archive.altered_entries());
+            }
+        }
+        let mut scripts = BTreeMap::new();
+        for (name, script) in &self.scripts {
+            if script.is_dirty() {
+                scripts.insert(name.clone(), script.read(|script| script.to_string()));
+            }
+        }
+        if !archives.is_empty() {
+            std::fs::write(dir.join("messages.yaml"), serde_yaml::to_string(&archives)?)
+                .context("Failed to autosave message archives")?;
+        }
+        if !scripts.is_empty() {
+            std::fs::write(dir.join("scripts.yaml"), serde_yaml::to_string(&scripts)?)
+                .context("Failed to autosave message scripts")?;
+        }
+        Ok(())
+    }
+
+    /// Reapply messages written by [MessageSystem::autosave].
+    pub fn recover(&mut self, dir: &Path) -> Result<()> {
+        let path = dir.join("messages.yaml");
+        if path.is_file() {
+            let archives: BTreeMap<String, BTreeMap<String, String>> =
+                serde_yaml::from_str(&std::fs::read_to_string(path)?)
+                    .context("Failed to parse autosaved message archives")?;
+            for (name, entries) in archives {
+                let archive = self
+                    .archives
+                    .get(&name)
+                    .ok_or_else(|| anyhow!("Unknown message archive '{}'", name))?;
+                for (key, value) in entries {
+                    archive.put(key, value);
+                }
+            }
+        }
+        let path = dir.join("scripts.yaml");
+        if path.is_file() {
+            let scripts: BTreeMap<String, String> =
+                serde_yaml::from_str(&std::fs::read_to_string(path)?)
+                    .context("Failed to parse autosaved message scripts")?;
+            for (name, contents) in scripts {
+                self.open_script(&name)?.access(|script| {
+                    *script = contents;
+                    true
+                });
+            }
+        }
+        Ok(())
+    }
 }
 
 pub struct OpenMessageArchive(Arc<RwLock<OpenMessageArchiveInner>>);
@@ -281,11 +343,26 @@ impl OpenMessageArchive {
         let mut archive = self.0.write();
         archive.put(key, value);
     }
+
+    /// Whether any messages were changed since the archive was loaded or last saved.
+    pub fn is_dirty(&self) -> bool {
+        self.0.read().dirty
+    }
+
+    fn altered_entries(&self) -> BTreeMap<String, String> {
+        let archive = self.0.read();
+        archive
+            .altered_keys
+            .iter()
+            .filter_map(|key| Some((key.clone(), archive.message_map.get(key)?.clone())))
+            .collect()
+    }
 }
 
 struct OpenMessageArchiveInner {
     message_map: IndexMap<String, String>,
     altered_keys: IndexSet<String>,
+    dirty: bool,
     bundle: MessageBundle,
     path: String,
 }
@@ -311,6 +388,7 @@ impl OpenMessageArchiveInner {
             bundle,
             path,
             altered_keys,
+            dirty: false,
         })
     }
 
@@ -341,6 +419,7 @@ impl OpenMessageArchiveInner {
                 self.bundle.replace_entries(IndexMap::new())?;
                 file_system.write(&self.path, &raw_bundle, true)?;
             }
+            self.dirty = false;
         } else {
             info!(
                 "Skipping updates to message archive '{}' since no edits were made.",
@@ -352,6 +431,7 @@ impl OpenMessageArchiveInner {
 
     pub fn put(&mut self, key: String, value: String) {
         self.altered_keys.insert(key.clone());
+        self.dirty = true;
         self.message_map.insert(key, value);
     }
 }
@@ -1,13 +1,14 @@
-use std::collections::{BTreeSet, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::Arc;
+use std::time::SystemTime;
 
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use parking_lot::Mutex;
 use tracing::{error, info};
 
-use crate::{BundlePersistFormat, CobaltFileSystemProxy};
+use crate::{BundlePersistFormat, CobaltFileSystemProxy, ScriptCopy};
 
 pub struct ScriptSystem {
     file_system: Arc<CobaltFileSystemProxy>,
@@ -29,6 +30,8 @@ impl ScriptSystem {
         editor_args: &str,
     ) -> Result<()> {
         let script_path = if let Some(script) = self.opened_scripts.get(script_name) {
+            // External editors need the file on disk, so copies are written when they're opened.
+            script.write_pending_copy(&self.file_system)?;
             script.absolute_script_path.clone()
         } else {
             let script = OpenScript::load(&self.file_system, script_name)?;
@@ -48,6 +51,27 @@ impl ScriptSystem {
         Ok(())
     }
 
+    /// Copy the script `source` to a new script called `script_name`.
+    /// The copy is kept in memory and written on the next save, or when it's opened.
+    pub fn create(&mut self, script_name: &str, source: &str) -> Result<()> {
+        if self.opened_scripts.contains_key(script_name) {
+            bail!("Script '{}' already exists.", script_name);
+        }
+        let (absolute_script_path, persist_format, copy) =
+            self.file_system.copy_script(source, script_name)?;
+        self.opened_scripts.insert(
+            script_name.to_string(),
+            OpenScript {
+                absolute_script_path,
+                persist_format: Mutex::new(persist_format),
+                // Never synced, so the copy counts as a change until it's bundled.
+                synced_at: Mutex::new(None),
+                pending_copy: Mutex::new(Some(copy)),
+            },
+        );
+        Ok(())
+    }
+
     pub fn save(&self, backup_root: &Path) -> Result<()> {
         for script in self.opened_scripts.values() {
             script.save(&self.file_system, backup_root)?;
@@ -55,6 +79,55 @@ impl ScriptSystem {
         Ok(())
     }
 
+    pub fn is_dirty(&self) -> bool {
+        self.opened_scripts.values().any(|script| script.is_dirty())
+    }
+
+    /// Write the source of every copy that hasn't been written yet to `script_copies.yaml` in `dir`.
+    pub fn autosave(&self, dir: &Path) -> Result<()> {
+        let copies: BTreeMap<&String, String> = self
+            .opened_scripts
+            .iter()
+            .filter_map(|(name, script)| {
+                let copy = script.pending_copy.lock();
+                copy.as_ref().map(|copy| (name, copy.source.clone()))
+            })
+            .collect();
+        if !copies.is_empty() {
+            std::fs::write(
+                dir.join("script_copies.yaml"),
+                serde_yaml::to_string(&copies)?,
+            )
+            .context("Failed to autosave script copies")?;
+        }
+        Ok(())
+    }
+
+    /// Copy the scripts listed by [ScriptSystem::autosave] again.
+    pub fn recover(&mut self, dir: &Path) -> Result<()> {
+        let path = dir.join("script_copies.yaml");
+        if !path.is_file() {
+            return Ok(());
+        }
+        let copies: BTreeMap<String, String> =
+            serde_yaml::from_str(&std::fs::read_to_string(path)?)
+                .context("Failed to parse autosaved script copies")?;
+        for (name, source) in copies {
+            if !self.opened_scripts.contains_key(&name) {
+                self.create(&name, &source)
+                    .with_context(|| format!("Failed to recover script '{}'", name))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether any copied script hasn't been written yet.
+    pub fn has_pending_copies(&self) -> bool {
+        self.opened_scripts
+            .values()
+            .any(|script| script.pending_copy.lock().is_some())
+    }
+
     pub fn forget(&mut self, script_name: &str) {
         self.opened_scripts.remove(script_name);
     }
@@ -64,19 +137,29 @@ impl ScriptSystem {
     }
 
     pub fn list_all(&self) -> BTreeSet<String> {
-        match self.file_system.list_scripts() {
+        let mut scripts = match self.file_system.list_scripts() {
             Ok(scripts) => scripts,
             Err(err) => {
                 error!("Failed to list scripts: {:?}", err);
                 BTreeSet::new()
             }
-        }
+        };
+        // Copies aren't on disk until they're written.
+        scripts.extend(
+            self.opened_scripts
+                .iter()
+                .filter(|(_, script)| script.pending_copy.lock().is_some())
+                .map(|(name, _)| name.clone()),
+        );
+        scripts
     }
 }
 
 pub struct OpenScript {
     absolute_script_path: PathBuf,
     persist_format: Mutex<BundlePersistFormat>,
+    synced_at: Mutex<Option<SystemTime>>,
+    pending_copy: Mutex<Option<ScriptCopy>>,
 }
 
 impl OpenScript {
@@ -88,17 +171,49 @@ impl OpenScript {
         info!("Loaded script {:?}", persist_format);
 
         Ok(Self {
+            synced_at: Mutex::new(modified_time(&absolute_script_path)),
             absolute_script_path,
             persist_format: Mutex::new(persist_format),
+            pending_copy: Mutex::new(None),
         })
     }
 
+    /// Scripts are edited outside of Astra, so they are dirty if the extracted file
+    /// changed since it was last bundled. Cobalt scripts are edited in place and only dirty
+    /// until a copy is written.
+    pub fn is_dirty(&self) -> bool {
+        self.pending_copy.lock().is_some()
+            || matches!(
+                *self.persist_format.lock(),
+                BundlePersistFormat::Vanilla { .. }
+            ) && modified_time(&self.absolute_script_path) != *self.synced_at.lock()
+    }
+
+    /// Write the script to disk if it's a copy that hasn't been written yet.
+    pub fn write_pending_copy(&self, file_system: &CobaltFileSystemProxy) -> Result<()> {
+        let mut pending_copy = self.pending_copy.lock();
+        if let Some(copy) = pending_copy.as_ref() {
+            file_system.write_script_copy(copy, &self.persist_format.lock())?;
+            *pending_copy = None;
+        }
+        Ok(())
+    }
+
     pub fn save(&self, file_system: &CobaltFileSystemProxy, backup_root: &Path) -> Result<()> {
         info!("Saving script {:?}", self.persist_format);
+        self.write_pending_copy(file_system)?;
         file_system.save_script(
             &self.absolute_script_path,
             &mut self.persist_format.lock(),
             backup_root,
-        )
+        )?;
+        *self.synced_at.lock() = modified_time(&self.absolute_script_path);
+        Ok(())
     }
 }
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use astra_formats::{MonoBehavior, TerrainBundle, TerrainData};
 use parking_lot::RwLock;
 
@@ -21,13 +21,17 @@ impl TerrainSystem {
         })
     }
 
+    fn terrain_path(terrain_name: &str) -> PathBuf {
+        Path::new(r"StreamingAssets\aa\Switch\fe_assets_gamedata\terrains")
+            .join(terrain_name.to_lowercase())
+            .with_extension("bundle")
+    }
+
     pub fn open(&mut self, terrain_name: &str) -> Result<OpenTerrain> {
         if let Some(terrain) = self.terrain.get(terrain_name).cloned() {
             Ok(terrain)
         } else {
-            let path = Path::new(r"StreamingAssets\aa\Switch\fe_assets_gamedata\terrains")
-                .join(terrain_name.to_lowercase())
-                .with_extension("bundle");
+            let path = Self::terrain_path(terrain_name);
             let open_bundle = OpenTerrain::load(&self.file_system, path)?;
             let terrain = open_bundle.clone();
             self.terrain.insert(terrain_name.to_string(), open_bundle);
@@ -35,12 +39,83 @@ impl TerrainSystem {
         }
     }
 
+    /// Copy the terrain `source` to a new terrain called `terrain_name`. The copy is written on the next save.
+    pub fn create(&mut self, terrain_name: &str, source: &str) -> Result<OpenTerrain> {
+        let path = Self::terrain_path(terrain_name);
+        if self.terrain.contains_key(terrain_name) || self.file_system.exists(&path, false)? {
+            bail!("Terrain '{}' already exists.", terrain_name);
+        }
+        let mut terrain = OpenTerrainInner::load(&self.file_system, Self::terrain_path(source))?;
+        // Copy from the open terrain so unsaved changes to the source are included.
+        if let Some(open) = self.terrain.get(source) {
+            terrain.data = open.0.read().data.clone();
+        }
+        terrain.path = path;
+        terrain.dirty = true;
+        let terrain = OpenTerrain(Arc::new(RwLock::new(terrain)));
+        self.terrain
+            .insert(terrain_name.to_string(), terrain.clone());
+        Ok(terrain)
+    }
+
+    /// Drop an open terrain. Unsaved changes are lost.
+    pub fn forget(&mut self, terrain_name: &str) {
+        self.terrain.remove(terrain_name);
+    }
+
     pub fn save(&self, backup_root: &Path) -> Result<()> {
         for terrain in self.terrain.values() {
             terrain.save(&self.file_system, backup_root)?;
         }
         Ok(())
     }
+
+    pub fn is_dirty(&self) -> bool {
+        self.terrain.values().any(|terrain| terrain.is_dirty())
+    }
+
+    /// Write every modified terrain bundle to a `terrains` subfolder of `dir`.
+    pub fn autosave(&self, dir: &Path) -> Result<()> {
+        let terrain_dir = dir.join("terrains");
+        for (name, terrain) in &self.terrain {
+            if terrain.is_dirty() {
+                std::fs::create_dir_all(&terrain_dir)?;
+                let path = terrain_dir.join(name).with_extension("bundle");
+                std::fs::write(&path, terrain.0.write().serialize()?)
+                    .with_context(|| format!("Failed to autosave terrain to {}", path.display()))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace terrain with the copies written by [TerrainSystem::autosave].
+    /// Recovered terrain is marked dirty, including terrain created since the last save.
+    pub fn recover(&mut self, dir: &Path) -> Result<()> {
+        let terrain_dir = dir.join("terrains");
+        if !terrain_dir.is_dir() {
+            return Ok(());
+        }
+        for entry in std::fs::read_dir(&terrain_dir)? {
+            let path = entry?.path();
+            let Some(name) = path.file_stem().map(|s| s.to_string_lossy().into_owned()) else {
+                continue;
+            };
+            let raw_bundle = std::fs::read(&path)
+                .with_context(|| format!("Failed to read autosaved terrain {}", path.display()))?;
+            let mut recovered =
+                OpenTerrainInner::from_slice(&raw_bundle, Self::terrain_path(&name))
+                    .with_context(|| format!("Failed to recover terrain '{}'", name))?;
+            recovered.dirty = true;
+            match self.terrain.get(&name) {
+                Some(terrain) => *terrain.0.write() = recovered,
+                None => {
+                    self.terrain
+                        .insert(name, OpenTerrain(Arc::new(RwLock::new(recovered))));
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 pub struct OpenTerrain(Arc<RwLock<OpenTerrainInner>>);
@@ -61,6 +136,11 @@ impl OpenTerrain {
         self.0.write().save(file_system, backup_root)
     }
 
+    /// Whether the terrain was changed since it was loaded or last saved.
+    pub fn is_dirty(&self) -> bool {
+        self.0.read().dirty
+    }
+
     pub fn read<R>(&self, consumer: impl FnOnce(&TerrainData) -> R) -> R {
         consumer(&self.0.read().data.data)
     }
@@ -83,7 +163,11 @@ struct OpenTerrainInner {
 impl OpenTerrainInner {
     pub fn load(file_system: &LocalizedFileSystem, path: PathBuf) -> Result<Self> {
         let raw_bundle = file_system.read(&path, false)?;
-        let mut bundle = TerrainBundle::from_slice(&raw_bundle)?;
+        Self::from_slice(&raw_bundle, path)
+    }
+
+    fn from_slice(raw_bundle: &[u8], path: PathBuf) -> Result<Self> {
+        let mut bundle = TerrainBundle::from_slice(raw_bundle)?;
         let data = bundle.take_data()?;
         Ok(Self {
             data,
@@ -93,12 +177,20 @@ impl OpenTerrainInner {
         })
     }
 
+    /// Build the bundle with the current data.
+    fn serialize(&mut self) -> Result<Vec<u8>> {
+        self.bundle.replace_data(self.data.clone())?;
+        let raw_bundle = self.bundle.serialize();
+        self.bundle.replace_data(Default::default())?;
+        raw_bundle
+    }
+
     pub fn save(&mut self, file_system: &LocalizedFileSystem, backup_root: &Path) -> Result<()> {
         if self.dirty {
             file_system.backup(&self.path, backup_root, false)?;
-            self.bundle.replace_data(self.data.clone())?;
-            file_system.write(&self.path, &self.bundle.serialize()?, false)?;
-            self.bundle.replace_data(Default::default())?;
+            let raw_bundle = self.serialize()?;
+            file_system.write(&self.path, &raw_bundle, false)?;
+            self.dirty = false;
         }
         Ok(())
     }
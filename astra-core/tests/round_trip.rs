@@ -0,0 +1,397 @@
+//! Round-trip fidelity tests for books and bundles.
+//!
+//! The synthetic fixtures under `tests/fixtures` always run. Point the
+//! `ASTRA_ROMFS` environment variable at a dumped RomFS to also round-trip
+//! every book, message archive, terrain and script bundle in the game.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use astra_formats::{Book, MessageBundle, TerrainBundle, TextBundle};
+use astra_types::{
+    AchievementBook, AiBook, AmiiboBook, AnimSetBook, AnimalBook, ArenaBook, AssetTableBook,
+    AstraBookSchema, BookSchema, CalculatorBook, ChapterBook, ChartBook, CookBook, DisposBook,
+    DragonRidePresetParamDataBook, DragonRidePrizeListBook, DragonRideTargetPatternBook,
+    EffectBook, EncountBook, EndRollBook, FishingFishBook, FriendListBook, GodBook,
+    GroundAttributeBook, HubAreaBook, HubDemoBook, HubDisposBook, HubFortuneTellingBook,
+    HubInvestmentBook, HubMapIconBook, HubMyRoomBook, HubResourceBook, HubTalkBook, ItemBook,
+    JobBook, JukeboxBook, KeyHelpDataBook, KillBonusBook, LaterTalkBook, MapEditorBook,
+    MapHistoryBook, MascotBook, MovieBook, MuscleExerciseDataBook, MusicBook, ParamsBook,
+    PersonBook, PhotographSpotBook, ProfileCardBook, RangeBook, RelayBook, RelianceBook, RingBook,
+    RingCleaningVoiceBook, ShopBook, SkillBook, SoundEventBook, TerrainBook, TitleBook,
+    TutorialBook, UnknownAttributes, VibrationBook,
+};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use walkdir::WalkDir;
+
+const ROMFS_VAR: &str = "ASTRA_ROMFS";
+
+type BookRoundTrip = fn(&str) -> Result<()>;
+
+struct BookEntry {
+    stem: &'static str,
+    schema: &'static BookSchema,
+    round_trip: BookRoundTrip,
+}
+
+macro_rules! book {
+    ($stem:literal, $book:ty) => {
+        BookEntry {
+            stem: $stem,
+            schema: &<$book as AstraBookSchema>::SCHEMA,
+            round_trip: round_trip_book::<$book>,
+        }
+    };
+}
+
+/// Every gamedata book Astra knows how to edit, keyed by bundle file stem.
+/// Dispos books are found by folder rather than stem, so they aren't listed.
+const BOOKS: &[BookEntry] = &[
+    book!("achieve", AchievementBook),
+    book!("ai", AiBook),
+    book!("amiibolist", AmiiboBook),
+    book!("animal", AnimalBook),
+    book!("animset", AnimSetBook),
+    book!("arena", ArenaBook),
+    book!("assettable", AssetTableBook),
+    book!("calculator", CalculatorBook),
+    book!("chapter", ChapterBook),
+    book!("chart", ChartBook),
+    book!("cook", CookBook),
+    book!("dragonridepresetparamdata", DragonRidePresetParamDataBook),
+    book!("dragonrideprizelist", DragonRidePrizeListBook),
+    book!("dragonridetargetpattern", DragonRideTargetPatternBook),
+    book!("effect", EffectBook),
+    book!("encount", EncountBook),
+    book!("endroll", EndRollBook),
+    book!("fishingfishdata", FishingFishBook),
+    book!("friendlist", FriendListBook),
+    book!("god", GodBook),
+    book!("groundattribute", GroundAttributeBook),
+    book!("hubarea", HubAreaBook),
+    book!("hubdemo", HubDemoBook),
+    book!("hubdispos", HubDisposBook),
+    book!("hubfortunetelling", HubFortuneTellingBook),
+    book!("hubinvestment", HubInvestmentBook),
+    book!("hubmapicon", HubMapIconBook),
+    book!("hubmyroom", HubMyRoomBook),
+    book!("hubresource", HubResourceBook),
+    book!("hubtalk", HubTalkBook),
+    book!("item", ItemBook),
+    book!("job", JobBook),
+    book!("jukebox", JukeboxBook),
+    book!("keyhelpdata", KeyHelpDataBook),
+    book!("killbonus", KillBonusBook),
+    book!("latertalk", LaterTalkBook),
+    book!("mapeditor", MapEditorBook),
+    book!("maphistory", MapHistoryBook),
+    book!("mascot", MascotBook),
+    book!("movie", MovieBook),
+    book!("muscleexercisedata", MuscleExerciseDataBook),
+    book!("music", MusicBook),
+    book!("params", ParamsBook),
+    book!("person", PersonBook),
+    book!("photographspot", PhotographSpotBook),
+    book!("profilecard", ProfileCardBook),
+    book!("range", RangeBook),
+    book!("relay", RelayBook),
+    book!("reliance", RelianceBook),
+    book!("ring", RingBook),
+    book!("ringcleaningvoice", RingCleaningVoiceBook),
+    book!("shop", ShopBook),
+    book!("skill", SkillBook),
+    book!("soundevent", SoundEventBook),
+    book!("terrain", TerrainBook),
+    book!("title", TitleBook),
+    book!("tutorial", TutorialBook),
+    book!("vibration", VibrationBook),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+struct XmlElement {
+    depth: usize,
+    name: String,
+    attributes: BTreeMap<String, String>,
+}
+
+/// Flatten a document into its elements in order, ignoring whitespace,
+/// declarations and attribute order.
+fn flatten_xml(xml: &str) -> Result<Vec<XmlElement>> {
+    let mut reader = Reader::from_str(xml.trim_start_matches('\u{feff}'));
+    reader.trim_text(true);
+    let mut elements = vec![];
+    let mut depth = 0;
+    loop {
+        let (element, is_empty) = match reader.read_event()? {
+            Event::Start(element) => (element, false),
+            Event::Empty(element) => (element, true),
+            Event::End(_) => {
+                depth -= 1;
+                continue;
+            }
+            Event::Eof => break,
+            _ => continue,
+        };
+        let mut attributes = BTreeMap::new();
+        for attribute in element.attributes() {
+            let attribute = attribute?;
+            attributes.insert(
+                String::from_utf8_lossy(attribute.key.as_ref()).into_owned(),
+                attribute.unescape_value()?.into_owned(),
+            );
+        }
+        elements.push(XmlElement {
+            depth,
+            name: String::from_utf8_lossy(element.name().as_ref()).into_owned(),
+            attributes,
+        });
+        if !is_empty {
+            depth += 1;
+        }
+    }
+    Ok(elements)
+}
+
+fn assert_xml_equivalent(expected: &str, actual: &str) -> Result<()> {
+    let expected = flatten_xml(expected).context("failed to parse original XML")?;
+    let actual = flatten_xml(actual).context("failed to parse round-tripped XML")?;
+    for (index, (before, after)) in expected.iter().zip(&actual).enumerate() {
+        if before != after {
+            let dropped: Vec<_> = before
+                .attributes
+                .keys()
+                .filter(|key| !after.attributes.contains_key(*key))
+                .collect();
+            bail!(
+                "element #{} differs (dropped attributes: {:?})\n  before: {:?}\n  after:  {:?}",
+                index,
+                dropped,
+                before,
+                after
+            );
+        }
+    }
+    if expected.len() != actual.len() {
+        bail!(
+            "element count changed from {} to {}",
+            expected.len(),
+            actual.len()
+        );
+    }
+    Ok(())
+}
+
+fn round_trip_book<T>(xml: &str) -> Result<()>
+where
+    T: TryFrom<Book, Error = anyhow::Error>,
+    for<'a> &'a T: Into<Book>,
+{
+    let data = T::try_from(Book::from_string(xml)?)?;
+    let book: Book = (&data).into();
+    assert_xml_equivalent(xml, &book.serialize()?)
+}
+
+fn round_trip_message_bundle(raw: &[u8]) -> Result<()> {
+    let mut bundle = MessageBundle::from_slice(raw)?;
+    let entries = bundle.take_entries()?;
+    bundle.replace_entries(entries.clone())?;
+    let round_tripped = MessageBundle::from_slice(&bundle.serialize()?)?.take_entries()?;
+    for (key, value) in &entries {
+        match round_tripped.get(key) {
+            Some(other) if other == value => {}
+            Some(other) => bail!("message '{}' changed from {:?} to {:?}", key, value, other),
+            None => bail!("message '{}' was dropped", key),
+        }
+    }
+    if entries.len() != round_tripped.len() {
+        bail!(
+            "entry count changed from {} to {}",
+            entries.len(),
+            round_tripped.len()
+        );
+    }
+    Ok(())
+}
+
+fn round_trip_terrain_bundle(raw: &[u8]) -> Result<()> {
+    let mut bundle = TerrainBundle::from_slice(raw)?;
+    let before = bundle.take_data()?;
+    bundle.replace_data(before.clone())?;
+    let after = TerrainBundle::from_slice(&bundle.serialize()?)?.take_data()?;
+    let (before, after) = (&before.data, &after.data);
+    if (before.x, before.z, before.width, before.height)
+        != (after.x, after.z, after.width, after.height)
+    {
+        bail!(
+            "dimensions changed from {:?} to {:?}",
+            (before.x, before.z, before.width, before.height),
+            (after.x, after.z, after.width, after.height)
+        );
+    }
+    let before: Vec<_> = before.terrains.iter().map(|tid| tid.as_str()).collect();
+    let after: Vec<_> = after.terrains.iter().map(|tid| tid.as_str()).collect();
+    if before != after {
+        bail!("terrain tiles changed");
+    }
+    Ok(())
+}
+
+fn round_trip_text_bundle(raw: &[u8]) -> Result<()> {
+    let mut bundle = TextBundle::from_slice(raw)?;
+    let contents = bundle.take_raw()?;
+    bundle.replace_raw(contents.clone())?;
+    let round_tripped = TextBundle::from_slice(&bundle.serialize()?)?.take_raw()?;
+    if contents != round_tripped {
+        bail!(
+            "contents changed ({} bytes before, {} bytes after)",
+            contents.len(),
+            round_tripped.len()
+        );
+    }
+    Ok(())
+}
+
+fn round_trip_gamedata_bundle(raw: &[u8], round_trip: BookRoundTrip) -> Result<()> {
+    round_trip_text_bundle(raw)?;
+    let xml = TextBundle::from_slice(raw)?.take_string()?;
+    round_trip(&xml)
+}
+
+fn romfs_root() -> Option<PathBuf> {
+    match std::env::var_os(ROMFS_VAR) {
+        Some(root) => Some(PathBuf::from(root)),
+        None => {
+            eprintln!("{} is not set, skipping RomFS round-trip test", ROMFS_VAR);
+            None
+        }
+    }
+}
+
+fn bundles_under(root: &Path, dir: &str, suffix: &str) -> Vec<PathBuf> {
+    let mut paths: Vec<_> = WalkDir::new(root.join("StreamingAssets/aa/Switch").join(dir))
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .filter(|path| path.to_string_lossy().ends_with(suffix))
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Run `round_trip` over every file and collect failures so a single run
+/// reports everything that would be lost on save.
+fn check_all(
+    paths: &[PathBuf],
+    failures: &mut Vec<String>,
+    round_trip: impl Fn(&Path, &[u8]) -> Result<()>,
+) {
+    for path in paths {
+        let result = std::fs::read(path)
+            .map_err(anyhow::Error::from)
+            .and_then(|raw| round_trip(path, &raw));
+        if let Err(err) = result {
+            failures.push(format!("{}: {:#}", path.display(), err));
+        }
+    }
+}
+
+#[test]
+fn fixture_keyed_book_round_trips() {
+    round_trip_book::<VibrationBook>(include_str!("fixtures/vibration.xml")).unwrap();
+}
+
+#[test]
+fn fixture_multi_sheet_book_round_trips() {
+    round_trip_book::<TutorialBook>(include_str!("fixtures/tutorial.xml")).unwrap();
+}
+
+#[test]
+fn fixture_detects_dropped_attributes() {
+    let original = include_str!("fixtures/vibration.xml");
+    let lossy = original.replace(r#" AmplitudeMagnitude="0.5""#, "");
+    assert!(assert_xml_equivalent(original, &lossy).is_err());
+    assert!(assert_xml_equivalent(original, original).is_ok());
+}
+
+#[test]
+fn fixture_preserves_unknown_attributes() {
+    let xml = include_str!("fixtures/vibration.xml").replace(
+        r#" AmplitudeMagnitude="0.5""#,
+        r#" AmplitudeMagnitude="0.5" FromAFutureUpdate="1""#,
+    );
+    round_trip_book::<VibrationBook>(&xml).unwrap();
+    let book = VibrationBook::try_from(Book::from_string(&xml).unwrap()).unwrap();
+    let unknown = book.unknown_attributes();
+    assert_eq!(unknown.len(), 1);
+    assert!(unknown.iter().all(|key| key.ends_with("FromAFutureUpdate")));
+}
+
+#[test]
+fn every_book_schema_has_a_round_trip() {
+    for schema in astra_types::BOOKS {
+        let covered = schema.name == DisposBook::SCHEMA.name
+            || BOOKS.iter().any(|book| book.schema.name == schema.name);
+        assert!(covered, "{} has no round trip entry", schema.name);
+    }
+    for book in BOOKS {
+        assert!(
+            astra_types::book_schema(book.schema.name).is_some(),
+            "{} ({}) is missing from astra_types::BOOKS",
+            book.schema.name,
+            book.stem
+        );
+        let count = BOOKS.iter().filter(|other| other.stem == book.stem).count();
+        assert_eq!(count, 1, "{} is listed more than once", book.stem);
+    }
+}
+
+#[test]
+fn romfs_round_trips() {
+    let Some(root) = romfs_root() else {
+        return;
+    };
+    let mut failures = vec![];
+
+    let books = bundles_under(&root, "fe_assets_gamedata", ".xml.bundle");
+    check_all(&books, &mut failures, |path, raw| {
+        let stem = path
+            .file_name()
+            .map(|name| name.to_string_lossy().trim_end_matches(".xml.bundle").to_string())
+            .unwrap_or_default();
+        let is_dispos = path
+            .parent()
+            .and_then(|parent| parent.file_name())
+            .is_some_and(|parent| parent == "dispos");
+        if is_dispos {
+            round_trip_gamedata_bundle(raw, round_trip_book::<DisposBook>)
+        } else if let Some(book) = BOOKS.iter().find(|book| book.stem == stem) {
+            round_trip_gamedata_bundle(raw, book.round_trip)
+        } else {
+            // Books Astra doesn't model still have to survive the bundle layer.
+            round_trip_text_bundle(raw)
+        }
+    });
+
+    let messages = bundles_under(&root, "fe_assets_message", ".bytes.bundle");
+    check_all(&messages, &mut failures, |_, raw| {
+        round_trip_message_bundle(raw)
+    });
+
+    let terrains = bundles_under(&root, "fe_assets_gamedata/terrains", ".bundle");
+    check_all(&terrains, &mut failures, |_, raw| {
+        round_trip_terrain_bundle(raw)
+    });
+
+    let scripts = bundles_under(&root, "fe_assets_scripts", ".txt.bundle");
+    check_all(&scripts, &mut failures, |_, raw| round_trip_text_bundle(raw));
+
+    assert!(
+        failures.is_empty(),
+        "{} file(s) did not round-trip:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
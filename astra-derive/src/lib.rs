@@ -1,12 +1,15 @@
 use darling::FromField;
-use quote::{quote, quote_spanned};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::spanned::Spanned;
-use syn::{parse_macro_input, Expr, Fields, Item};
+use syn::{parse_macro_input, Expr, Fields, GenericArgument, Item, PathArguments, Type};
 
 #[derive(Debug, FromField)]
 #[darling(attributes(astra))]
 struct FieldOptions {
-    pub key: String,
+    #[darling(default)]
+    pub key: Option<String>,
+    #[darling(default)]
+    pub extra: bool,
     #[darling(default)]
     pub public_array: bool,
     #[darling(default)]
@@ -42,16 +45,36 @@ pub fn astra(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let mut extractors = vec![];
     let mut initializers = vec![];
     let mut setters = vec![];
+    let mut field_schemas = vec![];
     let mut field_options = vec![];
     let mut public_array_entry = quote! {};
     let mut unique_book_entry = quote! {};
+    let mut extra_field = None;
     for f in &fields.named {
         let options = match FieldOptions::from_field(f) {
             Ok(options) => options,
             Err(err) => return err.write_errors().into(),
         };
-        let key = &options.key;
         let ident = f.ident.as_ref().unwrap();
+        if options.extra {
+            if extra_field.is_some() {
+                return quote_spanned! { f.span() =>
+                    compile_error!("only one field can be marked #[astra(extra)]");
+                }
+                .into();
+            }
+            extra_field = Some(ident.clone());
+            continue;
+        }
+        let key = match &options.key {
+            Some(key) => key,
+            None => {
+                return quote_spanned! { f.span() =>
+                    compile_error!("expected #[astra(key = \"...\")] or #[astra(extra)]");
+                }
+                .into();
+            }
+        };
         if options.public_array {
             public_array_entry = quote! {
                 impl #impl_generics astra_formats::PublicArrayEntry for #name #ty_generics #where_clause {
@@ -87,6 +110,19 @@ pub fn astra(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
             });
         }
 
+        let ident_name = ident.to_string();
+        let type_name = f.ty.to_token_stream().to_string().replace(' ', "");
+        let (id, public_array) = (options.id, options.public_array);
+        field_schemas.push(quote! {
+            astra_types::FieldSchema {
+                ident: #ident_name,
+                key: #key,
+                ty: #type_name,
+                id: #id,
+                public_array: #public_array,
+            },
+        });
+
         initializers.push(quote! { #ident, });
         setters.push(quote! {
             if let Some(value) = self.#ident.to_sheet_param_attribute() {
@@ -96,6 +132,23 @@ pub fn astra(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         field_options.push(options);
     }
 
+    let row_name = name.to_string();
+
+    // Whatever is left in the param map after extracting known keys is unknown to us.
+    // Keep it on the row so saving doesn't drop attributes we don't model.
+    let (extra_initializer, extra_setter, extra_collector) = match &extra_field {
+        Some(ident) => (
+            quote! { #ident: values, },
+            quote! {
+                for (key, value) in &self.#ident {
+                    map.entry(key.clone()).or_insert_with(|| value.clone());
+                }
+            },
+            quote! { keys.extend(self.#ident.keys().cloned()); },
+        ),
+        None => (quote! {}, quote! {}, quote! {}),
+    };
+
     quote! {
         impl #impl_generics astra_formats::FromSheetDataParam for #name #ty_generics #where_clause {
             fn from_sheet_data_param(
@@ -104,6 +157,7 @@ pub fn astra(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
                 #(#extractors)*
                 Ok(Self {
                     #(#initializers)*
+                    #extra_initializer
                 })
             }
         }
@@ -115,10 +169,24 @@ pub fn astra(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
                 use astra_formats::ToSheetParamAttribute;
                 let mut map = astra_formats::indexmap::IndexMap::new();
                 #(#setters)*
+                #extra_setter
                 map
             }
         }
 
+        impl #impl_generics astra_types::UnknownAttributes for #name #ty_generics #where_clause {
+            fn collect_unknown_attributes(&self, keys: &mut std::collections::BTreeSet<String>) {
+                #extra_collector
+            }
+        }
+
+        impl #impl_generics astra_types::AstraSchema for #name #ty_generics #where_clause {
+            const NAME: &'static str = #row_name;
+            const FIELDS: &'static [astra_types::FieldSchema] = &[
+                #(#field_schemas)*
+            ];
+        }
+
         #public_array_entry
 
         #unique_book_entry
@@ -154,8 +222,32 @@ pub fn astra_book(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let mut from_sheet_conversions = vec![];
     let mut ref_to_sheet_conversions = vec![];
     let mut to_sheet_conversions = vec![];
+    let mut unknown_attribute_collectors = vec![];
+    let mut sheet_schemas = vec![];
     for f in &fields.named {
         let ident = f.ident.as_ref().unwrap();
+        let (layout, row_type) = match sheet_layout(&f.ty) {
+            Some(layout) => layout,
+            None => {
+                return quote_spanned! { f.ty.span() =>
+                    compile_error!("expected Sheet<Vec<T>>, Sheet<IndexMap<String, T>> or Sheet<IndexMap<String, Vec<T>>>");
+                }
+                .into();
+            }
+        };
+        let ident_name = ident.to_string();
+        let row_type_name = row_type.to_token_stream().to_string().replace(' ', "");
+        sheet_schemas.push(quote! {
+            astra_types::SheetSchema {
+                ident: #ident_name,
+                layout: astra_types::SheetLayout::#layout,
+                row_type: #row_type_name,
+                fields: <#row_type as astra_types::AstraSchema>::FIELDS,
+            },
+        });
+        unknown_attribute_collectors.push(quote! {
+            astra_types::UnknownAttributes::collect_unknown_attributes(&self.#ident, keys);
+        });
         from_sheet_conversions.push(quote! {
             #ident: value.sheets
                 .pop()
@@ -170,8 +262,18 @@ pub fn astra_book(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         });
     }
     from_sheet_conversions.reverse();
+    let book_name = name.to_string();
 
     quote! {
+        impl #impl_generics astra_types::AstraBookSchema for #name #ty_generics #where_clause {
+            const SCHEMA: astra_types::BookSchema = astra_types::BookSchema {
+                name: #book_name,
+                sheets: &[
+                    #(#sheet_schemas)*
+                ],
+            };
+        }
+
         impl #impl_generics astra_formats::AstraBook for #name #ty_generics #where_clause {
             fn load<PathTy: AsRef<std::path::Path>>(path: PathTy) -> astra_formats::error::Result<Self> {
                 astra_formats::Book::load(path)?.try_into()
@@ -192,6 +294,12 @@ pub fn astra_book(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
             }
         }
 
+        impl #impl_generics astra_types::UnknownAttributes for #name #ty_generics #where_clause {
+            fn collect_unknown_attributes(&self, keys: &mut std::collections::BTreeSet<String>) {
+                #(#unknown_attribute_collectors)*
+            }
+        }
+
         impl #impl_generics TryFrom<astra_formats::Book> for #name #ty_generics #ty_generics #where_clause {
             type Error = astra_formats::error::Error;
 
@@ -226,3 +334,40 @@ pub fn astra_book(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     }
     .into()
 }
+
+/// Work out how rows are laid out in a sheet field and what the row type is.
+fn sheet_layout(ty: &Type) -> Option<(proc_macro2::TokenStream, &Type)> {
+    let sheet_data = generic_args(ty, "Sheet")?;
+    let data = sheet_data.first()?;
+    if let Some(args) = generic_args(data, "Vec") {
+        return Some((quote! { List }, args.first()?));
+    }
+    let args = generic_args(data, "IndexMap")?;
+    let value = args.get(1)?;
+    match generic_args(value, "Vec") {
+        Some(args) => Some((quote! { Grouped }, args.first()?)),
+        None => Some((quote! { Keyed }, value)),
+    }
+}
+
+fn generic_args<'a>(ty: &'a Type, expected: &str) -> Option<Vec<&'a Type>> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != expected {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    Some(
+        args.args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+    )
+}
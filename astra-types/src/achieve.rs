@@ -28,6 +28,8 @@ pub struct AchieveData {
     pub kizuna_reward: i32,
     #[astra(key = "@Chapter")]
     pub chapter: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -38,4 +40,6 @@ pub struct BelongData {
     pub name: String,
     #[astra(key = "@DefeatAchieve")]
     pub defeat_achieve: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -23,4 +23,6 @@ pub struct AiData {
     pub str_value_1: String,
     #[astra(key = "@Trans")]
     pub trans: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -21,4 +21,6 @@ pub struct AmiiboData {
     pub ticket_num: i32,
     #[astra(key = "@KizunaNum")]
     pub kizuna_num: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -83,4 +83,6 @@ pub struct AnimSet {
     pub win: String,
     #[astra(key = "@WinLoop")]
     pub win_loop: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
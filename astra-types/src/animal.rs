@@ -137,4 +137,6 @@ pub struct AnimalData {
     pub e_005: bool,
     #[astra(key = "@E006")]
     pub e_006: bool,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -17,4 +17,6 @@ pub struct ArenaData {
     pub pid: String,
     #[astra(key = "@Iid")]
     pub iid: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
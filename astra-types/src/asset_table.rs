@@ -1,4 +1,5 @@
 use astra_derive::{Astra, AstraBook};
+use astra_formats::indexmap::IndexMap;
 use astra_formats::Sheet;
 
 #[derive(AstraBook)]
@@ -172,4 +173,6 @@ pub struct AssetDef {
     pub material: String,
     #[astra(key = "@Comment")]
     pub comment: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
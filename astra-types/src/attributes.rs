@@ -0,0 +1,40 @@
+use std::collections::BTreeSet;
+
+use astra_formats::indexmap::IndexMap;
+use astra_formats::Sheet;
+
+/// Access to XML attributes which Astra doesn't model.
+///
+/// Rows keep these in their `#[astra(extra)]` field so they can be written back
+/// untouched. Implemented by the `Astra` and `AstraBook` derives.
+pub trait UnknownAttributes {
+    fn collect_unknown_attributes(&self, keys: &mut BTreeSet<String>);
+
+    fn unknown_attributes(&self) -> BTreeSet<String> {
+        let mut keys = BTreeSet::new();
+        self.collect_unknown_attributes(&mut keys);
+        keys
+    }
+}
+
+impl<T: UnknownAttributes> UnknownAttributes for Vec<T> {
+    fn collect_unknown_attributes(&self, keys: &mut BTreeSet<String>) {
+        for item in self {
+            item.collect_unknown_attributes(keys);
+        }
+    }
+}
+
+impl<T: UnknownAttributes> UnknownAttributes for IndexMap<String, T> {
+    fn collect_unknown_attributes(&self, keys: &mut BTreeSet<String>) {
+        for item in self.values() {
+            item.collect_unknown_attributes(keys);
+        }
+    }
+}
+
+impl<T: UnknownAttributes> UnknownAttributes for Sheet<T> {
+    fn collect_unknown_attributes(&self, keys: &mut BTreeSet<String>) {
+        self.data.collect_unknown_attributes(keys);
+    }
+}
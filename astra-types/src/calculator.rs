@@ -1,4 +1,5 @@
 use astra_derive::{Astra, AstraBook};
+use astra_formats::indexmap::IndexMap;
 use astra_formats::Sheet;
 
 #[derive(AstraBook)]
@@ -15,6 +16,8 @@ pub struct CalculatorCommon {
     pub condition: Vec<String>,
     #[astra(key = "@Function")]
     pub function: Vec<String>,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -181,4 +184,6 @@ pub struct ExpTableEntry {
     pub p_39: i32,
     #[astra(key = "@P40")]
     pub p_40: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
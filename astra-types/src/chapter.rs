@@ -80,6 +80,8 @@ pub struct Chapter {
     pub net_kill_bonus_index: u8,
     #[astra(key = "@NetRankingIndex")]
     pub net_ranking_index: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug)]
@@ -100,4 +102,6 @@ pub struct Challenge {
     pub unlock_cid: String,
     #[astra(key = "@SortieCount")]
     pub sortie_count: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -35,6 +35,8 @@ pub struct ChartData {
     pub item_5_iid: String,
     #[astra(key = "@GodId")]
     pub god_id: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -67,6 +69,8 @@ pub struct ChartGodData {
     pub eirik_level: i32,
     #[astra(key = "@Flag")]
     pub flag: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -77,4 +81,6 @@ pub struct ChartParam {
     pub name: String,
     #[astra(key = "@Value")]
     pub value: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -52,6 +52,8 @@ pub struct CookData {
     pub mask_color_075_b: u8,
     #[astra(key = "@SeEvent")]
     pub se_event: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -82,6 +84,8 @@ pub struct FoodData {
     pub prefab_name: String,
     #[astra(key = "@SeEvent")]
     pub se_event: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -122,6 +126,8 @@ pub struct TasteData {
     pub deriving_probability: i8,
     #[astra(key = "@DerivedTid")]
     pub derived_tid: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -132,6 +138,8 @@ pub struct TasteConditionData {
     pub cid: String,
     #[astra(key = "@Name")]
     pub name: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -146,6 +154,8 @@ pub struct IngredientData {
     pub flag: i32,
     #[astra(key = "@Category")]
     pub category: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -234,4 +244,6 @@ pub struct FoodNamingConfig {
     pub name_type_38: i8,
     #[astra(key = "@NameType39")]
     pub name_type_39: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
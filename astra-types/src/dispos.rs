@@ -113,4 +113,6 @@ pub struct Spawn {
     pub ai_move_limit: String,
     #[astra(key = "@AI_Flag")]
     pub ai_flag: u32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
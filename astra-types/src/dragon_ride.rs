@@ -63,6 +63,8 @@ pub struct DragonRidePresetParamData {
     pub stime_8: f32,
     #[astra(key = "@Srandom_8")]
     pub srandom_8: f32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(AstraBook)]
@@ -90,6 +92,8 @@ pub struct DragonRidePrizeData {
     pub item_5: String,
     #[astra(key = "@Item6")]
     pub item_6: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(AstraBook)]
@@ -117,4 +121,6 @@ pub struct DragonRideTargetPattern {
     pub target_7: i8,
     #[astra(key = "@Target8")]
     pub target_8: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
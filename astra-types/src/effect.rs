@@ -28,6 +28,8 @@ pub struct Effect {
     pub shake_time: f32,
     #[astra(key = "@ShakeMagnitude")]
     pub shake_magnitude: f32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -40,4 +42,6 @@ pub struct EffectSequence {
     pub shoot: String,
     #[astra(key = "@Hit")]
     pub hit: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
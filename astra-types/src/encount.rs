@@ -18,6 +18,8 @@ pub struct EncountEquipment {
     pub category: String,
     #[astra(key = "@Percentage")]
     pub percentage: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -32,6 +34,8 @@ pub struct EncountWeaponCategory {
     pub rank_condition_less: u8,
     #[astra(key = "@Percentage")]
     pub percentage: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -44,6 +48,8 @@ pub struct EncountEnemyType {
     pub jobs: Vec<String>,
     #[astra(key = "@Flag")]
     pub flag: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -54,4 +60,6 @@ pub struct EncountRarityConfig {
     pub nation_level: u8,
     #[astra(key = "@Iid")]
     pub iid: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -1,4 +1,5 @@
 use astra_derive::{Astra, AstraBook};
+use astra_formats::indexmap::IndexMap;
 use astra_formats::Sheet;
 
 #[derive(AstraBook)]
@@ -18,4 +19,6 @@ pub struct EndRollData {
     pub text_2: String,
     #[astra(key = "@Text3")]
     pub text_3: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -72,6 +72,8 @@ pub struct FishingFishData {
     pub best_rod_type: i8,
     #[astra(key = "@TextureID")]
     pub texture_id: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -88,6 +90,8 @@ pub struct FishSizeData {
     pub bonus_minimum: f32,
     #[astra(key = "@BonusMaximum")]
     pub bonus_maximum: f32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -104,6 +108,8 @@ pub struct FishSpawn {
     pub lottery_param: i32,
     #[astra(key = "@FishID")]
     pub fish_id: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -114,6 +120,8 @@ pub struct FishingTargetListData {
     pub fish_id: String,
     #[astra(key = "@Priority")]
     pub priority: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -142,6 +150,8 @@ pub struct FishingAssistData {
     pub level_09: f32,
     #[astra(key = "@Level_10")]
     pub level_10: f32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -172,4 +182,6 @@ pub struct FishingRadicalParamData {
     pub power_04: f32,
     #[astra(key = "@Regene_04")]
     pub regene_04: f32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -27,4 +27,6 @@ pub struct FriendListData {
     pub image_name_s: String,
     #[astra(key = "@Country")]
     pub country: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -111,6 +111,8 @@ pub struct GodData {
     pub net_ranking_index: u8,
     #[astra(key = "@AIEngageAttackType")]
     pub ai_engage_attack_type: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -167,6 +169,8 @@ pub struct GodLevelData {
     pub aptitude_cost_special: u16,
     #[astra(key = "@Flag")]
     pub flag: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -181,4 +185,6 @@ pub struct GodBondLevelData {
     pub reliance_level: String,
     #[astra(key = "@Cost")]
     pub cost: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -17,4 +17,6 @@ pub struct GroundAttribute {
     pub sound: String,
     #[astra(key = "@Particle")]
     pub particle: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
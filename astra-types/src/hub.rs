@@ -26,6 +26,8 @@ pub struct HubAreaData {
     pub map_point_no: u8,
     #[astra(key = "@FacilityAidList")]
     pub facility_aid_list: Vec<String>,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -38,6 +40,8 @@ pub struct HubFacilityData {
     pub condition_cid: String,
     #[astra(key = "@IconName")]
     pub icon_name: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(AstraBook)]
@@ -67,6 +71,8 @@ pub struct HubDemoData {
     pub lod_bias: f32,
     #[astra(key = "@IsDisabledLodCrossfadeAnime")]
     pub is_disabled_lod_crossfade_anime: bool,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(AstraBook)]
@@ -144,6 +150,8 @@ pub struct HubSpawn {
     pub weight: f32,
     #[astra(key = "@OptimizeType")]
     pub optimize_type: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -156,6 +164,8 @@ pub struct HubSpawnRandomSet {
     pub rate: i32,
     #[astra(key = "@Count")]
     pub count: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -176,6 +186,8 @@ pub struct HubUnityBehavior {
     pub move_sec: String,
     #[astra(key = "@MoveSpeed")]
     pub move_speed: f32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(AstraBook)]
@@ -197,6 +209,8 @@ pub struct HubFortuneTellingData {
     pub reverse_text: String,
     #[astra(key = "@ReverseTextEx")]
     pub reverse_text_ex: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(AstraBook)]
@@ -230,6 +244,8 @@ pub struct HubNationData {
     pub foodstuff_info: String,
     #[astra(key = "@AnimalInfo")]
     pub animal_info: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -268,6 +284,8 @@ pub struct HubMaterialBonus {
     pub silver: u8,
     #[astra(key = "@PieceOfBond")]
     pub piece_of_bond: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -286,6 +304,8 @@ pub struct HubIngredientBonus {
     pub lv_4: u8,
     #[astra(key = "@Lv5")]
     pub lv_5: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -306,6 +326,8 @@ pub struct HubAnimalBonus {
     pub appear_rate_lv_5: u8,
     #[astra(key = "@CaptureLevel")]
     pub capture_level: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -316,6 +338,8 @@ pub struct HubItemBonus {
     pub item_id: String,
     #[astra(key = "@Num")]
     pub num: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -326,6 +350,8 @@ pub struct HubIngredientBonusGroup {
     pub foodstuff: String,
     #[astra(key = "@Num")]
     pub num: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -336,6 +362,8 @@ pub struct HubAnimalBonusGroup {
     pub animal_id: String,
     #[astra(key = "@Num")]
     pub num: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(AstraBook)]
@@ -353,6 +381,8 @@ pub struct HubMapIconData {
     pub large_scale: f32,
     #[astra(key = "@SmallScale")]
     pub small_scale: f32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(AstraBook)]
@@ -380,6 +410,8 @@ pub struct HubMyRoomData {
     pub s_1: i8,
     #[astra(key = "@S2")]
     pub s_2: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(AstraBook)]
@@ -391,6 +423,8 @@ pub struct HubResourceBook {
 pub struct HubResourceData {
     #[astra(key = "@Name", id)]
     pub name: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(AstraBook)]
@@ -413,6 +447,8 @@ pub struct HubTalkData {
     pub args_1: u8,
     #[astra(key = "@Item")]
     pub item: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -427,6 +463,8 @@ pub struct HubTalkRelativeData {
     pub pid_3: String,
     #[astra(key = "@PID4")]
     pub pid_4: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -435,6 +473,8 @@ pub struct HubTalkFacilityData {
     pub pattern: String,
     #[astra(key = "@PID")]
     pub pid: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -443,4 +483,6 @@ pub struct HubCrystalData {
     pub cid: String,
     #[astra(key = "@Count")]
     pub count: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -146,6 +146,8 @@ pub struct Item {
     pub add_help: String,
     #[astra(key = "@HighRankItem")]
     pub high_rank_item: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug)]
@@ -156,6 +158,8 @@ pub struct ItemCategory {
     pub category: String,
     #[astra(key = "@Help")]
     pub help: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug, Default, Clone)]
@@ -180,6 +184,8 @@ pub struct ForgeImproveData {
     pub hit: i8,
     #[astra(key = "@Critical")]
     pub critical: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug, Default, Clone)]
@@ -200,6 +206,8 @@ pub struct ForgeEvolveData {
     pub price: u16,
     #[astra(key = "@RefineLevel")]
     pub refine_level: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug, Default, Clone)]
@@ -224,6 +232,8 @@ pub struct ForgeExchangeData {
     pub for_steel: u16,
     #[astra(key = "@ForSilver")]
     pub for_silver: u16,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug)]
@@ -242,6 +252,8 @@ pub struct WeaponRankData {
     pub critical: u8,
     #[astra(key = "@Recover")]
     pub recover: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug)]
@@ -250,6 +262,8 @@ pub struct ItemInteractData {
     pub kind: String,
     #[astra(key = "@Flag")]
     pub flag: u32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug, Default, Clone)]
@@ -294,6 +308,8 @@ pub struct Accessory {
     pub silver: i32,
     #[astra(key = "@Mask")]
     pub mask: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug)]
@@ -400,6 +416,8 @@ pub struct GiftData {
     pub v_48: i8,
     #[astra(key = "@V49")]
     pub v_49: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug)]
@@ -420,6 +438,8 @@ pub struct RewardData {
     pub is_show: bool,
     #[astra(key = "@Condition")]
     pub condition: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug)]
@@ -502,6 +522,8 @@ pub struct EngageWeaponEnhancementData {
     pub def: i8,
     #[astra(key = "@Mdef")]
     pub mdef: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug)]
@@ -514,4 +536,6 @@ pub struct VersusRewardData {
     pub nums: Vec<i32>,
     #[astra(key = "@Conditions")]
     pub conditions: Vec<String>,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
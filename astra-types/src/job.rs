@@ -258,6 +258,8 @@ pub struct Job {
     pub lunatic_skill: String,
     #[astra(key = "@Attrs")]
     pub attrs: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug)]
@@ -272,4 +274,6 @@ pub struct FightingStyle {
     pub help: String,
     #[astra(key = "@Skills")]
     pub skills: Vec<String>,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
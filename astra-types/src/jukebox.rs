@@ -17,4 +17,6 @@ pub struct JukeboxData {
     pub name: String,
     #[astra(key = "@Condition")]
     pub condition: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -15,4 +15,6 @@ pub struct KeyHelpData {
     pub button_index: i8,
     #[astra(key = "@MID")]
     pub mid: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -18,6 +18,8 @@ pub struct KillBonus1 {
     pub rate: u8,
     #[astra(key = "@Cid")]
     pub cid: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -34,4 +36,6 @@ pub struct KillBonus2 {
     pub flag: i32,
     #[astra(key = "@Cid")]
     pub cid: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
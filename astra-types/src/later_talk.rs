@@ -21,4 +21,6 @@ pub struct PostBattleConversation {
     pub back_degree: i32,
     #[astra(key = "@LightDegree")]
     pub light_degree: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -1,3 +1,6 @@
+// Lets code generated by astra-derive refer to astra_types from inside this crate.
+extern crate self as astra_types;
+
 mod achieve;
 mod ai;
 mod amiibo;
@@ -5,6 +8,7 @@ mod anim_set;
 mod animal;
 mod arena;
 mod asset_table;
+mod attributes;
 mod calculator;
 mod chapter;
 mod chart;
@@ -39,6 +43,7 @@ mod range;
 mod relay;
 mod reliance;
 mod ring;
+mod schema;
 mod shop;
 mod skill;
 mod sound_event;
@@ -54,6 +59,7 @@ pub use anim_set::*;
 pub use animal::*;
 pub use arena::*;
 pub use asset_table::*;
+pub use attributes::*;
 pub use calculator::*;
 pub use chapter::*;
 pub use chart::*;
@@ -88,6 +94,7 @@ pub use range::*;
 pub use relay::*;
 pub use reliance::*;
 pub use ring::*;
+pub use schema::*;
 pub use shop::*;
 pub use skill::*;
 pub use sound_event::*;
@@ -18,6 +18,8 @@ pub struct MapEditorObject {
     pub sound_event: String,
     #[astra(key = "@Category")]
     pub category: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -30,4 +32,6 @@ pub struct MapEditorCategory {
     pub count_max: i32,
     #[astra(key = "@IconName")]
     pub icon_name: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -17,4 +17,6 @@ pub struct MapHistory {
     pub action: String,
     #[astra(key = "@Priority")]
     pub priority: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
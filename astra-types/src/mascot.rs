@@ -18,6 +18,8 @@ pub struct MascotAccessoryData {
     pub aid: String,
     #[astra(key = "@Type")]
     pub ty: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -30,6 +32,8 @@ pub struct MascotColorData {
     pub g: u8,
     #[astra(key = "@B")]
     pub b: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -40,6 +44,8 @@ pub struct MascotParamData {
     pub param_name: String,
     #[astra(key = "@Value")]
     pub value: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -50,4 +56,6 @@ pub struct MascotFoodData {
     pub iid: String,
     #[astra(key = "@Value")]
     pub value: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -37,4 +37,6 @@ pub struct Movie {
     pub mess_file_name: String,
     #[astra(key = "@DLCDirectoryName")]
     pub dlc_directory_name: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -31,6 +31,8 @@ pub struct MuscleExerciseDifficulty {
     pub target_score: i32,
     #[astra(key = "@EndlessGoalCount")]
     pub endless_goal_count: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -47,6 +49,8 @@ pub struct MuscleExerciseSetup {
     pub area_g_center: f32,
     #[astra(key = "@AreaG_Radius")]
     pub area_g_radius: f32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -109,6 +113,8 @@ pub struct MuscleExercisePrizeData {
     pub bond_f: i32,
     #[astra(key = "@Border_F")]
     pub border_f: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -123,6 +129,8 @@ pub struct MuscleSitUpFallData {
     pub gain_power: f32,
     #[astra(key = "@FallSpeed")]
     pub fall_speed: f32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -137,6 +145,8 @@ pub struct MusclePushUpSpeedData {
     pub speed_max: f32,
     #[astra(key = "@LevelUpCount")]
     pub level_up_count: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -155,6 +165,8 @@ pub struct MuscleSquatJudgeAreaData {
     pub good_frame_latter: f32,
     #[astra(key = "@BadFrameLatter")]
     pub bad_frame_latter: f32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -191,6 +203,8 @@ pub struct MuscleSquatScoreListData {
     pub type_5: String,
     #[astra(key = "@LotteryParam_5")]
     pub lottery_param_5: f32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -219,6 +233,8 @@ pub struct MuscleSquatMusicSheet {
     pub type_e_r: i8,
     #[astra(key = "@Ensure")]
     pub ensure: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -247,4 +263,6 @@ pub struct MuscleAssistData {
     pub level_09: i32,
     #[astra(key = "@Level_10")]
     pub level_10: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -27,4 +27,6 @@ pub struct MusicData {
     pub is_change: bool,
     #[astra(key = "@Gid")]
     pub gid: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
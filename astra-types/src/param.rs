@@ -1,4 +1,5 @@
 use astra_derive::{Astra, AstraBook};
+use astra_formats::indexmap::IndexMap;
 use astra_formats::Sheet;
 
 #[derive(AstraBook)]
@@ -24,4 +25,6 @@ pub struct GameParam {
     pub step: f32,
     #[astra(key = "@Enum")]
     pub en: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -215,4 +215,6 @@ pub struct Person {
     pub summon_rate: i32,
     #[astra(key = "@SummonGod")]
     pub summon_god: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
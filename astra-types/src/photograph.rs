@@ -26,6 +26,8 @@ pub struct PhotographSpot {
     pub pause_group_name_list_3: Vec<String>,
     #[astra(key = "@PauseGroupNameList4")]
     pub pause_group_name_list_4: Vec<String>,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -44,4 +46,6 @@ pub struct PhotographPose {
     pub face_anime: String,
     #[astra(key = "@CharaIdList")]
     pub chara_id_list: Vec<String>,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -30,6 +30,8 @@ pub struct ProfileCardImageComponent {
     pub condition: i8,
     #[astra(key = "@Arg")]
     pub arg: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -44,6 +46,8 @@ pub struct ProfileCardColorComponent {
     pub condition: i8,
     #[astra(key = "@Arg")]
     pub arg: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -58,6 +62,8 @@ pub struct ProfileCardNameComponent {
     pub condition: i8,
     #[astra(key = "@Arg")]
     pub arg: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -74,6 +80,8 @@ pub struct ProfileCardCategorizedComponent {
     pub condition: i8,
     #[astra(key = "@Arg")]
     pub arg: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -90,6 +98,8 @@ pub struct ProfileCardCategorizedImageComponent {
     pub condition: i8,
     #[astra(key = "@Arg")]
     pub arg: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -104,6 +114,8 @@ pub struct ProfileCardFavoriteMapData {
     pub condition: i8,
     #[astra(key = "@Arg")]
     pub arg: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -118,4 +130,6 @@ pub struct ProfileCardDefaultCommentData {
     pub id_2: String,
     #[astra(key = "@Id3")]
     pub id_3: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
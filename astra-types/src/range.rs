@@ -27,4 +27,6 @@ pub struct RangeData {
     pub value_7: i8,
     #[astra(key = "@Value8")]
     pub value_8: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -36,6 +36,8 @@ pub struct RelayData {
     pub game_over_award: String,
     #[astra(key = "@UnlockCid")]
     pub unlock_cid: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -56,6 +58,8 @@ pub struct RelayStampData {
     pub flag: i32,
     #[astra(key = "@Voice")]
     pub voice: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -74,6 +78,8 @@ pub struct RelayClearAwardData {
     pub flag: i32,
     #[astra(key = "@Condition")]
     pub condition: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -90,4 +96,6 @@ pub struct RelayAwardData {
     pub awards: Vec<String>,
     #[astra(key = "@Flag")]
     pub flag: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -99,6 +99,8 @@ pub struct RelianceData {
     pub exp_type_40: u8,
     #[astra(key = "@ExpType41")]
     pub exp_type_41: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug, Default, Clone)]
@@ -113,6 +115,8 @@ pub struct RelianceExpData {
     pub exp_b: u8,
     #[astra(key = "@ExpA")]
     pub exp_a: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Astra, Debug, Default, Clone)]
@@ -131,4 +135,6 @@ pub struct RelianceBonusData {
     pub avoid: i8,
     #[astra(key = "@Secure")]
     pub secure: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -61,6 +61,8 @@ pub struct RingData {
     pub rim_color_g: u8,
     #[astra(key = "@RimColorB")]
     pub rim_color_b: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(AstraBook)]
@@ -82,4 +84,6 @@ pub struct RingPolishVoiceData {
     pub unit_face_anim: String,
     #[astra(key = "@GodFaceAnim")]
     pub god_face_anim: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -0,0 +1,153 @@
+use crate::{
+    AchievementBook, AiBook, AmiiboBook, AnimSetBook, AnimalBook, ArenaBook, AssetTableBook,
+    CalculatorBook, ChapterBook, ChartBook, CookBook, DisposBook, DragonRidePresetParamDataBook,
+    DragonRidePrizeListBook, DragonRideTargetPatternBook, EffectBook, EncountBook, EndRollBook,
+    FishingFishBook, FriendListBook, GodBook, GroundAttributeBook, HubAreaBook, HubDemoBook,
+    HubDisposBook, HubFortuneTellingBook, HubInvestmentBook, HubMapIconBook, HubMyRoomBook,
+    HubResourceBook, HubTalkBook, ItemBook, JobBook, JukeboxBook, KeyHelpDataBook, KillBonusBook,
+    LaterTalkBook, MapEditorBook, MapHistoryBook, MascotBook, MovieBook, MuscleExerciseDataBook,
+    MusicBook, ParamsBook, PersonBook, PhotographSpotBook, ProfileCardBook, RangeBook, RelayBook,
+    RelianceBook, RingBook, RingCleaningVoiceBook, ShopBook, SkillBook, SoundEventBook,
+    TerrainBook, TitleBook, TutorialBook, VibrationBook,
+};
+
+/// Every book type in this crate.
+pub static BOOKS: &[&BookSchema] = &[
+    &AchievementBook::SCHEMA,
+    &AiBook::SCHEMA,
+    &AmiiboBook::SCHEMA,
+    &AnimSetBook::SCHEMA,
+    &AnimalBook::SCHEMA,
+    &ArenaBook::SCHEMA,
+    &AssetTableBook::SCHEMA,
+    &CalculatorBook::SCHEMA,
+    &ChapterBook::SCHEMA,
+    &ChartBook::SCHEMA,
+    &CookBook::SCHEMA,
+    &DisposBook::SCHEMA,
+    &DragonRidePresetParamDataBook::SCHEMA,
+    &DragonRidePrizeListBook::SCHEMA,
+    &DragonRideTargetPatternBook::SCHEMA,
+    &EffectBook::SCHEMA,
+    &EncountBook::SCHEMA,
+    &EndRollBook::SCHEMA,
+    &FishingFishBook::SCHEMA,
+    &FriendListBook::SCHEMA,
+    &GodBook::SCHEMA,
+    &GroundAttributeBook::SCHEMA,
+    &HubAreaBook::SCHEMA,
+    &HubDemoBook::SCHEMA,
+    &HubDisposBook::SCHEMA,
+    &HubFortuneTellingBook::SCHEMA,
+    &HubInvestmentBook::SCHEMA,
+    &HubMapIconBook::SCHEMA,
+    &HubMyRoomBook::SCHEMA,
+    &HubResourceBook::SCHEMA,
+    &HubTalkBook::SCHEMA,
+    &ItemBook::SCHEMA,
+    &JobBook::SCHEMA,
+    &JukeboxBook::SCHEMA,
+    &KeyHelpDataBook::SCHEMA,
+    &KillBonusBook::SCHEMA,
+    &LaterTalkBook::SCHEMA,
+    &MapEditorBook::SCHEMA,
+    &MapHistoryBook::SCHEMA,
+    &MascotBook::SCHEMA,
+    &MovieBook::SCHEMA,
+    &MuscleExerciseDataBook::SCHEMA,
+    &MusicBook::SCHEMA,
+    &ParamsBook::SCHEMA,
+    &PersonBook::SCHEMA,
+    &PhotographSpotBook::SCHEMA,
+    &ProfileCardBook::SCHEMA,
+    &RangeBook::SCHEMA,
+    &RelayBook::SCHEMA,
+    &RelianceBook::SCHEMA,
+    &RingBook::SCHEMA,
+    &RingCleaningVoiceBook::SCHEMA,
+    &ShopBook::SCHEMA,
+    &SkillBook::SCHEMA,
+    &SoundEventBook::SCHEMA,
+    &TerrainBook::SCHEMA,
+    &TitleBook::SCHEMA,
+    &TutorialBook::SCHEMA,
+    &VibrationBook::SCHEMA,
+];
+
+/// Look up a book schema by type name, ex. `PersonBook`.
+pub fn book_schema(name: &str) -> Option<&'static BookSchema> {
+    BOOKS.iter().copied().find(|book| book.name == name)
+}
+
+/// Static description of a row type. Implemented by the `Astra` derive.
+pub trait AstraSchema {
+    /// Name of the row type, ex. `Person`.
+    const NAME: &'static str;
+    const FIELDS: &'static [FieldSchema];
+
+    fn field_by_key(key: &str) -> Option<&'static FieldSchema> {
+        Self::FIELDS.iter().find(|field| field.key == key)
+    }
+
+    fn field_by_ident(ident: &str) -> Option<&'static FieldSchema> {
+        Self::FIELDS.iter().find(|field| field.ident == ident)
+    }
+}
+
+/// Static description of a book type. Implemented by the `AstraBook` derive.
+pub trait AstraBookSchema {
+    const SCHEMA: BookSchema;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldSchema {
+    /// Name of the field on the Rust struct.
+    pub ident: &'static str,
+    /// XML attribute the field is read from, ex. `@Pid`.
+    pub key: &'static str,
+    /// Rust type of the field as written in the struct, ex. `Option<u8>`.
+    pub ty: &'static str,
+    pub id: bool,
+    pub public_array: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SheetLayout {
+    /// `Sheet<Vec<T>>`
+    List,
+    /// `Sheet<IndexMap<String, T>>`, keyed by the `id` field.
+    Keyed,
+    /// `Sheet<IndexMap<String, Vec<T>>>`, grouped by the `public_array` field.
+    Grouped,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SheetSchema {
+    /// Name of the sheet field on the book struct.
+    pub ident: &'static str,
+    pub layout: SheetLayout,
+    pub row_type: &'static str,
+    pub fields: &'static [FieldSchema],
+}
+
+impl SheetSchema {
+    pub fn field_by_key(&self, key: &str) -> Option<&'static FieldSchema> {
+        self.fields.iter().find(|field| field.key == key)
+    }
+
+    pub fn field_by_ident(&self, ident: &str) -> Option<&'static FieldSchema> {
+        self.fields.iter().find(|field| field.ident == ident)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BookSchema {
+    pub name: &'static str,
+    pub sheets: &'static [SheetSchema],
+}
+
+impl BookSchema {
+    pub fn sheet(&self, ident: &str) -> Option<&'static SheetSchema> {
+        self.sheets.iter().find(|sheet| sheet.ident == ident)
+    }
+}
@@ -20,6 +20,8 @@ pub struct ShopInventory {
     pub stock: i16,
     #[astra(key = "@Attribute")]
     pub attribute: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -28,4 +30,6 @@ pub struct AccessoryShopInventory {
     pub condition: String,
     #[astra(key = "@Aid")]
     pub aid: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
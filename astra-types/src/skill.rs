@@ -209,4 +209,6 @@ pub struct Skill {
     pub inheritance_cost: u16,
     #[astra(key = "@InheritanceSort")]
     pub inheritance_sort: u16,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -21,4 +21,6 @@ pub struct SoundEvent {
     pub event_name_3: String,
     #[astra(key = "@EventName4")]
     pub event_name_4: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
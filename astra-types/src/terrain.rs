@@ -86,6 +86,8 @@ pub struct TerrainData {
     pub color_g: u8,
     #[astra(key = "@ColorB")]
     pub color_b: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -114,4 +116,6 @@ pub struct TerrainCostData {
     pub color_b: u8,
     #[astra(key = "@ColorA")]
     pub color_a: u8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -16,6 +16,8 @@ pub struct TitleCallData {
     pub pid_or_gid: String,
     #[astra(key = "@Cid")]
     pub cid: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -26,4 +28,6 @@ pub struct TitlePedestalData {
     pub pedestal_name: String,
     #[astra(key = "@Cid")]
     pub cid: String,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
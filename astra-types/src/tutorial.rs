@@ -28,6 +28,8 @@ pub struct TutorialData {
     pub no: i8,
     #[astra(key = "@SSType")]
     pub ss_type: i8,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
 
 #[derive(Debug, Default, Clone, Astra)]
@@ -48,4 +50,6 @@ pub struct TipData {
     pub variable: String,
     #[astra(key = "@Allow")]
     pub allow: i32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
@@ -17,4 +17,6 @@ pub struct VibrationDefineData {
     pub vibration_file_name: String,
     #[astra(key = "@AmplitudeMagnitude")]
     pub amplitude_magnitude: f32,
+    #[astra(extra)]
+    pub extra_attributes: IndexMap<String, String>,
 }
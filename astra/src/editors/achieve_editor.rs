@@ -7,7 +7,7 @@ use indexmap::IndexMap;
 use crate::{
     editor_tab_strip, id_field, keyed_add_modal_content, model_drop_down,
     msbt_key_value_singleline, sheet_retriever, standard_keyed_display, EditorState, KeyedViewItem,
-    ListEditorContent, PropertyGrid, ViewItem,
+    ListEditorContent, PropertyGrid, RowForm, ViewItem,
 };
 
 sheet_retriever!(Achievement, AchievementBook, achievements, IndexMap<String, AchieveData>);
@@ -112,44 +112,48 @@ impl AchieveEditor {
         match self.tab {
             Tab::Achievement => {
                 self.achieve_content.left_panel(ctx, &self.achieve, state);
-                self.achieve.write(|data| {
-                    self.achieve_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("achievement", selection)
-                            .new_section("")
-                            .field("AID", |ui, d| ui.add(id_field(&mut d.aid)))
-                            .field("Name", |ui, d| {
-                                msbt_key_value_singleline!(ui, state, "animal", d.name)
-                            })
-                            .default_field("Category", |d| &mut d.category)
-                            .default_field("Kind", |d| &mut d.kind)
-                            .default_field("Count", |d| &mut d.count)
-                            .default_field("Arg", |d| &mut d.arg)
-                            .default_field("Count Unit", |d| &mut d.count_unit)
-                            .default_field("Kizuna Reward", |d| &mut d.kizuna_reward)
-                            .field("Chapter", |ui, d| {
-                                state.chapter.read(|data| {
-                                    ui.add(model_drop_down(data, state, &mut d.chapter))
-                                })
-                            })
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.achieve_content.content(ctx, &self.achieve, state);
             }
             Tab::Belong => {
                 self.belong_content.left_panel(ctx, &self.belong, state);
-                self.belong.write(|data| {
-                    self.belong_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("achievement", selection)
-                            .new_section("")
-                            .field("BID", |ui, d| ui.add(id_field(&mut d.bid)))
-                            .default_field("Name", |d| &mut d.name)
-                            .default_field("Defeat Achieve", |d| &mut d.defeat_achieve)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.belong_content.content(ctx, &self.belong, state);
             }
         }
     }
 }
+
+impl RowForm for AchieveData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("achievement", selection)
+            .new_section("")
+            .field("AID", |ui, d| ui.add(id_field(&mut d.aid)))
+            .field("Name", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "animal", d.name)
+            })
+            .default_field("Category", |d| &mut d.category)
+            .default_field("Kind", |d| &mut d.kind)
+            .default_field("Count", |d| &mut d.count)
+            .default_field("Arg", |d| &mut d.arg)
+            .default_field("Count Unit", |d| &mut d.count_unit)
+            .default_field("Kizuna Reward", |d| &mut d.kizuna_reward)
+            .field("Chapter", |ui, d| {
+                state
+                    .chapter
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.chapter)))
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for BelongData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("achievement", selection)
+            .new_section("")
+            .field("BID", |ui, d| ui.add(id_field(&mut d.bid)))
+            .default_field("Name", |d| &mut d.name)
+            .default_field("Defeat Achieve", |d| &mut d.defeat_achieve)
+            .show(ui)
+            .changed()
+    }
+}
@@ -3,8 +3,11 @@ use std::borrow::Cow;
 use astra_types::{AiBook, AiData};
 use indexmap::IndexMap;
 
+use egui::Ui;
+
 use crate::{
-    sheet_retriever, EditorState, GroupEditorContent, GroupViewItem, PropertyGrid, ViewItem,
+    sheet_retriever, EditorState, GroupEditorContent, GroupViewItem, PropertyGrid, RowForm,
+    ViewItem,
 };
 
 sheet_retriever!(Ai, AiBook, ai_data, IndexMap<String, Vec<AiData>>);
@@ -45,19 +48,21 @@ impl AiEditor {
     pub fn show(&mut self, ctx: &egui::Context, state: &EditorState) {
         self.content.left_panel(ctx, &self.sheet, state);
 
-        self.sheet.write(|data| {
-            self.content.content(ctx, data, |ui, selection| {
-                PropertyGrid::new("ai", selection)
-                    .new_section("")
-                    .default_field("Active", |d| &mut d.active)
-                    .default_field("Code", |d| &mut d.code)
-                    .default_field("Mind", |d| &mut d.mind)
-                    .default_field("Str Value 0", |d| &mut d.str_value_0)
-                    .default_field("Str Value 1", |d| &mut d.str_value_1)
-                    .default_field("Trans", |d| &mut d.trans)
-                    .show(ui)
-                    .changed()
-            })
-        });
+        self.content.content(ctx, &self.sheet, state);
+    }
+}
+
+impl RowForm for AiData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("ai", selection)
+            .new_section("")
+            .default_field("Active", |d| &mut d.active)
+            .default_field("Code", |d| &mut d.code)
+            .default_field("Mind", |d| &mut d.mind)
+            .default_field("Str Value 0", |d| &mut d.str_value_0)
+            .default_field("Str Value 1", |d| &mut d.str_value_1)
+            .default_field("Trans", |d| &mut d.trans)
+            .show(ui)
+            .changed()
     }
 }
@@ -3,9 +3,11 @@ use std::borrow::Cow;
 use astra_types::{ArenaBook, ArenaData};
 use indexmap::IndexMap;
 
+use egui::Ui;
+
 use crate::{
     model_drop_down, sheet_retriever, EditorState, GroupEditorContent, GroupViewItem, PropertyGrid,
-    ViewItem,
+    RowForm, ViewItem,
 };
 
 sheet_retriever!(Arena, ArenaBook, arena_data, IndexMap<String, Vec<ArenaData>>);
@@ -49,20 +51,22 @@ impl ArenaEditor {
     pub fn show(&mut self, ctx: &egui::Context, state: &EditorState) {
         self.content.left_panel(ctx, &self.sheet, state);
 
-        self.sheet.write(|data| {
-            self.content.content(ctx, data, |ui, selection| {
-                PropertyGrid::new("arena", selection)
-                    .new_section("")
-                    .default_field("Rate", |d| &mut d.rate)
-                    .default_field("Pid", |d| &mut d.pid)
-                    .field("Item", |ui, d| {
-                        state
-                            .item
-                            .read(|data| ui.add(model_drop_down(data, state, &mut d.iid)))
-                    })
-                    .show(ui)
-                    .changed()
+        self.content.content(ctx, &self.sheet, state);
+    }
+}
+
+impl RowForm for ArenaData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("arena", selection)
+            .new_section("")
+            .default_field("Rate", |d| &mut d.rate)
+            .default_field("Pid", |d| &mut d.pid)
+            .field("Item", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.iid)))
             })
-        });
+            .show(ui)
+            .changed()
     }
 }
@@ -1,25 +1,32 @@
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc::{Receiver, TryRecvError};
 use std::sync::Arc;
 
+use anyhow::{bail, Result};
 use astra_core::{Astra, OpenTerrain};
 use astra_types::{Chapter, ChapterBook, Spawn, TerrainData};
 use egui::{Button, CentralPanel, ComboBox, DragValue, SidePanel, Slider, TopBottomPanel, Ui};
 use egui_extras::{Size, StripBuilder};
 use egui_modal::{Icon, Modal};
 use indexmap::IndexMap;
+use itertools::Itertools;
 use parking_lot::RwLock;
 
 use crate::widgets::{
-    bitgrid_i32, bitgrid_u16, chapter_encount_type, chapter_spot_state, force_drop_down, id_field,
-    keyed_add_modal_content, optional_u8_drag, TerrainBrush,
+    bitgrid_i32, bitgrid_u16, chapter_encount_type, chapter_spot_state, dispos_comparison,
+    force_drop_down, group_color, id_field, keyed_add_modal_content, optional_u8_drag,
+    DisposGridState, MapFileAction, MapFilesForm, OverlaySource, TerrainBrush, TerrainReplaceForm,
+    TerrainResize, TerrainResizeForm,
 };
 use crate::{
-    blank_slate, dispos_grid, editor_tab_strip, indexed_model_drop_down, model_drop_down,
-    msbt_key_value_singleline, terrain_grid, AppConfig, CacheItem, CachedView, ChapterSheet,
-    ChapterSheetRetriever, EditorState, GroupEditorContent, ListEditorContent, PropertyGrid,
-    SheetHandle, SpawnSheet, SpawnSheetRetriever,
+    blank_slate, dispos_grid, editor_tab_strip, indexed_model_drop_down, map_image, map_to_tmx,
+    model_drop_down, msbt_key_value_singleline, parse_tmx, pin_tab, replace_tiles, shift_spawns,
+    spawns_outside, terrain_grid, AppConfig, CacheItem, CachedView, ChapterFiles, ChapterSheet,
+    ChapterSheetRetriever, ChapterWizard, EditHistory, EditorState, Formation, GroupEditorContent,
+    HistoryTracker, ListEditorContent, PropertyGrid, RowForm, SheetHandle, SheetTab, SpawnSheet,
+    SpawnSheetRetriever, TerrainLayout, TerrainPattern,
 };
 
 const CHAPTER_FLAG_LABELS: &[&str] = &[
@@ -55,6 +62,35 @@ const SPAWN_FLAG_LABELS: &[&str] = &[
     "Guest",
 ];
 
+/// Describe how many of a group's spawns have paths and which spawn flags they use.
+fn group_conditions(spawns: &[Spawn]) -> String {
+    let paths = spawns
+        .iter()
+        .filter(|spawn| (spawn.appear_x, spawn.appear_y) != (spawn.dispos_x, spawn.dispos_y))
+        .count();
+    let flags = SPAWN_FLAG_LABELS
+        .iter()
+        .enumerate()
+        .filter_map(|(bit, label)| {
+            let count = spawns
+                .iter()
+                .filter(|spawn| spawn.flag & (1 << bit) != 0)
+                .count();
+            match count {
+                0 => None,
+                count if count == spawns.len() => Some(label.to_string()),
+                count => Some(format!("{} ({}/{})", label, count, spawns.len())),
+            }
+        })
+        .join(", ");
+    format!(
+        "{} spawn(s), {} with a path\nFlags: {}",
+        spawns.len(),
+        paths,
+        if flags.is_empty() { "None" } else { &flags }
+    )
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Tab {
     Core,
@@ -82,10 +118,97 @@ pub enum Difficulty {
     Lunatic,
 }
 
+/// Extra information drawn over the dispos grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisposOverlay {
+    None,
+    /// Where the selected spawn can move and attack.
+    SelectedRange,
+    /// How many enemies can attack each tile on the chosen difficulty.
+    EnemyThreat,
+    /// Arrows from where spawns appear to where they're placed, colored by group.
+    AppearPaths,
+}
+
+impl DisposOverlay {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DisposOverlay::None => "No Overlay",
+            DisposOverlay::SelectedRange => "Selected Unit Range",
+            DisposOverlay::EnemyThreat => "Enemy Threat",
+            DisposOverlay::AppearPaths => "Reinforcement Paths",
+        }
+    }
+}
+
+/// Chapter terrain with edits recorded in the undo history.
+/// Kept for the whole session so history entries and overlays always refer to the same tracker.
+#[derive(Clone)]
+struct TrackedTerrain {
+    terrain: OpenTerrain,
+    history: Arc<HistoryTracker>,
+    revision_number: Arc<AtomicUsize>,
+}
+
+impl TrackedTerrain {
+    pub fn new(terrain: OpenTerrain) -> Self {
+        let (capture, apply) = (terrain.clone(), terrain.clone());
+        let revision_number: Arc<AtomicUsize> = Default::default();
+        let apply_revision = revision_number.clone();
+        Self {
+            terrain,
+            history: HistoryTracker::new(
+                move || capture.read(|data| data.clone()),
+                move |value: &astra_formats::TerrainData| {
+                    apply.write(|data| {
+                        *data = value.clone();
+                        true
+                    });
+                    apply_revision.fetch_add(1, Ordering::Relaxed);
+                },
+                |value: &astra_formats::TerrainData| {
+                    value
+                        .terrains
+                        .iter()
+                        .map(|tid| std::mem::size_of_val(tid) + tid.as_str().len())
+                        .sum()
+                },
+            ),
+            revision_number,
+        }
+    }
+
+    /// Incremented whenever the terrain changes, including undo and redo.
+    pub fn revision_number(&self) -> usize {
+        self.revision_number.load(Ordering::Relaxed)
+    }
+
+    pub fn read<R>(&self, consumer: impl FnOnce(&astra_formats::TerrainData) -> R) -> R {
+        self.terrain.read(consumer)
+    }
+
+    pub fn write(&self, consumer: impl FnOnce(&mut astra_formats::TerrainData) -> bool) {
+        self.history.track(|| {
+            let mut changed = false;
+            self.terrain.write(|data| {
+                changed = consumer(data);
+                changed
+            });
+            if changed {
+                self.revision_number.fetch_add(1, Ordering::Relaxed);
+            }
+            changed
+        });
+    }
+}
+
 struct OpenChapterState {
     dispos: Option<SpawnSheet>,
     encount_dispos: Option<SpawnSheet>,
-    terrain: Option<OpenTerrain>,
+    terrain: Option<TrackedTerrain>,
+    dispos_name: String,
+    encount_dispos_name: String,
+    terrain_name: String,
     script: String,
     encount_script: String,
     kizuna_script: String,
@@ -96,19 +219,26 @@ impl OpenChapterState {
         chapter: &Chapter,
         astra: &mut Astra,
         spawn_cache: &mut HashMap<String, SpawnSheet>,
+        terrain_cache: &mut HashMap<String, TrackedTerrain>,
     ) -> Self {
-        let cid_part = chapter.cid.trim_start_matches("CID_");
-        let dispos_stem = chapter.dispos.replace('*', cid_part).to_lowercase();
-        let encount_stem = format!("{}e", dispos_stem);
-        let terrain =
-            astra.get_chapter_terrain(&chapter.terrain.replace('*', cid_part).to_lowercase());
+        let files = ChapterFiles::of(chapter);
         Self {
-            dispos: load_dispos_sheet(spawn_cache, astra, dispos_stem),
-            encount_dispos: load_dispos_sheet(spawn_cache, astra, encount_stem),
-            terrain,
-            script: chapter.script_bmap.replace('*', cid_part).to_lowercase(),
-            encount_script: chapter.script_encount.replace('*', cid_part).to_lowercase(),
-            kizuna_script: chapter.script_kizuna.replace('*', cid_part).to_lowercase(),
+            dispos: load_dispos_sheet(spawn_cache, astra, files.dispos.clone()),
+            encount_dispos: load_dispos_sheet(spawn_cache, astra, files.encount_dispos.clone()),
+            terrain: load_terrain(terrain_cache, astra, files.terrain.clone()),
+            dispos_name: files.dispos,
+            encount_dispos_name: files.encount_dispos,
+            terrain_name: files.terrain,
+            script: files.script,
+            encount_script: files.encount_script,
+            kizuna_script: files.kizuna_script,
+        }
+    }
+
+    fn dispos_name(&self, kind: DisposKind) -> &str {
+        match kind {
+            DisposKind::Main => &self.dispos_name,
+            DisposKind::Encount => &self.encount_dispos_name,
         }
     }
 }
@@ -129,6 +259,39 @@ fn load_dispos_sheet(
     }
 }
 
+fn load_terrain(
+    cache: &mut HashMap<String, TrackedTerrain>,
+    astra: &mut Astra,
+    key: String,
+) -> Option<TrackedTerrain> {
+    if let Entry::Vacant(e) = cache.entry(key.clone()) {
+        let terrain = TrackedTerrain::new(astra.get_chapter_terrain(&key)?);
+        e.insert(terrain.clone());
+        Some(terrain)
+    } else {
+        cache.get(&key).cloned()
+    }
+}
+
+/// Placeholder for a chapter file that couldn't be found, with a way to create it.
+fn missing_file_message(
+    ui: &mut Ui,
+    message: &str,
+    wizard: &mut ChapterWizard,
+    chapter_index: Option<usize>,
+) {
+    ui.vertical_centered(|ui| {
+        ui.add_space(ui.available_height() / 2. - 30.);
+        ui.heading(message);
+        if let Some(index) = chapter_index {
+            if ui.button("Create from Another Chapter…").clicked() {
+                let modal = Modal::new(ui.ctx(), "chapter_wizard_modal");
+                wizard.open_missing_files(&modal, index);
+            }
+        }
+    });
+}
+
 #[derive(Default)]
 enum ChapterLoader {
     #[default]
@@ -139,14 +302,21 @@ enum ChapterLoader {
 }
 
 impl ChapterLoader {
-    pub fn load(&mut self, state: &mut EditorState, chapter_index: Option<usize>) {
+    pub fn load(
+        &mut self,
+        state: &mut EditorState,
+        terrain_cache: &Arc<RwLock<HashMap<String, TrackedTerrain>>>,
+        chapter_index: Option<usize>,
+    ) {
         *self = match chapter_index {
             Some(chapter_index) => {
                 let (sender, receiver) = std::sync::mpsc::channel();
                 let spawn_cache = state.spawns.clone();
+                let terrain_cache = terrain_cache.clone();
                 let astra = state.astra.clone();
                 std::thread::spawn(move || {
                     let mut spawn_cache = spawn_cache.write();
+                    let mut terrain_cache = terrain_cache.write();
                     let mut astra = astra.write();
                     let chapter = astra.get_chapter_book();
                     sender.send(chapter.read(|data| {
@@ -154,7 +324,12 @@ impl ChapterLoader {
                             .data
                             .get_index(chapter_index)
                             .map(|(_, chapter)| {
-                                OpenChapterState::load(chapter, &mut astra, &mut spawn_cache)
+                                OpenChapterState::load(
+                                    chapter,
+                                    &mut astra,
+                                    &mut spawn_cache,
+                                    &mut terrain_cache,
+                                )
                             })
                     }))
                 });
@@ -187,11 +362,23 @@ pub struct ChapterEditor {
     dispos_kind: DisposKind,
     coordinate_kind: CoordinateKind,
     dispos_difficulty: Difficulty,
+    dispos_overlay: DisposOverlay,
+    compare_difficulties: bool,
     hovered_tile: Option<String>,
     hovered_spawn: Option<String>,
+    hovered_threat: Option<String>,
+    dispos_grid_state: DisposGridState,
+    /// Spawns copied from the dispos grid. Kept when switching chapters so they can be pasted into another one.
+    formation: Option<Formation>,
     script_open_error: Option<String>,
     selected_chapter_index: Option<usize>,
+    requires_load: bool,
     terrain_brush: TerrainBrush,
+    terrain_pattern: Option<TerrainPattern>,
+    replace_form: TerrainReplaceForm,
+    wizard: ChapterWizard,
+    resize_form: TerrainResizeForm,
+    map_files_form: MapFilesForm,
 
     terrain_content: ListEditorContent<IndexMap<String, TerrainData>, TerrainData, EditorState>,
     dispos_content: GroupEditorContent,
@@ -200,6 +387,7 @@ pub struct ChapterEditor {
     chapter: ChapterSheet,
     cache: CachedView<ChapterSheetRetriever, ChapterBook, Chapter>,
     loader: ChapterLoader,
+    terrain_cache: Arc<RwLock<HashMap<String, TrackedTerrain>>>,
 }
 
 impl ChapterEditor {
@@ -209,11 +397,22 @@ impl ChapterEditor {
             dispos_kind: DisposKind::Main,
             coordinate_kind: CoordinateKind::Dispos,
             dispos_difficulty: Difficulty::All,
+            dispos_overlay: DisposOverlay::None,
+            compare_difficulties: false,
             hovered_tile: None,
             hovered_spawn: None,
+            hovered_threat: None,
+            dispos_grid_state: Default::default(),
+            formation: None,
             script_open_error: None,
             selected_chapter_index: None,
+            requires_load: false,
             terrain_brush: Default::default(),
+            terrain_pattern: None,
+            replace_form: TerrainReplaceForm::new(),
+            wizard: ChapterWizard::new(),
+            resize_form: TerrainResizeForm::new(),
+            map_files_form: MapFilesForm::new(),
 
             terrain_content: ListEditorContent::new("chapter_terrain_list_editor")
                 .with_add_modal_content(keyed_add_modal_content),
@@ -223,14 +422,41 @@ impl ChapterEditor {
             chapter: state.chapter.clone(),
             cache: CachedView::new(state.chapter.clone(), state),
             loader: Default::default(),
+            terrain_cache: Default::default(),
         }
     }
 
     pub fn select(&mut self, index: Option<usize>) {
-        self.selected_chapter_index = index;
+        if index != self.selected_chapter_index {
+            self.selected_chapter_index = index;
+            self.requires_load = true;
+        }
+    }
+
+    pub fn selection(&self) -> Option<usize> {
+        self.selected_chapter_index
+    }
+
+    /// The group and index of the selected spawn, if the dispos tab is open.
+    pub fn selected_spawn(&self) -> Option<(String, usize)> {
+        match self.tab {
+            Tab::Dispos => self.dispos_content.selection().cloned(),
+            _ => None,
+        }
+    }
+
+    /// Open the dispos tab with a spawn selected.
+    pub fn select_spawn(&mut self, spawn: Option<(String, usize)>) {
+        self.tab = Tab::Dispos;
+        *self.dispos_content.selection_mut() = spawn;
     }
 
     pub fn show(&mut self, ctx: &egui::Context, state: &mut EditorState, config: &mut AppConfig) {
+        // Selections made from outside the editor (ex. navigation) haven't been loaded yet.
+        if std::mem::take(&mut self.requires_load) {
+            self.loader
+                .load(state, &self.terrain_cache, self.selected_chapter_index);
+        }
         self.loader.update();
 
         if self.loader.is_loading() {
@@ -268,12 +494,27 @@ impl ChapterEditor {
                                 false
                             }
                         });
-                        self.loader.load(state, self.selected_chapter_index);
+                        self.loader
+                            .load(state, &self.terrain_cache, self.selected_chapter_index);
                     }
                 });
             });
         }
 
+        let wizard_modal = Modal::new(ctx, "chapter_wizard_modal");
+        let mut created = None;
+        wizard_modal.show(|ui| {
+            created = self
+                .wizard
+                .show(&wizard_modal, ui, &self.chapter, state, &self.astra);
+        });
+        if let Some(index) = created {
+            self.selected_chapter_index = Some(index);
+            self.loader
+                .load(state, &self.terrain_cache, self.selected_chapter_index);
+            return;
+        }
+
         if !matches!(self.loader, ChapterLoader::Loaded(_)) {
             CentralPanel::default().show(ctx, |ui| {
                 blank_slate(ui);
@@ -281,6 +522,18 @@ impl ChapterEditor {
             return;
         }
 
+        let map_files_modal = Modal::new(ctx, "chapter_map_files_modal");
+        let mut map_file_action = None;
+        map_files_modal.show(|ui| {
+            map_file_action = self.map_files_form.show(&map_files_modal, ui);
+        });
+        if let Some(action) = map_file_action {
+            let status = self
+                .run_map_file_action(action, state, config)
+                .map_err(|err| format!("{:#}", err));
+            self.map_files_form.set_status(status);
+        }
+
         self.cache.refresh(state);
 
         match self.tab {
@@ -303,6 +556,14 @@ impl ChapterEditor {
                 let modal = Modal::new(ui.ctx(), "chapter_delete_confirm_modal");
                 modal.open();
             }
+            if ui
+                .add(Button::new("+").min_size([30., 0.].into()))
+                .on_hover_text("New chapter from an existing one")
+                .clicked()
+            {
+                let modal = Modal::new(ui.ctx(), "chapter_wizard_modal");
+                self.wizard.open_new(&modal, self.selected_chapter_index);
+            }
             self.chapter.read(|data| {
                 if ui
                     .add(indexed_model_drop_down(
@@ -312,7 +573,8 @@ impl ChapterEditor {
                     ))
                     .changed()
                 {
-                    self.loader.load(state, self.selected_chapter_index);
+                    self.loader
+                        .load(state, &self.terrain_cache, self.selected_chapter_index);
                 }
             });
             ui.selectable_value(&mut self.tab, Tab::Core, "Core");
@@ -365,6 +627,50 @@ impl ChapterEditor {
                         "Lunatic",
                     );
                 });
+            let dispos = match &self.loader {
+                ChapterLoader::Loaded(Some(state)) => match self.dispos_kind {
+                    DisposKind::Main => state.dispos.as_ref(),
+                    DisposKind::Encount => state.encount_dispos.as_ref(),
+                },
+                _ => None,
+            };
+            if ui
+                .add_enabled(dispos.is_some(), Button::new("📌 Pin Dispos"))
+                .on_hover_text("Show this chapter's dispos in the split view")
+                .clicked()
+            {
+                if let Some(dispos) = dispos {
+                    let cid = self.chapter.read(|data| {
+                        self.selected_chapter_index
+                            .and_then(|index| data.get_index(index))
+                            .map(|(key, _)| key.clone())
+                            .unwrap_or_default()
+                    });
+                    let title = match self.dispos_kind {
+                        DisposKind::Main => format!("{} Dispos", cid),
+                        DisposKind::Encount => format!("{} Encount Dispos", cid),
+                    };
+                    pin_tab(SheetTab::<_, _, _, Spawn>::new(title, dispos.clone()));
+                }
+            }
+            let has_terrain = matches!(
+                &self.loader,
+                ChapterLoader::Loaded(Some(state)) if state.terrain.is_some()
+            );
+            if ui
+                .add_enabled(has_terrain, Button::new("🗺 Map Files…"))
+                .on_hover_text("Export the map as an image or Tiled map, or import a Tiled map")
+                .clicked()
+            {
+                let file_stem = self.chapter.read(|data| {
+                    self.selected_chapter_index
+                        .and_then(|index| data.get_index(index))
+                        .map(|(_, chapter)| ChapterFiles::of(chapter).dispos)
+                        .unwrap_or_default()
+                });
+                let modal = Modal::new(ui.ctx(), "chapter_map_files_modal");
+                self.map_files_form.open(&modal, &file_stem);
+            }
         });
     }
 
@@ -607,47 +913,113 @@ impl ChapterEditor {
                 ui.horizontal_top(|ui| {
                     ui.label("Tile Brightness");
                     ui.add(Slider::new(&mut config.terrain_brightness, 0.0..=1.0));
+                    ui.checkbox(&mut self.compare_difficulties, "Compare Difficulties")
+                        .on_hover_text("Show Normal, Hard and Lunatic side by side");
+                    ComboBox::from_id_source("dispos_overlay")
+                        .selected_text(self.dispos_overlay.label())
+                        .show_ui(ui, |ui| {
+                            for overlay in [
+                                DisposOverlay::None,
+                                DisposOverlay::SelectedRange,
+                                DisposOverlay::EnemyThreat,
+                                DisposOverlay::AppearPaths,
+                            ] {
+                                ui.selectable_value(
+                                    &mut self.dispos_overlay,
+                                    overlay,
+                                    overlay.label(),
+                                );
+                            }
+                        })
+                        .response
+                        .on_hover_text(
+                            "Movement and attack ranges ignore other units, skills and terrain effects.",
+                        );
+                    Self::formation_buttons(
+                        ui,
+                        dispos,
+                        self.dispos_content.selection_mut(),
+                        &mut self.dispos_grid_state,
+                        &mut self.formation,
+                    );
                     if let Some(tile) = self.hovered_tile.as_deref() {
                         ui.label(format!("Tile: {}", tile));
                     }
                     if let Some(spawn) = self.hovered_spawn.as_deref() {
                         ui.label(format!("Spawn: {}", spawn));
                     }
+                    if let Some(threat) = self.hovered_threat.as_deref() {
+                        ui.label(format!("Threat: {}", threat));
+                    }
                 });
+                if self.dispos_overlay == DisposOverlay::AppearPaths {
+                    Self::group_legend(ui, dispos);
+                }
             });
 
             self.dispos_content.left_panel(ctx, dispos, state);
 
+            self.dispos_content.right_panel(ctx, dispos, state);
+
+            let dispos_revision = dispos.revision_number();
             dispos.write(|data| {
-                let mut changed = self.dispos_content.right_panel(ctx, data, |ui, spawn| {
-                    Self::spawn_property_grid(ui, spawn, state)
-                });
+                let mut changed = false;
 
                 CentralPanel::default().show(ctx, |ui| {
                     let terrain = match &self.loader {
-                        ChapterLoader::Loaded(Some(state)) => state.terrain.as_ref(),
+                        ChapterLoader::Loaded(Some(chapter)) => {
+                            chapter.terrain.as_ref().map(|terrain| (chapter, terrain))
+                        }
                         _ => None,
                     };
-                    if let Some(chapter_terrain) = terrain {
+                    if let Some((chapter, chapter_terrain)) = terrain {
                         chapter_terrain.read(|terrain_data| {
+                            if self.compare_difficulties {
+                                let result = dispos_comparison(
+                                    ui,
+                                    terrain_data,
+                                    state,
+                                    data,
+                                    self.dispos_content.selection_mut(),
+                                    self.coordinate_kind,
+                                    config,
+                                );
+                                self.hovered_tile = None;
+                                self.hovered_spawn = result.hovered_spawn;
+                                self.hovered_threat = None;
+                                return;
+                            }
+                            let source = OverlaySource {
+                                dispos: chapter.dispos_name(self.dispos_kind).to_string(),
+                                dispos_revision,
+                                terrain: chapter.terrain_name.clone(),
+                                terrain_revision: chapter_terrain.revision_number(),
+                            };
                             let result = dispos_grid(
                                 ui,
                                 terrain_data,
                                 state,
                                 data,
+                                source,
                                 self.dispos_content.selection_mut(),
+                                &mut self.dispos_grid_state,
                                 self.coordinate_kind,
                                 self.dispos_difficulty,
+                                self.dispos_overlay,
                                 config,
                             );
                             changed |= result.changed;
                             self.hovered_tile = result.hovered_tile;
                             self.hovered_spawn = result.hovered_spawn;
+                            self.hovered_threat = result.hovered_threat;
                         });
                     } else {
-                        ui.centered_and_justified(|ui| {
-                            ui.heading("Terrain not found.");
-                        });
+                        missing_file_message(
+                            ui,
+                            "Terrain not found.",
+                            &mut self.wizard,
+                            self.selected_chapter_index,
+                        );
                     }
                 });
 
@@ -655,14 +1027,356 @@ impl ChapterEditor {
             });
         } else {
             CentralPanel::default().show(ctx, |ui| {
-                ui.centered_and_justified(|ui| {
-                    ui.heading("Dispos not found.");
+                missing_file_message(
+                    ui,
+                    "Dispos not found.",
+                    &mut self.wizard,
+                    self.selected_chapter_index,
+                );
+            });
+        }
+    }
+
+    fn group_legend(ui: &mut Ui, dispos: &SpawnSheet) {
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Groups:")
+                .on_hover_text("Drag either end of a path to move it. Turns and other triggers are set in the chapter's script.");
+            dispos.read(|data| {
+                for (index, (group, spawns)) in data.iter().enumerate() {
+                    ui.colored_label(group_color(index), format!("⏺ {}", group))
+                        .on_hover_text(group_conditions(spawns));
+                }
+            });
+        });
+    }
+
+    fn formation_buttons(
+        ui: &mut Ui,
+        dispos: &SpawnSheet,
+        selected_spawn: &Option<(String, usize)>,
+        grid_state: &mut DisposGridState,
+        formation: &mut Option<Formation>,
+    ) {
+        let selection = grid_state.selected(selected_spawn);
+        if ui
+            .add_enabled(!selection.is_empty(), Button::new("Copy Formation"))
+            .on_hover_text(
+                "Right click spawns to select them, right drag or shift + right click to select several.\n\
+                 Drag or use the arrow keys to move the selection.",
+            )
+            .clicked()
+        {
+            *formation = Some(dispos.read(|data| Formation::copy(data, &selection)));
+        }
+        if let Some(pending) = &grid_state.pending_paste {
+            ui.label(format!(
+                "Click a tile to place {} spawn(s). Esc cancels.",
+                pending.len()
+            ));
+        } else if ui
+            .add_enabled(
+                formation.as_ref().is_some_and(|f| !f.is_empty()),
+                Button::new("Paste Formation"),
+            )
+            .clicked()
+        {
+            grid_state.pending_paste = formation.clone();
+        }
+    }
+
+    fn terrain_tab_content(
+        &mut self,
+        ctx: &egui::Context,
+        state: &EditorState,
+        config: &mut AppConfig,
+    ) {
+        let terrain = match &self.loader {
+            ChapterLoader::Loaded(Some(state)) => state.terrain.as_ref(),
+            _ => None,
+        };
+        if let Some(chapter_terrain) = terrain {
+            let resize_modal = Modal::new(ctx, "terrain_resize_modal");
+            let mut resize = None;
+            resize_modal.show(|ui| {
+                resize = self.resize_form.show(&resize_modal, ui, state);
+            });
+            let replace_modal = Modal::new(ctx, "terrain_replace_modal");
+            let mut replace = None;
+            replace_modal.show(|ui| {
+                replace = self.replace_form.show(&replace_modal, ui, state);
+            });
+            if let Some((from, to)) = replace {
+                let mut result = Ok(0);
+                chapter_terrain.write(|data| {
+                    result = replace_tiles(data, &from, &to);
+                    matches!(result, Ok(count) if count > 0)
+                });
+                self.replace_form.set_status(
+                    result
+                        .map(|count| format!("Replaced {} tile(s).", count))
+                        .map_err(|err| err.to_string()),
+                );
+            }
+
+            SidePanel::right("terrain_right_panel").show(ctx, |ui| {
+                StripBuilder::new(ui)
+                    .size(Size::exact(200.))
+                    .size(Size::exact(5.))
+                    .size(Size::exact(20.))
+                    .horizontal(|mut strip| {
+                        strip.cell(|ui| {
+                            Self::terrain_top_level_form(chapter_terrain, ui);
+                            if ui.button("Resize…").clicked() {
+                                chapter_terrain
+                                    .read(|data| self.resize_form.open(&resize_modal, data));
+                            }
+                            if ui.button("Replace Tiles…").clicked() {
+                                let from = state.terrain.read(|data| {
+                                    self.terrain_content
+                                        .selection()
+                                        .and_then(|index| data.get_index(index))
+                                        .map(|(tid, _)| tid.clone())
+                                });
+                                self.replace_form.open(&replace_modal, from);
+                            }
+                        });
+                        strip.cell(|ui| {
+                            ui.horizontal_centered(|ui| ui.separator());
+                        });
+                        strip.cell(|ui| {
+                            for brush in [
+                                TerrainBrush::Stamp,
+                                TerrainBrush::Fill,
+                                TerrainBrush::Box,
+                                TerrainBrush::Line,
+                                TerrainBrush::Eyedropper,
+                                TerrainBrush::Pattern,
+                            ] {
+                                ui.selectable_value(&mut self.terrain_brush, brush, brush.icon())
+                                    .on_hover_text(brush.description());
+                            }
+                        });
+                    });
+            });
+
+            TopBottomPanel::bottom("dispos_bottom_panel").show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Tile Brightness");
+                    ui.add(Slider::new(&mut config.terrain_brightness, 0.0..=1.0));
+                    if let TerrainBrush::Pattern = self.terrain_brush {
+                        match &self.terrain_pattern {
+                            Some(pattern) => {
+                                ui.label(format!("Pattern: {}x{}", pattern.width, pattern.height))
+                            }
+                            None => ui.label("Drag to copy a pattern."),
+                        };
+                    }
+                    if let Some(tile) = self.hovered_tile.as_deref() {
+                        ui.label(format!("Tile: {}", tile));
+                    }
                 });
             });
+
+            self.terrain_content.left_panel(ctx, &state.terrain, state);
+
+            CentralPanel::default().show(ctx, |ui| {
+                chapter_terrain.write(|terrain_data| {
+                    let result = terrain_grid(
+                        ui,
+                        terrain_data,
+                        self.terrain_content.selection(),
+                        state,
+                        config,
+                        self.terrain_brush,
+                        &mut self.terrain_pattern,
+                    );
+                    self.hovered_tile = result.hovered_tile;
+                    if let Some(selection) = result.selected_tile {
+                        let tile_index = state.terrain.read(|data| data.get_index_of(&selection));
+                        if let Some(index) = tile_index {
+                            self.terrain_content.select(Some(index));
+                        }
+                    }
+                    result.changed
+                });
+            });
+
+            if let Some(resize) = resize {
+                self.resize_terrain(resize);
+            }
+        } else {
+            CentralPanel::default().show(ctx, |ui| {
+                missing_file_message(
+                    ui,
+                    "Terrain not found.",
+                    &mut self.wizard,
+                    self.selected_chapter_index,
+                );
+            });
         }
     }
 
-    fn spawn_property_grid(ui: &mut Ui, spawn: &mut Spawn, state: &EditorState) -> bool {
+    fn terrain_top_level_form(chapter_terrain: &TrackedTerrain, ui: &mut Ui) {
+        chapter_terrain.write(|data| {
+            PropertyGrid::new("terrain_top_level_property_grid", data)
+                .new_section("")
+                .default_field("X", |d| &mut d.x)
+                .default_field("Z", |d| &mut d.z)
+                .default_field("Playable Width", |d| &mut d.width)
+                .default_field("Playable Height", |d| &mut d.height)
+                .show(ui)
+                .changed()
+        });
+    }
+
+    fn resize_terrain(&mut self, resize: TerrainResize) {
+        let ChapterLoader::Loaded(Some(chapter)) = &self.loader else {
+            return;
+        };
+        let Some(terrain) = &chapter.terrain else {
+            return;
+        };
+        // The terrain and the spawns moved with it are undone together.
+        let status = EditHistory::group(|| {
+            let mut result = Ok(());
+            terrain.write(|data| {
+                result = TerrainLayout::of(data).and_then(|layout| {
+                    layout.resize(
+                        data,
+                        resize.width,
+                        resize.height,
+                        resize.shift,
+                        &resize.fill,
+                    )
+                });
+                result.is_ok()
+            });
+            result.map_err(|err| err.to_string()).map(|_| {
+                let mut outside = 0;
+                for dispos in [&chapter.dispos, &chapter.encount_dispos]
+                    .into_iter()
+                    .flatten()
+                {
+                    if resize.move_spawns && resize.shift != (0, 0) {
+                        dispos.write(|data| {
+                            shift_spawns(data, resize.shift);
+                            true
+                        });
+                    }
+                    outside +=
+                        dispos.read(|data| spawns_outside(data, resize.width, resize.height));
+                }
+                if outside > 0 {
+                    format!(
+                        "Resized. {} spawn(s) are outside the playable area.",
+                        outside
+                    )
+                } else {
+                    "Resized.".to_string()
+                }
+            })
+        });
+        self.resize_form.set_status(status);
+    }
+
+    /// Export or import the map files picked in the map files form.
+    /// Spawns come from and go to the dispos currently shown (main or encount).
+    fn run_map_file_action(
+        &mut self,
+        action: MapFileAction,
+        state: &EditorState,
+        config: &AppConfig,
+    ) -> Result<String> {
+        let ChapterLoader::Loaded(Some(chapter)) = &self.loader else {
+            bail!("no chapter is open");
+        };
+        let Some(terrain) = &chapter.terrain else {
+            bail!("the chapter has no terrain");
+        };
+        let dispos = match self.dispos_kind {
+            DisposKind::Main => chapter.dispos.as_ref(),
+            DisposKind::Encount => chapter.encount_dispos.as_ref(),
+        };
+        match action {
+            MapFileAction::ExportPng(path) => {
+                let image = terrain.read(|terrain| match dispos {
+                    Some(dispos) => {
+                        dispos.read(|spawns| map_image(terrain, Some(spawns), state, config))
+                    }
+                    None => map_image(terrain, None, state, config),
+                })?;
+                image.save(&path)?;
+                Ok(format!("Saved {}.", path.display()))
+            }
+            MapFileAction::ExportTmx(path) => {
+                let stem = path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                    .unwrap_or_else(|| "map".to_string());
+                let tileset_file_name = format!("{}_tiles.png", stem);
+                let export = terrain.read(|terrain| match dispos {
+                    Some(dispos) => dispos.read(|spawns| {
+                        map_to_tmx(terrain, Some(spawns), state, config, &tileset_file_name)
+                    }),
+                    None => map_to_tmx(terrain, None, state, config, &tileset_file_name),
+                })?;
+                std::fs::write(&path, export.xml)?;
+                export
+                    .tileset
+                    .save(path.with_file_name(&tileset_file_name))?;
+                Ok(format!(
+                    "Saved {} and {}.",
+                    path.display(),
+                    tileset_file_name
+                ))
+            }
+            MapFileAction::ImportTmx {
+                path,
+                terrain: import_terrain,
+                spawns: import_spawns,
+            } => {
+                let map = parse_tmx(&std::fs::read_to_string(&path)?)?;
+                // Check the spawns first so a failed import doesn't leave half the map applied.
+                let spawns = if import_spawns {
+                    let Some(spawns) = map.spawns.clone() else {
+                        bail!("the map has no spawn object layer");
+                    };
+                    let Some(dispos) = dispos else {
+                        bail!("the chapter has no dispos to import spawns into");
+                    };
+                    Some((dispos, spawns))
+                } else {
+                    None
+                };
+                let mut imported = vec![];
+                if import_terrain {
+                    let mut result = Ok(());
+                    terrain.write(|data| {
+                        result = map.apply_terrain(data);
+                        result.is_ok()
+                    });
+                    result?;
+                    imported.push("terrain");
+                }
+                if let Some((dispos, spawns)) = spawns {
+                    dispos.write(|data| {
+                        *data = spawns;
+                        true
+                    });
+                    imported.push("spawns");
+                }
+                Ok(format!(
+                    "Imported {} from {}.",
+                    imported.join(" and "),
+                    path.display()
+                ))
+            }
+        }
+    }
+}
+
+impl RowForm for Spawn {
+    fn form(ui: &mut Ui, state: &EditorState, spawn: &mut Self) -> bool {
         PropertyGrid::new("spawn", spawn)
             .horizontal_scroll()
             .new_section("Core")
@@ -839,87 +1553,4 @@ impl ChapterEditor {
             .show(ui)
             .changed()
     }
-
-    fn terrain_tab_content(
-        &mut self,
-        ctx: &egui::Context,
-        state: &EditorState,
-        config: &mut AppConfig,
-    ) {
-        let terrain = match &self.loader {
-            ChapterLoader::Loaded(Some(state)) => state.terrain.as_ref(),
-            _ => None,
-        };
-        if let Some(chapter_terrain) = terrain {
-            SidePanel::right("terrain_right_panel").show(ctx, |ui| {
-                StripBuilder::new(ui)
-                    .size(Size::exact(200.))
-                    .size(Size::exact(5.))
-                    .size(Size::exact(20.))
-                    .horizontal(|mut strip| {
-                        strip.cell(|ui| Self::terrain_top_level_form(chapter_terrain, ui));
-                        strip.cell(|ui| {
-                            ui.horizontal_centered(|ui| ui.separator());
-                        });
-                        strip.cell(|ui| {
-                            ui.selectable_value(&mut self.terrain_brush, TerrainBrush::Stamp, "🖊");
-                            ui.selectable_value(&mut self.terrain_brush, TerrainBrush::Fill, "💧");
-                            ui.selectable_value(&mut self.terrain_brush, TerrainBrush::Box, "⬜");
-                        });
-                    });
-            });
-
-            TopBottomPanel::bottom("dispos_bottom_panel").show(ctx, |ui| {
-                ui.horizontal(|ui| {
-                    ui.label("Tile Brightness");
-                    ui.add(Slider::new(&mut config.terrain_brightness, 0.0..=1.0));
-                    if let Some(tile) = self.hovered_tile.as_deref() {
-                        ui.label(format!("Tile: {}", tile));
-                    }
-                });
-            });
-
-            self.terrain_content.left_panel(ctx, &state.terrain, state);
-
-            CentralPanel::default().show(ctx, |ui| {
-                chapter_terrain.write(|terrain_data| {
-                    let result = terrain_grid(
-                        ui,
-                        terrain_data,
-                        self.terrain_content.selection(),
-                        state,
-                        config,
-                        self.terrain_brush,
-                    );
-                    self.hovered_tile = result.hovered_tile;
-                    if let Some(selection) = result.selected_tile {
-                        let tile_index = state.terrain.read(|data| data.get_index_of(&selection));
-                        if let Some(index) = tile_index {
-                            self.terrain_content.select(Some(index));
-                        }
-                    }
-                    result.changed
-                });
-            });
-        } else {
-            CentralPanel::default().show(ctx, |ui| {
-                ui.centered_and_justified(|ui| {
-                    ui.heading("Terrain not found.");
-                });
-            });
-        }
-    }
-
-    fn terrain_top_level_form(chapter_terrain: &OpenTerrain, ui: &mut Ui) {
-        chapter_terrain.write(|data| {
-            PropertyGrid::new("terrain_top_level_property_grid", data)
-                .new_section("")
-                .default_field("X", |d| &mut d.x)
-                .default_field("Z", |d| &mut d.z)
-                .default_field("Playable Width", |d| &mut d.width)
-                .default_field("Playable Height", |d| &mut d.height)
-                .show(ui)
-                .changed()
-        });
-    }
 }
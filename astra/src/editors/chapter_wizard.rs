@@ -0,0 +1,288 @@
+use std::sync::Arc;
+
+use astra_core::Astra;
+use astra_types::Chapter;
+use egui::{Button, Ui};
+use egui_modal::Modal;
+use parking_lot::RwLock;
+
+use crate::{indexed_model_drop_down, ChapterSheet, EditorState};
+
+/// The files a chapter's fields point to. `*` in a field stands for the chapter's ID without `CID_`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChapterFiles {
+    pub dispos: String,
+    pub encount_dispos: String,
+    pub terrain: String,
+    pub script: String,
+    pub encount_script: String,
+    pub kizuna_script: String,
+}
+
+impl ChapterFiles {
+    pub fn of(chapter: &Chapter) -> Self {
+        let cid_part = chapter.cid.trim_start_matches("CID_");
+        let resolve = |field: &str| field.replace('*', cid_part).to_lowercase();
+        let dispos = resolve(&chapter.dispos);
+        Self {
+            encount_dispos: format!("{}e", dispos),
+            dispos,
+            terrain: resolve(&chapter.terrain),
+            script: resolve(&chapter.script_bmap),
+            encount_script: resolve(&chapter.script_encount),
+            kizuna_script: resolve(&chapter.script_kizuna),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WizardMode {
+    /// Add a chapter row along with its files.
+    NewChapter,
+    /// Create the missing files for an existing chapter.
+    MissingFiles(usize),
+}
+
+/// Creates a chapter by copying the dispos, terrain and scripts of another one.
+pub struct ChapterWizard {
+    mode: WizardMode,
+    cid: String,
+    source: Option<usize>,
+    copy_dispos: bool,
+    copy_terrain: bool,
+    copy_scripts: bool,
+    error: Option<String>,
+}
+
+impl ChapterWizard {
+    pub fn new() -> Self {
+        Self {
+            mode: WizardMode::NewChapter,
+            cid: String::new(),
+            source: None,
+            copy_dispos: true,
+            copy_terrain: true,
+            copy_scripts: true,
+            error: None,
+        }
+    }
+
+    /// Open the wizard to add a new chapter, copying from `source` by default.
+    pub fn open_new(&mut self, modal: &Modal, source: Option<usize>) {
+        self.mode = WizardMode::NewChapter;
+        self.cid = "CID_".to_string();
+        self.source = source;
+        self.error = None;
+        modal.open();
+    }
+
+    /// Open the wizard to create the files the chapter at `index` is missing.
+    pub fn open_missing_files(&mut self, modal: &Modal, index: usize) {
+        self.mode = WizardMode::MissingFiles(index);
+        self.source = None;
+        self.error = None;
+        modal.open();
+    }
+
+    /// Draw the wizard inside a modal.
+    /// Returns the index of the chapter that was created or given new files, so it can be reloaded.
+    pub fn show(
+        &mut self,
+        modal: &Modal,
+        ui: &mut Ui,
+        chapters: &ChapterSheet,
+        state: &EditorState,
+        astra: &Arc<RwLock<Astra>>,
+    ) -> Option<usize> {
+        let mut result = None;
+        let validation = chapters.read(|data| match self.mode {
+            WizardMode::NewChapter if self.cid.trim().is_empty() => {
+                Err("Enter a chapter ID.".to_string())
+            }
+            WizardMode::NewChapter if data.contains_key(self.cid.trim()) => {
+                Err("A chapter with this ID already exists.".to_string())
+            }
+            WizardMode::MissingFiles(index) if self.source == Some(index) => {
+                Err("Pick a different chapter to copy from.".to_string())
+            }
+            _ if self.source.is_none() => Err("Pick a chapter to copy from.".to_string()),
+            _ => Ok(()),
+        });
+
+        modal.title(
+            ui,
+            match self.mode {
+                WizardMode::NewChapter => "New Chapter",
+                WizardMode::MissingFiles(_) => "Create Chapter Files",
+            },
+        );
+        modal.frame(ui, |ui| {
+            egui::Grid::new("chapter_wizard_grid")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    if let WizardMode::NewChapter = self.mode {
+                        ui.label("Chapter ID");
+                        ui.text_edit_singleline(&mut self.cid);
+                        ui.end_row();
+                    }
+                    ui.label("Copy From");
+                    chapters.read(|data| {
+                        ui.add(indexed_model_drop_down(data, state, &mut self.source));
+                    });
+                    ui.end_row();
+                });
+            ui.checkbox(&mut self.copy_dispos, "Copy Dispos")
+                .on_hover_text("Includes the encount dispos if the source chapter has one.");
+            ui.checkbox(&mut self.copy_terrain, "Copy Terrain");
+            ui.checkbox(&mut self.copy_scripts, "Copy Scripts")
+                .on_hover_text("Copies the map, encount and kizuna scripts that exist.");
+            if let WizardMode::NewChapter = self.mode {
+                ui.label("Files that aren't copied are shared with the source chapter.");
+            }
+            if astra.read().project().cobalt_dir.is_none() {
+                ui.colored_label(
+                    ui.visuals().warn_fg_color,
+                    "Without Cobalt, new bundles also need entries in the game's asset catalog to load.",
+                );
+            }
+            if let Err(message) = &validation {
+                ui.colored_label(ui.visuals().error_fg_color, message);
+            }
+            if let Some(error) = &self.error {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
+        });
+        modal.buttons(ui, |ui| {
+            modal.button(ui, "Cancel");
+            if ui
+                .add_enabled(validation.is_ok(), Button::new("Create"))
+                .clicked()
+            {
+                result = self.create(chapters, &mut astra.write());
+                if result.is_some() && self.error.is_none() {
+                    modal.close();
+                }
+            }
+        });
+        result
+    }
+
+    /// Copy the files for the target chapter. A new chapter's row is only inserted once
+    /// every copy succeeds, otherwise the copies are dropped so nothing is left half-created.
+    fn create(&mut self, chapters: &ChapterSheet, astra: &mut Astra) -> Option<usize> {
+        let source_index = self.source?;
+        let source = chapters.read(|data| data.get_index(source_index).map(|(_, c)| c.clone()))?;
+        let source_files = ChapterFiles::of(&source);
+
+        let target = match self.mode {
+            WizardMode::NewChapter => self.new_chapter(&source, &source_files),
+            WizardMode::MissingFiles(index) => {
+                chapters.read(|data| data.get_index(index).map(|(_, c)| c.clone()))?
+            }
+        };
+        let target_files = ChapterFiles::of(&target);
+
+        let mut errors = vec![];
+        let mut created_dispos = vec![];
+        let mut created_terrain = None;
+        let mut created_scripts = vec![];
+        if self.copy_dispos {
+            match astra.create_dispos(&target_files.dispos, &source_files.dispos) {
+                Ok(_) => created_dispos.push(&target_files.dispos),
+                Err(err) => errors.push(format!("Dispos: {}", err)),
+            }
+            if astra.get_dispos(&source_files.encount_dispos).is_some() {
+                match astra
+                    .create_dispos(&target_files.encount_dispos, &source_files.encount_dispos)
+                {
+                    Ok(_) => created_dispos.push(&target_files.encount_dispos),
+                    Err(err) => errors.push(format!("Encount dispos: {}", err)),
+                }
+            }
+        }
+        if self.copy_terrain {
+            match astra.create_chapter_terrain(&target_files.terrain, &source_files.terrain) {
+                Ok(_) => created_terrain = Some(&target_files.terrain),
+                Err(err) => errors.push(format!("Terrain: {}", err)),
+            }
+        }
+        if self.copy_scripts {
+            let scripts = astra.list_all_scripts();
+            for (target, source) in [
+                (&target_files.script, &source_files.script),
+                (&target_files.encount_script, &source_files.encount_script),
+                (&target_files.kizuna_script, &source_files.kizuna_script),
+            ] {
+                if source.is_empty() || !scripts.contains(source) {
+                    continue;
+                }
+                match astra.create_script(target, source) {
+                    Ok(()) => created_scripts.push(target),
+                    Err(err) => errors.push(format!("Script '{}': {}", target, err)),
+                }
+            }
+        }
+
+        self.error = (!errors.is_empty()).then(|| errors.join("\n"));
+        match self.mode {
+            WizardMode::NewChapter if self.error.is_some() => {
+                for dispos in created_dispos {
+                    astra.forget_dispos(dispos);
+                }
+                if let Some(terrain) = created_terrain {
+                    astra.forget_chapter_terrain(terrain);
+                }
+                for script in created_scripts {
+                    astra.forget_script(script);
+                }
+                None
+            }
+            WizardMode::NewChapter => {
+                chapters.write(|data| {
+                    data.insert(target.cid.clone(), target);
+                    data.move_index(data.len() - 1, (source_index + 1).min(data.len() - 1));
+                    true
+                });
+                Some(source_index + 1)
+            }
+            // Files that already existed are reported but the ones that were missing are kept.
+            WizardMode::MissingFiles(index) => Some(index),
+        }
+    }
+
+    /// Copy the source chapter's row for the new ID. Fields for copied files follow the new ID,
+    /// the rest point at the source chapter's files.
+    fn new_chapter(&self, source: &Chapter, source_files: &ChapterFiles) -> Chapter {
+        let follow = |copy: bool, field: &str, resolved: &str| {
+            if !copy {
+                resolved.to_string()
+            } else if field.contains('*') || field.is_empty() {
+                field.to_string()
+            } else {
+                "*".to_string()
+            }
+        };
+        let mut chapter = source.clone();
+        chapter.cid = self.cid.trim().to_string();
+        chapter.dispos = follow(self.copy_dispos, &source.dispos, &source_files.dispos);
+        chapter.terrain = follow(self.copy_terrain, &source.terrain, &source_files.terrain);
+        chapter.script_bmap = follow(self.copy_scripts, &source.script_bmap, &source_files.script);
+        chapter.script_encount = follow(
+            self.copy_scripts,
+            &source.script_encount,
+            &source_files.encount_script,
+        );
+        chapter.script_kizuna = follow(
+            self.copy_scripts,
+            &source.script_kizuna,
+            &source_files.kizuna_script,
+        );
+        chapter
+    }
+}
+
+impl Default for ChapterWizard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -7,7 +7,7 @@ use indexmap::IndexMap;
 use crate::{
     editor_tab_strip, id_field, keyed_add_modal_content, model_drop_down, sheet_retriever,
     EditorState, GroupEditorContent, GroupViewItem, KeyedViewItem, ListEditorContent, PropertyGrid,
-    ViewItem,
+    RowForm, ViewItem,
 };
 
 sheet_retriever!(Chart, ChartBook, chart_data, IndexMap<String, Vec<ChartData>>);
@@ -125,99 +125,105 @@ impl ChartEditor {
         match self.tab {
             Tab::Main => {
                 self.chart_content.left_panel(ctx, &self.chart, state);
-                self.chart.write(|data| {
-                    self.chart_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("chart", selection)
-                            .new_section("")
-                            .field("PID", |ui, d| {
-                                state
-                                    .person
-                                    .read(|data| ui.add(model_drop_down(data, state, &mut d.pid)))
-                            })
-                            .default_field("Level N", |d| &mut d.level_n)
-                            .default_field("Level H", |d| &mut d.level_h)
-                            .default_field("Level L", |d| &mut d.level_l)
-                            .field("Class", |ui, d| {
-                                state
-                                    .job
-                                    .read(|data| ui.add(model_drop_down(data, state, &mut d.jid)))
-                            })
-                            .field("Item 1", |ui, d| {
-                                state.item.read(|data| {
-                                    ui.add(model_drop_down(data, state, &mut d.item_1_iid))
-                                })
-                            })
-                            .field("Item 2", |ui, d| {
-                                state.item.read(|data| {
-                                    ui.add(model_drop_down(data, state, &mut d.item_2_iid))
-                                })
-                            })
-                            .field("Item 3", |ui, d| {
-                                state.item.read(|data| {
-                                    ui.add(model_drop_down(data, state, &mut d.item_3_iid))
-                                })
-                            })
-                            .field("Item 4", |ui, d| {
-                                state.item.read(|data| {
-                                    ui.add(model_drop_down(data, state, &mut d.item_4_iid))
-                                })
-                            })
-                            .field("Item 5", |ui, d| {
-                                state.item.read(|data| {
-                                    ui.add(model_drop_down(data, state, &mut d.item_5_iid))
-                                })
-                            })
-                            .field("God", |ui, d| {
-                                state.god.read(|data| {
-                                    ui.add(model_drop_down(data, state, &mut d.god_id))
-                                })
-                            })
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.chart_content.content(ctx, &self.chart, state);
             }
             Tab::GodData => {
                 self.chart_god_content
                     .left_panel(ctx, &self.chart_god, state);
-                self.chart_god.write(|data| {
-                    self.chart_god_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("chart_god", selection)
-                            .new_section("")
-                            .field("Chapter", |ui, d| ui.add(id_field(&mut d.chapter)))
-                            .default_field("Marth Level", |d| &mut d.marth_level)
-                            .default_field("Siglud Level", |d| &mut d.siglud_level)
-                            .default_field("Celica Level", |d| &mut d.celica_level)
-                            .default_field("Micaiah Level", |d| &mut d.micaiah_level)
-                            .default_field("Roy Level", |d| &mut d.roy_level)
-                            .default_field("Leaf Level", |d| &mut d.leaf_level)
-                            .default_field("Lucina Level", |d| &mut d.lucina_level)
-                            .default_field("Lin Level", |d| &mut d.lin_level)
-                            .default_field("Ike Level", |d| &mut d.ike_level)
-                            .default_field("Byleth Level", |d| &mut d.byleth_level)
-                            .default_field("Kamui Level", |d| &mut d.kamui_level)
-                            .default_field("Eirik Level", |d| &mut d.eirik_level)
-                            .default_field("Flag", |d| &mut d.flag)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.chart_god_content.content(ctx, &self.chart_god, state);
             }
             Tab::Params => {
                 self.chart_param_content
                     .left_panel(ctx, &self.chart_param, state);
-                self.chart_param.write(|data| {
-                    self.chart_param_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("chart_param", selection)
-                                .new_section("")
-                                .default_field("Name", |d| &mut d.name)
-                                .default_field("Value", |d| &mut d.value)
-                                .show(ui)
-                                .changed()
-                        })
-                })
+                self.chart_param_content
+                    .content(ctx, &self.chart_param, state)
             }
         }
     }
 }
+
+impl RowForm for ChartData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("chart", selection)
+            .new_section("")
+            .field("PID", |ui, d| {
+                state
+                    .person
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.pid)))
+            })
+            .default_field("Level N", |d| &mut d.level_n)
+            .default_field("Level H", |d| &mut d.level_h)
+            .default_field("Level L", |d| &mut d.level_l)
+            .field("Class", |ui, d| {
+                state
+                    .job
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.jid)))
+            })
+            .field("Item 1", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.item_1_iid)))
+            })
+            .field("Item 2", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.item_2_iid)))
+            })
+            .field("Item 3", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.item_3_iid)))
+            })
+            .field("Item 4", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.item_4_iid)))
+            })
+            .field("Item 5", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.item_5_iid)))
+            })
+            .field("God", |ui, d| {
+                state
+                    .god
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.god_id)))
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for ChartGodData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("chart_god", selection)
+            .new_section("")
+            .field("Chapter", |ui, d| ui.add(id_field(&mut d.chapter)))
+            .default_field("Marth Level", |d| &mut d.marth_level)
+            .default_field("Siglud Level", |d| &mut d.siglud_level)
+            .default_field("Celica Level", |d| &mut d.celica_level)
+            .default_field("Micaiah Level", |d| &mut d.micaiah_level)
+            .default_field("Roy Level", |d| &mut d.roy_level)
+            .default_field("Leaf Level", |d| &mut d.leaf_level)
+            .default_field("Lucina Level", |d| &mut d.lucina_level)
+            .default_field("Lin Level", |d| &mut d.lin_level)
+            .default_field("Ike Level", |d| &mut d.ike_level)
+            .default_field("Byleth Level", |d| &mut d.byleth_level)
+            .default_field("Kamui Level", |d| &mut d.kamui_level)
+            .default_field("Eirik Level", |d| &mut d.eirik_level)
+            .default_field("Flag", |d| &mut d.flag)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for ChartParam {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("chart_param", selection)
+            .new_section("")
+            .default_field("Name", |d| &mut d.name)
+            .default_field("Value", |d| &mut d.value)
+            .show(ui)
+            .changed()
+    }
+}
@@ -10,7 +10,7 @@ use indexmap::IndexMap;
 use crate::{
     editor_tab_strip, id_field, keyed_add_modal_content, sheet_retriever, EditorState,
     GroupEditorContent, GroupViewItem, KeyedViewItem, ListEditorContent, ModelDropDown,
-    PropertyGrid, ViewItem,
+    PropertyGrid, RowForm, ViewItem,
 };
 
 sheet_retriever!(
@@ -157,120 +157,126 @@ impl DragonRideEditor {
             Tab::Presets => {
                 self.preset_params_content
                     .left_panel(ctx, &self.preset_params, state);
-                self.preset_params.write(|data| {
-                    self.preset_params_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("dragon_ridepreset_params", selection)
-                                .new_section("")
-                                .field("Group", |ui, d| ui.add(id_field(&mut d.group)))
-                                .default_field("Is Time Test", |d| &mut d.is_time_test)
-                                .default_field("Is Walk Through On", |d| &mut d.is_walk_through_on)
-                                .default_field("Course 1", |d| &mut d.course_1)
-                                .default_field("Stime 1", |d| &mut d.stime_1)
-                                .default_field("Srandom 1", |d| &mut d.srandom_1)
-                                .default_field("Course 2", |d| &mut d.course_2)
-                                .default_field("Stime 2", |d| &mut d.stime_2)
-                                .default_field("Srandom 2", |d| &mut d.srandom_2)
-                                .default_field("Course 3", |d| &mut d.course_3)
-                                .default_field("Stime 3", |d| &mut d.stime_3)
-                                .default_field("Srandom 3", |d| &mut d.srandom_3)
-                                .default_field("Course 4", |d| &mut d.course_4)
-                                .default_field("Stime 4", |d| &mut d.stime_4)
-                                .default_field("Srandom 4", |d| &mut d.srandom_4)
-                                .default_field("Course 5", |d| &mut d.course_5)
-                                .default_field("Stime 5", |d| &mut d.stime_5)
-                                .default_field("Srandom 5", |d| &mut d.srandom_5)
-                                .default_field("Course 6", |d| &mut d.course_6)
-                                .default_field("Stime 6", |d| &mut d.stime_6)
-                                .default_field("Srandom 6", |d| &mut d.srandom_6)
-                                .default_field("Course 7", |d| &mut d.course_7)
-                                .default_field("Stime 7", |d| &mut d.stime_7)
-                                .default_field("Srandom 7", |d| &mut d.srandom_7)
-                                .default_field("Course 8", |d| &mut d.course_8)
-                                .default_field("Stime 8", |d| &mut d.stime_8)
-                                .default_field("Srandom 8", |d| &mut d.srandom_8)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.preset_params_content
+                    .content(ctx, &self.preset_params, state);
             }
             Tab::Prizes => {
                 self.prizes_content.left_panel(ctx, &self.prizes, state);
-                self.prizes.write(|data| {
-                    self.prizes_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("dragon_ride_prizes", selection)
-                            .new_section("")
-                            .field("Group", |ui, d| ui.add(id_field(&mut d.group)))
-                            .default_field("Bond Fragments", |d| &mut d.piece_of_bond)
-                            .default_field("Item Count", |d| &mut d.item_count)
-                            .field("Item 1", |ui, d| {
-                                state.item.read(|data| {
-                                    ModelDropDown::default()
-                                        .transform(&item_key_transform, &item_key_reverse_transform)
-                                        .show(ui, data, state, &mut d.item_1)
-                                })
-                            })
-                            .field("Item 2", |ui, d| {
-                                state.item.read(|data| {
-                                    ModelDropDown::default()
-                                        .transform(&item_key_transform, &item_key_reverse_transform)
-                                        .show(ui, data, state, &mut d.item_2)
-                                })
-                            })
-                            .field("Item 3", |ui, d| {
-                                state.item.read(|data| {
-                                    ModelDropDown::default()
-                                        .transform(&item_key_transform, &item_key_reverse_transform)
-                                        .show(ui, data, state, &mut d.item_3)
-                                })
-                            })
-                            .field("Item 4", |ui, d| {
-                                state.item.read(|data| {
-                                    ModelDropDown::default()
-                                        .transform(&item_key_transform, &item_key_reverse_transform)
-                                        .show(ui, data, state, &mut d.item_4)
-                                })
-                            })
-                            .field("Item 5", |ui, d| {
-                                state.item.read(|data| {
-                                    ModelDropDown::default()
-                                        .transform(&item_key_transform, &item_key_reverse_transform)
-                                        .show(ui, data, state, &mut d.item_5)
-                                })
-                            })
-                            .field("Item 6", |ui, d| {
-                                state.item.read(|data| {
-                                    ModelDropDown::default()
-                                        .transform(&item_key_transform, &item_key_reverse_transform)
-                                        .show(ui, data, state, &mut d.item_6)
-                                })
-                            })
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.prizes_content.content(ctx, &self.prizes, state);
             }
             Tab::TargetPatterns => {
                 self.target_patterns_content
                     .left_panel(ctx, &self.target_patterns, state);
-                self.target_patterns.write(|data| {
-                    self.target_patterns_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("dragon_ride_target_patterns", selection)
-                                .new_section("")
-                                .default_field("Target 1", |d| &mut d.target_1)
-                                .default_field("Target 2", |d| &mut d.target_2)
-                                .default_field("Target 3", |d| &mut d.target_3)
-                                .default_field("Target 4", |d| &mut d.target_4)
-                                .default_field("Target 5", |d| &mut d.target_5)
-                                .default_field("Target 6", |d| &mut d.target_6)
-                                .default_field("Target 7", |d| &mut d.target_7)
-                                .default_field("Target 8", |d| &mut d.target_8)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.target_patterns_content
+                    .content(ctx, &self.target_patterns, state);
             }
         }
     }
 }
+
+impl RowForm for DragonRidePresetParamData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("dragon_ridepreset_params", selection)
+            .new_section("")
+            .field("Group", |ui, d| ui.add(id_field(&mut d.group)))
+            .default_field("Is Time Test", |d| &mut d.is_time_test)
+            .default_field("Is Walk Through On", |d| &mut d.is_walk_through_on)
+            .default_field("Course 1", |d| &mut d.course_1)
+            .default_field("Stime 1", |d| &mut d.stime_1)
+            .default_field("Srandom 1", |d| &mut d.srandom_1)
+            .default_field("Course 2", |d| &mut d.course_2)
+            .default_field("Stime 2", |d| &mut d.stime_2)
+            .default_field("Srandom 2", |d| &mut d.srandom_2)
+            .default_field("Course 3", |d| &mut d.course_3)
+            .default_field("Stime 3", |d| &mut d.stime_3)
+            .default_field("Srandom 3", |d| &mut d.srandom_3)
+            .default_field("Course 4", |d| &mut d.course_4)
+            .default_field("Stime 4", |d| &mut d.stime_4)
+            .default_field("Srandom 4", |d| &mut d.srandom_4)
+            .default_field("Course 5", |d| &mut d.course_5)
+            .default_field("Stime 5", |d| &mut d.stime_5)
+            .default_field("Srandom 5", |d| &mut d.srandom_5)
+            .default_field("Course 6", |d| &mut d.course_6)
+            .default_field("Stime 6", |d| &mut d.stime_6)
+            .default_field("Srandom 6", |d| &mut d.srandom_6)
+            .default_field("Course 7", |d| &mut d.course_7)
+            .default_field("Stime 7", |d| &mut d.stime_7)
+            .default_field("Srandom 7", |d| &mut d.srandom_7)
+            .default_field("Course 8", |d| &mut d.course_8)
+            .default_field("Stime 8", |d| &mut d.stime_8)
+            .default_field("Srandom 8", |d| &mut d.srandom_8)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for DragonRidePrizeData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("dragon_ride_prizes", selection)
+            .new_section("")
+            .field("Group", |ui, d| ui.add(id_field(&mut d.group)))
+            .default_field("Bond Fragments", |d| &mut d.piece_of_bond)
+            .default_field("Item Count", |d| &mut d.item_count)
+            .field("Item 1", |ui, d| {
+                state.item.read(|data| {
+                    ModelDropDown::default()
+                        .transform(&item_key_transform, &item_key_reverse_transform)
+                        .show(ui, data, state, &mut d.item_1)
+                })
+            })
+            .field("Item 2", |ui, d| {
+                state.item.read(|data| {
+                    ModelDropDown::default()
+                        .transform(&item_key_transform, &item_key_reverse_transform)
+                        .show(ui, data, state, &mut d.item_2)
+                })
+            })
+            .field("Item 3", |ui, d| {
+                state.item.read(|data| {
+                    ModelDropDown::default()
+                        .transform(&item_key_transform, &item_key_reverse_transform)
+                        .show(ui, data, state, &mut d.item_3)
+                })
+            })
+            .field("Item 4", |ui, d| {
+                state.item.read(|data| {
+                    ModelDropDown::default()
+                        .transform(&item_key_transform, &item_key_reverse_transform)
+                        .show(ui, data, state, &mut d.item_4)
+                })
+            })
+            .field("Item 5", |ui, d| {
+                state.item.read(|data| {
+                    ModelDropDown::default()
+                        .transform(&item_key_transform, &item_key_reverse_transform)
+                        .show(ui, data, state, &mut d.item_5)
+                })
+            })
+            .field("Item 6", |ui, d| {
+                state.item.read(|data| {
+                    ModelDropDown::default()
+                        .transform(&item_key_transform, &item_key_reverse_transform)
+                        .show(ui, data, state, &mut d.item_6)
+                })
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for DragonRideTargetPattern {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("dragon_ride_target_patterns", selection)
+            .new_section("")
+            .default_field("Target 1", |d| &mut d.target_1)
+            .default_field("Target 2", |d| &mut d.target_2)
+            .default_field("Target 3", |d| &mut d.target_3)
+            .default_field("Target 4", |d| &mut d.target_4)
+            .default_field("Target 5", |d| &mut d.target_5)
+            .default_field("Target 6", |d| &mut d.target_6)
+            .default_field("Target 7", |d| &mut d.target_7)
+            .default_field("Target 8", |d| &mut d.target_8)
+            .show(ui)
+            .changed()
+    }
+}
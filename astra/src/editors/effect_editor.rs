@@ -6,7 +6,7 @@ use indexmap::IndexMap;
 
 use crate::{
     editor_tab_strip, id_field, keyed_add_modal_content, model_drop_down, sheet_retriever,
-    CachedView, EditorState, KeyedViewItem, ListEditorContent, PropertyGrid, ViewItem,
+    EditorState, KeyedViewItem, ListEditorContent, PropertyGrid, RowForm, ViewItem,
 };
 
 sheet_retriever!(Effect, EffectBook, effects, IndexMap<String, Effect>);
@@ -59,7 +59,6 @@ pub struct EffectEditor {
     tab: Tab,
     effect: EffectSheet,
     sequence: EffectSequenceSheet,
-    effect_cache: CachedView<EffectSheetRetriever, EffectBook, Effect>,
     effect_content: ListEditorContent<IndexMap<String, Effect>, Effect, EditorState>,
     sequence_content:
         ListEditorContent<IndexMap<String, EffectSequence>, EffectSequence, EditorState>,
@@ -71,7 +70,6 @@ impl EffectEditor {
             tab: Tab::Effects,
             effect: state.effect.clone(),
             sequence: state.effect_sequence.clone(),
-            effect_cache: CachedView::new(state.effect.clone(), state),
             effect_content: ListEditorContent::new("effect")
                 .with_add_modal_content(keyed_add_modal_content),
             sequence_content: ListEditorContent::new("effect_sequence")
@@ -87,49 +85,58 @@ impl EffectEditor {
     }
 
     pub fn show(&mut self, ctx: &egui::Context, state: &EditorState) {
-        self.effect_cache.refresh(state);
         match self.tab {
             Tab::Effects => {
                 self.effect_content.left_panel(ctx, &self.effect, state);
-                self.effect.write(|data| {
-                    self.effect_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("effects", selection)
-                            .new_section("")
-                            .field("EID", |ui, d| ui.add(id_field(&mut d.eid)))
-                            .default_field("File Path", |d| &mut d.file_path)
-                            .default_field("Sound Label", |d| &mut d.sound_label)
-                            .default_field("Ty", |d| &mut d.ty)
-                            .default_field("Resident", |d| &mut d.resident)
-                            .default_field("Delay Time", |d| &mut d.delay_time)
-                            .default_field("Wait Time", |d| &mut d.wait_time)
-                            .default_field("Shake Time", |d| &mut d.shake_time)
-                            .default_field("Shake Magnitude", |d| &mut d.shake_magnitude)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.effect_content.content(ctx, &self.effect, state);
             }
             Tab::Sequences => {
                 self.sequence_content.left_panel(ctx, &self.sequence, state);
-                self.sequence.write(|data| {
-                    self.sequence_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("effect_sequences", selection)
-                            .new_section("")
-                            .field("Sequence", |ui, d| ui.add(id_field(&mut d.sequence)))
-                            .field("Active", |ui, d| {
-                                ui.add(model_drop_down(self.effect_cache.get(), &(), &mut d.active))
-                            })
-                            .field("Shoot", |ui, d| {
-                                ui.add(model_drop_down(self.effect_cache.get(), &(), &mut d.shoot))
-                            })
-                            .field("Hit", |ui, d| {
-                                ui.add(model_drop_down(self.effect_cache.get(), &(), &mut d.hit))
-                            })
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.sequence_content.content(ctx, &self.sequence, state);
             }
         }
     }
 }
+
+impl RowForm for Effect {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("effects", selection)
+            .new_section("")
+            .field("EID", |ui, d| ui.add(id_field(&mut d.eid)))
+            .default_field("File Path", |d| &mut d.file_path)
+            .default_field("Sound Label", |d| &mut d.sound_label)
+            .default_field("Ty", |d| &mut d.ty)
+            .default_field("Resident", |d| &mut d.resident)
+            .default_field("Delay Time", |d| &mut d.delay_time)
+            .default_field("Wait Time", |d| &mut d.wait_time)
+            .default_field("Shake Time", |d| &mut d.shake_time)
+            .default_field("Shake Magnitude", |d| &mut d.shake_magnitude)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for EffectSequence {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("effect_sequences", selection)
+            .new_section("")
+            .field("Sequence", |ui, d| ui.add(id_field(&mut d.sequence)))
+            .field("Active", |ui, d| {
+                state
+                    .effect
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.active)))
+            })
+            .field("Shoot", |ui, d| {
+                state
+                    .effect
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.shoot)))
+            })
+            .field("Hit", |ui, d| {
+                state
+                    .effect
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.hit)))
+            })
+            .show(ui)
+            .changed()
+    }
+}
@@ -6,7 +6,7 @@ use indexmap::IndexMap;
 use crate::{
     editable_list, editor_tab_strip, keyed_add_modal_content, model_drop_down, sheet_retriever,
     EditorState, GroupEditorContent, GroupViewItem, KeyedViewItem, ListEditorContent, PropertyGrid,
-    ViewItem,
+    RowForm, ViewItem,
 };
 
 use astra_types::{
@@ -165,17 +165,8 @@ impl EncountEditor {
             Tab::Equipment => {
                 self.encount_equipment_content
                     .left_panel(ctx, &self.encount_equipment, state);
-                self.encount_equipment.write(|data| {
-                    self.encount_equipment_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("encount_equipment", selection)
-                                .new_section("")
-                                .default_field("Category", |d| &mut d.category)
-                                .default_field("Percentage", |d| &mut d.percentage)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.encount_equipment_content
+                    .content(ctx, &self.encount_equipment, state);
             }
 
             Tab::WeaponCategory => {
@@ -184,50 +175,18 @@ impl EncountEditor {
                     &self.encount_weapon_categories,
                     state,
                 );
-                self.encount_weapon_categories.write(|data| {
-                    self.encount_weapon_categories_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("encount_weapon_categories", selection)
-                                .new_section("")
-                                .field("Item", |ui, d| {
-                                    state.item.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.iid))
-                                    })
-                                })
-                                .default_field("Rank Condition More", |d| {
-                                    &mut d.rank_condition_more
-                                })
-                                .default_field("Rank Condition Less", |d| {
-                                    &mut d.rank_condition_less
-                                })
-                                .default_field("Percentage", |d| &mut d.percentage)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.encount_weapon_categories_content.content(
+                    ctx,
+                    &self.encount_weapon_categories,
+                    state,
+                );
             }
 
             Tab::EnemyType => {
                 self.encount_enemy_types_content
                     .left_panel(ctx, &self.encount_enemy_types, state);
-                self.encount_enemy_types.write(|data| {
-                    self.encount_enemy_types_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("encount_enemy_types", selection)
-                                .new_section("")
-                                .default_field("E Jid", |d| &mut d.e_jid)
-                                .field("Classes", |ui, d| {
-                                    state.job.read(|data| {
-                                        ui.add(editable_list(&mut d.jobs, |_, d, ui| {
-                                            ui.add(model_drop_down(data, state, d))
-                                        }))
-                                    })
-                                })
-                                .default_field("Flag", |d| &mut d.flag)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.encount_enemy_types_content
+                    .content(ctx, &self.encount_enemy_types, state);
             }
 
             Tab::RarityConfig => {
@@ -236,22 +195,73 @@ impl EncountEditor {
                     &self.encount_rarity_configs,
                     state,
                 );
-                self.encount_rarity_configs.write(|data| {
-                    self.encount_rarity_configs_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("encount_rarity_configs", selection)
-                                .new_section("")
-                                .default_field("Nation Level", |d| &mut d.nation_level)
-                                .field("Item", |ui, d| {
-                                    state.item.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.iid))
-                                    })
-                                })
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.encount_rarity_configs_content.content(
+                    ctx,
+                    &self.encount_rarity_configs,
+                    state,
+                );
             }
         }
     }
 }
+
+impl RowForm for EncountEquipment {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("encount_equipment", selection)
+            .new_section("")
+            .default_field("Category", |d| &mut d.category)
+            .default_field("Percentage", |d| &mut d.percentage)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for EncountWeaponCategory {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("encount_weapon_categories", selection)
+            .new_section("")
+            .field("Item", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.iid)))
+            })
+            .default_field("Rank Condition More", |d| &mut d.rank_condition_more)
+            .default_field("Rank Condition Less", |d| &mut d.rank_condition_less)
+            .default_field("Percentage", |d| &mut d.percentage)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for EncountEnemyType {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("encount_enemy_types", selection)
+            .new_section("")
+            .default_field("E Jid", |d| &mut d.e_jid)
+            .field("Classes", |ui, d| {
+                state.job.read(|data| {
+                    ui.add(editable_list(&mut d.jobs, |_, d, ui| {
+                        ui.add(model_drop_down(data, state, d))
+                    }))
+                })
+            })
+            .default_field("Flag", |d| &mut d.flag)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for EncountRarityConfig {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("encount_rarity_configs", selection)
+            .new_section("")
+            .default_field("Nation Level", |d| &mut d.nation_level)
+            .field("Item", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.iid)))
+            })
+            .show(ui)
+            .changed()
+    }
+}
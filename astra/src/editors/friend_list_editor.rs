@@ -2,9 +2,11 @@ use std::borrow::Cow;
 
 use indexmap::IndexMap;
 
+use egui::Ui;
+
 use crate::{
     id_field, keyed_add_modal_content, model_drop_down, msbt_key_value_multiline, sheet_retriever,
-    EditorState, KeyedViewItem, ListEditorContent, PropertyGrid, ViewItem,
+    EditorState, KeyedViewItem, ListEditorContent, PropertyGrid, RowForm, ViewItem,
 };
 
 use astra_types::{FriendListBook, FriendListData};
@@ -71,28 +73,30 @@ impl FriendListEditor {
         self.friend_list_data_content
             .left_panel(ctx, &self.friend_list_data, state);
 
-        self.friend_list_data.write(|data| {
-            self.friend_list_data_content
-                .content(ctx, data, |ui, selection| {
-                    PropertyGrid::new("friend_list_data", selection)
-                        .new_section("")
-                        .field("FLID", |ui, d| ui.add(id_field(&mut d.flid)))
-                        .field("Person", |ui, d| {
-                            state
-                                .person
-                                .read(|data| ui.add(model_drop_down(data, state, &mut d.pid)))
-                        })
-                        .default_field("Level", |d| &mut d.level)
-                        .field("Content Text", |ui, d| {
-                            msbt_key_value_multiline!(ui, state, "friendlist", d.content_text)
-                        })
-                        .default_field("Stamp Name", |d| &mut d.stamp_name)
-                        .default_field("Image Name", |d| &mut d.image_name)
-                        .default_field("Image Name S", |d| &mut d.image_name_s)
-                        .default_field("Country", |d| &mut d.country)
-                        .show(ui)
-                        .changed()
-                })
-        });
+        self.friend_list_data_content
+            .content(ctx, &self.friend_list_data, state);
+    }
+}
+
+impl RowForm for FriendListData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("friend_list_data", selection)
+            .new_section("")
+            .field("FLID", |ui, d| ui.add(id_field(&mut d.flid)))
+            .field("Person", |ui, d| {
+                state
+                    .person
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.pid)))
+            })
+            .default_field("Level", |d| &mut d.level)
+            .field("Content Text", |ui, d| {
+                msbt_key_value_multiline!(ui, state, "friendlist", d.content_text)
+            })
+            .default_field("Stamp Name", |d| &mut d.stamp_name)
+            .default_field("Image Name", |d| &mut d.image_name)
+            .default_field("Image Name S", |d| &mut d.image_name_s)
+            .default_field("Country", |d| &mut d.country)
+            .show(ui)
+            .changed()
     }
 }
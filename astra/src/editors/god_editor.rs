@@ -1,13 +1,12 @@
-use astra_types::{GodBondLevelData, GodBook, GodData, GodLevelData};
+use astra_types::{GodBondLevelData, GodData, GodLevelData};
 use egui::{DragValue, Ui};
 use indexmap::IndexMap;
 
-use crate::model::{CacheItem, CachedView, GodDataSheetRetriever};
 use crate::widgets::{bitgrid_i32, id_field, keyed_add_modal_content};
 use crate::{
     editable_list, editor_tab_strip, model_drop_down, msbt_key_value_multiline,
     msbt_key_value_singleline, EditorState, GodBondLevelDataSheet, GodDataSheet, GodLevelDataSheet,
-    GroupEditorContent, ListEditorContent, PropertyGrid,
+    GroupEditorContent, ListEditorContent, PropertyGrid, RowForm,
 };
 
 const FLAG_LABELS: &[&str] = &[
@@ -29,7 +28,6 @@ enum Tab {
 pub struct GodEditor {
     tab: Tab,
     god: GodDataSheet,
-    cache: CachedView<GodDataSheetRetriever, GodBook, GodData>,
     level_data: GodLevelDataSheet,
     bond_data: GodBondLevelDataSheet,
     main_content: ListEditorContent<IndexMap<String, GodData>, GodData, EditorState>,
@@ -42,7 +40,6 @@ impl GodEditor {
         Self {
             tab: Tab::Main,
             god: state.god.clone(),
-            cache: CachedView::new(state.god.clone(), state),
             level_data: state.god_level_data.clone(),
             bond_data: state.god_bond_level_data.clone(),
             main_content: ListEditorContent::new("gods")
@@ -56,6 +53,10 @@ impl GodEditor {
         self.main_content.select(index);
     }
 
+    pub fn selection(&self) -> Option<usize> {
+        self.main_content.selection()
+    }
+
     pub fn tab_strip(&mut self, ui: &mut Ui) {
         editor_tab_strip(ui, |ui| {
             ui.selectable_value(&mut self.tab, Tab::Main, "Main");
@@ -73,33 +74,18 @@ impl GodEditor {
             Tab::BondLevelData => self.bond_data_content.left_panel(ctx, &self.bond_data, &()),
         }
 
-        self.cache.refresh(state);
-
         match self.tab {
-            Tab::Main => self.god.write(|data| {
-                self.main_content.content(ctx, data, |ui, data| {
-                    Self::god_property_grid(self.cache.get(), ui, data, state)
-                })
-            }),
-            Tab::LevelData => self.level_data.write(|data| {
-                self.level_data_content.content(ctx, data, |ui, data| {
-                    Self::level_data_property_grid(ui, data, state)
-                })
-            }),
-            Tab::BondLevelData => self.bond_data.write(|data| {
-                self.bond_data_content.content(ctx, data, |ui, data| {
-                    Self::bond_level_data_property_grid(ui, data)
-                })
-            }),
+            Tab::Main => self.main_content.content(ctx, &self.god, state),
+            Tab::LevelData => self
+                .level_data_content
+                .content(ctx, &self.level_data, state),
+            Tab::BondLevelData => self.bond_data_content.content(ctx, &self.bond_data, state),
         }
     }
+}
 
-    fn god_property_grid(
-        cache: &IndexMap<String, CacheItem<GodData>>,
-        ui: &mut Ui,
-        data: &mut GodData,
-        state: &EditorState,
-    ) -> bool {
+impl RowForm for GodData {
+    fn form(ui: &mut Ui, state: &EditorState, data: &mut Self) -> bool {
         PropertyGrid::new("gods", data)
             .new_section("")
             .field("GID", |ui, god| ui.add(id_field(&mut god.gid)))
@@ -135,7 +121,9 @@ impl GodEditor {
             })
             .field("Changed", |ui, god| {
                 ui.add(editable_list(&mut god.change, |_, value, ui| {
-                    ui.add(model_drop_down(cache, &(), value))
+                    state
+                        .god
+                        .read(|data| ui.add(model_drop_down(data, state, value)))
                 }))
             })
             .default_field("Link", |god| &mut god.link)
@@ -170,7 +158,9 @@ impl GodEditor {
                     .read(|data| ui.add(model_drop_down(data, state, &mut god.engage_attack_link)))
             })
             .field("Link Emblem", |ui, god| {
-                ui.add(model_drop_down(cache, &(), &mut god.link_gid))
+                state
+                    .god
+                    .read(|data| ui.add(model_drop_down(data, state, &mut god.link_gid)))
             })
             .field("Gbid", |ui, god| ui.text_edit_singleline(&mut god.gbid))
             .field("Grow Table", |ui, god| {
@@ -245,8 +235,10 @@ impl GodEditor {
             .show(ui)
             .changed()
     }
+}
 
-    fn level_data_property_grid(ui: &mut Ui, data: &mut GodLevelData, state: &EditorState) -> bool {
+impl RowForm for GodLevelData {
+    fn form(ui: &mut Ui, state: &EditorState, data: &mut Self) -> bool {
         PropertyGrid::new("god_level_data", data)
             .new_section("")
             .field("Level", |ui, data| ui.add(DragValue::new(&mut data.level)))
@@ -371,8 +363,10 @@ impl GodEditor {
             .show(ui)
             .changed()
     }
+}
 
-    fn bond_level_data_property_grid(ui: &mut Ui, data: &mut GodBondLevelData) -> bool {
+impl RowForm for GodBondLevelData {
+    fn form(ui: &mut Ui, _: &EditorState, data: &mut Self) -> bool {
         PropertyGrid::new("god_bond_level_data", data)
             .new_section("")
             .default_field("Level", |d| &mut d.level)
@@ -6,9 +6,9 @@ use indexmap::IndexMap;
 use crate::{
     bond_fragment_field, editable_list, editor_tab_strip, id_field, iron_field_i8,
     keyed_add_modal_content, model_drop_down, msbt_key_value_multiline, msbt_key_value_singleline,
-    sheet_retriever, silver_field, standard_keyed_display, steel_field, CachedView, DropDownModal,
-    EditorState, GroupEditorContent, GroupViewItem, KeyedViewItem, ListEditorContent,
-    ModelDropDown, PropertyGrid, ViewItem,
+    sheet_retriever, silver_field, standard_keyed_display, steel_field, DropDownModal, EditorState,
+    GroupEditorContent, GroupViewItem, KeyedViewItem, ListEditorContent, ModelDropDown,
+    PropertyGrid, RowForm, ViewItem,
 };
 
 use astra_types::{
@@ -743,7 +743,6 @@ pub struct HubAreaEditor {
     relative_data: HubTalkRelativeDataSheet,
     talk_facility_data: HubTalkFacilityDataSheet,
     crystal_data: HubCrystalDataSheet,
-    facility_data_cache: CachedView<HubFacilityDataSheetRetriever, HubAreaBook, HubFacilityData>,
     hub_area_data_content:
         ListEditorContent<IndexMap<String, HubAreaData>, HubAreaData, EditorState>,
     hub_facility_data_content:
@@ -803,7 +802,6 @@ impl HubAreaEditor {
             relative_data: state.hub_relative_data.clone(),
             talk_facility_data: state.hub_talk_facility_data.clone(),
             crystal_data: state.hub_crystal_data.clone(),
-            facility_data_cache: CachedView::new(state.hub_facility_data.clone(), state),
             hub_area_data_content: ListEditorContent::new("hub_area_data_editor")
                 .with_add_modal_content(keyed_add_modal_content),
             hub_facility_data_content: ListEditorContent::new("hub_facility_data_editor")
@@ -870,203 +868,40 @@ impl HubAreaEditor {
     }
 
     pub fn show(&mut self, ctx: &egui::Context, state: &EditorState) {
-        self.facility_data_cache.refresh(state);
-
         match self.tab {
             Tab::Area => {
                 self.hub_area_data_content
                     .left_panel(ctx, &self.hub_area_data, state);
-                self.hub_area_data.write(|data| {
-                    self.hub_area_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("hub_area_data", selection)
-                                .new_section("")
-                                .field("AID", |ui, d| ui.add(id_field(&mut d.aid)))
-                                .field("MID", |ui, d| {
-                                    msbt_key_value_singleline!(ui, state, "hub", d.mid)
-                                })
-                                .field("Help", |ui, d| {
-                                    msbt_key_value_multiline!(ui, state, "hub", d.mid_h)
-                                })
-                                .field("Condition Chapter", |ui, d| {
-                                    state.chapter.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.condition_cid))
-                                    })
-                                })
-                                .default_field("Scene Name", |d| &mut d.scene_name)
-                                .default_field("Locator Name", |d| &mut d.locator_name)
-                                .default_field("Map Point No", |d| &mut d.map_point_no)
-                                .field("Facilities", |ui, d| {
-                                    let cache = self.facility_data_cache.get();
-                                    ui.add(editable_list(&mut d.facility_aid_list, |_, d, ui| {
-                                        ui.add(model_drop_down(cache, &(), d))
-                                    }))
-                                })
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.hub_area_data_content
+                    .content(ctx, &self.hub_area_data, state);
             }
             Tab::Facility => {
                 self.hub_facility_data_content
                     .left_panel(ctx, &self.hub_facility_data, state);
-                self.hub_facility_data.write(|data| {
-                    self.hub_facility_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("hub_facility_data", selection)
-                                .new_section("")
-                                .field("AID", |ui, d| ui.add(id_field(&mut d.aid)))
-                                .field("MID", |ui, d| {
-                                    msbt_key_value_singleline!(ui, state, "hub", d.mid)
-                                })
-                                .field("Condition Chapter", |ui, d| {
-                                    state.chapter.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.condition_cid))
-                                    })
-                                })
-                                .default_field("Icon Name", |d| &mut d.icon_name)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.hub_facility_data_content
+                    .content(ctx, &self.hub_facility_data, state);
             }
             Tab::Demo => {
                 self.hub_demo_data_content
                     .left_panel(ctx, &self.hub_demo_data, state);
-                self.hub_demo_data.write(|data| {
-                    self.hub_demo_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("hub_demo_data", selection)
-                                .new_section("")
-                                .field("Locator", |ui, d| ui.add(id_field(&mut d.locator)))
-                                .field("MID", |ui, d| {
-                                    msbt_key_value_singleline!(ui, state, "hub", d.mid)
-                                })
-                                .default_field("Camera Name", |d| &mut d.camera_name)
-                                .default_field("Tutorial", |d| &mut d.tutorial)
-                                .default_field("Condition", |d| &mut d.condition)
-                                .default_field("Timezone", |d| &mut d.timezone)
-                                .default_field("Flag Name", |d| &mut d.flag_name)
-                                .default_field("Manual Culling Name", |d| {
-                                    &mut d.manual_culling_name
-                                })
-                                .default_field("Lod Bias", |d| &mut d.lod_bias)
-                                .default_field("Is Disabled Lod Crossfade Anime", |d| {
-                                    &mut d.is_disabled_lod_crossfade_anime
-                                })
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.hub_demo_data_content
+                    .content(ctx, &self.hub_demo_data, state);
             }
             Tab::Spawn => {
                 self.spawns_content.left_panel(ctx, &self.spawns, state);
-                self.spawns.write(|data| {
-                    self.spawns_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("spawns", selection)
-                            .new_section("")
-                            .field("HID", |ui, d| ui.add(id_field(&mut d.hid)))
-                            .default_field("Locator", |d| &mut d.locator)
-                            .default_field("Parent Locator", |d| &mut d.parent_locator)
-                            .default_field("Is Must Child", |d| &mut d.is_must_child)
-                            .default_field("Fade Distance", |d| &mut d.fade_distance)
-                            .default_field("Priority", |d| &mut d.priority)
-                            .field("Chapter", |ui, d| {
-                                state.chapter.read(|data| {
-                                    ui.add(model_drop_down(data, state, &mut d.chapter))
-                                })
-                            })
-                            .default_field("Phase", |d| &mut d.phase)
-                            .default_field("Timezone Flag", |d| &mut d.timezone_flag)
-                            .default_field("Flag Name", |d| &mut d.flag_name)
-                            .default_field("Any Condition", |d| &mut d.any_condition)
-                            .default_field("Content Type", |d| &mut d.content_type)
-                            .default_field("Aid", |d| &mut d.aid)
-                            .default_field("Talk Pattern", |d| &mut d.talk_pattern)
-                            .field("MID", |ui, d| {
-                                msbt_key_value_singleline!(ui, state, "hub", d.main_label)
-                            })
-                            .field("Help", |ui, d| {
-                                msbt_key_value_multiline!(ui, state, "hub", d.help_label)
-                            })
-                            .default_field("Script Name", |d| &mut d.script_name)
-                            .default_field("Access Type", |d| &mut d.access_type)
-                            .default_field("Idle Body Name", |d| &mut d.idle_body_name)
-                            .default_field("Idle Face Name", |d| &mut d.idle_face_name)
-                            .default_field("Idle Type", |d| &mut d.idle_type)
-                            .default_field("Disabled Anim", |d| &mut d.disabled_anim)
-                            .default_field("Disabled Talk", |d| &mut d.disabled_talk)
-                            .default_field("Ignore Story", |d| &mut d.ignore_story)
-                            .default_field("Bind", |d| &mut d.bind)
-                            .default_field("Dispos Type", |d| &mut d.dispos_type)
-                            .default_field("Access Angle", |d| &mut d.access_angle)
-                            .default_field("Move Name", |d| &mut d.move_name)
-                            .field("Area", |ui, d| {
-                                state.hub_area_data.read(|data| {
-                                    ModelDropDown::default()
-                                        .transform(&area_key_transform, &area_key_reverse_transform)
-                                        .show(ui, data, state, &mut d.area)
-                                })
-                            })
-                            .default_field("Layer", |d| &mut d.layer)
-                            .default_field("Disabled Mini Map", |d| &mut d.disabled_mini_map)
-                            .default_field("Weight", |d| &mut d.weight)
-                            .default_field("Optimize Type", |d| &mut d.optimize_type)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.spawns_content.content(ctx, &self.spawns, state);
             }
             Tab::SpawnSet => {
                 self.random_sets_content
                     .left_panel(ctx, &self.random_sets, state);
-                self.random_sets.write(|data| {
-                    self.random_sets_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("random_sets", selection)
-                                .new_section("")
-                                .field("Id", |ui, d| {
-                                    ui.vertical(|ui| {
-                                        let mut response = state.person.read(|data| {
-                                            ui.add(model_drop_down(data, state, &mut d.id))
-                                        });
-                                        response |= state.god.read(|data| {
-                                            ui.add(model_drop_down(data, state, &mut d.id))
-                                        });
-                                        response |= state.item.read(|data| {
-                                            ui.add(model_drop_down(data, state, &mut d.id))
-                                        });
-                                        response |= ui.text_edit_singleline(&mut d.id);
-                                        response
-                                    })
-                                    .inner
-                                })
-                                .default_field("Rate", |d| &mut d.rate)
-                                .default_field("Count", |d| &mut d.count)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.random_sets_content
+                    .content(ctx, &self.random_sets, state);
             }
             Tab::UnityBehavior => {
                 self.unity_behavior_content
                     .left_panel(ctx, &self.unity_behavior, state);
-                self.unity_behavior.write(|data| {
-                    self.unity_behavior_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("unity_behavior", selection)
-                                .new_section("")
-                                .default_field("Move Type", |d| &mut d.move_type)
-                                .default_field("Locator", |d| &mut d.locator)
-                                .default_field("Body Name", |d| &mut d.body_name)
-                                .default_field("Face Name", |d| &mut d.face_name)
-                                .default_field("Is Turn", |d| &mut d.is_turn)
-                                .default_field("Move Sec", |d| &mut d.move_sec)
-                                .default_field("Move Speed", |d| &mut d.move_speed)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.unity_behavior_content
+                    .content(ctx, &self.unity_behavior, state);
             }
             Tab::Fortune => {
                 self.fortune_telling_data_content.left_panel(
@@ -1074,163 +909,38 @@ impl HubAreaEditor {
                     &self.fortune_telling_data,
                     state,
                 );
-                self.fortune_telling_data.write(|data| {
-                    self.fortune_telling_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("fortune_telling_data", selection)
-                                .new_section("")
-                                .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                                .default_field("Texture Name", |d| &mut d.texture_name)
-                                .default_field("Primary Text", |d| &mut d.primary_text)
-                                .default_field("Primary Text Ex", |d| &mut d.primary_text_ex)
-                                .default_field("Reverse Text", |d| &mut d.reverse_text)
-                                .default_field("Reverse Text Ex", |d| &mut d.reverse_text_ex)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.fortune_telling_data_content
+                    .content(ctx, &self.fortune_telling_data, state);
             }
             Tab::Nation => {
                 self.nation_data_content
                     .left_panel(ctx, &self.nation_data, state);
-                self.nation_data.write(|data| {
-                    self.nation_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("nation_data", selection)
-                                .new_section("")
-                                .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                                .field("Name", |ui, d| {
-                                    msbt_key_value_singleline!(ui, state, "hub", d.name)
-                                })
-                                .field("Chapter", |ui, d| {
-                                    state.chapter.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.chapter))
-                                    })
-                                })
-                                .default_field("Is Not Level", |d| &mut d.is_not_level)
-                                .default_field("Symbol Texture", |d| &mut d.symbol_texture)
-                                .default_field("Level Info", |d| &mut d.level_info)
-                                .default_field("Foodstuff Info", |d| &mut d.foodstuff_info)
-                                .default_field("Animal Info", |d| &mut d.animal_info)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.nation_data_content
+                    .content(ctx, &self.nation_data, state);
             }
             Tab::MaterialBonus => {
                 self.material_bonuses_content
                     .left_panel(ctx, &self.material_bonuses, state);
-                self.material_bonuses.write(|data| {
-                    self.material_bonuses_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("material_bonuses", selection)
-                                .new_section("")
-                                .default_field("Cost", |d| &mut d.cost)
-                                .field("Bonus Name", |ui, d| {
-                                    msbt_key_value_singleline!(ui, state, "hub", d.bonus_name)
-                                })
-                                .default_field("Bonus Item", |d| &mut d.bonus_item)
-                                .default_field("Bonus Food", |d| &mut d.bonus_food)
-                                .default_field("Bonus Animal", |d| &mut d.bonus_animal)
-                                .field("Bonus Accessory", |ui, d| {
-                                    state.accessory.read(|data| {
-                                        ui.add(model_drop_down(
-                                            data,
-                                            state,
-                                            &mut d.bonus_accessory_aid,
-                                        ))
-                                    })
-                                })
-                                .field("Bonus Iron", |ui, d| {
-                                    iron_field_i8(ui, state, &mut d.bonus_iron)
-                                })
-                                .field("Bonus Steel", |ui, d| {
-                                    steel_field(ui, state, &mut d.bonus_steel)
-                                })
-                                .field("Bonus Silver", |ui, d| {
-                                    silver_field(ui, state, &mut d.bonus_silver)
-                                })
-                                .field("Bonus Bond Fragments", |ui, d| {
-                                    bond_fragment_field(ui, state, &mut d.bonus_piece_of_bond)
-                                })
-                                .field("Iron", |ui, d| iron_field_i8(ui, state, &mut d.iron))
-                                .field("Steel", |ui, d| steel_field(ui, state, &mut d.steel))
-                                .field("Silver", |ui, d| silver_field(ui, state, &mut d.silver))
-                                .field("Bond Fragments", |ui, d| {
-                                    bond_fragment_field(ui, state, &mut d.piece_of_bond)
-                                })
-                                .default_field("Gold Enemy Rate", |d| &mut d.gold_enemy_rate)
-                                .default_field("Exp Enemy Rate", |d| &mut d.exp_enemy_rate)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.material_bonuses_content
+                    .content(ctx, &self.material_bonuses, state);
             }
             Tab::IngredientBonus => {
                 self.ingredient_bonuses_content
                     .left_panel(ctx, &self.ingredient_bonuses, state);
-                self.ingredient_bonuses.write(|data| {
-                    self.ingredient_bonuses_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("ingredient_bonuses", selection)
-                                .new_section("")
-                                .field("Foodstuff", |ui, d| {
-                                    state.item.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.foodstuff))
-                                    })
-                                })
-                                .default_field("Lv 1", |d| &mut d.lv_1)
-                                .default_field("Lv 2", |d| &mut d.lv_2)
-                                .default_field("Lv 3", |d| &mut d.lv_3)
-                                .default_field("Lv 4", |d| &mut d.lv_4)
-                                .default_field("Lv 5", |d| &mut d.lv_5)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.ingredient_bonuses_content
+                    .content(ctx, &self.ingredient_bonuses, state);
             }
             Tab::AnimalBonus => {
                 self.animal_bonuses_content
                     .left_panel(ctx, &self.animal_bonuses, state);
-                self.animal_bonuses.write(|data| {
-                    self.animal_bonuses_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("animal_bonuses", selection)
-                                .new_section("")
-                                .field("Animal", |ui, d| {
-                                    state.animal.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.anid))
-                                    })
-                                })
-                                .default_field("Appear Rate Lv 1", |d| &mut d.appear_rate_lv_1)
-                                .default_field("Appear Rate Lv 2", |d| &mut d.appear_rate_lv_2)
-                                .default_field("Appear Rate Lv 3", |d| &mut d.appear_rate_lv_3)
-                                .default_field("Appear Rate Lv 4", |d| &mut d.appear_rate_lv_4)
-                                .default_field("Appear Rate Lv 5", |d| &mut d.appear_rate_lv_5)
-                                .default_field("Capture Level", |d| &mut d.capture_level)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.animal_bonuses_content
+                    .content(ctx, &self.animal_bonuses, state);
             }
             Tab::ItemBonus => {
                 self.item_bonuses_content
                     .left_panel(ctx, &self.item_bonuses, state);
-                self.item_bonuses.write(|data| {
-                    self.item_bonuses_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("item_bonuses", selection)
-                                .new_section("")
-                                .field("Item", |ui, d| {
-                                    state.item.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.item_id))
-                                    })
-                                })
-                                .default_field("Num", |d| &mut d.num)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.item_bonuses_content
+                    .content(ctx, &self.item_bonuses, state);
             }
             Tab::IngredientBonusGroup => {
                 self.ingredient_bonus_groups_content.left_panel(
@@ -1238,169 +948,493 @@ impl HubAreaEditor {
                     &self.ingredient_bonus_groups,
                     state,
                 );
-                self.ingredient_bonus_groups.write(|data| {
-                    self.ingredient_bonus_groups_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("ingredient_bonus_groups", selection)
-                                .new_section("")
-                                .field("Foodstuff", |ui, d| {
-                                    state.item.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.foodstuff))
-                                    })
-                                })
-                                .default_field("Num", |d| &mut d.num)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.ingredient_bonus_groups_content.content(
+                    ctx,
+                    &self.ingredient_bonus_groups,
+                    state,
+                );
             }
             Tab::AnimalBonusGroup => {
                 self.animal_bonus_groups_content
                     .left_panel(ctx, &self.animal_bonus_groups, state);
-                self.animal_bonus_groups.write(|data| {
-                    self.animal_bonus_groups_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("animal_bonus_groups", selection)
-                                .new_section("")
-                                .field("Animal", |ui, d| {
-                                    state.animal.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.animal_id))
-                                    })
-                                })
-                                .default_field("Num", |d| &mut d.num)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.animal_bonus_groups_content
+                    .content(ctx, &self.animal_bonus_groups, state);
             }
             Tab::MapIconData => {
                 self.map_icon_data_content
                     .left_panel(ctx, &self.map_icon_data, state);
-                self.map_icon_data.write(|data| {
-                    self.map_icon_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("map_icon_data", selection)
-                                .new_section("")
-                                .field("Dispos Name", |ui, d| ui.add(id_field(&mut d.dispos_name)))
-                                .default_field("Icon Name", |d| &mut d.icon_name)
-                                .default_field("Large Scale", |d| &mut d.large_scale)
-                                .default_field("Small Scale", |d| &mut d.small_scale)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.map_icon_data_content
+                    .content(ctx, &self.map_icon_data, state);
             }
             Tab::MyRoomData => {
                 self.my_room_data_content
                     .left_panel(ctx, &self.my_room_data, state);
-                self.my_room_data.write(|data| {
-                    self.my_room_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("my_room_data", selection)
-                                .new_section("")
-                                .field("PID", |ui, d| ui.add(id_field(&mut d.pid)))
-                                .default_field("C 1", |d| &mut d.c_1)
-                                .default_field("C 2", |d| &mut d.c_2)
-                                .default_field("B 1", |d| &mut d.b_1)
-                                .default_field("B 2", |d| &mut d.b_2)
-                                .default_field("A 1", |d| &mut d.a_1)
-                                .default_field("A 2", |d| &mut d.a_2)
-                                .default_field("S 1", |d| &mut d.s_1)
-                                .default_field("S 2", |d| &mut d.s_2)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.my_room_data_content
+                    .content(ctx, &self.my_room_data, state);
             }
             Tab::TalkData => {
                 self.talk_data_content
                     .left_panel(ctx, &self.talk_data, state);
-                self.talk_data.write(|data| {
-                    self.talk_data_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("talk_data", selection)
-                            .new_section("")
-                            .field("KRID", |ui, d| ui.add(id_field(&mut d.krid)))
-                            .default_field("Count", |d| &mut d.count)
-                            .default_field("Args 0", |d| &mut d.args_0)
-                            .default_field("Args 1", |d| &mut d.args_1)
-                            .field("Item", |ui, d| {
-                                state
-                                    .item
-                                    .read(|data| ui.add(model_drop_down(data, state, &mut d.item)))
-                            })
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.talk_data_content.content(ctx, &self.talk_data, state);
             }
             Tab::TalkRelativeData => {
                 self.relative_data_content
                     .left_panel(ctx, &self.relative_data, state);
-                self.relative_data.write(|data| {
-                    self.relative_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("relative_data", selection)
-                                .new_section("")
-                                .field("PID", |ui, d| ui.add(id_field(&mut d.pid)))
-                                .field("Person 1", |ui, d| {
-                                    state.person.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.pid_1))
-                                    })
-                                })
-                                .field("Person 2", |ui, d| {
-                                    state.person.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.pid_2))
-                                    })
-                                })
-                                .field("Person 3", |ui, d| {
-                                    state.person.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.pid_3))
-                                    })
-                                })
-                                .field("Person 4", |ui, d| {
-                                    state.person.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.pid_4))
-                                    })
-                                })
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.relative_data_content
+                    .content(ctx, &self.relative_data, state);
             }
             Tab::TalkFacilityData => {
                 self.talk_facility_data_content
                     .left_panel(ctx, &self.talk_facility_data, state);
-                self.talk_facility_data.write(|data| {
-                    self.talk_facility_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("talk_facility_data", selection)
-                                .new_section("")
-                                .field("Pattern", |ui, d| ui.add(id_field(&mut d.pattern)))
-                                .field("Person", |ui, d| {
-                                    state.person.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.pid))
-                                    })
-                                })
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.talk_facility_data_content
+                    .content(ctx, &self.talk_facility_data, state);
             }
             Tab::CrystalData => {
                 self.crystal_data_content
                     .left_panel(ctx, &self.crystal_data, state);
-                self.crystal_data.write(|data| {
-                    self.crystal_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("crystal_data", selection)
-                                .new_section("")
-                                .field("CID", |ui, d| ui.add(id_field(&mut d.cid)))
-                                .default_field("Count", |d| &mut d.count)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.crystal_data_content
+                    .content(ctx, &self.crystal_data, state);
             }
         }
     }
 }
+
+impl RowForm for HubAreaData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("hub_area_data", selection)
+            .new_section("")
+            .field("AID", |ui, d| ui.add(id_field(&mut d.aid)))
+            .field("MID", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "hub", d.mid)
+            })
+            .field("Help", |ui, d| {
+                msbt_key_value_multiline!(ui, state, "hub", d.mid_h)
+            })
+            .field("Condition Chapter", |ui, d| {
+                state
+                    .chapter
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.condition_cid)))
+            })
+            .default_field("Scene Name", |d| &mut d.scene_name)
+            .default_field("Locator Name", |d| &mut d.locator_name)
+            .default_field("Map Point No", |d| &mut d.map_point_no)
+            .field("Facilities", |ui, d| {
+                state.hub_facility_data.read(|data| {
+                    ui.add(editable_list(&mut d.facility_aid_list, |_, d, ui| {
+                        ui.add(model_drop_down(data, state, d))
+                    }))
+                })
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubFacilityData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("hub_facility_data", selection)
+            .new_section("")
+            .field("AID", |ui, d| ui.add(id_field(&mut d.aid)))
+            .field("MID", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "hub", d.mid)
+            })
+            .field("Condition Chapter", |ui, d| {
+                state
+                    .chapter
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.condition_cid)))
+            })
+            .default_field("Icon Name", |d| &mut d.icon_name)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubDemoData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("hub_demo_data", selection)
+            .new_section("")
+            .field("Locator", |ui, d| ui.add(id_field(&mut d.locator)))
+            .field("MID", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "hub", d.mid)
+            })
+            .default_field("Camera Name", |d| &mut d.camera_name)
+            .default_field("Tutorial", |d| &mut d.tutorial)
+            .default_field("Condition", |d| &mut d.condition)
+            .default_field("Timezone", |d| &mut d.timezone)
+            .default_field("Flag Name", |d| &mut d.flag_name)
+            .default_field("Manual Culling Name", |d| &mut d.manual_culling_name)
+            .default_field("Lod Bias", |d| &mut d.lod_bias)
+            .default_field("Is Disabled Lod Crossfade Anime", |d| {
+                &mut d.is_disabled_lod_crossfade_anime
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubSpawn {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("spawns", selection)
+            .new_section("")
+            .field("HID", |ui, d| ui.add(id_field(&mut d.hid)))
+            .default_field("Locator", |d| &mut d.locator)
+            .default_field("Parent Locator", |d| &mut d.parent_locator)
+            .default_field("Is Must Child", |d| &mut d.is_must_child)
+            .default_field("Fade Distance", |d| &mut d.fade_distance)
+            .default_field("Priority", |d| &mut d.priority)
+            .field("Chapter", |ui, d| {
+                state
+                    .chapter
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.chapter)))
+            })
+            .default_field("Phase", |d| &mut d.phase)
+            .default_field("Timezone Flag", |d| &mut d.timezone_flag)
+            .default_field("Flag Name", |d| &mut d.flag_name)
+            .default_field("Any Condition", |d| &mut d.any_condition)
+            .default_field("Content Type", |d| &mut d.content_type)
+            .default_field("Aid", |d| &mut d.aid)
+            .default_field("Talk Pattern", |d| &mut d.talk_pattern)
+            .field("MID", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "hub", d.main_label)
+            })
+            .field("Help", |ui, d| {
+                msbt_key_value_multiline!(ui, state, "hub", d.help_label)
+            })
+            .default_field("Script Name", |d| &mut d.script_name)
+            .default_field("Access Type", |d| &mut d.access_type)
+            .default_field("Idle Body Name", |d| &mut d.idle_body_name)
+            .default_field("Idle Face Name", |d| &mut d.idle_face_name)
+            .default_field("Idle Type", |d| &mut d.idle_type)
+            .default_field("Disabled Anim", |d| &mut d.disabled_anim)
+            .default_field("Disabled Talk", |d| &mut d.disabled_talk)
+            .default_field("Ignore Story", |d| &mut d.ignore_story)
+            .default_field("Bind", |d| &mut d.bind)
+            .default_field("Dispos Type", |d| &mut d.dispos_type)
+            .default_field("Access Angle", |d| &mut d.access_angle)
+            .default_field("Move Name", |d| &mut d.move_name)
+            .field("Area", |ui, d| {
+                state.hub_area_data.read(|data| {
+                    ModelDropDown::default()
+                        .transform(&area_key_transform, &area_key_reverse_transform)
+                        .show(ui, data, state, &mut d.area)
+                })
+            })
+            .default_field("Layer", |d| &mut d.layer)
+            .default_field("Disabled Mini Map", |d| &mut d.disabled_mini_map)
+            .default_field("Weight", |d| &mut d.weight)
+            .default_field("Optimize Type", |d| &mut d.optimize_type)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubSpawnRandomSet {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("random_sets", selection)
+            .new_section("")
+            .field("Id", |ui, d| {
+                ui.vertical(|ui| {
+                    let mut response = state
+                        .person
+                        .read(|data| ui.add(model_drop_down(data, state, &mut d.id)));
+                    response |= state
+                        .god
+                        .read(|data| ui.add(model_drop_down(data, state, &mut d.id)));
+                    response |= state
+                        .item
+                        .read(|data| ui.add(model_drop_down(data, state, &mut d.id)));
+                    response |= ui.text_edit_singleline(&mut d.id);
+                    response
+                })
+                .inner
+            })
+            .default_field("Rate", |d| &mut d.rate)
+            .default_field("Count", |d| &mut d.count)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubUnityBehavior {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("unity_behavior", selection)
+            .new_section("")
+            .default_field("Move Type", |d| &mut d.move_type)
+            .default_field("Locator", |d| &mut d.locator)
+            .default_field("Body Name", |d| &mut d.body_name)
+            .default_field("Face Name", |d| &mut d.face_name)
+            .default_field("Is Turn", |d| &mut d.is_turn)
+            .default_field("Move Sec", |d| &mut d.move_sec)
+            .default_field("Move Speed", |d| &mut d.move_speed)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubFortuneTellingData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("fortune_telling_data", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .default_field("Texture Name", |d| &mut d.texture_name)
+            .default_field("Primary Text", |d| &mut d.primary_text)
+            .default_field("Primary Text Ex", |d| &mut d.primary_text_ex)
+            .default_field("Reverse Text", |d| &mut d.reverse_text)
+            .default_field("Reverse Text Ex", |d| &mut d.reverse_text_ex)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubNationData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("nation_data", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .field("Name", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "hub", d.name)
+            })
+            .field("Chapter", |ui, d| {
+                state
+                    .chapter
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.chapter)))
+            })
+            .default_field("Is Not Level", |d| &mut d.is_not_level)
+            .default_field("Symbol Texture", |d| &mut d.symbol_texture)
+            .default_field("Level Info", |d| &mut d.level_info)
+            .default_field("Foodstuff Info", |d| &mut d.foodstuff_info)
+            .default_field("Animal Info", |d| &mut d.animal_info)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubMaterialBonus {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("material_bonuses", selection)
+            .new_section("")
+            .default_field("Cost", |d| &mut d.cost)
+            .field("Bonus Name", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "hub", d.bonus_name)
+            })
+            .default_field("Bonus Item", |d| &mut d.bonus_item)
+            .default_field("Bonus Food", |d| &mut d.bonus_food)
+            .default_field("Bonus Animal", |d| &mut d.bonus_animal)
+            .field("Bonus Accessory", |ui, d| {
+                state
+                    .accessory
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.bonus_accessory_aid)))
+            })
+            .field("Bonus Iron", |ui, d| {
+                iron_field_i8(ui, state, &mut d.bonus_iron)
+            })
+            .field("Bonus Steel", |ui, d| {
+                steel_field(ui, state, &mut d.bonus_steel)
+            })
+            .field("Bonus Silver", |ui, d| {
+                silver_field(ui, state, &mut d.bonus_silver)
+            })
+            .field("Bonus Bond Fragments", |ui, d| {
+                bond_fragment_field(ui, state, &mut d.bonus_piece_of_bond)
+            })
+            .field("Iron", |ui, d| iron_field_i8(ui, state, &mut d.iron))
+            .field("Steel", |ui, d| steel_field(ui, state, &mut d.steel))
+            .field("Silver", |ui, d| silver_field(ui, state, &mut d.silver))
+            .field("Bond Fragments", |ui, d| {
+                bond_fragment_field(ui, state, &mut d.piece_of_bond)
+            })
+            .default_field("Gold Enemy Rate", |d| &mut d.gold_enemy_rate)
+            .default_field("Exp Enemy Rate", |d| &mut d.exp_enemy_rate)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubIngredientBonus {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("ingredient_bonuses", selection)
+            .new_section("")
+            .field("Foodstuff", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.foodstuff)))
+            })
+            .default_field("Lv 1", |d| &mut d.lv_1)
+            .default_field("Lv 2", |d| &mut d.lv_2)
+            .default_field("Lv 3", |d| &mut d.lv_3)
+            .default_field("Lv 4", |d| &mut d.lv_4)
+            .default_field("Lv 5", |d| &mut d.lv_5)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubAnimalBonus {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("animal_bonuses", selection)
+            .new_section("")
+            .field("Animal", |ui, d| {
+                state
+                    .animal
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.anid)))
+            })
+            .default_field("Appear Rate Lv 1", |d| &mut d.appear_rate_lv_1)
+            .default_field("Appear Rate Lv 2", |d| &mut d.appear_rate_lv_2)
+            .default_field("Appear Rate Lv 3", |d| &mut d.appear_rate_lv_3)
+            .default_field("Appear Rate Lv 4", |d| &mut d.appear_rate_lv_4)
+            .default_field("Appear Rate Lv 5", |d| &mut d.appear_rate_lv_5)
+            .default_field("Capture Level", |d| &mut d.capture_level)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubItemBonus {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("item_bonuses", selection)
+            .new_section("")
+            .field("Item", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.item_id)))
+            })
+            .default_field("Num", |d| &mut d.num)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubIngredientBonusGroup {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("ingredient_bonus_groups", selection)
+            .new_section("")
+            .field("Foodstuff", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.foodstuff)))
+            })
+            .default_field("Num", |d| &mut d.num)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubAnimalBonusGroup {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("animal_bonus_groups", selection)
+            .new_section("")
+            .field("Animal", |ui, d| {
+                state
+                    .animal
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.animal_id)))
+            })
+            .default_field("Num", |d| &mut d.num)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubMapIconData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("map_icon_data", selection)
+            .new_section("")
+            .field("Dispos Name", |ui, d| ui.add(id_field(&mut d.dispos_name)))
+            .default_field("Icon Name", |d| &mut d.icon_name)
+            .default_field("Large Scale", |d| &mut d.large_scale)
+            .default_field("Small Scale", |d| &mut d.small_scale)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubMyRoomData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("my_room_data", selection)
+            .new_section("")
+            .field("PID", |ui, d| ui.add(id_field(&mut d.pid)))
+            .default_field("C 1", |d| &mut d.c_1)
+            .default_field("C 2", |d| &mut d.c_2)
+            .default_field("B 1", |d| &mut d.b_1)
+            .default_field("B 2", |d| &mut d.b_2)
+            .default_field("A 1", |d| &mut d.a_1)
+            .default_field("A 2", |d| &mut d.a_2)
+            .default_field("S 1", |d| &mut d.s_1)
+            .default_field("S 2", |d| &mut d.s_2)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubTalkData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("talk_data", selection)
+            .new_section("")
+            .field("KRID", |ui, d| ui.add(id_field(&mut d.krid)))
+            .default_field("Count", |d| &mut d.count)
+            .default_field("Args 0", |d| &mut d.args_0)
+            .default_field("Args 1", |d| &mut d.args_1)
+            .field("Item", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.item)))
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubTalkRelativeData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("relative_data", selection)
+            .new_section("")
+            .field("PID", |ui, d| ui.add(id_field(&mut d.pid)))
+            .field("Person 1", |ui, d| {
+                state
+                    .person
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.pid_1)))
+            })
+            .field("Person 2", |ui, d| {
+                state
+                    .person
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.pid_2)))
+            })
+            .field("Person 3", |ui, d| {
+                state
+                    .person
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.pid_3)))
+            })
+            .field("Person 4", |ui, d| {
+                state
+                    .person
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.pid_4)))
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubTalkFacilityData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("talk_facility_data", selection)
+            .new_section("")
+            .field("Pattern", |ui, d| ui.add(id_field(&mut d.pattern)))
+            .field("Person", |ui, d| {
+                state
+                    .person
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.pid)))
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for HubCrystalData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("crystal_data", selection)
+            .new_section("")
+            .field("CID", |ui, d| ui.add(id_field(&mut d.cid)))
+            .default_field("Count", |d| &mut d.count)
+            .show(ui)
+            .changed()
+    }
+}
@@ -5,7 +5,7 @@ use indexmap::IndexMap;
 
 use crate::{
     editor_tab_strip, model_drop_down, sheet_retriever, EditorState, GroupEditorContent,
-    GroupViewItem, PropertyGrid, ViewItem,
+    GroupViewItem, PropertyGrid, RowForm, ViewItem,
 };
 
 use astra_types::{Item, KillBonus1, KillBonus2, KillBonusBook};
@@ -105,50 +105,54 @@ impl KillBonusEditor {
             Tab::KillBonus1 => {
                 self.kill_bonuses_1_content
                     .left_panel(ctx, &self.kill_bonuses_1, state);
-                self.kill_bonuses_1.write(|data| {
-                    self.kill_bonuses_1_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("kill_bonuses_1", selection)
-                                .new_section("")
-                                .field("Item", |ui, d| {
-                                    state.item.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.iid))
-                                    })
-                                })
-                                .default_field("Rate", |d| &mut d.rate)
-                                .field("Chapter", |ui, d| {
-                                    state.chapter.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.cid))
-                                    })
-                                })
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.kill_bonuses_1_content
+                    .content(ctx, &self.kill_bonuses_1, state);
             }
 
             Tab::KillBonus2 => {
                 self.kill_bonuses_2_content
                     .left_panel(ctx, &self.kill_bonuses_2, state);
-                self.kill_bonuses_2.write(|data| {
-                    self.kill_bonuses_2_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("kill_bonuses_2", selection)
-                                .new_section("")
-                                .default_field("Kind", |d| &mut d.kind)
-                                .default_field("Value", |d| &mut d.value)
-                                .default_field("Rate", |d| &mut d.rate)
-                                .default_field("Flag", |d| &mut d.flag)
-                                .field("Chapter", |ui, d| {
-                                    state.chapter.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.cid))
-                                    })
-                                })
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.kill_bonuses_2_content
+                    .content(ctx, &self.kill_bonuses_2, state);
             }
         }
     }
 }
+
+impl RowForm for KillBonus1 {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("kill_bonuses_1", selection)
+            .new_section("")
+            .field("Item", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.iid)))
+            })
+            .default_field("Rate", |d| &mut d.rate)
+            .field("Chapter", |ui, d| {
+                state
+                    .chapter
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.cid)))
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for KillBonus2 {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("kill_bonuses_2", selection)
+            .new_section("")
+            .default_field("Kind", |d| &mut d.kind)
+            .default_field("Value", |d| &mut d.value)
+            .default_field("Rate", |d| &mut d.rate)
+            .default_field("Flag", |d| &mut d.flag)
+            .field("Chapter", |ui, d| {
+                state
+                    .chapter
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.cid)))
+            })
+            .show(ui)
+            .changed()
+    }
+}
@@ -2,9 +2,11 @@ use std::borrow::Cow;
 
 use indexmap::IndexMap;
 
+use egui::Ui;
+
 use crate::{
     model_drop_down, sheet_retriever, EditorState, GroupEditorContent, GroupViewItem, PropertyGrid,
-    ViewItem,
+    RowForm, ViewItem,
 };
 
 use astra_types::{LaterTalkBook, Person, PostBattleConversation};
@@ -71,22 +73,24 @@ impl LaterTalkEditor {
             state,
         );
 
-        self.post_battle_conversations.write(|data| {
-            self.post_battle_conversations_content
-                .content(ctx, data, |ui, selection| {
-                    PropertyGrid::new("post_battle_conversations", selection)
-                        .new_section("")
-                        .field("Person", |ui, d| {
-                            state
-                                .person
-                                .read(|data| ui.add(model_drop_down(data, state, &mut d.person)))
-                        })
-                        .default_field("Field", |d| &mut d.field)
-                        .default_field("Back Degree", |d| &mut d.back_degree)
-                        .default_field("Light Degree", |d| &mut d.light_degree)
-                        .show(ui)
-                        .changed()
-                })
-        });
+        self.post_battle_conversations_content
+            .content(ctx, &self.post_battle_conversations, state);
+    }
+}
+
+impl RowForm for PostBattleConversation {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("post_battle_conversations", selection)
+            .new_section("")
+            .field("Person", |ui, d| {
+                state
+                    .person
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.person)))
+            })
+            .default_field("Field", |d| &mut d.field)
+            .default_field("Back Degree", |d| &mut d.back_degree)
+            .default_field("Light Degree", |d| &mut d.light_degree)
+            .show(ui)
+            .changed()
     }
 }
@@ -5,7 +5,7 @@ use indexmap::IndexMap;
 
 use crate::{
     editor_tab_strip, id_field, keyed_add_modal_content, model_drop_down, sheet_retriever,
-    CachedView, EditorState, KeyedViewItem, ListEditorContent, PropertyGrid, ViewItem,
+    EditorState, KeyedViewItem, ListEditorContent, PropertyGrid, RowForm, ViewItem,
 };
 
 use astra_types::{MapEditorBook, MapEditorCategory, MapEditorObject};
@@ -91,7 +91,6 @@ pub struct MapEditorEditor {
     tab: Tab,
     objects: MapEditorObjectSheet,
     categories: MapEditorCategorySheet,
-    categories_cache: CachedView<MapEditorCategorySheetRetriever, MapEditorBook, MapEditorCategory>,
     objects_content:
         ListEditorContent<IndexMap<String, MapEditorObject>, MapEditorObject, EditorState>,
     categories_content:
@@ -104,7 +103,6 @@ impl MapEditorEditor {
             tab: Tab::MapEditorObject,
             objects: state.map_editor_objects.clone(),
             categories: state.map_editor_categories.clone(),
-            categories_cache: CachedView::new(state.map_editor_categories.clone(), state),
             objects_content: ListEditorContent::new("objects_editor")
                 .with_add_modal_content(keyed_add_modal_content),
             categories_content: ListEditorContent::new("categories_editor")
@@ -120,45 +118,46 @@ impl MapEditorEditor {
     }
 
     pub fn show(&mut self, ctx: &egui::Context, state: &EditorState) {
-        self.categories_cache.refresh(state);
-
         match self.tab {
             Tab::MapEditorObject => {
                 self.objects_content.left_panel(ctx, &self.objects, state);
-                self.objects.write(|data| {
-                    self.objects_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("objects", selection)
-                            .new_section("")
-                            .field("Object Name", |ui, d| ui.add(id_field(&mut d.object_name)))
-                            .default_field("Sound Event", |d| &mut d.sound_event)
-                            .field("Category", |ui, d| {
-                                ui.add(model_drop_down(
-                                    self.categories_cache.get(),
-                                    &(),
-                                    &mut d.category,
-                                ))
-                            })
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.objects_content.content(ctx, &self.objects, state);
             }
 
             Tab::MapEditorCategory => {
                 self.categories_content
                     .left_panel(ctx, &self.categories, state);
-                self.categories.write(|data| {
-                    self.categories_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("categories", selection)
-                            .new_section("")
-                            .field("Category ID", |ui, d| ui.add(id_field(&mut d.cid)))
-                            .default_field("Count Max", |d| &mut d.count_max)
-                            .default_field("Icon Name", |d| &mut d.icon_name)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.categories_content
+                    .content(ctx, &self.categories, state);
             }
         }
     }
 }
+
+impl RowForm for MapEditorObject {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("objects", selection)
+            .new_section("")
+            .field("Object Name", |ui, d| ui.add(id_field(&mut d.object_name)))
+            .default_field("Sound Event", |d| &mut d.sound_event)
+            .field("Category", |ui, d| {
+                state
+                    .map_editor_categories
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.category)))
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for MapEditorCategory {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("categories", selection)
+            .new_section("")
+            .field("Category ID", |ui, d| ui.add(id_field(&mut d.cid)))
+            .default_field("Count Max", |d| &mut d.count_max)
+            .default_field("Icon Name", |d| &mut d.icon_name)
+            .show(ui)
+            .changed()
+    }
+}
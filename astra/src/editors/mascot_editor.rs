@@ -5,7 +5,7 @@ use indexmap::IndexMap;
 
 use crate::{
     editor_tab_strip, keyed_add_modal_content, model_drop_down, rgb_color_picker, sheet_retriever,
-    DropDownModal, EditorState, KeyedViewItem, ListEditorContent, PropertyGrid, ViewItem,
+    DropDownModal, EditorState, KeyedViewItem, ListEditorContent, PropertyGrid, RowForm, ViewItem,
 };
 
 use astra_types::{
@@ -188,69 +188,79 @@ impl MascotEditor {
             Tab::Accessory => {
                 self.accessory_data_content
                     .left_panel(ctx, &self.accessory_data, state);
-                self.accessory_data.write(|data| {
-                    self.accessory_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("accessory_data", selection)
-                                .new_section("")
-                                .field("Accessory", |ui, d| {
-                                    state.accessory.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.aid))
-                                    })
-                                })
-                                .default_field("Ty", |d| &mut d.ty)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.accessory_data_content
+                    .content(ctx, &self.accessory_data, state);
             }
             Tab::Color => {
                 self.color_data_content
                     .left_panel(ctx, &self.color_data, state);
-                self.color_data.write(|data| {
-                    self.color_data_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("color_data", selection)
-                            .new_section("")
-                            .field("Color", |ui, d| {
-                                ui.add(rgb_color_picker(&mut d.r, &mut d.g, &mut d.b))
-                            })
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.color_data_content
+                    .content(ctx, &self.color_data, state);
             }
             Tab::Param => {
                 self.param_data_content
                     .left_panel(ctx, &self.param_data, state);
-                self.param_data.write(|data| {
-                    self.param_data_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("param_data", selection)
-                            .new_section("")
-                            .default_field("Param Name", |d| &mut d.param_name)
-                            .default_field("Value", |d| &mut d.value)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.param_data_content
+                    .content(ctx, &self.param_data, state);
             }
             Tab::Food => {
                 self.food_data_content
                     .left_panel(ctx, &self.food_data, state);
-                self.food_data.write(|data| {
-                    self.food_data_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("food_data", selection)
-                            .new_section("")
-                            .field("Item", |ui, d| {
-                                state
-                                    .item
-                                    .read(|data| ui.add(model_drop_down(data, state, &mut d.iid)))
-                            })
-                            .default_field("Value", |d| &mut d.value)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.food_data_content.content(ctx, &self.food_data, state);
             }
         }
     }
 }
+
+impl RowForm for MascotAccessoryData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("accessory_data", selection)
+            .new_section("")
+            .field("Accessory", |ui, d| {
+                state
+                    .accessory
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.aid)))
+            })
+            .default_field("Ty", |d| &mut d.ty)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for MascotColorData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("color_data", selection)
+            .new_section("")
+            .field("Color", |ui, d| {
+                ui.add(rgb_color_picker(&mut d.r, &mut d.g, &mut d.b))
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for MascotParamData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("param_data", selection)
+            .new_section("")
+            .default_field("Param Name", |d| &mut d.param_name)
+            .default_field("Value", |d| &mut d.value)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for MascotFoodData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("food_data", selection)
+            .new_section("")
+            .field("Item", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.iid)))
+            })
+            .default_field("Value", |d| &mut d.value)
+            .show(ui)
+            .changed()
+    }
+}
@@ -12,7 +12,7 @@ use crate::{
     editable_list, editor_tab_strip, id_field, keyed_add_modal_content, model_drop_down,
     msbt_key_value_multiline, msbt_key_value_singleline, sheet_retriever, standard_keyed_display,
     EditorState, GroupEditorContent, GroupViewItem, KeyedViewItem, ListEditorContent, PropertyGrid,
-    ViewItem,
+    RowForm, ViewItem,
 };
 
 sheet_retriever!(Amiibo, AmiiboBook, amiibo, IndexMap<String, AmiiboData>);
@@ -286,180 +286,194 @@ impl MiscEditor {
             Tab::Amiibo => {
                 self.amiibo_content.left_panel(ctx, &self.amiibo, &());
 
-                self.amiibo.write(|data| {
-                    self.amiibo_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("amiibo", selection)
-                            .new_section("")
-                            .field("Numbering Id", |ui, d| {
-                                ui.add(id_field(&mut d.numbering_id))
-                            })
-                            .field("Items", |ui, d| {
-                                ui.add(editable_list(&mut d.items, |_, item, ui| {
-                                    state
-                                        .item
-                                        .read(|data| ui.add(model_drop_down(data, state, item)))
-                                }))
-                            })
-                            .default_field("Aid", |d| &mut d.aid)
-                            .default_field("Bgm", |d| &mut d.bgm)
-                            .default_field("Ticket Num", |d| &mut d.ticket_num)
-                            .default_field("Kizuna Num", |d| &mut d.kizuna_num)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.amiibo_content.content(ctx, &self.amiibo, state);
             }
             Tab::Credits => {
                 self.end_roll_data_content
                     .left_panel(ctx, &self.end_roll_data, state);
 
-                self.end_roll_data.write(|data| {
-                    self.end_roll_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("end_roll_data", selection)
-                                .new_section("")
-                                .default_field("Ty", |d| &mut d.ty)
-                                .default_field("Text 1", |d| &mut d.text_1)
-                                .default_field("Text 2", |d| &mut d.text_2)
-                                .default_field("Text 3", |d| &mut d.text_3)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.end_roll_data_content
+                    .content(ctx, &self.end_roll_data, state);
             }
             Tab::GroundAttribute => {
                 self.ground_attributes_content
                     .left_panel(ctx, &self.ground_attributes, state);
 
-                self.ground_attributes.write(|data| {
-                    self.ground_attributes_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("ground_attributes", selection)
-                                .new_section("")
-                                .field("Label", |ui, d| ui.add(id_field(&mut d.label)))
-                                .default_field("Name", |d| &mut d.name)
-                                .default_field("Sound", |d| &mut d.sound)
-                                .default_field("Particle", |d| &mut d.particle)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.ground_attributes_content
+                    .content(ctx, &self.ground_attributes, state);
             }
             Tab::Jukebox => {
                 self.jukebox_data_content
                     .left_panel(ctx, &self.jukebox_data, state);
 
-                self.jukebox_data.write(|data| {
-                    self.jukebox_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("jukebox_data", selection)
-                                .new_section("")
-                                .field("Event Name", |ui, d| ui.add(id_field(&mut d.event_name)))
-                                // TOOD: Figure out what MSBT has this
-                                .default_field("Name", |d| &mut d.name)
-                                .default_field("Condition", |d| &mut d.condition)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.jukebox_data_content
+                    .content(ctx, &self.jukebox_data, state);
             }
             Tab::KeyHelp => {
                 self.key_help_data_content
                     .left_panel(ctx, &self.key_help_data, state);
 
-                self.key_help_data.write(|data| {
-                    self.key_help_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("key_help_data", selection)
-                                .new_section("")
-                                .default_field("Button Index", |d| &mut d.button_index)
-                                .field("MID", |ui, d| {
-                                    msbt_key_value_multiline!(ui, state, "system", d.mid)
-                                })
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.key_help_data_content
+                    .content(ctx, &self.key_help_data, state);
             }
             Tab::MapHistory => {
                 self.history_content.left_panel(ctx, &self.history, state);
 
-                self.history.write(|data| {
-                    self.history_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("history", selection)
-                            .new_section("")
-                            .field("MHID", |ui, d| ui.add(id_field(&mut d.mhid)))
-                            .field("Action", |ui, d| {
-                                msbt_key_value_singleline!(ui, state, "maphistory", d.action)
-                            })
-                            .default_field("Priority", |d| &mut d.priority)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.history_content.content(ctx, &self.history, state);
             }
             Tab::Range => {
                 self.ranges_content.left_panel(ctx, &self.ranges, state);
 
-                self.ranges.write(|data| {
-                    self.ranges_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("ranges", selection)
-                            .new_section("")
-                            .default_field("Value 1", |d| &mut d.value_1)
-                            .default_field("Value 2", |d| &mut d.value_2)
-                            .default_field("Value 3", |d| &mut d.value_3)
-                            .default_field("Value 4", |d| &mut d.value_4)
-                            .default_field("Value 5", |d| &mut d.value_5)
-                            .default_field("Value 6", |d| &mut d.value_6)
-                            .default_field("Value 7", |d| &mut d.value_7)
-                            .default_field("Value 8", |d| &mut d.value_8)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.ranges_content.content(ctx, &self.ranges, state);
             }
             Tab::SoundEvent => {
                 self.sound_events_content
                     .left_panel(ctx, &self.sound_events, state);
 
-                self.sound_events.write(|data| {
-                    self.sound_events_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("sound_events", selection)
-                                .new_section("")
-                                .field("Movie File Name", |ui, d| {
-                                    ui.add(id_field(&mut d.movie_file_name))
-                                })
-                                .default_field("Event Name 1", |d| &mut d.event_name_1)
-                                .default_field("Event Name 2", |d| &mut d.event_name_2)
-                                .default_field("Event Name 3", |d| &mut d.event_name_3)
-                                .default_field("Event Name 4", |d| &mut d.event_name_4)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.sound_events_content
+                    .content(ctx, &self.sound_events, state);
             }
             Tab::Vibration => {
                 self.vibration_data_content
                     .left_panel(ctx, &self.vibration_data, state);
 
-                self.vibration_data.write(|data| {
-                    self.vibration_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("vibration_data", selection)
-                                .new_section("")
-                                .field("Event Name", |ui, d| ui.add(id_field(&mut d.event_name)))
-                                .default_field("Vibration File Name", |d| {
-                                    &mut d.vibration_file_name
-                                })
-                                .default_field("Amplitude Magnitude", |d| {
-                                    &mut d.amplitude_magnitude
-                                })
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.vibration_data_content
+                    .content(ctx, &self.vibration_data, state);
             }
         }
     }
 }
+
+impl RowForm for AmiiboData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("amiibo", selection)
+            .new_section("")
+            .field("Numbering Id", |ui, d| {
+                ui.add(id_field(&mut d.numbering_id))
+            })
+            .field("Items", |ui, d| {
+                ui.add(editable_list(&mut d.items, |_, item, ui| {
+                    state
+                        .item
+                        .read(|data| ui.add(model_drop_down(data, state, item)))
+                }))
+            })
+            .default_field("Aid", |d| &mut d.aid)
+            .default_field("Bgm", |d| &mut d.bgm)
+            .default_field("Ticket Num", |d| &mut d.ticket_num)
+            .default_field("Kizuna Num", |d| &mut d.kizuna_num)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for EndRollData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("end_roll_data", selection)
+            .new_section("")
+            .default_field("Ty", |d| &mut d.ty)
+            .default_field("Text 1", |d| &mut d.text_1)
+            .default_field("Text 2", |d| &mut d.text_2)
+            .default_field("Text 3", |d| &mut d.text_3)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for GroundAttribute {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("ground_attributes", selection)
+            .new_section("")
+            .field("Label", |ui, d| ui.add(id_field(&mut d.label)))
+            .default_field("Name", |d| &mut d.name)
+            .default_field("Sound", |d| &mut d.sound)
+            .default_field("Particle", |d| &mut d.particle)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for JukeboxData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("jukebox_data", selection)
+            .new_section("")
+            .field("Event Name", |ui, d| ui.add(id_field(&mut d.event_name)))
+            // TOOD: Figure out what MSBT has this
+            .default_field("Name", |d| &mut d.name)
+            .default_field("Condition", |d| &mut d.condition)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for KeyHelpData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("key_help_data", selection)
+            .new_section("")
+            .default_field("Button Index", |d| &mut d.button_index)
+            .field("MID", |ui, d| {
+                msbt_key_value_multiline!(ui, state, "system", d.mid)
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for MapHistory {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("history", selection)
+            .new_section("")
+            .field("MHID", |ui, d| ui.add(id_field(&mut d.mhid)))
+            .field("Action", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "maphistory", d.action)
+            })
+            .default_field("Priority", |d| &mut d.priority)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for RangeData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("ranges", selection)
+            .new_section("")
+            .default_field("Value 1", |d| &mut d.value_1)
+            .default_field("Value 2", |d| &mut d.value_2)
+            .default_field("Value 3", |d| &mut d.value_3)
+            .default_field("Value 4", |d| &mut d.value_4)
+            .default_field("Value 5", |d| &mut d.value_5)
+            .default_field("Value 6", |d| &mut d.value_6)
+            .default_field("Value 7", |d| &mut d.value_7)
+            .default_field("Value 8", |d| &mut d.value_8)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for SoundEvent {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("sound_events", selection)
+            .new_section("")
+            .field("Movie File Name", |ui, d| {
+                ui.add(id_field(&mut d.movie_file_name))
+            })
+            .default_field("Event Name 1", |d| &mut d.event_name_1)
+            .default_field("Event Name 2", |d| &mut d.event_name_2)
+            .default_field("Event Name 3", |d| &mut d.event_name_3)
+            .default_field("Event Name 4", |d| &mut d.event_name_4)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for VibrationDefineData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("vibration_data", selection)
+            .new_section("")
+            .field("Event Name", |ui, d| ui.add(id_field(&mut d.event_name)))
+            .default_field("Vibration File Name", |d| &mut d.vibration_file_name)
+            .default_field("Amplitude Magnitude", |d| &mut d.amplitude_magnitude)
+            .show(ui)
+            .changed()
+    }
+}
@@ -7,6 +7,7 @@ mod arena_editor;
 mod asset_table_editor;
 mod calculator_editor;
 mod chapter_editor;
+mod chapter_wizard;
 mod chart_editor;
 mod cobalt_config_editor;
 mod cook_editor;
@@ -39,6 +40,7 @@ mod save_screen;
 mod script_manager;
 mod shop_editor;
 mod skill_editor;
+mod table_editor;
 mod terrain_editor;
 mod text_data_editor;
 mod title_editor;
@@ -53,6 +55,7 @@ pub use arena_editor::*;
 pub use asset_table_editor::*;
 pub use calculator_editor::*;
 pub use chapter_editor::*;
+pub use chapter_wizard::*;
 pub use chart_editor::*;
 pub use cobalt_config_editor::*;
 pub use cook_editor::*;
@@ -85,6 +88,7 @@ pub use save_screen::*;
 pub use script_manager::*;
 pub use shop_editor::*;
 pub use skill_editor::*;
+pub use table_editor::*;
 pub use terrain_editor::*;
 pub use text_data_editor::*;
 pub use title_editor::*;
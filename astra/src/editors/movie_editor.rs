@@ -2,10 +2,12 @@ use std::borrow::Cow;
 
 use indexmap::IndexMap;
 
+use egui::Ui;
+
 use crate::{
     id_field, keyed_add_modal_content, msbt_key_value_multiline, msbt_key_value_singleline,
     sheet_retriever, standard_keyed_display, EditorState, KeyedViewItem, ListEditorContent,
-    PropertyGrid, ViewItem,
+    PropertyGrid, RowForm, ViewItem,
 };
 
 use astra_types::{Movie, MovieBook};
@@ -47,44 +49,46 @@ impl MovieEditor {
     pub fn show(&mut self, ctx: &egui::Context, state: &EditorState) {
         self.movies_content.left_panel(ctx, &self.movies, state);
 
-        self.movies.write(|data| {
-            self.movies_content.content(ctx, data, |ui, selection| {
-                PropertyGrid::new("movies", selection)
-                    .new_section("")
-                    .field("Movie File Name", |ui, d| {
-                        ui.add(id_field(&mut d.movie_file_name))
-                    })
-                    .field("Name", |ui, d| {
-                        msbt_key_value_singleline!(ui, state, "moviename", d.name)
-                    })
-                    .field("Help", |ui, d| {
-                        msbt_key_value_multiline!(ui, state, "moviename", d.help)
-                    })
-                    .default_field("Condition", |d| &mut d.condition)
-                    .default_field("No", |d| &mut d.no)
-                    .default_field("Before Sound Event Name 1", |d| {
-                        &mut d.before_sound_event_name_1
-                    })
-                    .default_field("Before Sound Event Name 2", |d| {
-                        &mut d.before_sound_event_name_2
-                    })
-                    .default_field("Before Sound Event Name 3", |d| {
-                        &mut d.before_sound_event_name_3
-                    })
-                    .default_field("After Sound Event Name 1", |d| {
-                        &mut d.after_sound_event_name_1
-                    })
-                    .default_field("After Sound Event Name 2", |d| {
-                        &mut d.after_sound_event_name_2
-                    })
-                    .default_field("After Sound Event Name 3", |d| {
-                        &mut d.after_sound_event_name_3
-                    })
-                    .default_field("Mess File Name", |d| &mut d.mess_file_name)
-                    .default_field("Dlc Directory Name", |d| &mut d.dlc_directory_name)
-                    .show(ui)
-                    .changed()
+        self.movies_content.content(ctx, &self.movies, state);
+    }
+}
+
+impl RowForm for Movie {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("movies", selection)
+            .new_section("")
+            .field("Movie File Name", |ui, d| {
+                ui.add(id_field(&mut d.movie_file_name))
+            })
+            .field("Name", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "moviename", d.name)
+            })
+            .field("Help", |ui, d| {
+                msbt_key_value_multiline!(ui, state, "moviename", d.help)
+            })
+            .default_field("Condition", |d| &mut d.condition)
+            .default_field("No", |d| &mut d.no)
+            .default_field("Before Sound Event Name 1", |d| {
+                &mut d.before_sound_event_name_1
+            })
+            .default_field("Before Sound Event Name 2", |d| {
+                &mut d.before_sound_event_name_2
+            })
+            .default_field("Before Sound Event Name 3", |d| {
+                &mut d.before_sound_event_name_3
+            })
+            .default_field("After Sound Event Name 1", |d| {
+                &mut d.after_sound_event_name_1
+            })
+            .default_field("After Sound Event Name 2", |d| {
+                &mut d.after_sound_event_name_2
+            })
+            .default_field("After Sound Event Name 3", |d| {
+                &mut d.after_sound_event_name_3
             })
-        });
+            .default_field("Mess File Name", |d| &mut d.mess_file_name)
+            .default_field("Dlc Directory Name", |d| &mut d.dlc_directory_name)
+            .show(ui)
+            .changed()
     }
 }
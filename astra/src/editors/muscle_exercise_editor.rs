@@ -5,7 +5,8 @@ use indexmap::IndexMap;
 
 use crate::{
     editor_tab_strip, id_field, keyed_add_modal_content, sheet_retriever, EditorState,
-    GroupEditorContent, GroupViewItem, KeyedViewItem, ListEditorContent, PropertyGrid, ViewItem,
+    GroupEditorContent, GroupViewItem, KeyedViewItem, ListEditorContent, PropertyGrid, RowForm,
+    ViewItem,
 };
 
 use astra_types::{
@@ -314,226 +315,241 @@ impl MuscleExerciseDataEditor {
             Tab::Difficulty => {
                 self.difficulty_content
                     .left_panel(ctx, &self.difficulty, state);
-                self.difficulty.write(|data| {
-                    self.difficulty_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("difficulty", selection)
-                            .new_section("")
-                            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                            .default_field("Exercise Type", |d| &mut d.exercise_type)
-                            .default_field("Level", |d| &mut d.level)
-                            .default_field("Good Score", |d| &mut d.good_score)
-                            .default_field("Perfect Score", |d| &mut d.perfect_score)
-                            .default_field("Target Score", |d| &mut d.target_score)
-                            .default_field("Endless Goal Count", |d| &mut d.endless_goal_count)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.difficulty_content
+                    .content(ctx, &self.difficulty, state);
             }
 
             Tab::Setup => {
                 self.setups_content.left_panel(ctx, &self.setups, state);
-                self.setups.write(|data| {
-                    self.setups_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("setups", selection)
-                            .new_section("")
-                            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                            .default_field("Level", |d| &mut d.level)
-                            .default_field("Area P Center", |d| &mut d.area_p_center)
-                            .default_field("Area P Radius", |d| &mut d.area_p_radius)
-                            .default_field("Area G Center", |d| &mut d.area_g_center)
-                            .default_field("Area G Radius", |d| &mut d.area_g_radius)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.setups_content.content(ctx, &self.setups, state);
             }
 
             Tab::PrizeData => {
                 self.prizes_content.left_panel(ctx, &self.prizes, state);
-                self.prizes.write(|data| {
-                    self.prizes_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("prizes", selection)
-                            .new_section("")
-                            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                            .default_field("Exercise Type", |d| &mut d.exercise_type)
-                            .default_field("Bonus Sss", |d| &mut d.bonus_sss)
-                            .default_field("Bond Sss", |d| &mut d.bond_sss)
-                            .default_field("Border Sss", |d| &mut d.border_sss)
-                            .default_field("Bonus Ss", |d| &mut d.bonus_ss)
-                            .default_field("Bond Ss", |d| &mut d.bond_ss)
-                            .default_field("Border Ss", |d| &mut d.border_ss)
-                            .default_field("Bonus S", |d| &mut d.bonus_s)
-                            .default_field("Bond S", |d| &mut d.bond_s)
-                            .default_field("Border S", |d| &mut d.border_s)
-                            .default_field("Bonus A", |d| &mut d.bonus_a)
-                            .default_field("Bond A", |d| &mut d.bond_a)
-                            .default_field("Border A", |d| &mut d.border_a)
-                            .default_field("Bonus B", |d| &mut d.bonus_b)
-                            .default_field("Bond B", |d| &mut d.bond_b)
-                            .default_field("Border B", |d| &mut d.border_b)
-                            .default_field("Bonus C", |d| &mut d.bonus_c)
-                            .default_field("Bond C", |d| &mut d.bond_c)
-                            .default_field("Border C", |d| &mut d.border_c)
-                            .default_field("Bonus D", |d| &mut d.bonus_d)
-                            .default_field("Bond D", |d| &mut d.bond_d)
-                            .default_field("Border D", |d| &mut d.border_d)
-                            .default_field("Bonus E", |d| &mut d.bonus_e)
-                            .default_field("Bond E", |d| &mut d.bond_e)
-                            .default_field("Border E", |d| &mut d.border_e)
-                            .default_field("Bonus F", |d| &mut d.bonus_f)
-                            .default_field("Bond F", |d| &mut d.bond_f)
-                            .default_field("Border F", |d| &mut d.border_f)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.prizes_content.content(ctx, &self.prizes, state);
             }
 
             Tab::SitUpFallData => {
                 self.sit_up_fall_data_content
                     .left_panel(ctx, &self.sit_up_fall_data, state);
-                self.sit_up_fall_data.write(|data| {
-                    self.sit_up_fall_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("sit_up_fall_data", selection)
-                                .new_section("")
-                                .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                                .default_field("Level", |d| &mut d.level)
-                                .default_field("Perfect Limit", |d| &mut d.perfect_limit)
-                                .default_field("Gain Power", |d| &mut d.gain_power)
-                                .default_field("Fall Speed", |d| &mut d.fall_speed)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.sit_up_fall_data_content
+                    .content(ctx, &self.sit_up_fall_data, state);
             }
 
             Tab::PushUpSpeedData => {
                 self.push_up_speed_content
                     .left_panel(ctx, &self.push_up_speed, state);
-                self.push_up_speed.write(|data| {
-                    self.push_up_speed_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("push_up_speed", selection)
-                                .new_section("")
-                                .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                                .default_field("Level", |d| &mut d.level)
-                                .default_field("Speed Min", |d| &mut d.speed_min)
-                                .default_field("Speed Max", |d| &mut d.speed_max)
-                                .default_field("Level Up Count", |d| &mut d.level_up_count)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.push_up_speed_content
+                    .content(ctx, &self.push_up_speed, state);
             }
 
             Tab::SquatJudgeAreaData => {
                 self.squat_judge_area_content
                     .left_panel(ctx, &self.squat_judge_area, state);
-                self.squat_judge_area.write(|data| {
-                    self.squat_judge_area_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("squat_judge_area", selection)
-                                .new_section("")
-                                .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                                .default_field("Bad Frame First", |d| &mut d.bad_frame_first)
-                                .default_field("Good Frame First", |d| &mut d.good_frame_first)
-                                .default_field("Perfect Frame First", |d| {
-                                    &mut d.perfect_frame_first
-                                })
-                                .default_field("Perfect Frame Latter", |d| {
-                                    &mut d.perfect_frame_latter
-                                })
-                                .default_field("Good Frame Latter", |d| &mut d.good_frame_latter)
-                                .default_field("Bad Frame Latter", |d| &mut d.bad_frame_latter)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.squat_judge_area_content
+                    .content(ctx, &self.squat_judge_area, state);
             }
 
             Tab::SquatScoreListData => {
                 self.score_list_data_content
                     .left_panel(ctx, &self.score_list_data, state);
-                self.score_list_data.write(|data| {
-                    self.score_list_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("score_list_data", selection)
-                                .new_section("")
-                                .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                                .default_field("Level", |d| &mut d.level)
-                                .default_field("Use Count", |d| &mut d.use_count)
-                                .default_field("Is Double Choice", |d| &mut d.is_double_choice)
-                                .default_field("Speed", |d| &mut d.speed)
-                                .default_field("Length", |d| &mut d.length)
-                                .default_field("Type 1", |d| &mut d.type_1)
-                                .default_field("Lottery Param 1", |d| &mut d.lottery_param_1)
-                                .default_field("Type 2", |d| &mut d.type_2)
-                                .default_field("Lottery Param 2", |d| &mut d.lottery_param_2)
-                                .default_field("Type 3", |d| &mut d.type_3)
-                                .default_field("Lottery Param 3", |d| &mut d.lottery_param_3)
-                                .default_field("Type 4", |d| &mut d.type_4)
-                                .default_field("Lottery Param 4", |d| &mut d.lottery_param_4)
-                                .default_field("Type 5", |d| &mut d.type_5)
-                                .default_field("Lottery Param 5", |d| &mut d.lottery_param_5)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.score_list_data_content
+                    .content(ctx, &self.score_list_data, state);
             }
 
             Tab::SquatMusicSheet => {
                 self.music_sheets_content
                     .left_panel(ctx, &self.music_sheets, state);
-                self.music_sheets.write(|data| {
-                    self.music_sheets_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("music_sheets", selection)
-                                .new_section("")
-                                .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                                .default_field("Type A L", |d| &mut d.type_a_l)
-                                .default_field("Type A R", |d| &mut d.type_a_r)
-                                .default_field("Type B L", |d| &mut d.type_b_l)
-                                .default_field("Type B R", |d| &mut d.type_b_r)
-                                .default_field("Type C L", |d| &mut d.type_c_l)
-                                .default_field("Type C R", |d| &mut d.type_c_r)
-                                .default_field("Type D L", |d| &mut d.type_d_l)
-                                .default_field("Type D R", |d| &mut d.type_d_r)
-                                .default_field("Type E L", |d| &mut d.type_e_l)
-                                .default_field("Type E R", |d| &mut d.type_e_r)
-                                .default_field("Ensure", |d| &mut d.ensure)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.music_sheets_content
+                    .content(ctx, &self.music_sheets, state);
             }
 
             Tab::AssistData => {
                 self.assist_data_content
                     .left_panel(ctx, &self.assist_data, state);
-                self.assist_data.write(|data| {
-                    self.assist_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("assist_data", selection)
-                                .new_section("")
-                                .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                                .default_field("Level 00", |d| &mut d.level_00)
-                                .default_field("Level 01", |d| &mut d.level_01)
-                                .default_field("Level 02", |d| &mut d.level_02)
-                                .default_field("Level 03", |d| &mut d.level_03)
-                                .default_field("Level 04", |d| &mut d.level_04)
-                                .default_field("Level 05", |d| &mut d.level_05)
-                                .default_field("Level 06", |d| &mut d.level_06)
-                                .default_field("Level 07", |d| &mut d.level_07)
-                                .default_field("Level 08", |d| &mut d.level_08)
-                                .default_field("Level 09", |d| &mut d.level_09)
-                                .default_field("Level 10", |d| &mut d.level_10)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.assist_data_content
+                    .content(ctx, &self.assist_data, state);
             }
         }
     }
 }
+
+impl RowForm for MuscleExerciseDifficulty {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("difficulty", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .default_field("Exercise Type", |d| &mut d.exercise_type)
+            .default_field("Level", |d| &mut d.level)
+            .default_field("Good Score", |d| &mut d.good_score)
+            .default_field("Perfect Score", |d| &mut d.perfect_score)
+            .default_field("Target Score", |d| &mut d.target_score)
+            .default_field("Endless Goal Count", |d| &mut d.endless_goal_count)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for MuscleExerciseSetup {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("setups", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .default_field("Level", |d| &mut d.level)
+            .default_field("Area P Center", |d| &mut d.area_p_center)
+            .default_field("Area P Radius", |d| &mut d.area_p_radius)
+            .default_field("Area G Center", |d| &mut d.area_g_center)
+            .default_field("Area G Radius", |d| &mut d.area_g_radius)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for MuscleExercisePrizeData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("prizes", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .default_field("Exercise Type", |d| &mut d.exercise_type)
+            .default_field("Bonus Sss", |d| &mut d.bonus_sss)
+            .default_field("Bond Sss", |d| &mut d.bond_sss)
+            .default_field("Border Sss", |d| &mut d.border_sss)
+            .default_field("Bonus Ss", |d| &mut d.bonus_ss)
+            .default_field("Bond Ss", |d| &mut d.bond_ss)
+            .default_field("Border Ss", |d| &mut d.border_ss)
+            .default_field("Bonus S", |d| &mut d.bonus_s)
+            .default_field("Bond S", |d| &mut d.bond_s)
+            .default_field("Border S", |d| &mut d.border_s)
+            .default_field("Bonus A", |d| &mut d.bonus_a)
+            .default_field("Bond A", |d| &mut d.bond_a)
+            .default_field("Border A", |d| &mut d.border_a)
+            .default_field("Bonus B", |d| &mut d.bonus_b)
+            .default_field("Bond B", |d| &mut d.bond_b)
+            .default_field("Border B", |d| &mut d.border_b)
+            .default_field("Bonus C", |d| &mut d.bonus_c)
+            .default_field("Bond C", |d| &mut d.bond_c)
+            .default_field("Border C", |d| &mut d.border_c)
+            .default_field("Bonus D", |d| &mut d.bonus_d)
+            .default_field("Bond D", |d| &mut d.bond_d)
+            .default_field("Border D", |d| &mut d.border_d)
+            .default_field("Bonus E", |d| &mut d.bonus_e)
+            .default_field("Bond E", |d| &mut d.bond_e)
+            .default_field("Border E", |d| &mut d.border_e)
+            .default_field("Bonus F", |d| &mut d.bonus_f)
+            .default_field("Bond F", |d| &mut d.bond_f)
+            .default_field("Border F", |d| &mut d.border_f)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for MuscleSitUpFallData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("sit_up_fall_data", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .default_field("Level", |d| &mut d.level)
+            .default_field("Perfect Limit", |d| &mut d.perfect_limit)
+            .default_field("Gain Power", |d| &mut d.gain_power)
+            .default_field("Fall Speed", |d| &mut d.fall_speed)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for MusclePushUpSpeedData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("push_up_speed", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .default_field("Level", |d| &mut d.level)
+            .default_field("Speed Min", |d| &mut d.speed_min)
+            .default_field("Speed Max", |d| &mut d.speed_max)
+            .default_field("Level Up Count", |d| &mut d.level_up_count)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for MuscleSquatJudgeAreaData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("squat_judge_area", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .default_field("Bad Frame First", |d| &mut d.bad_frame_first)
+            .default_field("Good Frame First", |d| &mut d.good_frame_first)
+            .default_field("Perfect Frame First", |d| &mut d.perfect_frame_first)
+            .default_field("Perfect Frame Latter", |d| &mut d.perfect_frame_latter)
+            .default_field("Good Frame Latter", |d| &mut d.good_frame_latter)
+            .default_field("Bad Frame Latter", |d| &mut d.bad_frame_latter)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for MuscleSquatScoreListData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("score_list_data", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .default_field("Level", |d| &mut d.level)
+            .default_field("Use Count", |d| &mut d.use_count)
+            .default_field("Is Double Choice", |d| &mut d.is_double_choice)
+            .default_field("Speed", |d| &mut d.speed)
+            .default_field("Length", |d| &mut d.length)
+            .default_field("Type 1", |d| &mut d.type_1)
+            .default_field("Lottery Param 1", |d| &mut d.lottery_param_1)
+            .default_field("Type 2", |d| &mut d.type_2)
+            .default_field("Lottery Param 2", |d| &mut d.lottery_param_2)
+            .default_field("Type 3", |d| &mut d.type_3)
+            .default_field("Lottery Param 3", |d| &mut d.lottery_param_3)
+            .default_field("Type 4", |d| &mut d.type_4)
+            .default_field("Lottery Param 4", |d| &mut d.lottery_param_4)
+            .default_field("Type 5", |d| &mut d.type_5)
+            .default_field("Lottery Param 5", |d| &mut d.lottery_param_5)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for MuscleSquatMusicSheet {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("music_sheets", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .default_field("Type A L", |d| &mut d.type_a_l)
+            .default_field("Type A R", |d| &mut d.type_a_r)
+            .default_field("Type B L", |d| &mut d.type_b_l)
+            .default_field("Type B R", |d| &mut d.type_b_r)
+            .default_field("Type C L", |d| &mut d.type_c_l)
+            .default_field("Type C R", |d| &mut d.type_c_r)
+            .default_field("Type D L", |d| &mut d.type_d_l)
+            .default_field("Type D R", |d| &mut d.type_d_r)
+            .default_field("Type E L", |d| &mut d.type_e_l)
+            .default_field("Type E R", |d| &mut d.type_e_r)
+            .default_field("Ensure", |d| &mut d.ensure)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for MuscleAssistData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("assist_data", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .default_field("Level 00", |d| &mut d.level_00)
+            .default_field("Level 01", |d| &mut d.level_01)
+            .default_field("Level 02", |d| &mut d.level_02)
+            .default_field("Level 03", |d| &mut d.level_03)
+            .default_field("Level 04", |d| &mut d.level_04)
+            .default_field("Level 05", |d| &mut d.level_05)
+            .default_field("Level 06", |d| &mut d.level_06)
+            .default_field("Level 07", |d| &mut d.level_07)
+            .default_field("Level 08", |d| &mut d.level_08)
+            .default_field("Level 09", |d| &mut d.level_09)
+            .default_field("Level 10", |d| &mut d.level_10)
+            .show(ui)
+            .changed()
+    }
+}
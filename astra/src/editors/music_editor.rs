@@ -2,10 +2,12 @@ use std::borrow::Cow;
 
 use indexmap::IndexMap;
 
+use egui::Ui;
+
 use crate::{
     keyed_add_modal_content, model_drop_down, msbt_key_value_multiline, msbt_key_value_singleline,
     sheet_retriever, standard_keyed_display, EditorState, KeyedViewItem, ListEditorContent,
-    PropertyGrid, ViewItem,
+    PropertyGrid, RowForm, ViewItem,
 };
 
 use astra_types::{MusicBook, MusicData};
@@ -47,30 +49,32 @@ impl MusicEditor {
     pub fn show(&mut self, ctx: &egui::Context, state: &EditorState) {
         self.music_content.left_panel(ctx, &self.music, state);
 
-        self.music.write(|data| {
-            self.music_content.content(ctx, data, |ui, selection| {
-                PropertyGrid::new("music", selection)
-                    .new_section("")
-                    .default_field("Event Name", |d| &mut d.event_name)
-                    .field("Name", |ui, d| {
-                        msbt_key_value_singleline!(ui, state, "musicname", d.name)
-                    })
-                    .field("Help", |ui, d| {
-                        msbt_key_value_multiline!(ui, state, "gamedata", d.help)
-                    })
-                    .default_field("Help", |d| &mut d.help)
-                    .default_field("Condition", |d| &mut d.condition)
-                    .default_field("Amiibo", |d| &mut d.amiibo)
-                    .default_field("Change Event Name", |d| &mut d.change_event_name)
-                    .default_field("Is Change", |d| &mut d.is_change)
-                    .field("Emblem", |ui, d| {
-                        state
-                            .god
-                            .read(|data| ui.add(model_drop_down(data, state, &mut d.gid)))
-                    })
-                    .show(ui)
-                    .changed()
+        self.music_content.content(ctx, &self.music, state);
+    }
+}
+
+impl RowForm for MusicData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("music", selection)
+            .new_section("")
+            .default_field("Event Name", |d| &mut d.event_name)
+            .field("Name", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "musicname", d.name)
+            })
+            .field("Help", |ui, d| {
+                msbt_key_value_multiline!(ui, state, "gamedata", d.help)
+            })
+            .default_field("Help", |d| &mut d.help)
+            .default_field("Condition", |d| &mut d.condition)
+            .default_field("Amiibo", |d| &mut d.amiibo)
+            .default_field("Change Event Name", |d| &mut d.change_event_name)
+            .default_field("Is Change", |d| &mut d.is_change)
+            .field("Emblem", |ui, d| {
+                state
+                    .god
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.gid)))
             })
-        });
+            .show(ui)
+            .changed()
     }
 }
@@ -1,7 +1,7 @@
 use astra_types::GameParam;
-use egui::DragValue;
+use egui::{DragValue, Ui};
 
-use crate::{EditorState, GameParamSheet, ListEditorContent, PropertyGrid};
+use crate::{EditorState, GameParamSheet, ListEditorContent, PropertyGrid, RowForm};
 
 pub struct GameParamEditor {
     param: GameParamSheet,
@@ -16,28 +16,30 @@ impl GameParamEditor {
         }
     }
 
-    pub fn show(&mut self, ctx: &egui::Context) {
+    pub fn show(&mut self, ctx: &egui::Context, state: &EditorState) {
         self.content.left_panel(ctx, &self.param, &());
 
-        self.param.write(|data| {
-            self.content.content(ctx, data, |ui, param| {
-                PropertyGrid::new("param", param)
-                    .new_section("")
-                    .field("Name", |ui, param| ui.text_edit_singleline(&mut param.name))
-                    .field("English", |ui, param| {
-                        ui.text_edit_singleline(&mut param.english)
-                    })
-                    .field("En", |ui, param| ui.text_edit_singleline(&mut param.en))
-                    .field("Value", |ui, param| {
-                        ui.add(DragValue::new(&mut param.value))
-                    })
-                    .field("Min", |ui, param| ui.add(DragValue::new(&mut param.min)))
-                    .field("Max", |ui, param| ui.add(DragValue::new(&mut param.max)))
-                    .field("Step", |ui, param| ui.add(DragValue::new(&mut param.step)))
-                    .field("Out", |ui, param| ui.text_edit_singleline(&mut param.out))
-                    .show(ui)
-                    .changed()
+        self.content.content(ctx, &self.param, state);
+    }
+}
+
+impl RowForm for GameParam {
+    fn form(ui: &mut Ui, _: &EditorState, param: &mut Self) -> bool {
+        PropertyGrid::new("param", param)
+            .new_section("")
+            .field("Name", |ui, param| ui.text_edit_singleline(&mut param.name))
+            .field("English", |ui, param| {
+                ui.text_edit_singleline(&mut param.english)
+            })
+            .field("En", |ui, param| ui.text_edit_singleline(&mut param.en))
+            .field("Value", |ui, param| {
+                ui.add(DragValue::new(&mut param.value))
             })
-        });
+            .field("Min", |ui, param| ui.add(DragValue::new(&mut param.min)))
+            .field("Max", |ui, param| ui.add(DragValue::new(&mut param.max)))
+            .field("Step", |ui, param| ui.add(DragValue::new(&mut param.step)))
+            .field("Out", |ui, param| ui.text_edit_singleline(&mut param.out))
+            .show(ui)
+            .changed()
     }
 }
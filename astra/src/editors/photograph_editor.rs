@@ -6,7 +6,7 @@ use indexmap::IndexMap;
 use crate::{
     editable_list, editor_tab_strip, id_field, keyed_add_modal_content, model_drop_down,
     sheet_retriever, EditorState, GroupEditorContent, GroupViewItem, KeyedViewItem,
-    ListEditorContent, PropertyGrid, ViewItem,
+    ListEditorContent, PropertyGrid, RowForm, ViewItem,
 };
 
 use astra_types::{PhotographPose, PhotographSpot, PhotographSpotBook};
@@ -86,59 +86,63 @@ impl PhotographSpotEditor {
         match self.tab {
             Tab::PhotographSpot => {
                 self.spots_content.left_panel(ctx, &self.spots, state);
-                self.spots.write(|data| {
-                    self.spots_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("spots", selection)
-                            .new_section("")
-                            .field("MID", |ui, d| ui.add(id_field(&mut d.mid)))
-                            .default_field("Name", |d| &mut d.name)
-                            .field("Condition Chapter", |ui, d| {
-                                state.chapter.read(|data| {
-                                    ui.add(model_drop_down(data, state, &mut d.condition_cid))
-                                })
-                            })
-                            .default_field("Locator Count", |d| &mut d.locator_count)
-                            .default_field("Pause Group Name List 1", |d| {
-                                &mut d.pause_group_name_list_1
-                            })
-                            .default_field("Pause Group Name List 2", |d| {
-                                &mut d.pause_group_name_list_2
-                            })
-                            .default_field("Pause Group Name List 3", |d| {
-                                &mut d.pause_group_name_list_3
-                            })
-                            .default_field("Pause Group Name List 4", |d| {
-                                &mut d.pause_group_name_list_4
-                            })
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.spots_content.content(ctx, &self.spots, state);
             }
 
             Tab::PhotographPose => {
                 self.poses_content.left_panel(ctx, &self.poses, state);
-                self.poses.write(|data| {
-                    self.poses_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("poses", selection)
-                            .new_section("")
-                            .default_field("Pause Name", |d| &mut d.pause_name)
-                            .default_field("Mid", |d| &mut d.mid)
-                            .default_field("No", |d| &mut d.no)
-                            .default_field("Anime Frame", |d| &mut d.anime_frame)
-                            .default_field("Face Anime", |d| &mut d.face_anime)
-                            .field("Characters", |ui, d| {
-                                state.person.read(|data| {
-                                    ui.add(editable_list(&mut d.chara_id_list, |_, d, ui| {
-                                        ui.add(model_drop_down(data, state, d))
-                                    }))
-                                })
-                            })
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.poses_content.content(ctx, &self.poses, state);
             }
         }
     }
 }
+
+impl RowForm for PhotographSpot {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("spots", selection)
+            .new_section("")
+            .field("MID", |ui, d| ui.add(id_field(&mut d.mid)))
+            .default_field("Name", |d| &mut d.name)
+            .field("Condition Chapter", |ui, d| {
+                state
+                    .chapter
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.condition_cid)))
+            })
+            .default_field("Locator Count", |d| &mut d.locator_count)
+            .default_field("Pause Group Name List 1", |d| {
+                &mut d.pause_group_name_list_1
+            })
+            .default_field("Pause Group Name List 2", |d| {
+                &mut d.pause_group_name_list_2
+            })
+            .default_field("Pause Group Name List 3", |d| {
+                &mut d.pause_group_name_list_3
+            })
+            .default_field("Pause Group Name List 4", |d| {
+                &mut d.pause_group_name_list_4
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for PhotographPose {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("poses", selection)
+            .new_section("")
+            .default_field("Pause Name", |d| &mut d.pause_name)
+            .default_field("Mid", |d| &mut d.mid)
+            .default_field("No", |d| &mut d.no)
+            .default_field("Anime Frame", |d| &mut d.anime_frame)
+            .default_field("Face Anime", |d| &mut d.face_anime)
+            .field("Characters", |ui, d| {
+                state.person.read(|data| {
+                    ui.add(editable_list(&mut d.chara_id_list, |_, d, ui| {
+                        ui.add(model_drop_down(data, state, d))
+                    }))
+                })
+            })
+            .show(ui)
+            .changed()
+    }
+}
@@ -5,8 +5,8 @@ use indexmap::IndexMap;
 
 use crate::{
     editor_tab_strip, id_field, keyed_add_modal_content, model_drop_down,
-    msbt_key_value_singleline, sheet_retriever, standard_keyed_display, CachedView, EditorState,
-    KeyedViewItem, ListEditorContent, PropertyGrid, ViewItem,
+    msbt_key_value_singleline, sheet_retriever, standard_keyed_display, EditorState, KeyedViewItem,
+    ListEditorContent, PropertyGrid, RowForm, ViewItem,
 };
 
 use astra_types::{
@@ -185,11 +185,6 @@ pub struct ProfileCardEditor {
     comment: ProfileCardCategorizedComponentSheet,
     favorite_map_editor_theme: ProfileCardCategorizedComponentSheet,
     default_comment: ProfileCardDefaultCommentDataSheet,
-    comment_cache: CachedView<
-        ProfileCardCategorizedComponentSheetRetriever,
-        ProfileCardBook,
-        ProfileCardCategorizedComponent,
-    >,
     bg_content: ListEditorContent<
         IndexMap<String, ProfileCardImageComponent>,
         ProfileCardImageComponent,
@@ -268,7 +263,6 @@ impl ProfileCardEditor {
             comment: state.profile_card_comment.clone(),
             favorite_map_editor_theme: state.profile_card_favorite_map_editor_theme.clone(),
             default_comment: state.profile_card_default_comment.clone(),
-            comment_cache: CachedView::new(state.profile_card_comment.clone(), state),
             bg_content: ListEditorContent::new("bg_editor")
                 .with_add_modal_content(keyed_add_modal_content),
             frames_content: ListEditorContent::new("frames_editor")
@@ -320,185 +314,181 @@ impl ProfileCardEditor {
     }
 
     pub fn show(&mut self, ctx: &egui::Context, state: &EditorState) {
-        self.comment_cache.refresh(state);
-
         match self.tab {
             Tab::Background | Tab::Frame | Tab::Lettering | Tab::StampData1 => {
-                let (id, sheet, content) = match self.tab {
-                    Tab::Background => ("bg", &mut self.bg, &mut self.bg_content),
-                    Tab::Frame => ("frame", &mut self.frames, &mut self.frames_content),
-                    Tab::Lettering => (
-                        "lettering",
-                        &mut self.lettering,
-                        &mut self.lettering_content,
-                    ),
-                    Tab::StampData1 => (
-                        "stamp_data_1",
-                        &mut self.stamp_data_1,
-                        &mut self.stamp_data_1_content,
-                    ),
+                let (sheet, content) = match self.tab {
+                    Tab::Background => (&self.bg, &mut self.bg_content),
+                    Tab::Frame => (&self.frames, &mut self.frames_content),
+                    Tab::Lettering => (&self.lettering, &mut self.lettering_content),
+                    Tab::StampData1 => (&self.stamp_data_1, &mut self.stamp_data_1_content),
                     _ => unimplemented!(),
                 };
                 content.left_panel(ctx, sheet, state);
-                sheet.write(|data| {
-                    content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new(id, selection)
-                            .new_section("")
-                            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                            .default_field("Image", |d| &mut d.image)
-                            .default_field("Condition", |d| &mut d.condition)
-                            .default_field("Arg", |d| &mut d.arg)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                content.content(ctx, sheet, state);
             }
             Tab::TextColor => {
                 self.text_colors_content
                     .left_panel(ctx, &self.text_colors, state);
-                self.text_colors.write(|data| {
-                    self.text_colors_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("text_colors", selection)
-                                .new_section("")
-                                .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                                .default_field("Color", |d| &mut d.color)
-                                .default_field("Condition", |d| &mut d.condition)
-                                .default_field("Arg", |d| &mut d.arg)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.text_colors_content
+                    .content(ctx, &self.text_colors, state);
             }
             Tab::StampData2 => {
                 self.stamp_data_2_content
                     .left_panel(ctx, &self.stamp_data_2, state);
-                self.stamp_data_2.write(|data| {
-                    self.stamp_data_2_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("stamp_data_2", selection)
-                                .new_section("")
-                                .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                                .default_field("Image", |d| &mut d.image)
-                                .default_field("Category", |d| &mut d.category)
-                                .default_field("Condition", |d| &mut d.condition)
-                                .default_field("Arg", |d| &mut d.arg)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.stamp_data_2_content
+                    .content(ctx, &self.stamp_data_2, state);
             }
             Tab::Title | Tab::FavoriteCharacter => {
-                let (id, sheet, content) = match self.tab {
-                    Tab::Title => ("title", &mut self.title, &mut self.title_content),
+                let (sheet, content) = match self.tab {
+                    Tab::Title => (&self.title, &mut self.title_content),
                     Tab::FavoriteCharacter => (
-                        "favorite_character",
-                        &mut self.favorite_character,
+                        &self.favorite_character,
                         &mut self.favorite_character_content,
                     ),
                     _ => unimplemented!(),
                 };
                 content.left_panel(ctx, sheet, state);
-                sheet.write(|data| {
-                    content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new(id, selection)
-                            .new_section("")
-                            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                            .field("Name", |ui, d| {
-                                msbt_key_value_singleline!(ui, state, "person", d.name)
-                            })
-                            .default_field("Condition", |d| &mut d.condition)
-                            .default_field("Arg", |d| &mut d.arg)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                content.content(ctx, sheet, state);
             }
 
             Tab::FavoriteMap => {
                 self.favorite_map_content
                     .left_panel(ctx, &self.favorite_map, state);
-                self.favorite_map.write(|data| {
-                    self.favorite_map_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("favorite_map", selection)
-                                .new_section("")
-                                .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                                .field("Chapter", |ui, d| {
-                                    state.chapter.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.cid))
-                                    })
-                                })
-                                .default_field("Condition", |d| &mut d.condition)
-                                .default_field("Arg", |d| &mut d.arg)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.favorite_map_content
+                    .content(ctx, &self.favorite_map, state);
             }
 
             Tab::Comment | Tab::FavoriteMapEditorTheme => {
-                let (id, sheet, content) = match self.tab {
-                    Tab::Comment => ("comment", &mut self.comment, &mut self.comment_content),
+                let (sheet, content) = match self.tab {
+                    Tab::Comment => (&self.comment, &mut self.comment_content),
                     Tab::FavoriteMapEditorTheme => (
-                        "favorite_map_editor_theme",
-                        &mut self.favorite_map_editor_theme,
+                        &self.favorite_map_editor_theme,
                         &mut self.favorite_map_editor_theme_content,
                     ),
                     _ => unimplemented!(),
                 };
                 content.left_panel(ctx, sheet, state);
-                sheet.write(|data| {
-                    content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new(id, selection)
-                            .new_section("")
-                            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                            .field("Name", |ui, d| {
-                                msbt_key_value_singleline!(ui, state, "profilecard", d.name)
-                            })
-                            .default_field("Category", |d| &mut d.category)
-                            .default_field("Condition", |d| &mut d.condition)
-                            .default_field("Arg", |d| &mut d.arg)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                content.content(ctx, sheet, state);
             }
             Tab::DefaultComment => {
                 self.default_comment_content
                     .left_panel(ctx, &self.default_comment, state);
-                self.default_comment.write(|data| {
-                    self.default_comment_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("default_comment", selection)
-                                .new_section("")
-                                .field("Language", |ui, d| ui.add(id_field(&mut d.language)))
-                                .field("Comment 1", |ui, d| {
-                                    ui.add(model_drop_down(
-                                        self.comment_cache.get(),
-                                        &(),
-                                        &mut d.id_1,
-                                    ))
-                                })
-                                .field("Comment 2", |ui, d| {
-                                    ui.add(model_drop_down(
-                                        self.comment_cache.get(),
-                                        &(),
-                                        &mut d.id_2,
-                                    ))
-                                })
-                                .field("Comment 3", |ui, d| {
-                                    ui.add(model_drop_down(
-                                        self.comment_cache.get(),
-                                        &(),
-                                        &mut d.id_3,
-                                    ))
-                                })
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.default_comment_content
+                    .content(ctx, &self.default_comment, state);
             }
         }
     }
 }
+
+impl RowForm for ProfileCardColorComponent {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("text_colors", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .default_field("Color", |d| &mut d.color)
+            .default_field("Condition", |d| &mut d.condition)
+            .default_field("Arg", |d| &mut d.arg)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for ProfileCardCategorizedImageComponent {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("stamp_data_2", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .default_field("Image", |d| &mut d.image)
+            .default_field("Category", |d| &mut d.category)
+            .default_field("Condition", |d| &mut d.condition)
+            .default_field("Arg", |d| &mut d.arg)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for ProfileCardFavoriteMapData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("favorite_map", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .field("Chapter", |ui, d| {
+                state
+                    .chapter
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.cid)))
+            })
+            .default_field("Condition", |d| &mut d.condition)
+            .default_field("Arg", |d| &mut d.arg)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for ProfileCardDefaultCommentData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("default_comment", selection)
+            .new_section("")
+            .field("Language", |ui, d| ui.add(id_field(&mut d.language)))
+            .field("Comment 1", |ui, d| {
+                state
+                    .profile_card_comment
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.id_1)))
+            })
+            .field("Comment 2", |ui, d| {
+                state
+                    .profile_card_comment
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.id_2)))
+            })
+            .field("Comment 3", |ui, d| {
+                state
+                    .profile_card_comment
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.id_3)))
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for ProfileCardImageComponent {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("profile_card_image", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .default_field("Image", |d| &mut d.image)
+            .default_field("Condition", |d| &mut d.condition)
+            .default_field("Arg", |d| &mut d.arg)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for ProfileCardNameComponent {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("profile_card_name", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .field("Name", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "person", d.name)
+            })
+            .default_field("Condition", |d| &mut d.condition)
+            .default_field("Arg", |d| &mut d.arg)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for ProfileCardCategorizedComponent {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("profile_card_categorized", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .field("Name", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "profilecard", d.name)
+            })
+            .default_field("Category", |d| &mut d.category)
+            .default_field("Condition", |d| &mut d.condition)
+            .default_field("Arg", |d| &mut d.arg)
+            .show(ui)
+            .changed()
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     editable_list, editor_tab_strip, id_field, keyed_add_modal_content, model_drop_down,
     msbt_key_value_multiline, msbt_key_value_singleline, sheet_retriever, standard_keyed_display,
     DropDownModal, EditorState, GroupEditorContent, GroupViewItem, KeyedViewItem,
-    ListEditorContent, PropertyGrid, ViewItem,
+    ListEditorContent, PropertyGrid, RowForm, ViewItem,
 };
 
 use astra_types::{
@@ -197,64 +197,15 @@ impl RelayEditor {
             Tab::Relay => {
                 self.relay_data_content
                     .left_panel(ctx, &self.relay_data, state);
-                self.relay_data.write(|data| {
-                    self.relay_data_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("relay_data", selection)
-                            .new_section("")
-                            .field("CID", |ui, d| ui.add(id_field(&mut d.cid)))
-                            .default_field("Difficulty", |d| &mut d.difficulty)
-                            .default_field("Max Turn", |d| &mut d.max_turn)
-                            .default_field("Max Unit", |d| &mut d.max_unit)
-                            .default_field("New Turn", |d| &mut d.new_turn)
-                            .default_field("Take Over Turn", |d| &mut d.take_over_turn)
-                            .default_field("Take Over Unit", |d| &mut d.take_over_unit)
-                            .default_field("Completion Award Main", |d| {
-                                &mut d.completion_award_main
-                            })
-                            .default_field("Completion Award Sub", |d| &mut d.completion_award_sub)
-                            .field("Game Over Award", |ui, d| {
-                                state.item.read(|data| {
-                                    ui.add(model_drop_down(data, state, &mut d.game_over_award))
-                                })
-                            })
-                            .field("Unlock Chapter", |ui, d| {
-                                state.chapter.read(|data| {
-                                    ui.add(model_drop_down(data, state, &mut d.unlock_cid))
-                                })
-                            })
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.relay_data_content
+                    .content(ctx, &self.relay_data, state);
             }
 
             Tab::Stamp => {
                 self.relay_stamp_data_content
                     .left_panel(ctx, &self.relay_stamp_data, state);
-                self.relay_stamp_data.write(|data| {
-                    self.relay_stamp_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("relay_stamp_data", selection)
-                                .new_section("")
-                                .field("Name", |ui, d| ui.add(id_field(&mut d.name)))
-                                .default_field("Serial No", |d| &mut d.serial_no)
-                                .field("Person", |ui, d| {
-                                    state.person.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.pid))
-                                    })
-                                })
-                                .field("Emblem", |ui, d| {
-                                    state.god.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.gid))
-                                    })
-                                })
-                                .default_field("Sort", |d| &mut d.sort)
-                                .default_field("Flag", |d| &mut d.flag)
-                                .default_field("Voice", |d| &mut d.voice)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.relay_stamp_data_content
+                    .content(ctx, &self.relay_stamp_data, state);
             }
 
             Tab::ClearAward => {
@@ -263,55 +214,114 @@ impl RelayEditor {
                     &self.relay_clear_award_data,
                     state,
                 );
-                self.relay_clear_award_data.write(|data| {
-                    self.relay_clear_award_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("relay_clear_award_data", selection)
-                                .new_section("")
-                                .field("Item", |ui, d| {
-                                    state.item.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.iid))
-                                    })
-                                })
-                                .default_field("Rate", |d| &mut d.rate)
-                                .default_field("Min Count", |d| &mut d.min_count)
-                                .default_field("Max Count", |d| &mut d.max_count)
-                                .default_field("Flag", |d| &mut d.flag)
-                                .default_field("Condition", |d| &mut d.condition)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.relay_clear_award_data_content.content(
+                    ctx,
+                    &self.relay_clear_award_data,
+                    state,
+                );
             }
 
             Tab::Award => {
                 self.relay_award_data_content
                     .left_panel(ctx, &self.relay_award_data, state);
-                self.relay_award_data.write(|data| {
-                    self.relay_award_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("relay_award_data", selection)
-                                .new_section("")
-                                .field("RAID", |ui, d| ui.add(id_field(&mut d.raid)))
-                                .field("Name", |ui, d| {
-                                    msbt_key_value_singleline!(ui, state, "network", d.name)
-                                })
-                                .field("Result Text", |ui, d| {
-                                    msbt_key_value_multiline!(ui, state, "network", d.result_text)
-                                })
-                                .field("Awards", |ui, d| {
-                                    state.item.read(|data| {
-                                        ui.add(editable_list(&mut d.awards, |_, d, ui| {
-                                            ui.add(model_drop_down(data, state, d))
-                                        }))
-                                    })
-                                })
-                                .default_field("Flag", |d| &mut d.flag)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.relay_award_data_content
+                    .content(ctx, &self.relay_award_data, state);
             }
         }
     }
 }
+
+impl RowForm for RelayData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("relay_data", selection)
+            .new_section("")
+            .field("CID", |ui, d| ui.add(id_field(&mut d.cid)))
+            .default_field("Difficulty", |d| &mut d.difficulty)
+            .default_field("Max Turn", |d| &mut d.max_turn)
+            .default_field("Max Unit", |d| &mut d.max_unit)
+            .default_field("New Turn", |d| &mut d.new_turn)
+            .default_field("Take Over Turn", |d| &mut d.take_over_turn)
+            .default_field("Take Over Unit", |d| &mut d.take_over_unit)
+            .default_field("Completion Award Main", |d| &mut d.completion_award_main)
+            .default_field("Completion Award Sub", |d| &mut d.completion_award_sub)
+            .field("Game Over Award", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.game_over_award)))
+            })
+            .field("Unlock Chapter", |ui, d| {
+                state
+                    .chapter
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.unlock_cid)))
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for RelayStampData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("relay_stamp_data", selection)
+            .new_section("")
+            .field("Name", |ui, d| ui.add(id_field(&mut d.name)))
+            .default_field("Serial No", |d| &mut d.serial_no)
+            .field("Person", |ui, d| {
+                state
+                    .person
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.pid)))
+            })
+            .field("Emblem", |ui, d| {
+                state
+                    .god
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.gid)))
+            })
+            .default_field("Sort", |d| &mut d.sort)
+            .default_field("Flag", |d| &mut d.flag)
+            .default_field("Voice", |d| &mut d.voice)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for RelayClearAwardData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("relay_clear_award_data", selection)
+            .new_section("")
+            .field("Item", |ui, d| {
+                state
+                    .item
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.iid)))
+            })
+            .default_field("Rate", |d| &mut d.rate)
+            .default_field("Min Count", |d| &mut d.min_count)
+            .default_field("Max Count", |d| &mut d.max_count)
+            .default_field("Flag", |d| &mut d.flag)
+            .default_field("Condition", |d| &mut d.condition)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for RelayAwardData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("relay_award_data", selection)
+            .new_section("")
+            .field("RAID", |ui, d| ui.add(id_field(&mut d.raid)))
+            .field("Name", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "network", d.name)
+            })
+            .field("Result Text", |ui, d| {
+                msbt_key_value_multiline!(ui, state, "network", d.result_text)
+            })
+            .field("Awards", |ui, d| {
+                state.item.read(|data| {
+                    ui.add(editable_list(&mut d.awards, |_, d, ui| {
+                        ui.add(model_drop_down(data, state, d))
+                    }))
+                })
+            })
+            .default_field("Flag", |d| &mut d.flag)
+            .show(ui)
+            .changed()
+    }
+}
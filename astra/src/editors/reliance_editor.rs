@@ -5,7 +5,7 @@ use indexmap::IndexMap;
 use crate::widgets::{id_field, keyed_add_modal_content};
 use crate::{
     editor_tab_strip, EditorState, GroupEditorContent, ListEditorContent, PropertyGrid,
-    RelianceBonusDataSheet, RelianceExpDataSheet,
+    RelianceBonusDataSheet, RelianceExpDataSheet, RowForm,
 };
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -41,25 +41,21 @@ impl RelianceEditor {
         });
     }
 
-    pub fn show(&mut self, ctx: &egui::Context) {
+    pub fn show(&mut self, ctx: &egui::Context, state: &EditorState) {
         match self.tab {
             Tab::ExpData => self.exp_data_content.left_panel(ctx, &self.exp_data, &()),
             Tab::Bonuses => self.bonuses_content.left_panel(ctx, &self.bonuses, &()),
         }
 
         match self.tab {
-            Tab::ExpData => self.exp_data.write(|data| {
-                self.exp_data_content
-                    .content(ctx, data, Self::exp_data_property_grid)
-            }),
-            Tab::Bonuses => self.bonuses.write(|data| {
-                self.bonuses_content
-                    .content(ctx, data, Self::bonuses_property_grid)
-            }),
+            Tab::ExpData => self.exp_data_content.content(ctx, &self.exp_data, state),
+            Tab::Bonuses => self.bonuses_content.content(ctx, &self.bonuses, state),
         }
     }
+}
 
-    fn exp_data_property_grid(ui: &mut Ui, data: &mut RelianceExpData) -> bool {
+impl RowForm for RelianceExpData {
+    fn form(ui: &mut Ui, _: &EditorState, data: &mut Self) -> bool {
         PropertyGrid::new("reliance_exp_data", data)
             .new_section("")
             .field("REXID", |ui, data| ui.add(id_field(&mut data.rexid)))
@@ -69,8 +65,10 @@ impl RelianceEditor {
             .show(ui)
             .changed()
     }
+}
 
-    fn bonuses_property_grid(ui: &mut Ui, data: &mut RelianceBonusData) -> bool {
+impl RowForm for RelianceBonusData {
+    fn form(ui: &mut Ui, _: &EditorState, data: &mut Self) -> bool {
         PropertyGrid::new("reliance_bonuses_data", data)
             .new_section("")
             .field("Level", |ui, data| ui.add(DragValue::new(&mut data.level)))
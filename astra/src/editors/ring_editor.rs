@@ -9,7 +9,7 @@ use crate::{
     msbt_key_value_multiline, msbt_key_value_singleline, optional_image, rgb_color_picker,
     ring_rank_drop_down, sheet_retriever, standard_keyed_display, stat_column_headers_no_sight,
     stats_row_no_sight, DecorationKind, EditorState, GroupEditorContent, GroupViewItem,
-    KeyedViewItem, ListEditorContent, PropertyGrid, ViewItem,
+    KeyedViewItem, ListEditorContent, PropertyGrid, RowForm, ViewItem,
 };
 
 use astra_types::{RingBook, RingCleaningVoiceBook, RingData, RingPolishVoiceData};
@@ -122,120 +122,106 @@ impl RingEditor {
             Tab::RingData => {
                 self.ring_data_content
                     .left_panel(ctx, &self.ring_data, state);
-                self.ring_data.write(|data| {
-                    self.ring_data_content.content(ctx, data, |ui, selection| {
-                        let mut changed = false;
-                        egui_grid::GridBuilder::new()
-                            .new_row(Size::exact(100.))
-                            .cell(Size::relative(0.2).at_least(215.))
-                            .cell(Size::remainder())
-                            .new_row(Size::remainder())
-                            .cell(Size::remainder())
-                            .show(ui, |mut grid| {
-                                grid.cell(|ui| {
-                                    ui.group(|ui| {
-                                        ui.add(optional_image(
-                                            selection.decoration(
-                                                state,
-                                                DecorationKind::Other("portrait"),
-                                            ),
-                                            [200., 90.],
-                                        ));
-                                    });
-                                });
-                                grid.cell(|ui| {
-                                    ui.group(|ui| {
-                                        Grid::new("ring_grid").show(ui, |ui| {
-                                            stat_column_headers_no_sight(ui);
-                                            ui.label("Bonus Stats");
-                                            stats_row_no_sight!(ui, selection, enhance, changed);
-                                        });
-                                        ui.allocate_space(ui.available_size());
-                                    });
-                                });
-                                grid.cell(|ui| {
-                                    changed |= PropertyGrid::new("ring_data", selection)
-                                        .new_section("")
-                                        .field("RNID", |ui, d| ui.add(id_field(&mut d.rnid)))
-                                        .field("Name", |ui, d| {
-                                            msbt_key_value_singleline!(
-                                                ui,
-                                                state,
-                                                "bondsring",
-                                                d.name
-                                            )
-                                        })
-                                        .field("Help", |ui, d| {
-                                            msbt_key_value_multiline!(
-                                                ui,
-                                                state,
-                                                "bondsring",
-                                                d.help
-                                            )
-                                        })
-                                        .field("GID", |ui, d| {
-                                            state.god.read(|data| {
-                                                ui.add(model_drop_down(data, state, &mut d.gid))
-                                            })
-                                        })
-                                        .default_field("Ring Model", |d| &mut d.ring_model)
-                                        .field("Rank", |ui, d| {
-                                            ui.add(ring_rank_drop_down(&mut d.rank))
-                                        })
-                                        .default_field("Icon", |d| &mut d.icon)
-                                        .field("Equip Skills", |ui, d| {
-                                            state.skill.read(|data| {
-                                                ui.add(editable_list(
-                                                    &mut d.equip_sids,
-                                                    |_, d, ui| {
-                                                        ui.add(model_drop_down(data, state, d))
-                                                    },
-                                                ))
-                                            })
-                                        })
-                                        .default_field("Is Single Rank", |d| &mut d.is_single_rank)
-                                        .field("Jewel Color (RGB)", |ui, d| {
-                                            ui.add(rgb_color_picker(
-                                                &mut d.jewel_color_r,
-                                                &mut d.jewel_color_g,
-                                                &mut d.jewel_color_b,
-                                            ))
-                                        })
-                                        .field("Rim Color (RGB)", |ui, d| {
-                                            ui.add(rgb_color_picker(
-                                                &mut d.rim_color_r,
-                                                &mut d.rim_color_g,
-                                                &mut d.rim_color_b,
-                                            ))
-                                        })
-                                        .show(ui)
-                                        .changed();
-                                });
-                            });
-
-                        changed
-                    })
-                });
+                self.ring_data_content.content(ctx, &self.ring_data, state);
             }
 
             Tab::RingPolishVoiceData => {
                 self.ring_polish_voice_content
                     .left_panel(ctx, &self.ring_polish_voice, state);
-                self.ring_polish_voice.write(|data| {
-                    self.ring_polish_voice_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("ring_polish_voice", selection)
-                                .new_section("")
-                                .default_field("Label", |d| &mut d.label)
-                                .default_field("Play Situation", |d| &mut d.play_situation)
-                                .default_field("Is Play Completed", |d| &mut d.is_play_completed)
-                                .default_field("Unit Face Anim", |d| &mut d.unit_face_anim)
-                                .default_field("God Face Anim", |d| &mut d.god_face_anim)
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.ring_polish_voice_content
+                    .content(ctx, &self.ring_polish_voice, state);
             }
         }
     }
 }
+
+impl RowForm for RingData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        let mut changed = false;
+        egui_grid::GridBuilder::new()
+            .new_row(Size::exact(100.))
+            .cell(Size::relative(0.2).at_least(215.))
+            .cell(Size::remainder())
+            .new_row(Size::remainder())
+            .cell(Size::remainder())
+            .show(ui, |mut grid| {
+                grid.cell(|ui| {
+                    ui.group(|ui| {
+                        ui.add(optional_image(
+                            selection.decoration(state, DecorationKind::Other("portrait")),
+                            [200., 90.],
+                        ));
+                    });
+                });
+                grid.cell(|ui| {
+                    ui.group(|ui| {
+                        Grid::new("ring_grid").show(ui, |ui| {
+                            stat_column_headers_no_sight(ui);
+                            ui.label("Bonus Stats");
+                            stats_row_no_sight!(ui, selection, enhance, changed);
+                        });
+                        ui.allocate_space(ui.available_size());
+                    });
+                });
+                grid.cell(|ui| {
+                    changed |= PropertyGrid::new("ring_data", selection)
+                        .new_section("")
+                        .field("RNID", |ui, d| ui.add(id_field(&mut d.rnid)))
+                        .field("Name", |ui, d| {
+                            msbt_key_value_singleline!(ui, state, "bondsring", d.name)
+                        })
+                        .field("Help", |ui, d| {
+                            msbt_key_value_multiline!(ui, state, "bondsring", d.help)
+                        })
+                        .field("GID", |ui, d| {
+                            state
+                                .god
+                                .read(|data| ui.add(model_drop_down(data, state, &mut d.gid)))
+                        })
+                        .default_field("Ring Model", |d| &mut d.ring_model)
+                        .field("Rank", |ui, d| ui.add(ring_rank_drop_down(&mut d.rank)))
+                        .default_field("Icon", |d| &mut d.icon)
+                        .field("Equip Skills", |ui, d| {
+                            state.skill.read(|data| {
+                                ui.add(editable_list(&mut d.equip_sids, |_, d, ui| {
+                                    ui.add(model_drop_down(data, state, d))
+                                }))
+                            })
+                        })
+                        .default_field("Is Single Rank", |d| &mut d.is_single_rank)
+                        .field("Jewel Color (RGB)", |ui, d| {
+                            ui.add(rgb_color_picker(
+                                &mut d.jewel_color_r,
+                                &mut d.jewel_color_g,
+                                &mut d.jewel_color_b,
+                            ))
+                        })
+                        .field("Rim Color (RGB)", |ui, d| {
+                            ui.add(rgb_color_picker(
+                                &mut d.rim_color_r,
+                                &mut d.rim_color_g,
+                                &mut d.rim_color_b,
+                            ))
+                        })
+                        .show(ui)
+                        .changed();
+                });
+            });
+
+        changed
+    }
+}
+
+impl RowForm for RingPolishVoiceData {
+    fn form(ui: &mut Ui, _: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("ring_polish_voice", selection)
+            .new_section("")
+            .default_field("Label", |d| &mut d.label)
+            .default_field("Play Situation", |d| &mut d.play_situation)
+            .default_field("Is Play Completed", |d| &mut d.is_play_completed)
+            .default_field("Unit Face Anim", |d| &mut d.unit_face_anim)
+            .default_field("God Face Anim", |d| &mut d.god_face_anim)
+            .show(ui)
+            .changed()
+    }
+}
@@ -0,0 +1,254 @@
+use egui::{CentralPanel, ComboBox, Ui};
+
+use astra_types::{
+    Accessory, AccessoryShopInventory, AchieveData, AiData, AmiiboData, AnimSet, AnimalData,
+    ArenaData, AssetDef, BelongData, CalculatorCommon, Chapter, ChartData, ChartGodData,
+    ChartParam, CookData, DragonRidePresetParamData, DragonRidePrizeData, DragonRideTargetPattern,
+    Effect, EffectSequence, EncountEnemyType, EncountEquipment, EncountRarityConfig,
+    EncountWeaponCategory, EndRollData, ExpTableEntry, FishSizeData, FishSpawn, FishingAssistData,
+    FishingFishData, FishingRadicalParamData, FishingTargetListData, FoodData, FoodNamingConfig,
+    ForgeEvolveData, ForgeExchangeData, ForgeImproveData, FriendListData, GameParam,
+    GodBondLevelData, GodData, GodLevelData, GroundAttribute, HubAnimalBonus, HubAnimalBonusGroup,
+    HubAreaData, HubCrystalData, HubDemoData, HubFacilityData, HubFortuneTellingData,
+    HubIngredientBonus, HubIngredientBonusGroup, HubItemBonus, HubMapIconData, HubMaterialBonus,
+    HubMyRoomData, HubNationData, HubResourceData, HubSpawn, HubSpawnRandomSet, HubTalkData,
+    HubTalkFacilityData, HubTalkRelativeData, HubUnityBehavior, IngredientData, Item, Job,
+    JukeboxData, KeyHelpData, KillBonus1, KillBonus2, MapEditorCategory, MapEditorObject,
+    MapHistory, MascotAccessoryData, MascotColorData, MascotFoodData, MascotParamData, Movie,
+    MuscleAssistData, MuscleExerciseDifficulty, MuscleExercisePrizeData, MuscleExerciseSetup,
+    MusclePushUpSpeedData, MuscleSitUpFallData, MuscleSquatJudgeAreaData, MuscleSquatMusicSheet,
+    MuscleSquatScoreListData, MusicData, Person, PhotographPose, PhotographSpot,
+    PostBattleConversation, ProfileCardCategorizedComponent, ProfileCardCategorizedImageComponent,
+    ProfileCardColorComponent, ProfileCardDefaultCommentData, ProfileCardFavoriteMapData,
+    ProfileCardImageComponent, ProfileCardNameComponent, RangeData, RelayAwardData,
+    RelayClearAwardData, RelayData, RelayStampData, RelianceBonusData, RelianceData,
+    RelianceExpData, RingData, RingPolishVoiceData, ShopInventory, Skill, SoundEvent,
+    TasteConditionData, TasteData, TerrainData, TipData, TitleCallData, TitlePedestalData,
+    TutorialData, VibrationDefineData,
+};
+
+use crate::model::{SheetHandle, SheetRetriever};
+use crate::{EditorState, RowModel, SchemaRow, SheetTable};
+
+/// A sheet that can be shown in the table editor.
+trait TableSource {
+    fn name(&self) -> &str;
+
+    fn show(&mut self, ui: &mut Ui);
+}
+
+struct SheetTableSource<R, B, M, I> {
+    name: String,
+    handle: SheetHandle<R, B, M>,
+    table: SheetTable<I>,
+}
+
+impl<R, B, M, I> TableSource for SheetTableSource<R, B, M, I>
+where
+    R: SheetRetriever<B, M>,
+    M: RowModel<I> + Send + Sync + 'static,
+    I: SchemaRow,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn show(&mut self, ui: &mut Ui) {
+        self.table.show(ui, &self.handle);
+    }
+}
+
+macro_rules! table_sources {
+    ($state:ident, $($field:ident: $row:ty),* $(,)?) => {
+        vec![
+            $(
+                Box::new(SheetTableSource {
+                    name: display_name(stringify!($field)),
+                    handle: $state.$field.clone(),
+                    table: SheetTable::<$row>::new(stringify!($field)),
+                }) as Box<dyn TableSource>,
+            )*
+        ]
+    };
+}
+
+/// Turn a field name like `god_level_data` into `God Level Data`.
+fn display_name(field: &str) -> String {
+    field
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub struct TableEditor {
+    sources: Vec<Box<dyn TableSource>>,
+    selected: usize,
+}
+
+impl TableEditor {
+    pub fn new(state: &EditorState) -> Self {
+        let mut sources = table_sources!(
+            state,
+            accessory: Accessory,
+            accessory_shop: AccessoryShopInventory,
+            achieve: AchieveData,
+            ai: AiData,
+            amiibo: AmiiboData,
+            anim_set: AnimSet,
+            animal: AnimalData,
+            arena: ArenaData,
+            armory_shop: ShopInventory,
+            asset_table: AssetDef,
+            belong: BelongData,
+            calculator: CalculatorCommon,
+            chapter: Chapter,
+            chart: ChartData,
+            chart_god: ChartGodData,
+            chart_param: ChartParam,
+            cook: CookData,
+            dragon_ride_presets: DragonRidePresetParamData,
+            dragon_ride_prizes: DragonRidePrizeData,
+            dragon_ride_target_patterns: DragonRideTargetPattern,
+            effect: Effect,
+            effect_sequence: EffectSequence,
+            encount_equipment: EncountEquipment,
+            encount_weapon_categories: EncountWeaponCategory,
+            encount_enemy_types: EncountEnemyType,
+            encount_rarity_configs: EncountRarityConfig,
+            end_roll_data: EndRollData,
+            exp_table: ExpTableEntry,
+            fishing_fish_data: FishingFishData,
+            fishing_size_data: FishSizeData,
+            fish_spawns: FishSpawn,
+            fishing_target_list: FishingTargetListData,
+            fishing_assist_data: FishingAssistData,
+            fishing_radical_param_data: FishingRadicalParamData,
+            flea_market: ShopInventory,
+            food: FoodData,
+            food_naming: FoodNamingConfig,
+            forge_improve: ForgeImproveData,
+            forge_evolve: ForgeEvolveData,
+            forge_exchange: ForgeExchangeData,
+            friend_list_data: FriendListData,
+            god: GodData,
+            god_level_data: GodLevelData,
+            god_bond_level_data: GodBondLevelData,
+            ground_attributes: GroundAttribute,
+            hub_area_data: HubAreaData,
+            hub_facility_data: HubFacilityData,
+            hub_demo_data: HubDemoData,
+            hub_spawns: HubSpawn,
+            hub_random_sets: HubSpawnRandomSet,
+            hub_unity_behavior: HubUnityBehavior,
+            hub_fortune_telling_data: HubFortuneTellingData,
+            hub_nation_data: HubNationData,
+            hub_material_bonuses: HubMaterialBonus,
+            hub_ingredient_bonuses: HubIngredientBonus,
+            hub_animal_bonuses: HubAnimalBonus,
+            hub_item_bonuses: HubItemBonus,
+            hub_ingredient_bonus_groups: HubIngredientBonusGroup,
+            hub_animal_bonus_groups: HubAnimalBonusGroup,
+            hub_map_icon_data: HubMapIconData,
+            hub_my_room_data: HubMyRoomData,
+            hub_resources: HubResourceData,
+            hub_talk_data: HubTalkData,
+            hub_relative_data: HubTalkRelativeData,
+            hub_talk_facility_data: HubTalkFacilityData,
+            hub_crystal_data: HubCrystalData,
+            item: Item,
+            item_shop: ShopInventory,
+            ingredient: IngredientData,
+            job: Job,
+            jukebox_data: JukeboxData,
+            key_help_data: KeyHelpData,
+            kill_bonuses_1: KillBonus1,
+            kill_bonuses_2: KillBonus2,
+            map_editor_objects: MapEditorObject,
+            map_editor_categories: MapEditorCategory,
+            map_history: MapHistory,
+            mascot_accessory_data: MascotAccessoryData,
+            mascot_color_data: MascotColorData,
+            mascot_param_data: MascotParamData,
+            mascot_food_data: MascotFoodData,
+            movies: Movie,
+            muscle_exercise_difficulty: MuscleExerciseDifficulty,
+            muscle_exercise_setups: MuscleExerciseSetup,
+            muscle_exercise_prizes: MuscleExercisePrizeData,
+            muscle_exercise_sit_up_fall_data: MuscleSitUpFallData,
+            muscle_exercise_push_up_speed: MusclePushUpSpeedData,
+            muscle_exercise_squat_judge_area: MuscleSquatJudgeAreaData,
+            muscle_exercise_score_list_data: MuscleSquatScoreListData,
+            muscle_exercise_music_sheets: MuscleSquatMusicSheet,
+            muscle_exercise_assist_data: MuscleAssistData,
+            music: MusicData,
+            param: GameParam,
+            person: Person,
+            post_battle_conversations: PostBattleConversation,
+            photograph_spots: PhotographSpot,
+            photograph_poses: PhotographPose,
+            profile_card_bg: ProfileCardImageComponent,
+            profile_card_frames: ProfileCardImageComponent,
+            profile_card_lettering: ProfileCardImageComponent,
+            profile_card_text_colors: ProfileCardColorComponent,
+            profile_card_stamp_data_1: ProfileCardImageComponent,
+            profile_card_stamp_data_2: ProfileCardCategorizedImageComponent,
+            profile_card_title: ProfileCardNameComponent,
+            profile_card_favorite_character: ProfileCardNameComponent,
+            profile_card_favorite_map: ProfileCardFavoriteMapData,
+            profile_card_comment: ProfileCardCategorizedComponent,
+            profile_card_favorite_map_editor_theme: ProfileCardCategorizedComponent,
+            profile_card_default_comment: ProfileCardDefaultCommentData,
+            ranges: RangeData,
+            relay_data: RelayData,
+            relay_stamp_data: RelayStampData,
+            relay_clear_award_data: RelayClearAwardData,
+            relay_award_data: RelayAwardData,
+            reliance: RelianceData,
+            reliance_exp_data: RelianceExpData,
+            reliance_bonus_data: RelianceBonusData,
+            ring_data: RingData,
+            ring_polish_voice: RingPolishVoiceData,
+            skill: Skill,
+            sound_events: SoundEvent,
+            taste: TasteData,
+            taste_condition: TasteConditionData,
+            title_call_data: TitleCallData,
+            title_pedestal_data: TitlePedestalData,
+            tips: TipData,
+            tutorials: TutorialData,
+            terrain: TerrainData,
+            vibration_data: VibrationDefineData,
+        );
+        sources.sort_by(|a, b| a.name().cmp(b.name()));
+        let selected = sources
+            .iter()
+            .position(|source| source.name() == "Job")
+            .unwrap_or_default();
+        Self { sources, selected }
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context) {
+        CentralPanel::default().show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Sheet");
+                ComboBox::from_id_source("table_editor_sheet")
+                    .width(300.)
+                    .selected_text(self.sources[self.selected].name())
+                    .show_ui(ui, |ui| {
+                        for (index, source) in self.sources.iter().enumerate() {
+                            ui.selectable_value(&mut self.selected, index, source.name());
+                        }
+                    });
+            });
+            ui.separator();
+            self.sources[self.selected].show(ui);
+        });
+    }
+}
@@ -5,7 +5,7 @@ use indexmap::IndexMap;
 
 use crate::{
     editor_tab_strip, id_field, keyed_add_modal_content, model_drop_down, sheet_retriever,
-    EditorState, KeyedViewItem, ListEditorContent, PropertyGrid, ViewItem,
+    EditorState, KeyedViewItem, ListEditorContent, PropertyGrid, RowForm, ViewItem,
 };
 
 use astra_types::{TitleBook, TitleCallData, TitlePedestalData};
@@ -91,41 +91,45 @@ impl TitleEditor {
             Tab::TitleCallData => {
                 self.call_data_content
                     .left_panel(ctx, &self.call_data, state);
-                self.call_data.write(|data| {
-                    self.call_data_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("call_data", selection)
-                            .new_section("")
-                            .field("PID or GID", |ui, d| ui.add(id_field(&mut d.pid_or_gid)))
-                            .field("Chapter", |ui, d| {
-                                state
-                                    .chapter
-                                    .read(|data| ui.add(model_drop_down(data, state, &mut d.cid)))
-                            })
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.call_data_content.content(ctx, &self.call_data, state);
             }
 
             Tab::TitlePedestalData => {
                 self.pedestal_data_content
                     .left_panel(ctx, &self.pedestal_data, state);
-                self.pedestal_data.write(|data| {
-                    self.pedestal_data_content
-                        .content(ctx, data, |ui, selection| {
-                            PropertyGrid::new("pedestal_data", selection)
-                                .new_section("")
-                                .field("Pedestal", |ui, d| ui.add(id_field(&mut d.pedestal_name)))
-                                .field("Chapter", |ui, d| {
-                                    state.chapter.read(|data| {
-                                        ui.add(model_drop_down(data, state, &mut d.cid))
-                                    })
-                                })
-                                .show(ui)
-                                .changed()
-                        })
-                });
+                self.pedestal_data_content
+                    .content(ctx, &self.pedestal_data, state);
             }
         }
     }
 }
+
+impl RowForm for TitleCallData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("call_data", selection)
+            .new_section("")
+            .field("PID or GID", |ui, d| ui.add(id_field(&mut d.pid_or_gid)))
+            .field("Chapter", |ui, d| {
+                state
+                    .chapter
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.cid)))
+            })
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for TitlePedestalData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("pedestal_data", selection)
+            .new_section("")
+            .field("Pedestal", |ui, d| ui.add(id_field(&mut d.pedestal_name)))
+            .field("Chapter", |ui, d| {
+                state
+                    .chapter
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.cid)))
+            })
+            .show(ui)
+            .changed()
+    }
+}
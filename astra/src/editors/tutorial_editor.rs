@@ -6,7 +6,8 @@ use indexmap::IndexMap;
 use crate::{
     editor_tab_strip, id_field, keyed_add_modal_content, model_drop_down, msbt_key_value_multiline,
     msbt_key_value_singleline, sheet_retriever, standard_keyed_display, EditorState,
-    GroupEditorContent, GroupViewItem, KeyedViewItem, ListEditorContent, PropertyGrid, ViewItem,
+    GroupEditorContent, GroupViewItem, KeyedViewItem, ListEditorContent, PropertyGrid, RowForm,
+    ViewItem,
 };
 
 use astra_types::{TipData, TutorialBook, TutorialData};
@@ -87,59 +88,63 @@ impl TutorialEditor {
             Tab::TutorialData => {
                 self.tutorials_content
                     .left_panel(ctx, &self.tutorials, state);
-                self.tutorials.write(|data| {
-                    self.tutorials_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("tutorials", selection)
-                            .new_section("")
-                            .field("Title", |ui, d| {
-                                msbt_key_value_singleline!(ui, state, "tutorial", d.title)
-                            })
-                            .field("MID", |ui, d| {
-                                msbt_key_value_multiline!(ui, state, "tutorial", d.mid)
-                            })
-                            .default_field("Sprite Atlas", |d| &mut d.sprite_atlas)
-                            .default_field("Ty", |d| &mut d.ty)
-                            .default_field("Notice", |d| &mut d.notice)
-                            .field("Chapter", |ui, d| {
-                                state
-                                    .chapter
-                                    .read(|data| ui.add(model_drop_down(data, state, &mut d.cid)))
-                            })
-                            .default_field("No", |d| &mut d.no)
-                            .default_field("Ss Type", |d| &mut d.ss_type)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.tutorials_content.content(ctx, &self.tutorials, state);
             }
 
             Tab::TipData => {
                 self.tips_content.left_panel(ctx, &self.tips, state);
-                self.tips.write(|data| {
-                    self.tips_content.content(ctx, data, |ui, selection| {
-                        PropertyGrid::new("tips", selection)
-                            .new_section("")
-                            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
-                            .field("Title", |ui, d| {
-                                msbt_key_value_singleline!(ui, state, "tutorial", d.title)
-                            })
-                            .field("Tips", |ui, d| {
-                                msbt_key_value_multiline!(ui, state, "tutorial", d.tips)
-                            })
-                            .default_field("Own Id", |d| &mut d.own_id)
-                            .default_field("Icon Info Id", |d| &mut d.icon_info_id)
-                            .field("Chapter", |ui, d| {
-                                state.chapter.read(|data| {
-                                    ui.add(model_drop_down(data, state, &mut d.chapter))
-                                })
-                            })
-                            .default_field("Variable", |d| &mut d.variable)
-                            .default_field("Allow", |d| &mut d.allow)
-                            .show(ui)
-                            .changed()
-                    })
-                });
+                self.tips_content.content(ctx, &self.tips, state);
             }
         }
     }
 }
+
+impl RowForm for TutorialData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("tutorials", selection)
+            .new_section("")
+            .field("Title", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "tutorial", d.title)
+            })
+            .field("MID", |ui, d| {
+                msbt_key_value_multiline!(ui, state, "tutorial", d.mid)
+            })
+            .default_field("Sprite Atlas", |d| &mut d.sprite_atlas)
+            .default_field("Ty", |d| &mut d.ty)
+            .default_field("Notice", |d| &mut d.notice)
+            .field("Chapter", |ui, d| {
+                state
+                    .chapter
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.cid)))
+            })
+            .default_field("No", |d| &mut d.no)
+            .default_field("Ss Type", |d| &mut d.ss_type)
+            .show(ui)
+            .changed()
+    }
+}
+
+impl RowForm for TipData {
+    fn form(ui: &mut Ui, state: &EditorState, selection: &mut Self) -> bool {
+        PropertyGrid::new("tips", selection)
+            .new_section("")
+            .field("ID", |ui, d| ui.add(id_field(&mut d.id)))
+            .field("Title", |ui, d| {
+                msbt_key_value_singleline!(ui, state, "tutorial", d.title)
+            })
+            .field("Tips", |ui, d| {
+                msbt_key_value_multiline!(ui, state, "tutorial", d.tips)
+            })
+            .default_field("Own Id", |d| &mut d.own_id)
+            .default_field("Icon Info Id", |d| &mut d.icon_info_id)
+            .field("Chapter", |ui, d| {
+                state
+                    .chapter
+                    .read(|data| ui.add(model_drop_down(data, state, &mut d.chapter)))
+            })
+            .default_field("Variable", |d| &mut d.variable)
+            .default_field("Allow", |d| &mut d.allow)
+            .show(ui)
+            .changed()
+    }
+}
@@ -6,7 +6,7 @@ use astra_formats::MsbtToken;
 use bimap::BiHashMap;
 use parking_lot::RwLock;
 
-use crate::{AppConfig, GodDataSheet, PersonSheet};
+use crate::{AppConfig, EditHistory, GodDataSheet, PersonSheet};
 
 #[derive(Debug, Clone)]
 struct KeyData {
@@ -26,15 +26,39 @@ impl MessageDbWrapper {
         self.0.read().message(key).map(|v| v.to_string())
     }
 
+    /// Edit a message in place. Changes are recorded in the undo history.
     pub fn with_message_mut(
         &self,
         key: &str,
         default_archive: &str,
         consumer: impl FnOnce(Option<&mut String>) -> bool,
     ) {
-        self.0
-            .write()
-            .with_message_mut(key, default_archive, consumer)
+        let before = self.message(key);
+        let mut changed = false;
+        self.0.write().with_message_mut(key, default_archive, |value| {
+            changed = consumer(value);
+            changed
+        });
+        let after = self.message(key);
+        if changed && before != after {
+            let db = self.clone();
+            let (key, default_archive) = (key.to_string(), default_archive.to_string());
+            EditHistory::record(
+                format!("message:{}", key),
+                before.unwrap_or_default(),
+                after.unwrap_or_default(),
+                move |message: &String| {
+                    db.0.write()
+                        .with_message_mut(&key, &default_archive, |value| match value {
+                            Some(value) => {
+                                *value = message.clone();
+                                true
+                            }
+                            None => false,
+                        });
+                },
+            );
+        }
     }
 
     pub fn build_translations(
@@ -0,0 +1,239 @@
+use anyhow::{bail, Context, Result};
+use indexmap::IndexMap;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use crate::SchemaRow;
+
+/// A row read from XML copied to the clipboard.
+#[derive(Debug, Clone)]
+pub struct CopiedRow {
+    /// The group the row was copied from, if it came from a grouped sheet.
+    pub group: Option<String>,
+    pub values: IndexMap<String, String>,
+}
+
+impl CopiedRow {
+    /// Keys of the copied values that aren't fields of the sheet's rows.
+    pub fn unknown_keys<I: SchemaRow>(&self) -> Vec<&str> {
+        self.values
+            .keys()
+            .map(String::as_str)
+            .filter(|key| !I::FIELDS.iter().any(|field| field.key == *key))
+            .collect()
+    }
+
+    /// Build a row from the copied values. Fields missing from the XML keep their default values.
+    /// Values for fields the sheet doesn't have usually mean the row came from another sheet,
+    /// so they're rejected unless `keep_unknown` is set. Kept values are treated like unknown
+    /// attributes in a book.
+    pub fn to_row<I: SchemaRow + Default>(&self, keep_unknown: bool) -> Result<I> {
+        let unknown = self.unknown_keys::<I>();
+        if !keep_unknown && !unknown.is_empty() {
+            bail!(
+                "the copied rows have fields this sheet doesn't: {}",
+                unknown.join(", ")
+            );
+        }
+        I::default().with_field_values(self.values.clone())
+    }
+}
+
+/// What to do when a pasted row has the same key as an existing row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyCollision {
+    #[default]
+    Rename,
+    Overwrite,
+    Skip,
+}
+
+impl KeyCollision {
+    pub fn label(&self) -> &'static str {
+        match self {
+            KeyCollision::Rename => "Keep Both",
+            KeyCollision::Overwrite => "Overwrite",
+            KeyCollision::Skip => "Skip",
+        }
+    }
+}
+
+/// Append a number to `key` until `exists` returns false for it.
+pub fn unique_key(key: &str, exists: impl Fn(&str) -> bool) -> String {
+    (1..)
+        .map(|n| format!("{}_{}", key, n))
+        .find(|candidate| !exists(candidate))
+        .unwrap()
+}
+
+/// Escape an attribute value. Line breaks are written as character references
+/// so they aren't turned into spaces when the XML is read.
+fn escape(value: &str) -> String {
+    quick_xml::escape::escape(value)
+        .replace('\n', "&#10;")
+        .replace('\r', "&#13;")
+}
+
+/// Rows key their values by attribute with an `@` prefix, ex. `@Pid`. Books leave it off.
+fn param_element<I: SchemaRow>(row: &I) -> String {
+    let mut element = String::from("<Param");
+    for (key, value) in row.to_sheet_data_param_values() {
+        let name = key.trim_start_matches('@');
+        element.push_str(&format!(" {}=\"{}\"", name, escape(&value)));
+    }
+    element.push_str(" />");
+    element
+}
+
+/// Serialize rows as `<Param>` elements, the same way they appear in a book.
+pub fn rows_to_xml<'a, I: SchemaRow + 'a>(rows: impl IntoIterator<Item = &'a I>) -> String {
+    rows.into_iter()
+        .map(|row| param_element(row) + "\n")
+        .collect()
+}
+
+/// Serialize groups of rows as `<Group>` elements containing `<Param>` elements.
+pub fn groups_to_xml<'a, I, G>(groups: impl IntoIterator<Item = (&'a str, G)>) -> String
+where
+    I: SchemaRow + 'a,
+    G: IntoIterator<Item = &'a I>,
+{
+    let mut xml = String::new();
+    for (name, rows) in groups {
+        xml.push_str(&format!("<Group Name=\"{}\">\n", escape(name)));
+        for row in rows {
+            xml.push_str("  ");
+            xml.push_str(&param_element(row));
+            xml.push('\n');
+        }
+        xml.push_str("</Group>\n");
+    }
+    xml
+}
+
+/// Read an element's attributes, keyed the same way as row values, ex. `@Pid`.
+fn attributes(element: &BytesStart<'_>) -> Result<IndexMap<String, String>> {
+    element
+        .attributes()
+        .map(|attribute| {
+            let attribute = attribute?;
+            Ok((
+                format!("@{}", String::from_utf8_lossy(attribute.key.as_ref())),
+                attribute.unescape_value()?.into_owned(),
+            ))
+        })
+        .collect()
+}
+
+/// Read the rows from XML made by [rows_to_xml] or [groups_to_xml].
+/// Other elements, like the `<Sheet>` and `<Data>` elements of a book, are ignored,
+/// so rows copied straight out of a book's XML file work too.
+pub fn parse_copied_rows(xml: &str) -> Result<Vec<CopiedRow>> {
+    let mut rows = vec![];
+    let mut group = None;
+    let mut reader = Reader::from_str(xml);
+    // Rows copied out of a book can close elements they don't open, ex. `</Data>`.
+    reader.check_end_names(false);
+    loop {
+        match reader.read_event().context("invalid XML")? {
+            Event::Start(element) | Event::Empty(element)
+                if element.name().as_ref() == b"Param" =>
+            {
+                rows.push(CopiedRow {
+                    group: group.clone(),
+                    values: attributes(&element)?,
+                })
+            }
+            Event::Start(element) if element.name().as_ref() == b"Group" => {
+                group = attributes(&element)?.shift_remove("@Name")
+            }
+            Event::End(element) if element.name().as_ref() == b"Group" => group = None,
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    if rows.is_empty() {
+        bail!("no rows found");
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use astra_types::RelianceExpData;
+
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> IndexMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn escaped_values_round_trip() {
+        let value = "<a & \"b\"> 'c'\r\nd";
+        let escaped = escape(value);
+        assert!(!escaped.contains(['<', '>', '"', '\n', '\r']));
+        let xml = format!("<Param Value=\"{}\" />", escaped);
+        let rows = parse_copied_rows(&xml).unwrap();
+        assert_eq!(rows[0].values, values(&[("@Value", value)]));
+    }
+
+    #[test]
+    fn parses_rows_and_groups() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+            <Data>
+              <Param Out="" Name="a&#10;b" />
+              <Group Name="G&amp;1">
+                <Param Name='c' ></Param>
+              </Group>
+              <Param Name="d" />
+            </Data>
+            </Book>"#;
+        let rows = parse_copied_rows(xml).unwrap();
+        let groups = rows
+            .iter()
+            .map(|row| row.group.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(groups, vec![None, Some("G&1"), None]);
+        assert_eq!(rows[0].values, values(&[("@Out", ""), ("@Name", "a\nb")]));
+        assert_eq!(rows[1].values, values(&[("@Name", "c")]));
+
+        assert!(parse_copied_rows("<Sheet></Sheet>").is_err());
+        assert!(parse_copied_rows("<Param Name=\"a").is_err());
+        assert!(parse_copied_rows("<Param Name=\"&bogus;\" />").is_err());
+    }
+
+    #[test]
+    fn copied_rows_round_trip() {
+        let row = RelianceExpData {
+            rexid: "REX_<1> & \"2\"".to_string(),
+            exp_c: 10,
+            exp_a: 30,
+            ..Default::default()
+        };
+        let xml = rows_to_xml([&row]);
+        let copied = parse_copied_rows(&xml).unwrap();
+        assert_eq!(copied.len(), 1);
+        let pasted = copied[0].to_row::<RelianceExpData>(false).unwrap();
+        assert_eq!(pasted.field_values(), row.field_values());
+
+        let xml = groups_to_xml([("G \"1\"", [&row])]);
+        let copied = parse_copied_rows(&xml).unwrap();
+        assert_eq!(copied[0].group.as_deref(), Some("G \"1\""));
+    }
+
+    #[test]
+    fn rejects_unknown_fields_unless_kept() {
+        let row = CopiedRow {
+            group: None,
+            values: values(&[("@Rexid", "REX_1"), ("@Pid", "PID_1")]),
+        };
+        assert_eq!(row.unknown_keys::<RelianceExpData>(), vec!["@Pid"]);
+        assert!(row.to_row::<RelianceExpData>(false).is_err());
+        let kept = row.to_row::<RelianceExpData>(true).unwrap();
+        assert_eq!(kept.rexid, "REX_1");
+    }
+}
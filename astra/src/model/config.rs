@@ -10,12 +10,16 @@ use egui::Color32;
 use maplit::hashmap;
 use serde::{Deserialize, Serialize};
 
-use crate::Theme;
+use crate::{RowTemplates, Shortcuts, Theme};
 
 fn default_show_network_warning() -> bool {
     true
 }
 
+fn default_autosave_interval_minutes() -> u32 {
+    5
+}
+
 fn default_terrain_brightness() -> f32 {
     0.7
 }
@@ -71,6 +75,12 @@ pub struct AppConfig {
     pub override_translation_keys_person: HashSet<String>,
     #[serde(default = "default_override_translation_keys_god")]
     pub override_translation_keys_god: HashSet<String>,
+    #[serde(default)]
+    pub autosave_enabled: bool,
+    #[serde(default = "default_autosave_interval_minutes")]
+    pub autosave_interval_minutes: u32,
+    #[serde(default)]
+    pub shortcuts: Shortcuts,
 }
 
 impl AppConfig {
@@ -146,6 +156,8 @@ pub struct ProjectDef {
     pub output_mode: ProjectOutputMode,
     pub active_country_dir_name: String,
     pub active_language_dir_name: String,
+    #[serde(default)]
+    pub row_templates: RowTemplates,
 }
 
 impl ProjectDef {
@@ -205,8 +217,17 @@ impl From<ProjectDef> for AstraProject {
                 patch_path,
             } => (data_path.into(), Some(patch_path.into())),
         };
+        // Backups are shared between projects, so give each project its own autosave folder.
+        let autosave_name: String = value
+            .name
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect();
         Self {
             backup_dir: PathBuf::from("Backups"),
+            autosave_dir: PathBuf::from("Backups")
+                .join("Autosave")
+                .join(autosave_name),
             rom_source: match value.rom_source {
                 RomSourceDef::Directory { romfs_path } => {
                     RomSource::Directory(PathBuf::from(romfs_path))
@@ -0,0 +1,695 @@
+use std::cmp::Ordering;
+
+use anyhow::{anyhow, bail, Result};
+use astra_types::FieldSchema;
+use indexmap::IndexMap;
+
+use crate::{compare_cells, SchemaRow};
+
+/// A value produced while evaluating an [Expr] against a row.
+/// Integers stay exact so `u64` and `i64` fields survive arithmetic unchanged.
+/// Text compares case insensitively.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i128),
+    Float(f64),
+    Text(String),
+    Bool(bool),
+}
+
+impl Value {
+    /// Interpret a cell or literal value, ex. `12`, `0x10`, `1.5`, `true` or `Iron Sword`.
+    pub fn from_cell(cell: &str) -> Self {
+        if let Some(n) = cell.parse().ok().or_else(|| parse_hex(cell)) {
+            Value::Int(n)
+        } else if let Ok(n) = cell.parse() {
+            Value::Float(n)
+        } else if let Ok(b) = cell.parse() {
+            Value::Bool(b)
+        } else {
+            Value::Text(cell.to_string())
+        }
+    }
+
+    fn truthy(&self) -> bool {
+        match self {
+            Value::Int(n) => *n != 0,
+            Value::Float(n) => *n != 0.,
+            Value::Text(s) => !s.is_empty(),
+            Value::Bool(b) => *b,
+        }
+    }
+
+    /// The value as a number, keeping integers exact.
+    pub fn number(&self) -> Result<Value> {
+        match self {
+            Value::Int(_) | Value::Float(_) => Ok(self.clone()),
+            Value::Bool(b) => Ok(Value::Int(*b as i128)),
+            Value::Text(s) if s.is_empty() => Ok(Value::Int(0)),
+            Value::Text(s) => Err(anyhow!("'{}' is not a number", s)),
+        }
+    }
+
+    fn float(&self) -> Result<f64> {
+        match self.number()? {
+            Value::Int(n) => Ok(n as f64),
+            Value::Float(n) => Ok(n),
+            _ => unreachable!(),
+        }
+    }
+
+    fn int(&self) -> Result<i128> {
+        match self.number()? {
+            Value::Int(n) => Ok(n),
+            Value::Float(n) if n.fract() == 0. => Ok(n as i128),
+            Value::Float(n) => Err(anyhow!("'{}' is not an integer", n)),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Apply an arithmetic operator, in integers if both sides are integers and in floats otherwise.
+    fn arithmetic(
+        &self,
+        other: &Value,
+        int: fn(i128, i128) -> Option<i128>,
+        float: fn(f64, f64) -> f64,
+    ) -> Result<Value> {
+        match (self.number()?, other.number()?) {
+            (Value::Int(a), Value::Int(b)) => int(a, b)
+                .map(Value::Int)
+                .ok_or_else(|| anyhow!("{} and {} overflow", a, b)),
+            _ => Ok(Value::Float(float(self.float()?, other.float()?))),
+        }
+    }
+
+    fn compare(&self, other: &Value) -> Ordering {
+        match (self, other) {
+            (Value::Text(_), _) | (_, Value::Text(_)) => compare_cells(
+                &self.to_string().to_lowercase(),
+                &other.to_string().to_lowercase(),
+            ),
+            _ => match (self.number(), other.number()) {
+                (Ok(Value::Int(a)), Ok(Value::Int(b))) => a.cmp(&b),
+                _ => {
+                    let (a, b) = (self.float(), other.float());
+                    a.unwrap_or_default().total_cmp(&b.unwrap_or_default())
+                }
+            },
+        }
+    }
+
+    /// Format the value so it can be parsed back into a field of the given type.
+    fn to_cell(&self, ty: &str) -> String {
+        let ty = ty.trim_start_matches("Option<").trim_end_matches('>');
+        let integer = ty.starts_with('i') || ty.starts_with('u');
+        match self {
+            Value::Int(_) | Value::Float(_) if ty == "bool" => self.truthy().to_string(),
+            Value::Float(n) if integer => (n.round() as i128).to_string(),
+            Value::Bool(b) if integer => (*b as i128).to_string(),
+            value => value.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Float(n) => write!(f, "{}", n),
+            Value::Text(s) => write!(f, "{}", s),
+            Value::Bool(b) => write!(f, "{}", b),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    BitAnd,
+    Contains,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+}
+
+/// An expression over the fields of a single row.
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Literal(Value),
+    Field(&'static str),
+    Neg(Box<Expr>),
+    Not(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Evaluate the expression against a row's values keyed by XML attribute.
+    pub fn eval(&self, values: &IndexMap<String, String>) -> Result<Value> {
+        Ok(match self {
+            Expr::Literal(value) => value.clone(),
+            Expr::Field(key) => {
+                Value::from_cell(values.get(*key).map(|v| v.as_str()).unwrap_or_default())
+            }
+            Expr::Neg(expr) => match expr.eval(values)?.number()? {
+                Value::Int(n) => Value::Int(-n),
+                Value::Float(n) => Value::Float(-n),
+                _ => unreachable!(),
+            },
+            Expr::Not(expr) => Value::Bool(!expr.eval(values)?.truthy()),
+            Expr::Binary(BinaryOp::And, lhs, rhs) => {
+                Value::Bool(lhs.eval(values)?.truthy() && rhs.eval(values)?.truthy())
+            }
+            Expr::Binary(BinaryOp::Or, lhs, rhs) => {
+                Value::Bool(lhs.eval(values)?.truthy() || rhs.eval(values)?.truthy())
+            }
+            Expr::Binary(op, lhs, rhs) => {
+                let (lhs, rhs) = (lhs.eval(values)?, rhs.eval(values)?);
+                match op {
+                    BinaryOp::Add => match (&lhs, &rhs) {
+                        (Value::Text(_), _) | (_, Value::Text(_)) => {
+                            Value::Text(format!("{}{}", lhs, rhs))
+                        }
+                        _ => lhs.arithmetic(&rhs, i128::checked_add, |a, b| a + b)?,
+                    },
+                    BinaryOp::Sub => lhs.arithmetic(&rhs, i128::checked_sub, |a, b| a - b)?,
+                    BinaryOp::Mul => lhs.arithmetic(&rhs, i128::checked_mul, |a, b| a * b)?,
+                    BinaryOp::Div | BinaryOp::Rem => {
+                        if !rhs.number()?.truthy() {
+                            bail!("division by zero");
+                        }
+                        if *op == BinaryOp::Rem {
+                            lhs.arithmetic(&rhs, i128::checked_rem, |a, b| a % b)?
+                        } else {
+                            // Integers only divide exactly. Anything else becomes a float
+                            // that's rounded when it's written to an integer field.
+                            let exact = |a: i128, b: i128| {
+                                a.checked_rem(b).filter(|r| *r == 0).and(a.checked_div(b))
+                            };
+                            match lhs.arithmetic(&rhs, exact, |a, b| a / b) {
+                                Ok(value) => value,
+                                Err(_) => Value::Float(lhs.float()? / rhs.float()?),
+                            }
+                        }
+                    }
+                    BinaryOp::BitAnd => Value::Int(lhs.int()? & rhs.int()?),
+                    BinaryOp::Contains => Value::Bool(
+                        lhs.to_string()
+                            .to_lowercase()
+                            .contains(&rhs.to_string().to_lowercase()),
+                    ),
+                    BinaryOp::Eq => Value::Bool(lhs.compare(&rhs).is_eq()),
+                    BinaryOp::Ne => Value::Bool(lhs.compare(&rhs).is_ne()),
+                    BinaryOp::Lt => Value::Bool(lhs.compare(&rhs).is_lt()),
+                    BinaryOp::Le => Value::Bool(lhs.compare(&rhs).is_le()),
+                    BinaryOp::Gt => Value::Bool(lhs.compare(&rhs).is_gt()),
+                    BinaryOp::Ge => Value::Bool(lhs.compare(&rhs).is_ge()),
+                    BinaryOp::And | BinaryOp::Or => unreachable!(),
+                }
+            }
+        })
+    }
+
+    /// Evaluate the expression as a condition.
+    pub fn matches(&self, values: &IndexMap<String, String>) -> Result<bool> {
+        Ok(self.eval(values)?.truthy())
+    }
+
+    /// Parse a standalone expression, ex. `rank == 1 && base_str < 10`.
+    pub fn parse(source: &str, fields: &'static [FieldSchema]) -> Result<Self> {
+        let mut parser = Parser::new(source, fields)?;
+        let expr = parser.expression()?;
+        parser.finish()?;
+        Ok(expr)
+    }
+}
+
+/// An assignment to one field of a row, ex. `base_str = base_str + 2 where rank == 1`.
+#[derive(Debug, Clone)]
+pub struct RowExpression {
+    target: &'static FieldSchema,
+    value: Expr,
+    condition: Option<Expr>,
+}
+
+impl RowExpression {
+    pub fn new(target: &'static FieldSchema, value: Expr, condition: Option<Expr>) -> Self {
+        Self {
+            target,
+            value,
+            condition,
+        }
+    }
+
+    pub fn parse(source: &str, fields: &'static [FieldSchema]) -> Result<Self> {
+        let mut parser = Parser::new(source, fields)?;
+        let target = parser.field()?;
+        parser.expect(&Token::Assign)?;
+        let value = parser.expression()?;
+        let condition = if parser.eat(&Token::Where) {
+            Some(parser.expression()?)
+        } else {
+            None
+        };
+        parser.finish()?;
+        Ok(Self::new(target, value, condition))
+    }
+
+    /// Apply the expression to a row.
+    /// Returns `None` if the row doesn't match the condition or the value is unchanged.
+    pub fn apply<I: SchemaRow>(&self, row: &I) -> Result<Option<I>> {
+        let values = row.field_values();
+        if let Some(condition) = &self.condition {
+            if !condition.matches(&values)? {
+                return Ok(None);
+            }
+        }
+        let value = self.value.eval(&values)?.to_cell(self.target.ty);
+        if values.get(self.target.key) == Some(&value) {
+            return Ok(None);
+        }
+        row.with_field_values([(self.target.key.to_string(), value)])
+            .map(Some)
+    }
+}
+
+fn parse_hex(value: &str) -> Option<i128> {
+    let hex = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))?;
+    i128::from_str_radix(hex, 16).ok()
+}
+
+/// Look up a field by its Rust name or XML attribute (case insensitive, `@` optional).
+pub fn find_field(fields: &'static [FieldSchema], name: &str) -> Option<&'static FieldSchema> {
+    let name = name.trim_start_matches('@');
+    fields.iter().find(|field| {
+        field.ident.eq_ignore_ascii_case(name)
+            || field.key.trim_start_matches('@').eq_ignore_ascii_case(name)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Int(i128),
+    Float(f64),
+    Bool(bool),
+    Text(String),
+    Ident(String),
+    Assign,
+    Where,
+    Op(BinaryOp),
+    Not,
+    Minus,
+    LParen,
+    RParen,
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        let next = chars.peek().copied();
+        let token = match (c, next) {
+            (c, _) if c.is_whitespace() => continue,
+            ('(', _) => Token::LParen,
+            (')', _) => Token::RParen,
+            ('+', _) => Token::Op(BinaryOp::Add),
+            ('-', _) => Token::Minus,
+            ('*', _) => Token::Op(BinaryOp::Mul),
+            ('/', _) => Token::Op(BinaryOp::Div),
+            ('%', _) => Token::Op(BinaryOp::Rem),
+            (':', _) => Token::Op(BinaryOp::Contains),
+            ('=', Some('=')) | ('!', Some('=')) | ('<', Some('=')) | ('>', Some('=')) => {
+                chars.next();
+                Token::Op(match c {
+                    '=' => BinaryOp::Eq,
+                    '!' => BinaryOp::Ne,
+                    '<' => BinaryOp::Le,
+                    _ => BinaryOp::Ge,
+                })
+            }
+            ('&', Some('&')) | ('|', Some('|')) => {
+                chars.next();
+                Token::Op(if c == '&' {
+                    BinaryOp::And
+                } else {
+                    BinaryOp::Or
+                })
+            }
+            ('&', _) => Token::Op(BinaryOp::BitAnd),
+            ('=', _) => Token::Assign,
+            ('!', _) => Token::Not,
+            ('<', _) => Token::Op(BinaryOp::Lt),
+            ('>', _) => Token::Op(BinaryOp::Gt),
+            ('"', _) => {
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => text.extend(chars.next()),
+                        Some(c) => text.push(c),
+                        None => bail!("unterminated string"),
+                    }
+                }
+                Token::Text(text)
+            }
+            (c, _) if c.is_ascii_digit() || c == '.' => {
+                let mut number = String::from(c);
+                while let Some(c) = chars.next_if(|c| c.is_ascii_alphanumeric() || *c == '.') {
+                    number.push(c);
+                }
+                if let Some(n) = number.parse().ok().or_else(|| parse_hex(&number)) {
+                    Token::Int(n)
+                } else {
+                    Token::Float(
+                        number
+                            .parse()
+                            .map_err(|_| anyhow!("invalid number '{}'", number))?,
+                    )
+                }
+            }
+            (c, _) if c.is_alphanumeric() || c == '_' || c == '@' => {
+                let mut ident = String::from(c);
+                while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || *c == '_') {
+                    ident.push(c);
+                }
+                match ident.to_lowercase().as_str() {
+                    "where" => Token::Where,
+                    "and" => Token::Op(BinaryOp::And),
+                    "or" => Token::Op(BinaryOp::Or),
+                    "not" => Token::Not,
+                    "true" => Token::Bool(true),
+                    "false" => Token::Bool(false),
+                    _ => Token::Ident(ident),
+                }
+            }
+            (c, _) => bail!("unexpected character '{}'", c),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    fields: &'static [FieldSchema],
+}
+
+impl Parser {
+    fn new(source: &str, fields: &'static [FieldSchema]) -> Result<Self> {
+        Ok(Self {
+            tokens: tokenize(source)?,
+            position: 0,
+            fields,
+        })
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &Token) -> Result<()> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            bail!("expected {:?}, found {:?}", token, self.peek())
+        }
+    }
+
+    fn finish(&self) -> Result<()> {
+        match self.peek() {
+            Some(token) => bail!("unexpected {:?}", token),
+            None => Ok(()),
+        }
+    }
+
+    fn field(&mut self) -> Result<&'static FieldSchema> {
+        match self.next() {
+            Some(Token::Ident(name)) => {
+                find_field(self.fields, &name).ok_or_else(|| anyhow!("unknown field '{}'", name))
+            }
+            token => bail!("expected a field, found {:?}", token),
+        }
+    }
+
+    fn expression(&mut self) -> Result<Expr> {
+        self.binary(0)
+    }
+
+    /// Precedence climbing over the binary operators, loosest first.
+    fn binary(&mut self, level: usize) -> Result<Expr> {
+        const LEVELS: &[&[BinaryOp]] = &[
+            &[BinaryOp::Or],
+            &[BinaryOp::And],
+            &[
+                BinaryOp::Contains,
+                BinaryOp::Eq,
+                BinaryOp::Ne,
+                BinaryOp::Lt,
+                BinaryOp::Le,
+                BinaryOp::Gt,
+                BinaryOp::Ge,
+            ],
+            &[BinaryOp::Add, BinaryOp::Sub],
+            &[
+                BinaryOp::Mul,
+                BinaryOp::Div,
+                BinaryOp::Rem,
+                BinaryOp::BitAnd,
+            ],
+        ];
+        if level == LEVELS.len() {
+            return self.unary();
+        }
+        let mut lhs = self.binary(level + 1)?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Op(op)) if LEVELS[level].contains(op) => *op,
+                Some(Token::Minus) if LEVELS[level].contains(&BinaryOp::Sub) => BinaryOp::Sub,
+                _ => return Ok(lhs),
+            };
+            self.position += 1;
+            let rhs = self.binary(level + 1)?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr> {
+        match self.next() {
+            Some(Token::Minus) => Ok(Expr::Neg(Box::new(self.unary()?))),
+            Some(Token::Not) => Ok(Expr::Not(Box::new(self.unary()?))),
+            Some(Token::Int(n)) => Ok(Expr::Literal(Value::Int(n))),
+            Some(Token::Float(n)) => Ok(Expr::Literal(Value::Float(n))),
+            Some(Token::Bool(b)) => Ok(Expr::Literal(Value::Bool(b))),
+            Some(Token::Text(s)) => Ok(Expr::Literal(Value::Text(s))),
+            Some(Token::Ident(name)) => find_field(self.fields, &name)
+                .map(|field| Expr::Field(field.key))
+                .ok_or_else(|| anyhow!("unknown field '{}'", name)),
+            Some(Token::LParen) => {
+                let expr = self.expression()?;
+                self.expect(&Token::RParen)?;
+                Ok(expr)
+            }
+            token => bail!("unexpected {:?}", token),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn field(ident: &'static str, key: &'static str, ty: &'static str) -> FieldSchema {
+        FieldSchema {
+            ident,
+            key,
+            ty,
+            id: false,
+            public_array: false,
+        }
+    }
+
+    const FIELDS: &[FieldSchema] = &[
+        field("rank", "@Rank", "u8"),
+        field("price", "@Price", "u64"),
+        field("weight", "@Weight", "f32"),
+        field("name", "@Name", "String"),
+        field("hidden", "@Hidden", "bool"),
+    ];
+
+    fn row(price: &str) -> IndexMap<String, String> {
+        [
+            ("@Rank", "2"),
+            ("@Price", price),
+            ("@Weight", "1.5"),
+            ("@Name", "Iron Sword"),
+            ("@Hidden", "false"),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+    }
+
+    fn eval(source: &str, values: &IndexMap<String, String>) -> Result<Value> {
+        Expr::parse(source, FIELDS)?.eval(values)
+    }
+
+    #[test]
+    fn tokenizes_operators_literals_and_keywords() {
+        assert_eq!(
+            tokenize(r#"@Rank >= 2 and name != "a\"b" || !x & 3.5"#).unwrap(),
+            vec![
+                Token::Ident("@Rank".to_string()),
+                Token::Op(BinaryOp::Ge),
+                Token::Int(2),
+                Token::Op(BinaryOp::And),
+                Token::Ident("name".to_string()),
+                Token::Op(BinaryOp::Ne),
+                Token::Text("a\"b".to_string()),
+                Token::Op(BinaryOp::Or),
+                Token::Not,
+                Token::Ident("x".to_string()),
+                Token::Op(BinaryOp::BitAnd),
+                Token::Float(3.5),
+            ]
+        );
+        assert_eq!(
+            tokenize("name:sword & 0x1F").unwrap(),
+            vec![
+                Token::Ident("name".to_string()),
+                Token::Op(BinaryOp::Contains),
+                Token::Ident("sword".to_string()),
+                Token::Op(BinaryOp::BitAnd),
+                Token::Int(31),
+            ]
+        );
+        assert_eq!(
+            tokenize("price = 1 WHERE true").unwrap(),
+            vec![
+                Token::Ident("price".to_string()),
+                Token::Assign,
+                Token::Int(1),
+                Token::Where,
+                Token::Bool(true),
+            ]
+        );
+        assert!(tokenize("\"open").is_err());
+        assert!(tokenize("rank # 2").is_err());
+    }
+
+    #[test]
+    fn parses_with_precedence() {
+        let values = row("100");
+        assert_eq!(eval("1 + 2 * 3", &values).unwrap(), Value::Int(7));
+        assert_eq!(eval("(1 + 2) * 3", &values).unwrap(), Value::Int(9));
+        assert_eq!(eval("10 - 2 - 3", &values).unwrap(), Value::Int(5));
+        assert_eq!(eval("-rank + 1", &values).unwrap(), Value::Int(-1));
+        assert_eq!(
+            eval("rank == 1 || rank == 2 && price > 50", &values).unwrap(),
+            Value::Bool(true)
+        );
+        assert!(Expr::parse("rank +", FIELDS).is_err());
+        assert!(Expr::parse("(rank", FIELDS).is_err());
+        assert!(Expr::parse("rank rank", FIELDS).is_err());
+        assert!(Expr::parse("strength > 1", FIELDS).is_err());
+    }
+
+    #[test]
+    fn finds_fields_by_ident_or_key() {
+        assert_eq!(find_field(FIELDS, "Price").unwrap().key, "@Price");
+        assert_eq!(find_field(FIELDS, "@price").unwrap().ident, "price");
+        assert_eq!(find_field(FIELDS, "Weight").unwrap().ident, "weight");
+        assert!(find_field(FIELDS, "strength").is_none());
+    }
+
+    #[test]
+    fn keeps_large_integers_exact() {
+        let values = row("18446744073709551615");
+        assert_eq!(
+            eval("price - 1", &values).unwrap(),
+            Value::Int(18446744073709551614)
+        );
+        assert_eq!(
+            eval("price == 18446744073709551615", &values).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            eval("price > 18446744073709551614", &values).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(eval("price & 255", &values).unwrap(), Value::Int(255));
+    }
+
+    #[test]
+    fn mixes_integers_and_floats() {
+        let values = row("100");
+        assert_eq!(eval("price / 4", &values).unwrap(), Value::Int(25));
+        assert_eq!(eval("price / 3", &values).unwrap().to_cell("u64"), "33");
+        assert_eq!(eval("price % 7", &values).unwrap(), Value::Int(2));
+        assert_eq!(eval("weight * 2", &values).unwrap(), Value::Float(3.));
+        assert_eq!(eval("price * 1.5", &values).unwrap().to_cell("u64"), "150");
+        assert!(eval("price / 0", &values).is_err());
+        assert!(eval("name * 2", &values).is_err());
+        assert!(eval("weight & 1", &values).is_err());
+    }
+
+    #[test]
+    fn evaluates_text_and_bools() {
+        let values = row("100");
+        assert_eq!(
+            eval(r#"name + "+""#, &values).unwrap(),
+            Value::Text("Iron Sword+".to_string())
+        );
+        assert_eq!(
+            eval(r#"name == "Iron Sword""#, &values).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            eval(r#"name == "iron sword""#, &values).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            eval(r#"name : "SWORD" && rank == 0x2"#, &values).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(eval("hidden == false", &values).unwrap(), Value::Bool(true));
+        assert_eq!(eval("not hidden", &values).unwrap(), Value::Bool(true));
+        assert_eq!(eval("hidden + 1", &values).unwrap(), Value::Int(1));
+        assert_eq!(Value::Int(2).to_cell("bool"), "true");
+        assert_eq!(Value::Bool(true).to_cell("Option<i8>"), "1");
+    }
+
+    #[test]
+    fn parses_row_expressions() {
+        let expression = RowExpression::parse("price = price + 1 where rank == 2", FIELDS).unwrap();
+        assert_eq!(expression.target.key, "@Price");
+        assert!(expression.condition.is_some());
+        assert!(RowExpression::parse("price + 1", FIELDS).is_err());
+        assert!(RowExpression::parse("price = 1 where", FIELDS).is_err());
+    }
+}
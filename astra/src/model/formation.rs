@@ -0,0 +1,146 @@
+use anyhow::{bail, Result};
+use astra_types::Spawn;
+use indexmap::IndexMap;
+
+use crate::CoordinateKind;
+
+/// A spawn in a dispos, as its group name and index in the group.
+pub type SpawnRef = (String, usize);
+
+/// The spawn's `(x, y)` position for the given kind of coordinates.
+pub fn spawn_position(spawn: &Spawn, coordinate_kind: CoordinateKind) -> (i32, i32) {
+    match coordinate_kind {
+        CoordinateKind::Dispos => (spawn.dispos_x as i32, spawn.dispos_y as i32),
+        CoordinateKind::Appear => (spawn.appear_x as i32, spawn.appear_y as i32),
+    }
+}
+
+pub fn set_spawn_position(spawn: &mut Spawn, coordinate_kind: CoordinateKind, (x, y): (i32, i32)) {
+    let (x, y) = (clamp_coordinate(x), clamp_coordinate(y));
+    match coordinate_kind {
+        CoordinateKind::Dispos => {
+            spawn.dispos_x = x;
+            spawn.dispos_y = y;
+        }
+        CoordinateKind::Appear => {
+            spawn.appear_x = x;
+            spawn.appear_y = y;
+        }
+    }
+}
+
+fn clamp_coordinate(value: i32) -> i8 {
+    value.clamp(i8::MIN as i32, i8::MAX as i32) as i8
+}
+
+fn in_area((x, y): (i32, i32), width: i32, height: i32) -> bool {
+    (0..width).contains(&x) && (0..height).contains(&y)
+}
+
+/// Move spawns together by `offset`. Nothing moves if any of them would leave
+/// the `width` x `height` playable area. Returns true if the spawns moved.
+pub fn move_spawns(
+    dispos: &mut IndexMap<String, Vec<Spawn>>,
+    spawns: &[SpawnRef],
+    coordinate_kind: CoordinateKind,
+    offset: (i32, i32),
+    width: i32,
+    height: i32,
+) -> bool {
+    if offset == (0, 0) {
+        return false;
+    }
+    let moved = |spawn: &Spawn| {
+        let (x, y) = spawn_position(spawn, coordinate_kind);
+        (x + offset.0, y + offset.1)
+    };
+    let fits = spawns
+        .iter()
+        .filter_map(|(group, index)| dispos.get(group).and_then(|group| group.get(*index)))
+        .all(|spawn| in_area(moved(spawn), width, height));
+    if !fits {
+        return false;
+    }
+    let mut changed = false;
+    for (group, index) in spawns {
+        if let Some(spawn) = dispos
+            .get_mut(group)
+            .and_then(|group| group.get_mut(*index))
+        {
+            let position = moved(spawn);
+            set_spawn_position(spawn, coordinate_kind, position);
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Spawns copied from a dispos so they can be placed again as a group, in the same or another chapter.
+#[derive(Debug, Clone, Default)]
+pub struct Formation {
+    spawns: Vec<(String, Spawn)>,
+}
+
+impl Formation {
+    pub fn copy(dispos: &IndexMap<String, Vec<Spawn>>, spawns: &[SpawnRef]) -> Self {
+        Self {
+            spawns: spawns
+                .iter()
+                .filter_map(|(group, index)| {
+                    dispos
+                        .get(group)
+                        .and_then(|spawns| spawns.get(*index))
+                        .map(|spawn| (group.clone(), spawn.clone()))
+                })
+                .collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.spawns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spawns.is_empty()
+    }
+
+    /// Add the formation's spawns to their groups in `dispos`, creating any that are missing.
+    /// The corner of the formation closest to (0, 0) is placed on `at`. Both kinds of coordinates
+    /// move by the same amount so spawns keep their appear positions relative to their dispos ones.
+    /// Returns where the new spawns ended up.
+    pub fn paste(
+        &self,
+        dispos: &mut IndexMap<String, Vec<Spawn>>,
+        coordinate_kind: CoordinateKind,
+        at: (i32, i32),
+        width: i32,
+        height: i32,
+    ) -> Result<Vec<SpawnRef>> {
+        let positions = self
+            .spawns
+            .iter()
+            .map(|(_, spawn)| spawn_position(spawn, coordinate_kind));
+        let corner = positions
+            .clone()
+            .reduce(|(x_a, y_a), (x_b, y_b)| (x_a.min(x_b), y_a.min(y_b)))
+            .unwrap_or_default();
+        let offset = (at.0 - corner.0, at.1 - corner.1);
+        let mut positions = positions.map(|(x, y)| (x + offset.0, y + offset.1));
+        if !positions.all(|position| in_area(position, width, height)) {
+            bail!("the formation doesn't fit in the playable area there");
+        }
+
+        let mut pasted = vec![];
+        for (group, spawn) in &self.spawns {
+            let mut spawn = spawn.clone();
+            for kind in [CoordinateKind::Dispos, CoordinateKind::Appear] {
+                let (x, y) = spawn_position(&spawn, kind);
+                set_spawn_position(&mut spawn, kind, (x + offset.0, y + offset.1));
+            }
+            let spawns = dispos.entry(group.clone()).or_default();
+            spawns.push(spawn);
+            pasted.push((group.clone(), spawns.len() - 1));
+        }
+        Ok(pasted)
+    }
+}
@@ -0,0 +1,489 @@
+use std::any::Any;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+/// Changes to the same source within this window are merged into one history entry.
+/// Keeps dragging a slider or painting a stroke from filling the history one frame at a time.
+const MERGE_WINDOW: Duration = Duration::from_millis(750);
+
+/// Maximum number of entries kept in the undo stack.
+const HISTORY_LIMIT: usize = 200;
+
+/// Approximate number of bytes the undo stack may hold before the oldest entries are dropped.
+/// The latest entry is always kept, however large it is.
+const HISTORY_SIZE_LIMIT: usize = 256 * 1024 * 1024;
+
+static HISTORY: OnceLock<Mutex<EditHistory>> = OnceLock::new();
+
+static NEXT_TRACKER_ID: AtomicUsize = AtomicUsize::new(0);
+
+type Snapshot = Arc<dyn Any + Send + Sync>;
+type Capture = Arc<dyn Fn() -> Snapshot + Send + Sync>;
+type Restore = Arc<dyn Fn(&Snapshot) + Send + Sync>;
+type Size = Arc<dyn Fn(&Snapshot) -> usize + Send + Sync>;
+
+/// A snapshot that may not have been taken yet.
+/// Trackers leave the "after" state of their latest change empty while the live value still
+/// matches it, so a burst of edits doesn't copy the value on every frame.
+type LazySnapshot = Arc<Mutex<Option<Snapshot>>>;
+
+struct HistoryEntry {
+    source: String,
+    time: Instant,
+    before: Snapshot,
+    after: LazySnapshot,
+    capture: Option<Capture>,
+    restore: Restore,
+    sealed: bool,
+    /// Approximate number of bytes used by the "before" and "after" states.
+    size: usize,
+}
+
+impl HistoryEntry {
+    fn after(&self) -> Option<Snapshot> {
+        let mut after = self.after.lock();
+        if after.is_none() {
+            *after = self.capture.as_ref().map(|capture| capture());
+        }
+        after.clone()
+    }
+}
+
+/// Global undo/redo stack shared by every editor.
+/// Entries restore full snapshots of whatever was edited, so undo works the same for sheets,
+/// terrain, and messages.
+///
+/// Since snapshots are full copies, each entry for a sheet costs about twice the sheet's size.
+/// Entries report their approximate size and the oldest are dropped once the stack is over
+/// [HISTORY_SIZE_LIMIT], so editing a large sheet keeps fewer steps than [HISTORY_LIMIT].
+#[derive(Default)]
+pub struct EditHistory {
+    undo: Vec<HistoryEntry>,
+    redo: Vec<HistoryEntry>,
+    /// Whether changes are being collected by [EditHistory::group].
+    grouping: bool,
+}
+
+impl EditHistory {
+    fn with<V>(consumer: impl FnOnce(&mut EditHistory) -> V) -> V {
+        let lock = HISTORY.get_or_init(|| Mutex::new(EditHistory::default()));
+        let mut history = lock.lock();
+        consumer(&mut history)
+    }
+
+    /// Record a change. `source` identifies what was changed and is used to merge rapid edits.
+    /// Meant for small values, since only their inline size counts towards [HISTORY_SIZE_LIMIT].
+    pub fn record<T>(
+        source: String,
+        before: T,
+        after: T,
+        restore: impl Fn(&T) + Send + Sync + 'static,
+    ) where
+        T: Send + Sync + 'static,
+    {
+        Self::push(
+            source,
+            Arc::new(before),
+            Arc::new(Mutex::new(Some(Arc::new(after)))),
+            None,
+            Arc::new(move |snapshot: &Snapshot| {
+                if let Some(value) = snapshot.downcast_ref::<T>() {
+                    restore(value);
+                }
+            }),
+            2 * std::mem::size_of::<T>(),
+        );
+    }
+
+    fn push(
+        source: String,
+        before: Snapshot,
+        after: LazySnapshot,
+        capture: Option<Capture>,
+        restore: Restore,
+        size: usize,
+    ) {
+        Self::with(|history| {
+            history.redo.clear();
+            let now = Instant::now();
+            if let Some(last) = history.undo.last_mut() {
+                if !last.sealed
+                    && last.source == source
+                    && now.duration_since(last.time) < MERGE_WINDOW
+                {
+                    last.after = after;
+                    last.capture = capture;
+                    last.time = now;
+                    last.size = size;
+                    return;
+                }
+            }
+            history.undo.push(HistoryEntry {
+                source,
+                time: now,
+                before,
+                after,
+                capture,
+                restore,
+                sealed: false,
+                size,
+            });
+            // Entries in a group are counted once the group is combined.
+            if !history.grouping {
+                history.trim();
+            }
+        });
+    }
+
+    /// Drop the oldest entries until the undo stack is within [HISTORY_LIMIT] and [HISTORY_SIZE_LIMIT].
+    fn trim(&mut self) {
+        let mut size: usize = self.undo.iter().map(|entry| entry.size).sum();
+        while self.undo.len() > 1 && (self.undo.len() > HISTORY_LIMIT || size > HISTORY_SIZE_LIMIT)
+        {
+            size -= self.undo.remove(0).size;
+        }
+    }
+
+    /// Run several changes and record them as a single entry, so one undo reverts all of them.
+    pub fn group<V>(edit: impl FnOnce() -> V) -> V {
+        let Some(start) = Self::with(|history| {
+            if history.grouping {
+                return None;
+            }
+            history.grouping = true;
+            // Keep the changes from merging into an earlier entry.
+            if let Some(last) = history.undo.last_mut() {
+                last.sealed = true;
+            }
+            Some(history.undo.len())
+        }) else {
+            return edit();
+        };
+        let result = edit();
+        let entries: Vec<_> = Self::with(|history| {
+            history.grouping = false;
+            history
+                .undo
+                .drain(start.min(history.undo.len())..)
+                .collect()
+        });
+        if let [.., last] = entries.as_slice() {
+            // Take the "after" states now since the trackers won't fill them in for the combined entry.
+            let after: Vec<_> = entries.iter().map(|entry| entry.after()).collect();
+            let before: Vec<_> = entries
+                .iter()
+                .map(|entry| Some(entry.before.clone()))
+                .collect();
+            let restores: Vec<_> = entries.iter().map(|entry| entry.restore.clone()).collect();
+            let entry = HistoryEntry {
+                source: format!("group:{}", NEXT_TRACKER_ID.fetch_add(1, Ordering::Relaxed)),
+                time: last.time,
+                before: Arc::new(before),
+                after: Arc::new(Mutex::new(Some(Arc::new(after)))),
+                capture: None,
+                restore: Arc::new(move |snapshot: &Snapshot| {
+                    if let Some(parts) = snapshot.downcast_ref::<Vec<Option<Snapshot>>>() {
+                        for (restore, part) in restores.iter().zip(parts) {
+                            if let Some(part) = part {
+                                restore(part);
+                            }
+                        }
+                    }
+                }),
+                sealed: true,
+                size: entries.iter().map(|entry| entry.size).sum(),
+            };
+            Self::with(|history| {
+                history.undo.push(entry);
+                history.trim();
+            });
+        }
+        result
+    }
+
+    /// Whether a change from `source` made now would be merged into the latest entry.
+    fn merges(source: &str) -> bool {
+        Self::with(|history| {
+            history.undo.last().is_some_and(|last| {
+                !last.sealed && last.source == source && last.time.elapsed() < MERGE_WINDOW
+            })
+        })
+    }
+
+    /// Extend the merge window of the latest entry after another change from the same source.
+    fn touch(source: &str) {
+        Self::with(|history| {
+            if let Some(last) = history.undo.last_mut().filter(|last| last.source == source) {
+                last.time = Instant::now();
+            }
+        });
+    }
+
+    /// Revert the most recent change. Returns false if there was nothing to undo.
+    pub fn undo() -> bool {
+        // Release the lock before restoring since restoring may record new changes.
+        let Some(entry) = Self::with(|history| history.undo.pop()) else {
+            return false;
+        };
+        // Take the "after" state while the value still has it so the change can be redone.
+        entry.after();
+        (entry.restore)(&entry.before);
+        Self::with(|history| {
+            if let Some(last) = history.undo.last_mut() {
+                last.sealed = true;
+            }
+            history.redo.push(entry);
+        });
+        true
+    }
+
+    /// Reapply the most recently undone change. Returns false if there was nothing to redo.
+    pub fn redo() -> bool {
+        let Some(mut entry) = Self::with(|history| history.redo.pop()) else {
+            return false;
+        };
+        if let Some(after) = entry.after() {
+            (entry.restore)(&after);
+        }
+        // Don't merge a redone change with whatever the user edits next.
+        entry.sealed = true;
+        Self::with(|history| history.undo.push(entry));
+        true
+    }
+
+    pub fn can_undo() -> bool {
+        Self::with(|history| !history.undo.is_empty())
+    }
+
+    pub fn can_redo() -> bool {
+        Self::with(|history| !history.redo.is_empty())
+    }
+
+    /// Forget every recorded change, ex. when switching projects.
+    pub fn clear() {
+        Self::with(|history| {
+            history.undo.clear();
+            history.redo.clear();
+        });
+    }
+}
+
+/// Records undo history for a value that is edited in place, such as a sheet or terrain.
+/// Keeps a snapshot of the value as of the last recorded change to use as the "before" state.
+/// The snapshot is only taken once an edit can't be merged into the previous one,
+/// so continuous edits like dragging a slider copy the value once rather than every frame.
+pub struct HistoryTracker {
+    source: String,
+    baseline: Snapshot,
+    state: Mutex<TrackerState>,
+    capture: Capture,
+    apply: Box<dyn Fn(&Snapshot) + Send + Sync>,
+    size: Size,
+}
+
+#[derive(Default)]
+struct TrackerState {
+    /// The value before the next edit, if it has been captured.
+    snapshot: Option<Snapshot>,
+    /// The "after" state of the tracker's latest entry, if it hasn't been captured.
+    pending: Option<LazySnapshot>,
+}
+
+impl HistoryTracker {
+    /// Create a tracker from functions that copy the current value, overwrite it,
+    /// and estimate how many bytes a copy uses.
+    /// The current value is copied right away as the baseline to compare edits against.
+    pub fn new<T>(
+        capture: impl Fn() -> T + Send + Sync + 'static,
+        apply: impl Fn(&T) + Send + Sync + 'static,
+        size: impl Fn(&T) -> usize + Send + Sync + 'static,
+    ) -> Arc<Self>
+    where
+        T: Send + Sync + 'static,
+    {
+        let baseline: Snapshot = Arc::new(capture());
+        Arc::new(Self {
+            source: format!(
+                "tracker:{}",
+                NEXT_TRACKER_ID.fetch_add(1, Ordering::Relaxed)
+            ),
+            baseline: baseline.clone(),
+            state: Mutex::new(TrackerState {
+                snapshot: Some(baseline),
+                pending: None,
+            }),
+            capture: Arc::new(move || Arc::new(capture())),
+            apply: Box::new(move |snapshot: &Snapshot| {
+                if let Some(value) = snapshot.downcast_ref::<T>() {
+                    apply(value);
+                }
+            }),
+            size: Arc::new(move |snapshot: &Snapshot| {
+                snapshot.downcast_ref::<T>().map(&size).unwrap_or_default()
+            }),
+        })
+    }
+
+    /// The value as it was when the tracker was created.
+    /// Returns [None] if `T` isn't the tracked type.
+    pub fn baseline<T>(&self) -> Option<Arc<T>>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.baseline.clone().downcast().ok()
+    }
+
+    /// Copy the current value as the "before" state of the next edit.
+    /// It's also the "after" state of the previous one if that wasn't captured yet.
+    fn capture_snapshot(&self) {
+        let snapshot = (self.capture)();
+        let mut state = self.state.lock();
+        if let Some(pending) = state.pending.take() {
+            *pending.lock() = Some(snapshot.clone());
+        }
+        state.snapshot = Some(snapshot);
+    }
+
+    /// Run an edit and record it if the edit returns true.
+    pub fn track(self: &Arc<Self>, edit: impl FnOnce() -> bool) -> bool {
+        let captured = self.state.lock().snapshot.is_some();
+        // The latest entry already has the "before" state for an edit that gets merged into it.
+        let merging = !captured && EditHistory::merges(&self.source);
+        if !captured && !merging {
+            self.capture_snapshot();
+        }
+        let changed = edit();
+        if changed && merging {
+            EditHistory::touch(&self.source);
+        } else if changed {
+            let after: LazySnapshot = Default::default();
+            let before = {
+                let mut state = self.state.lock();
+                state.pending = Some(after.clone());
+                state.snapshot.take()
+            };
+            let Some(before) = before else {
+                return changed;
+            };
+            // The "after" state isn't captured yet, so assume it's about as big as the "before" state.
+            let size = 2 * (self.size)(&before);
+            let tracker = self.clone();
+            EditHistory::push(
+                self.source.clone(),
+                before,
+                after,
+                Some(self.capture.clone()),
+                Arc::new(move |snapshot: &Snapshot| {
+                    (tracker.apply)(snapshot);
+                    let mut state = tracker.state.lock();
+                    state.snapshot = Some(snapshot.clone());
+                    state.pending = None;
+                }),
+                size,
+            );
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicI32;
+
+    use super::*;
+
+    // The history is global, so everything that touches it runs in one test.
+    #[test]
+    fn tracks_undo_and_redo_without_copying_every_edit() {
+        EditHistory::clear();
+        let value = Arc::new(AtomicI32::new(0));
+        let captures = Arc::new(AtomicUsize::new(0));
+        let tracker = {
+            let (read, write) = (value.clone(), value.clone());
+            let captures = captures.clone();
+            HistoryTracker::new(
+                move || {
+                    captures.fetch_add(1, Ordering::Relaxed);
+                    read.load(Ordering::Relaxed)
+                },
+                move |snapshot: &i32| write.store(*snapshot, Ordering::Relaxed),
+                |_| 4,
+            )
+        };
+        let set = |to: i32| {
+            tracker.track(|| value.swap(to, Ordering::Relaxed) != to);
+        };
+
+        set(1);
+        set(2);
+        set(2);
+        set(3);
+        assert_eq!(captures.load(Ordering::Relaxed), 1);
+        assert_eq!(*tracker.baseline::<i32>().unwrap(), 0);
+
+        assert!(EditHistory::undo());
+        assert_eq!(value.load(Ordering::Relaxed), 0);
+        assert!(!EditHistory::can_undo());
+        assert!(EditHistory::redo());
+        assert_eq!(value.load(Ordering::Relaxed), 3);
+
+        // Redone changes aren't merged with the next edit.
+        set(4);
+        assert!(EditHistory::undo());
+        assert_eq!(value.load(Ordering::Relaxed), 3);
+        assert!(EditHistory::undo());
+        assert_eq!(value.load(Ordering::Relaxed), 0);
+        assert!(EditHistory::redo());
+        assert!(EditHistory::redo());
+        assert_eq!(value.load(Ordering::Relaxed), 4);
+        assert!(!EditHistory::can_redo());
+
+        // Grouped changes are undone together, even from different trackers.
+        let other = Arc::new(AtomicI32::new(0));
+        let other_tracker = {
+            let (read, write) = (other.clone(), other.clone());
+            HistoryTracker::new(
+                move || read.load(Ordering::Relaxed),
+                move |snapshot: &i32| write.store(*snapshot, Ordering::Relaxed),
+                |_| 4,
+            )
+        };
+        EditHistory::group(|| {
+            set(5);
+            other_tracker.track(|| other.swap(1, Ordering::Relaxed) != 1);
+        });
+        assert!(EditHistory::undo());
+        assert_eq!(value.load(Ordering::Relaxed), 4);
+        assert_eq!(other.load(Ordering::Relaxed), 0);
+        assert!(EditHistory::redo());
+        assert_eq!(value.load(Ordering::Relaxed), 5);
+        assert_eq!(other.load(Ordering::Relaxed), 1);
+        assert!(EditHistory::undo());
+        assert!(EditHistory::undo());
+        assert_eq!(value.load(Ordering::Relaxed), 3);
+
+        // The oldest entries are dropped once the history is too big.
+        EditHistory::clear();
+        let big = Arc::new(AtomicI32::new(0));
+        let big_tracker = {
+            let (read, write) = (big.clone(), big.clone());
+            HistoryTracker::new(
+                move || read.load(Ordering::Relaxed),
+                move |snapshot: &i32| write.store(*snapshot, Ordering::Relaxed),
+                |_| HISTORY_SIZE_LIMIT / 3,
+            )
+        };
+        big_tracker.track(|| big.swap(1, Ordering::Relaxed) != 1);
+        other_tracker.track(|| other.swap(2, Ordering::Relaxed) != 2);
+        big_tracker.track(|| big.swap(2, Ordering::Relaxed) != 2);
+        assert!(EditHistory::undo());
+        assert_eq!(big.load(Ordering::Relaxed), 1);
+        assert!(EditHistory::undo());
+        assert_eq!(other.load(Ordering::Relaxed), 0);
+        assert!(!EditHistory::can_undo());
+        EditHistory::clear();
+    }
+}
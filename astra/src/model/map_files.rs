@@ -0,0 +1,639 @@
+use anyhow::{anyhow, bail, Context, Result};
+use astra_formats::image::imageops::{self, FilterType};
+use astra_formats::image::{DynamicImage, Rgba, RgbaImage};
+use astra_formats::UString;
+use astra_types::{AstraSchema, Spawn};
+use egui::Color32;
+use indexmap::IndexMap;
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use crate::util::get_tile_color;
+use crate::{AppConfig, EditorState, SchemaRow, TerrainLayout};
+
+/// The size of a tile in exported images and Tiled maps.
+pub const MAP_TILE_SIZE: u32 = 48;
+
+/// Tiles per row in the tileset image written next to a Tiled map.
+const TILESET_COLUMNS: u32 = 16;
+
+/// The Tiled property that holds the name of the dispos group a spawn belongs to.
+const GROUP_PROPERTY: &str = "DisposGroup";
+
+/// The same icon the dispos grid shows for a spawn.
+fn spawn_icon(spawn: &Spawn, state: &EditorState) -> Option<DynamicImage> {
+    let texture_cache = state.texture_cache.borrow();
+    let person = state.person.read(|data| {
+        data.get(&spawn.pid).map(|person| {
+            (
+                person.unit_icon_id.clone(),
+                person.gender,
+                person.jid.clone(),
+            )
+        })
+    });
+    person
+        .and_then(|(unit_icon_id, gender, person_jid)| {
+            let jid = if spawn.jid.is_empty() {
+                person_jid
+            } else {
+                spawn.jid.clone()
+            };
+            state.job.read(|data| {
+                data.get(&jid).and_then(|job| {
+                    texture_cache
+                        .get_unit_image(
+                            &unit_icon_id,
+                            if gender == 2 {
+                                &job.unit_icon_id_f
+                            } else {
+                                &job.unit_icon_id_m
+                            },
+                            &job.unit_icon_weapon_id,
+                        )
+                        .cloned()
+                })
+            })
+        })
+        .or_else(|| {
+            texture_cache
+                .get_unit_image("000Dummy", "000Dummy", "Dummy")
+                .cloned()
+        })
+}
+
+fn to_rgba(color: Color32) -> Rgba<u8> {
+    Rgba([color.r(), color.g(), color.b(), 255])
+}
+
+fn fill_tile(image: &mut RgbaImage, left: u32, top: u32, color: Rgba<u8>) {
+    for y in top..top + MAP_TILE_SIZE {
+        for x in left..left + MAP_TILE_SIZE {
+            image.put_pixel(x, y, color);
+        }
+    }
+}
+
+/// Draw a chapter's playable area and the spawns on it, laid out like the dispos grid.
+pub fn map_image(
+    terrain: &astra_formats::TerrainData,
+    dispos: Option<&IndexMap<String, Vec<Spawn>>>,
+    state: &EditorState,
+    config: &AppConfig,
+) -> Result<RgbaImage> {
+    let layout = TerrainLayout::of(terrain)?;
+    let (width, height) = (terrain.width as u32, terrain.height as u32);
+    if width == 0 || height == 0 {
+        bail!("the chapter has no playable area");
+    }
+    let grid_line = Rgba([0, 0, 0, 255]);
+    let mut image = RgbaImage::from_pixel(width * MAP_TILE_SIZE, height * MAP_TILE_SIZE, grid_line);
+    state.terrain.read(|tiles| {
+        for y in 0..height {
+            for x in 0..width {
+                let color = layout
+                    .index(x as usize, y as usize)
+                    .and_then(|index| terrain.terrains.get(index))
+                    .and_then(|tid| tiles.get(tid.as_str()))
+                    .map(|tile| to_rgba(get_tile_color(tile, config)))
+                    .unwrap_or(grid_line);
+                let top = (height - 1 - y) * MAP_TILE_SIZE;
+                // Leave a line between tiles like the grid's spacing.
+                let inset = RgbaImage::from_pixel(MAP_TILE_SIZE - 1, MAP_TILE_SIZE - 1, color);
+                imageops::replace(&mut image, &inset, (x * MAP_TILE_SIZE) as i64, top as i64);
+            }
+        }
+    });
+
+    for spawn in dispos
+        .into_iter()
+        .flat_map(|dispos| dispos.values().flatten())
+    {
+        let (x, y) = (spawn.dispos_x as i32, spawn.dispos_y as i32);
+        if !(0..width as i32).contains(&x) || !(0..height as i32).contains(&y) {
+            continue;
+        }
+        if let Some(icon) = spawn_icon(spawn, state) {
+            let icon = imageops::resize(
+                &icon.to_rgba8(),
+                MAP_TILE_SIZE,
+                MAP_TILE_SIZE,
+                FilterType::Nearest,
+            );
+            let top = (height as i32 - 1 - y) as u32 * MAP_TILE_SIZE;
+            imageops::overlay(
+                &mut image,
+                &icon,
+                (x as u32 * MAP_TILE_SIZE) as i64,
+                top as i64,
+            );
+        }
+    }
+    Ok(image)
+}
+
+/// A chapter map in Tiled's TMX format, along with the tileset image it refers to.
+pub struct TmxExport {
+    pub xml: String,
+    pub tileset: RgbaImage,
+}
+
+/// Convert a chapter's terrain and spawns to a Tiled map.
+/// Every tile kind gets a tile in the tileset with its TID as a property,
+/// and spawns become rectangle objects with their fields as properties.
+pub fn map_to_tmx(
+    terrain: &astra_formats::TerrainData,
+    dispos: Option<&IndexMap<String, Vec<Spawn>>>,
+    state: &EditorState,
+    config: &AppConfig,
+    tileset_file_name: &str,
+) -> Result<TmxExport> {
+    let layout = TerrainLayout::of(terrain)?;
+    let map_tid = |x: usize, y: usize| {
+        layout
+            .index(x, y)
+            .and_then(|index| terrain.terrains.get(index))
+            .map(|tid| tid.to_string())
+    };
+    let book_tids = state.terrain.read(|tiles| {
+        tiles
+            .values()
+            .map(|tile| (tile.tid.clone(), get_tile_color(tile, config)))
+            .collect()
+    });
+    Ok(write_tmx(
+        (terrain.width as usize, terrain.height as usize),
+        (terrain.x, terrain.z),
+        map_tid,
+        book_tids,
+        dispos,
+        tileset_file_name,
+    ))
+}
+
+/// [map_to_tmx] for a `width` x `height` map whose tiles come from `map_tid`.
+/// `tids` are the tile kinds from the terrain book with their colors.
+fn write_tmx(
+    (width, height): (usize, usize),
+    (origin_x, origin_z): (i32, i32),
+    map_tid: impl Fn(usize, usize) -> Option<String>,
+    mut tids: IndexMap<String, Color32>,
+    dispos: Option<&IndexMap<String, Vec<Spawn>>>,
+    tileset_file_name: &str,
+) -> TmxExport {
+    // Add any tiles the map uses that the book doesn't know about.
+    for y in 0..height {
+        for x in 0..width {
+            if let Some(tid) = map_tid(x, y) {
+                tids.entry(tid).or_insert(Color32::from_gray(0));
+            }
+        }
+    }
+    let colors: Vec<_> = tids.values().copied().collect();
+
+    let rows = (colors.len() as u32).div_ceil(TILESET_COLUMNS).max(1);
+    let mut tileset = RgbaImage::new(TILESET_COLUMNS * MAP_TILE_SIZE, rows * MAP_TILE_SIZE);
+    for (i, color) in colors.iter().enumerate() {
+        let (column, row) = (i as u32 % TILESET_COLUMNS, i as u32 / TILESET_COLUMNS);
+        fill_tile(
+            &mut tileset,
+            column * MAP_TILE_SIZE,
+            row * MAP_TILE_SIZE,
+            to_rgba(*color),
+        );
+    }
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let spawn_count = dispos.map(|dispos| dispos.values().flatten().count());
+    xml.push_str(&format!(
+        "<map version=\"1.10\" orientation=\"orthogonal\" renderorder=\"right-down\" width=\"{}\" height=\"{}\" tilewidth=\"{size}\" tileheight=\"{size}\" infinite=\"0\" nextlayerid=\"3\" nextobjectid=\"{}\">\n",
+        width,
+        height,
+        spawn_count.unwrap_or_default() + 1,
+        size = MAP_TILE_SIZE,
+    ));
+    xml.push_str(" <properties>\n");
+    xml.push_str(&format!(
+        "  <property name=\"X\" type=\"int\" value=\"{}\"/>\n",
+        origin_x
+    ));
+    xml.push_str(&format!(
+        "  <property name=\"Z\" type=\"int\" value=\"{}\"/>\n",
+        origin_z
+    ));
+    xml.push_str(" </properties>\n");
+    xml.push_str(&format!(
+        " <tileset firstgid=\"1\" name=\"Terrain\" tilewidth=\"{size}\" tileheight=\"{size}\" tilecount=\"{}\" columns=\"{}\">\n",
+        tids.len(),
+        TILESET_COLUMNS,
+        size = MAP_TILE_SIZE,
+    ));
+    xml.push_str(&format!(
+        "  <image source=\"{}\" width=\"{}\" height=\"{}\"/>\n",
+        escape(tileset_file_name),
+        tileset.width(),
+        tileset.height()
+    ));
+    for (id, tid) in tids.keys().enumerate() {
+        xml.push_str(&format!(
+            "  <tile id=\"{}\">\n   <properties>\n    <property name=\"TID\" value=\"{}\"/>\n   </properties>\n  </tile>\n",
+            id,
+            escape(tid)
+        ));
+    }
+    xml.push_str(" </tileset>\n");
+
+    // Tiled counts rows from the top, but the game counts them from the bottom.
+    xml.push_str(&format!(
+        " <layer id=\"1\" name=\"Terrain\" width=\"{}\" height=\"{}\">\n  <data encoding=\"csv\">\n",
+        width, height
+    ));
+    let rows: Vec<String> = (0..height)
+        .rev()
+        .map(|y| {
+            (0..width)
+                .map(|x| {
+                    map_tid(x, y)
+                        .and_then(|tid| tids.get_index_of(&tid))
+                        .map(|index| index + 1)
+                        .unwrap_or_default()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+    xml.push_str(&rows.join(",\n"));
+    xml.push_str("\n  </data>\n </layer>\n");
+
+    if let Some(dispos) = dispos {
+        let position_keys = position_keys();
+        xml.push_str(" <objectgroup id=\"2\" name=\"Spawns\">\n");
+        let mut id = 1;
+        for (group, spawns) in dispos {
+            for spawn in spawns {
+                let top = (height as i32 - 1 - spawn.dispos_y as i32) * MAP_TILE_SIZE as i32;
+                xml.push_str(&format!(
+                    "  <object id=\"{}\" name=\"{}\" type=\"Spawn\" x=\"{}\" y=\"{}\" width=\"{size}\" height=\"{size}\">\n   <properties>\n",
+                    id,
+                    escape(&spawn.pid),
+                    spawn.dispos_x as i32 * MAP_TILE_SIZE as i32,
+                    top,
+                    size = MAP_TILE_SIZE,
+                ));
+                xml.push_str(&format!(
+                    "    <property name=\"{}\" value=\"{}\"/>\n",
+                    GROUP_PROPERTY,
+                    escape(group)
+                ));
+                for (key, value) in spawn.field_values() {
+                    if !position_keys.contains(&key.as_str()) {
+                        xml.push_str(&format!(
+                            "    <property name=\"{}\" value=\"{}\"/>\n",
+                            escape(&key),
+                            escape(&value)
+                        ));
+                    }
+                }
+                xml.push_str("   </properties>\n  </object>\n");
+                id += 1;
+            }
+        }
+        xml.push_str(" </objectgroup>\n");
+    }
+    xml.push_str("</map>\n");
+    TmxExport { xml, tileset }
+}
+
+/// The spawn fields that come from an object's position instead of its properties.
+fn position_keys() -> [&'static str; 2] {
+    let key = |ident| {
+        Spawn::field_by_ident(ident)
+            .map(|field| field.key)
+            .unwrap_or_default()
+    };
+    [key("dispos_x"), key("dispos_y")]
+}
+
+/// A map read from a Tiled TMX file.
+#[derive(Debug, Clone, Default)]
+pub struct TmxMap {
+    pub width: i32,
+    pub height: i32,
+    pub x: Option<i32>,
+    pub z: Option<i32>,
+    /// The TID of every tile, row by row from the bottom like the game stores them.
+    pub tiles: Vec<String>,
+    /// The spawns from the first object layer, if the map has one.
+    pub spawns: Option<IndexMap<String, Vec<Spawn>>>,
+}
+
+impl TmxMap {
+    /// Replace the chapter's terrain with the map's tiles. The terrain buffer keeps its size.
+    pub fn apply_terrain(&self, terrain: &mut astra_formats::TerrainData) -> Result<()> {
+        let layout = TerrainLayout::of(terrain)?;
+        if self.width as usize > layout.stride || self.height as usize > layout.rows {
+            bail!(
+                "the {}x{} map doesn't fit in the chapter's {}x{} terrain buffer",
+                self.width,
+                self.height,
+                layout.stride,
+                layout.rows
+            );
+        }
+        for (i, tid) in self.tiles.iter().enumerate() {
+            let (x, y) = (i % self.width as usize, i / self.width as usize);
+            if let Some(index) = layout.index(x, y) {
+                terrain.terrains[index] = UString(tid.clone());
+            }
+        }
+        terrain.width = self.width;
+        terrain.height = self.height;
+        if let Some(x) = self.x {
+            terrain.x = x;
+        }
+        if let Some(z) = self.z {
+            terrain.z = z;
+        }
+        Ok(())
+    }
+}
+
+fn attribute(element: &BytesStart<'_>, name: &str) -> Result<Option<String>> {
+    match element.try_get_attribute(name)? {
+        Some(attribute) => Ok(Some(attribute.unescape_value()?.into_owned())),
+        None => Ok(None),
+    }
+}
+
+fn required_attribute(element: &BytesStart<'_>, name: &str) -> Result<String> {
+    attribute(element, name)?.ok_or_else(|| {
+        anyhow!(
+            "<{}> is missing '{}'",
+            String::from_utf8_lossy(element.name().as_ref()),
+            name
+        )
+    })
+}
+
+fn number_attribute<T: std::str::FromStr>(element: &BytesStart<'_>, name: &str) -> Result<T> {
+    let value = required_attribute(element, name)?;
+    value
+        .parse()
+        .map_err(|_| anyhow!("'{}' is not a valid {}", value, name))
+}
+
+/// An object from the spawn layer while its properties are being read.
+struct PendingSpawn {
+    x: f64,
+    y: f64,
+    properties: IndexMap<String, String>,
+}
+
+/// Read a map written by [map_to_tmx], after it's been edited in Tiled.
+/// Only CSV tile data and an embedded tileset with TID properties are supported.
+pub fn parse_tmx(xml: &str) -> Result<TmxMap> {
+    let mut map = TmxMap::default();
+    let mut tile_size = (MAP_TILE_SIZE as f64, MAP_TILE_SIZE as f64);
+    let mut first_gid = 1;
+    let mut tileset_tids: IndexMap<u32, String> = IndexMap::new();
+    let mut tile_id = None;
+    let mut gids = None;
+    let mut in_data = false;
+    let mut in_object_layer = false;
+    let mut pending_spawn: Option<PendingSpawn> = None;
+    let mut spawns: Vec<(String, Spawn)> = vec![];
+    let position_keys = position_keys();
+
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+    loop {
+        let event = reader.read_event().context("invalid TMX")?;
+        let is_empty = matches!(event, Event::Empty(_));
+        match &event {
+            Event::Start(element) | Event::Empty(element) => {
+                match element.name().as_ref() {
+                    b"map" => {
+                        map.width = number_attribute(element, "width")?;
+                        map.height = number_attribute(element, "height")?;
+                        tile_size = (
+                            number_attribute(element, "tilewidth")?,
+                            number_attribute(element, "tileheight")?,
+                        );
+                    }
+                    b"tileset" => {
+                        if attribute(element, "source")?.is_some() {
+                            bail!(
+                                "external tilesets aren't supported, embed the tileset in the map"
+                            );
+                        }
+                        first_gid = number_attribute(element, "firstgid")?;
+                    }
+                    b"tile" => tile_id = Some(number_attribute::<u32>(element, "id")?),
+                    b"data" => {
+                        if attribute(element, "encoding")?.as_deref() != Some("csv") {
+                            bail!("only CSV tile layers are supported");
+                        }
+                        in_data = gids.is_none();
+                    }
+                    b"objectgroup" if map.spawns.is_none() => {
+                        if is_empty {
+                            map.spawns = Some(IndexMap::new());
+                        } else {
+                            in_object_layer = true;
+                        }
+                    }
+                    b"object" if in_object_layer => {
+                        let spawn = PendingSpawn {
+                            x: number_attribute(element, "x")?,
+                            y: number_attribute(element, "y")?,
+                            properties: IndexMap::new(),
+                        };
+                        // Objects without properties are finished right away.
+                        if is_empty {
+                            spawns.push(finish_spawn(spawn, &map, tile_size, &position_keys)?);
+                        } else {
+                            pending_spawn = Some(spawn);
+                        }
+                    }
+                    b"property" => {
+                        let name = required_attribute(element, "name")?;
+                        let value = attribute(element, "value")?.unwrap_or_default();
+                        if let Some(spawn) = &mut pending_spawn {
+                            spawn.properties.insert(name, value);
+                        } else if let Some(id) = tile_id {
+                            if name == "TID" {
+                                tileset_tids.insert(id, value);
+                            }
+                        } else if name == "X" {
+                            map.x = value.parse().ok();
+                        } else if name == "Z" {
+                            map.z = value.parse().ok();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Text(text) if in_data => {
+                let values: Result<Vec<u32>> = text
+                    .unescape()?
+                    .split(',')
+                    .map(|value| value.trim())
+                    .filter(|value| !value.is_empty())
+                    .map(|value| {
+                        value
+                            .parse()
+                            .map_err(|_| anyhow!("'{}' is not a tile", value))
+                    })
+                    .collect();
+                gids = Some(values?);
+            }
+            Event::End(element) => match element.name().as_ref() {
+                b"tile" => tile_id = None,
+                b"data" => in_data = false,
+                b"objectgroup" if in_object_layer => {
+                    in_object_layer = false;
+                    map.spawns = Some(IndexMap::new());
+                }
+                b"object" => {
+                    if let Some(spawn) = pending_spawn.take() {
+                        spawns.push(finish_spawn(spawn, &map, tile_size, &position_keys)?);
+                    }
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if map.width <= 0 || map.height <= 0 {
+        bail!("the map has no tiles");
+    }
+    let gids = gids.ok_or_else(|| anyhow!("the map has no tile layer"))?;
+    if gids.len() != (map.width * map.height) as usize {
+        bail!(
+            "the tile layer has {} tiles but the map is {}x{}",
+            gids.len(),
+            map.width,
+            map.height
+        );
+    }
+    // Flip flags live in the top bits of a GID.
+    let tid = |gid: u32| {
+        let gid = gid & 0x0FFF_FFFF;
+        gid.checked_sub(first_gid)
+            .and_then(|id| tileset_tids.get(&id))
+            .cloned()
+            .ok_or_else(|| anyhow!("tile {} has no TID", gid))
+    };
+    let rows: Result<Vec<Vec<String>>> = gids
+        .chunks(map.width as usize)
+        .map(|row| row.iter().map(|gid| tid(*gid)).collect())
+        .collect();
+    map.tiles = rows?.into_iter().rev().flatten().collect();
+
+    if let Some(dispos) = &mut map.spawns {
+        for (group, spawn) in spawns {
+            dispos.entry(group).or_default().push(spawn);
+        }
+    }
+    Ok(map)
+}
+
+fn finish_spawn(
+    spawn: PendingSpawn,
+    map: &TmxMap,
+    tile_size: (f64, f64),
+    position_keys: &[&str; 2],
+) -> Result<(String, Spawn)> {
+    let mut properties = spawn.properties;
+    let group = properties
+        .shift_remove(GROUP_PROPERTY)
+        .ok_or_else(|| anyhow!("a spawn object is missing its {} property", GROUP_PROPERTY))?;
+    let x = (spawn.x / tile_size.0).floor() as i32;
+    let y = map.height - 1 - (spawn.y / tile_size.1).floor() as i32;
+    properties.insert(position_keys[0].to_string(), x.to_string());
+    properties.insert(position_keys[1].to_string(), y.to_string());
+    let spawn = Spawn::default()
+        .with_field_values(properties)
+        .with_context(|| format!("bad spawn in group '{}'", group))?;
+    Ok((group, spawn))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn(pid: &str, x: i8, y: i8) -> Spawn {
+        Spawn {
+            pid: pid.to_string(),
+            dispos_x: x,
+            dispos_y: y,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn tmx_round_trip() {
+        // Row by row from the bottom, with a tile the book doesn't know about.
+        let tiles = ["TID_A", "TID_B", "TID_C", "TID_A", "TID_?", "TID_B"];
+        let map_tid = |x: usize, y: usize| Some(tiles[y * 3 + x].to_string());
+        let book_tids = [
+            ("TID_A".to_string(), Color32::RED),
+            ("TID_B".to_string(), Color32::GREEN),
+            ("TID_C".to_string(), Color32::BLUE),
+            ("TID_Unused".to_string(), Color32::WHITE),
+        ]
+        .into_iter()
+        .collect();
+        let mut dispos = IndexMap::new();
+        dispos.insert(
+            "Player".to_string(),
+            vec![spawn("PID_A", 0, 0), spawn("PID_\"B\" & <C>", 2, 1)],
+        );
+        dispos.insert("Enemy".to_string(), vec![spawn("PID_D", 1, 1)]);
+
+        let export = write_tmx(
+            (3, 2),
+            (-4, 7),
+            map_tid,
+            book_tids,
+            Some(&dispos),
+            "tiles.png",
+        );
+        let map = parse_tmx(&export.xml).unwrap();
+        assert_eq!((map.width, map.height), (3, 2));
+        assert_eq!((map.x, map.z), (Some(-4), Some(7)));
+        assert_eq!(map.tiles, tiles);
+
+        let spawns = map.spawns.unwrap();
+        assert_eq!(spawns.keys().collect::<Vec<_>>(), ["Player", "Enemy"]);
+        for (group, original) in &dispos {
+            let parsed: Vec<_> = spawns[group].iter().map(|s| s.field_values()).collect();
+            let original: Vec<_> = original.iter().map(|s| s.field_values()).collect();
+            assert_eq!(parsed, original);
+        }
+    }
+
+    #[test]
+    fn tmx_without_spawns() {
+        let export = write_tmx(
+            (1, 1),
+            (0, 0),
+            |_, _| None,
+            IndexMap::new(),
+            None,
+            "tiles.png",
+        );
+        // Tiles missing from the terrain buffer are written as empty, which can't be imported.
+        assert!(parse_tmx(&export.xml).is_err());
+
+        let map_tid = |_, _| Some("TID_A".to_string());
+        let export = write_tmx((2, 1), (0, 0), map_tid, IndexMap::new(), None, "tiles.png");
+        let map = parse_tmx(&export.xml).unwrap();
+        assert_eq!(map.tiles, ["TID_A", "TID_A"]);
+        assert!(map.spawns.is_none());
+    }
+}
@@ -1,14 +1,36 @@
 mod cached_view;
+mod clipboard;
 mod config;
+mod expression;
+mod formation;
+mod history;
+mod map_files;
+mod navigation;
+mod query;
+mod rows;
 mod sheet;
 mod shortcuts;
+mod templates;
+mod terrain_layout;
 mod theme;
+mod unit_range;
 
 pub use cached_view::*;
+pub use clipboard::*;
 pub use config::*;
+pub use expression::*;
+pub use formation::*;
+pub use history::*;
+pub use map_files::*;
+pub use navigation::*;
+pub use query::*;
+pub use rows::*;
 pub use sheet::*;
 pub use shortcuts::*;
+pub use templates::*;
+pub use terrain_layout::*;
 pub use theme::*;
+pub use unit_range::*;
 
 use egui::TextureHandle;
 use indexmap::IndexMap;
@@ -102,6 +124,22 @@ pub trait ListModel<I> {
 
     /// Convert a row number to its index in the underlying collection.
     fn row_to_index(&self, row_number: usize) -> Option<usize>;
+
+    /// Retrieve the key of `item`, for models where keys are unique.
+    #[allow(unused)]
+    fn key_of(&self, item: &I) -> Option<String> {
+        None
+    }
+
+    /// Find an existing item with the same key as `item`, for models where keys are unique.
+    #[allow(unused)]
+    fn key_collision(&self, item: &I) -> Option<usize> {
+        None
+    }
+
+    /// Change the key of `item` so it doesn't collide with any item in this model.
+    #[allow(unused)]
+    fn make_key_unique(&self, item: &mut I) {}
 }
 
 impl<I> ListModel<I> for Vec<I>
@@ -207,6 +245,21 @@ where
     fn row_to_index(&self, row_number: usize) -> Option<usize> {
         (0..self.len()).contains(&row_number).then_some(row_number)
     }
+
+    fn key_of(&self, item: &I) -> Option<String> {
+        Some(item.key().into_owned())
+    }
+
+    fn key_collision(&self, item: &I) -> Option<usize> {
+        self.get_index_of(item.key().as_ref())
+    }
+
+    fn make_key_unique(&self, item: &mut I) {
+        let key = item.key().into_owned();
+        if self.contains_key(&key) {
+            item.set_key(unique_key(&key, |key| self.contains_key(key)));
+        }
+    }
 }
 
 /// A [ListModel] of items which have a unique ID.
@@ -237,6 +290,7 @@ pub struct FilterProxyBuilder {
     filter_expr: String,
     requires_refresh: bool,
     proxy_indices: Vec<usize>,
+    query_error: Option<String>,
 }
 
 impl FilterProxyBuilder {
@@ -245,6 +299,7 @@ impl FilterProxyBuilder {
             filter_expr: String::new(),
             requires_refresh: true,
             proxy_indices: vec![],
+            query_error: None,
         }
     }
 
@@ -280,23 +335,20 @@ impl FilterProxyBuilder {
         self.requires_refresh = true;
     }
 
-    /// Refresh the model now.
-    pub fn refresh<M, I, D>(&mut self, model: &M, dependencies: &D)
+    /// The reason the filter expression could not be used as a [FilterQuery], if any.
+    pub fn query_error(&self) -> Option<&str> {
+        self.query_error.as_deref()
+    }
+
+    fn rebuild<M, I>(&mut self, model: &M, mut matches: impl FnMut(&I) -> bool)
     where
         M: ListModel<I>,
-        I: ViewItem<Dependencies = D>,
     {
         self.proxy_indices.clear();
-        let filter_expr = self.filter_expr.to_lowercase();
         for i in 0..model.len() {
             let matches_filter = model
                 .item(i)
-                .map(|item| {
-                    let matches_search_by_index = (i + 1).to_string() == self.filter_expr;
-                    let matches_search_by_name =
-                        item.matches_filter(&filter_expr, &item.text(dependencies));
-                    matches_search_by_index || matches_search_by_name
-                })
+                .map(|item| (i + 1).to_string() == self.filter_expr || matches(item))
                 .unwrap_or_default();
             if matches_filter {
                 self.proxy_indices.push(i);
@@ -305,11 +357,46 @@ impl FilterProxyBuilder {
         self.requires_refresh = false;
     }
 
+    /// Refresh the model now.
+    pub fn refresh<M, I, D>(&mut self, model: &M, dependencies: &D)
+    where
+        M: ListModel<I>,
+        I: ViewItem<Dependencies = D>,
+    {
+        let filter_expr = self.filter_expr.to_lowercase();
+        self.rebuild(model, |item: &I| {
+            item.matches_filter(&filter_expr, &item.text(dependencies))
+        });
+    }
+
+    /// Refresh the model now, interpreting the filter expression as a [FilterQuery].
+    /// Falls back to a plain search if the expression isn't a valid query.
+    pub fn refresh_query<M, I, D>(&mut self, model: &M, dependencies: &D)
+    where
+        M: ListModel<I>,
+        I: ViewItem<Dependencies = D> + SchemaRow,
+    {
+        match FilterQuery::parse(&self.filter_expr, I::FIELDS) {
+            Ok(query) => {
+                self.query_error = None;
+                self.rebuild(model, |item: &I| {
+                    query.matches(item, &item.text(dependencies))
+                });
+            }
+            Err(err) => {
+                self.query_error = Some(err.to_string());
+                self.refresh(model, dependencies);
+            }
+        }
+    }
+
     /// Build a filtered view of a source model using this proxy's filter expression.
     /// Will ONLY trigger a refresh when required. There are three cases for this:
     /// * First time building a proxy (detected automatically)
     /// * Filter expression was altered (detected automatically)
     /// * Source model was changed. YOU must tell the proxy when this happened.
+    ///
+    /// The filter expression may filter on row fields. See [FilterQuery] for the syntax.
     pub fn model<'a, M, I, D>(
         &'a mut self,
         requires_refresh: bool,
@@ -318,10 +405,10 @@ impl FilterProxyBuilder {
     ) -> FilterProxyModel<'_, M>
     where
         M: ListModel<I>,
-        I: ViewItem<Dependencies = D>,
+        I: ViewItem<Dependencies = D> + SchemaRow,
     {
         if self.requires_refresh || requires_refresh {
-            self.refresh(model, dependencies);
+            self.refresh_query(model, dependencies);
         }
 
         FilterProxyModel {
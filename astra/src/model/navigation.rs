@@ -0,0 +1,95 @@
+use crate::Screens;
+
+/// A screen and the key of the row selected on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub screen: Screens,
+    pub key: Option<String>,
+    /// The group and index of the spawn selected in the chapter editor's dispos tab.
+    pub spawn: Option<(String, usize)>,
+}
+
+/// Back and forward stacks of visited locations, like a web browser.
+/// Only explicit navigation is recorded. Clicking through a list just changes
+/// where the user is, which is passed in whenever the history moves.
+#[derive(Debug, Default)]
+pub struct NavigationHistory {
+    back: Vec<Location>,
+    forward: Vec<Location>,
+}
+
+impl NavigationHistory {
+    const MAX_ENTRIES: usize = 100;
+
+    /// Record navigating from one location to another.
+    /// Moving somewhere new pushes `from` and clears the forward stack.
+    pub fn visit(&mut self, from: Location, to: Location) {
+        if from == to {
+            return;
+        }
+        self.back.push(from);
+        if self.back.len() > Self::MAX_ENTRIES {
+            self.back.remove(0);
+        }
+        self.forward.clear();
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        !self.back.is_empty()
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+
+    /// Step back from the current location, returning the location to show.
+    pub fn back(&mut self, current: Location) -> Option<Location> {
+        let target = self.back.pop()?;
+        self.forward.push(current);
+        Some(target)
+    }
+
+    /// Step forward from the current location, returning the location to show.
+    pub fn forward(&mut self, current: Location) -> Option<Location> {
+        let target = self.forward.pop()?;
+        self.back.push(current);
+        Some(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(screen: Screens, key: &str) -> Location {
+        Location {
+            screen,
+            key: Some(key.to_string()),
+            spawn: None,
+        }
+    }
+
+    #[test]
+    fn steps_back_and_forward() {
+        let mut history = NavigationHistory::default();
+        let chapter = location(Screens::Chapter, "CID_M001");
+        let person = location(Screens::Person, "PID_リュール");
+        let job = location(Screens::Job, "JID_神竜ノ子");
+        history.visit(chapter.clone(), chapter.clone());
+        assert!(!history.can_go_back());
+
+        history.visit(chapter.clone(), person.clone());
+        // The user picked another row before following the next reference.
+        let other = location(Screens::Person, "PID_ヴァンドレ");
+        history.visit(other.clone(), job.clone());
+
+        assert_eq!(history.back(job.clone()), Some(other.clone()));
+        assert_eq!(history.back(other.clone()), Some(chapter.clone()));
+        assert_eq!(history.back(chapter.clone()), None);
+        assert_eq!(history.forward(chapter.clone()), Some(other.clone()));
+        assert!(history.can_go_forward());
+
+        history.visit(other, person);
+        assert!(!history.can_go_forward());
+    }
+}
@@ -0,0 +1,278 @@
+use anyhow::{anyhow, bail, Result};
+use astra_types::FieldSchema;
+use indexmap::IndexMap;
+
+use crate::{find_field, BinaryOp, Expr, SchemaRow, Value, ViewItem};
+
+/// Tooltip text describing the [FilterQuery] syntax.
+pub const FILTER_HELP: &str = "Filter by field with field:text, field=value, field!=value, \
+    field>N, field>=N, field<N, field<=N, or field&FLAGS. \
+    Wrap an expression in parentheses for anything else, ex. (rank > 2 || price < 100). \
+    Other words search the display text. Use quotes for values with spaces.";
+
+/// Operators in the order they are matched. Longer operators must come first.
+const OPERATORS: [(&str, BinaryOp); 9] = [
+    ("==", BinaryOp::Eq),
+    ("!=", BinaryOp::Ne),
+    (">=", BinaryOp::Ge),
+    ("<=", BinaryOp::Le),
+    (":", BinaryOp::Contains),
+    ("=", BinaryOp::Eq),
+    ("<", BinaryOp::Lt),
+    (">", BinaryOp::Gt),
+    ("&", BinaryOp::BitAnd),
+];
+
+/// Build the [Expr] for a `field<op>value` term.
+fn field_term(field: &'static FieldSchema, op: BinaryOp, value: &str) -> Result<Expr> {
+    let value = match op {
+        BinaryOp::Contains => Value::Text(value.to_string()),
+        BinaryOp::Eq | BinaryOp::Ne => Value::from_cell(value),
+        _ => {
+            let value = Value::from_cell(value);
+            if value.number().is_err() {
+                bail!("'{}' is not a number", value);
+            }
+            value
+        }
+    };
+    Ok(Expr::Binary(
+        op,
+        Box::new(Expr::Field(field.key)),
+        Box::new(Expr::Literal(value)),
+    ))
+}
+
+/// Split a query on whitespace. Double quotes group words into a single term.
+/// A term starting with `(` runs to the matching `)` and keeps its quotes for [Expr::parse].
+fn split_terms(source: &str) -> Result<Vec<String>> {
+    let mut terms = vec![];
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut depth = 0;
+    let mut chars = source.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                if depth > 0 {
+                    current.push(c);
+                }
+            }
+            '\\' if quoted && depth > 0 => {
+                current.push(c);
+                current.extend(chars.next());
+            }
+            '(' if !quoted && (depth > 0 || current.is_empty()) => {
+                depth += 1;
+                current.push(c);
+            }
+            ')' if !quoted && depth > 0 => {
+                depth -= 1;
+                current.push(c);
+            }
+            c if c.is_whitespace() && !quoted && depth == 0 => {
+                if !current.is_empty() {
+                    terms.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if quoted {
+        bail!("unterminated quote");
+    }
+    if depth > 0 {
+        bail!("unclosed parenthesis");
+    }
+    if !current.is_empty() {
+        terms.push(current);
+    }
+    Ok(terms)
+}
+
+/// A search query for list views. Every term must match for a row to be shown.
+/// * `field:text` - the field contains the text (case insensitive)
+/// * `field=value`, `field!=value` - the field is (not) equal to the value
+/// * `field>N`, `field>=N`, `field<N`, `field<=N` - numeric comparisons
+/// * `field&N` - the field has any of the bits in N set (N may be hex, ex. `0x10`)
+/// * `(expression)` - any [Expr], ex. `(rank > 2 || price < 100)`
+/// * anything else is matched against the row's display text, same as a plain search
+///
+/// Field terms are shorthand for the matching [Expr], so both forms compare values the same way.
+/// Fields are looked up by Rust name or XML attribute, ex. `jid` or `@Jid`.
+/// Use double quotes for values with spaces, ex. `name:"iron sword"`.
+#[derive(Debug, Clone, Default)]
+pub struct FilterQuery {
+    text_terms: Vec<String>,
+    conditions: Vec<Expr>,
+}
+
+impl FilterQuery {
+    pub fn parse(source: &str, fields: &'static [FieldSchema]) -> Result<Self> {
+        let mut query = Self::default();
+        for term in split_terms(source)? {
+            if term.starts_with('(') {
+                query.conditions.push(Expr::parse(&term, fields)?);
+                continue;
+            }
+            let split = term.char_indices().find_map(|(i, _)| {
+                OPERATORS
+                    .iter()
+                    .find(|(token, _)| term[i..].starts_with(token))
+                    .map(|(token, op)| (i, token.len(), *op))
+            });
+            match split {
+                Some((index, len, op)) if index > 0 => {
+                    let name = &term[..index];
+                    let field = find_field(fields, name)
+                        .ok_or_else(|| anyhow!("unknown field '{}'", name))?;
+                    query
+                        .conditions
+                        .push(field_term(field, op, &term[index + len..])?);
+                }
+                _ => query.text_terms.push(term.to_lowercase()),
+            }
+        }
+        Ok(query)
+    }
+
+    /// A query that matches display text containing `search`, ignoring any query syntax.
+    pub fn text(search: &str) -> Self {
+        Self {
+            text_terms: vec![search.to_lowercase()],
+            conditions: vec![],
+        }
+    }
+
+    /// Whether the query filters on any row fields (as opposed to only display text).
+    pub fn has_field_terms(&self) -> bool {
+        !self.conditions.is_empty()
+    }
+
+    /// Check the display text terms against some text.
+    pub fn matches_text(&self, text: &str) -> bool {
+        let text = text.to_lowercase();
+        self.text_terms.iter().all(|term| text.contains(term))
+    }
+
+    /// Check the field terms against a row.
+    pub fn matches_fields<I: SchemaRow>(&self, item: &I) -> bool {
+        self.conditions.is_empty() || self.matches_values(&item.field_values())
+    }
+
+    /// Check the field terms against a row's values. Rows that fail to evaluate don't match.
+    fn matches_values(&self, values: &IndexMap<String, String>) -> bool {
+        self.conditions
+            .iter()
+            .all(|condition| condition.matches(values).unwrap_or_default())
+    }
+
+    /// Check every term against a row. `display_text` comes from calling `ViewItem::text`.
+    pub fn matches<I>(&self, item: &I, display_text: &str) -> bool
+    where
+        I: ViewItem + SchemaRow,
+    {
+        self.text_terms
+            .iter()
+            .all(|term| item.matches_filter(term, display_text))
+            && self.matches_fields(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn field(ident: &'static str, key: &'static str, ty: &'static str) -> FieldSchema {
+        FieldSchema {
+            ident,
+            key,
+            ty,
+            id: false,
+            public_array: false,
+        }
+    }
+
+    const FIELDS: &[FieldSchema] = &[
+        field("rank", "@Rank", "u8"),
+        field("flag", "@Flag", "i32"),
+        field("name", "@Name", "String"),
+    ];
+
+    fn row(rank: &str, flag: &str, name: &str) -> IndexMap<String, String> {
+        [("@Rank", rank), ("@Flag", flag), ("@Name", name)]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn matches(source: &str, values: &IndexMap<String, String>) -> bool {
+        FilterQuery::parse(source, FIELDS)
+            .unwrap()
+            .matches_values(values)
+    }
+
+    #[test]
+    fn splits_quotes_and_expressions() {
+        assert_eq!(
+            split_terms(r#"iron  name:"iron sword" (name == "a b" && rank > 1) x(y"#).unwrap(),
+            vec![
+                "iron",
+                "name:iron sword",
+                r#"(name == "a b" && rank > 1)"#,
+                "x(y",
+            ]
+        );
+        assert_eq!(
+            split_terms(r#"(name == "a\") b")"#).unwrap(),
+            vec![r#"(name == "a\") b")"#]
+        );
+        assert!(split_terms("name:\"iron").is_err());
+        assert!(split_terms("(rank > 1").is_err());
+    }
+
+    #[test]
+    fn parses_text_and_field_terms() {
+        let query = FilterQuery::parse("Iron rank>=2 @Name:sword", FIELDS).unwrap();
+        assert_eq!(query.text_terms, vec!["iron"]);
+        assert_eq!(query.conditions.len(), 2);
+        assert!(query.has_field_terms());
+        assert!(query.matches_text("IRON SWORD"));
+        assert!(!query.matches_text("Steel Sword"));
+
+        let query = FilterQuery::parse(":x =y", FIELDS).unwrap();
+        assert_eq!(query.text_terms, vec![":x", "=y"]);
+        assert!(!query.has_field_terms());
+
+        assert!(FilterQuery::parse("strength>2", FIELDS).is_err());
+        assert!(FilterQuery::parse("rank>high", FIELDS).is_err());
+        assert!(FilterQuery::parse("(rank >)", FIELDS).is_err());
+    }
+
+    #[test]
+    fn matches_field_terms() {
+        let values = row("2", "0x12", "Iron Sword");
+        assert!(matches("name:SWORD", &values));
+        assert!(matches(r#"name="iron sword""#, &values));
+        assert!(matches(
+            "rank==2 rank!=3 rank>1 rank>=2 rank<3 rank<=2",
+            &values
+        ));
+        assert!(matches("flag&0x10", &values));
+        assert!(!matches("flag&1", &values));
+        assert!(!matches("name:axe", &values));
+        assert!(!matches("rank>2", &values));
+        assert!(matches("rank<1", &row("", "0", "")));
+    }
+
+    #[test]
+    fn matches_expressions() {
+        let values = row("2", "0", "Iron Sword");
+        assert!(matches(r#"(rank > 5 || name:"iron")"#, &values));
+        assert!(matches(r#"(name == "IRON SWORD") rank=2"#, &values));
+        assert!(!matches("(rank * 2 == 5)", &values));
+        // Errors while evaluating don't match instead of failing the whole filter.
+        assert!(!matches("(name & 1)", &values));
+    }
+}
@@ -0,0 +1,220 @@
+use std::cmp::Ordering;
+
+use anyhow::Result;
+use astra_formats::{FromSheetDataParam, ToSheetDataParam};
+use astra_types::AstraSchema;
+use indexmap::IndexMap;
+
+/// A sheet viewed as a flat list of rows, regardless of how the sheet stores them.
+/// Grouped sheets are flattened in group order.
+pub trait RowModel<I> {
+    /// Retrieve a row if the index is in bounds.
+    fn row(&self, index: usize) -> Option<&I>;
+
+    /// Retrieve a mutable reference to a row if the index is in bounds.
+    fn row_mut(&mut self, index: usize) -> Option<&mut I>;
+
+    /// Find the row in an older copy of this model that corresponds to the row at `index`.
+    /// Rows are matched by key or id field where they have one, and by position otherwise.
+    fn baseline_row<'a>(&self, baseline: &'a Self, index: usize) -> Option<&'a I>;
+
+    /// Iterate over every row in order.
+    fn rows(&self) -> Box<dyn Iterator<Item = &I> + '_>;
+
+    /// An identifier for the row at `index` that can be looked up again with [RowModel::row_index].
+    /// Keyed models use the key so the id survives other rows being added or moved.
+    fn row_id(&self, index: usize) -> Option<String>;
+
+    /// Find the current index of a row from its id.
+    fn row_index(&self, id: &str) -> Option<usize>;
+}
+
+impl<I: SchemaRow> RowModel<I> for Vec<I> {
+    fn row(&self, index: usize) -> Option<&I> {
+        self.get(index)
+    }
+
+    fn row_mut(&mut self, index: usize) -> Option<&mut I> {
+        self.get_mut(index)
+    }
+
+    fn baseline_row<'a>(&self, baseline: &'a Self, index: usize) -> Option<&'a I> {
+        let Some(id_field) = I::FIELDS.iter().find(|field| field.id) else {
+            return baseline.get(index);
+        };
+        let id = self.get(index)?.field_values().swap_remove(id_field.key)?;
+        if id.is_empty() {
+            return baseline.get(index);
+        }
+        let same_id = |row: &&I| row.field_values().get(id_field.key) == Some(&id);
+        // Rows usually stay in place, so check the same position before searching.
+        baseline
+            .get(index)
+            .filter(same_id)
+            .or_else(|| baseline.iter().find(same_id))
+    }
+
+    fn rows(&self) -> Box<dyn Iterator<Item = &I> + '_> {
+        Box::new(self.iter())
+    }
+
+    fn row_id(&self, index: usize) -> Option<String> {
+        (index < self.len()).then(|| index.to_string())
+    }
+
+    fn row_index(&self, id: &str) -> Option<usize> {
+        id.parse().ok().filter(|index| *index < self.len())
+    }
+}
+
+impl<I> RowModel<I> for IndexMap<String, I> {
+    fn row(&self, index: usize) -> Option<&I> {
+        self.get_index(index).map(|(_, v)| v)
+    }
+
+    fn row_mut(&mut self, index: usize) -> Option<&mut I> {
+        self.get_index_mut(index).map(|(_, v)| v)
+    }
+
+    fn baseline_row<'a>(&self, baseline: &'a Self, index: usize) -> Option<&'a I> {
+        self.get_index(index).and_then(|(key, _)| baseline.get(key))
+    }
+
+    fn rows(&self) -> Box<dyn Iterator<Item = &I> + '_> {
+        Box::new(self.values())
+    }
+
+    fn row_id(&self, index: usize) -> Option<String> {
+        self.get_index(index).map(|(key, _)| key.clone())
+    }
+
+    fn row_index(&self, id: &str) -> Option<usize> {
+        self.get_index_of(id)
+    }
+}
+
+impl<I> RowModel<I> for IndexMap<String, Vec<I>> {
+    fn row(&self, index: usize) -> Option<&I> {
+        self.values().flatten().nth(index)
+    }
+
+    fn row_mut(&mut self, index: usize) -> Option<&mut I> {
+        self.values_mut().flatten().nth(index)
+    }
+
+    fn baseline_row<'a>(&self, baseline: &'a Self, mut index: usize) -> Option<&'a I> {
+        for (key, group) in self {
+            if index < group.len() {
+                return baseline.get(key).and_then(|group| group.get(index));
+            }
+            index -= group.len();
+        }
+        None
+    }
+
+    fn rows(&self) -> Box<dyn Iterator<Item = &I> + '_> {
+        Box::new(self.values().flatten())
+    }
+
+    fn row_id(&self, mut index: usize) -> Option<String> {
+        for (key, group) in self {
+            if index < group.len() {
+                return Some(format!("{}/{}", key, index));
+            }
+            index -= group.len();
+        }
+        None
+    }
+
+    fn row_index(&self, id: &str) -> Option<usize> {
+        let (key, position) = id.rsplit_once('/')?;
+        let position: usize = position.parse().ok()?;
+        let group_index = self.get_index_of(key)?;
+        if position >= self[group_index].len() {
+            return None;
+        }
+        let offset: usize = self
+            .values()
+            .take(group_index)
+            .map(|group| group.len())
+            .sum();
+        Some(offset + position)
+    }
+}
+
+/// A row whose fields can be read and written generically through its schema.
+pub trait SchemaRow: AstraSchema + FromSheetDataParam + ToSheetDataParam + Sized {
+    /// Retrieve the row's values keyed by XML attribute.
+    /// Every schema field has an entry. Unset optional fields are empty.
+    fn field_values(&self) -> IndexMap<String, String> {
+        let mut values = self.to_sheet_data_param_values();
+        for field in Self::FIELDS {
+            if !values.contains_key(field.key) {
+                values.insert(field.key.to_string(), String::new());
+            }
+        }
+        values
+    }
+
+    /// Build a copy of this row with the given attributes replaced.
+    /// Fails if any of the new values can't be parsed into the field's type.
+    fn with_field_values(
+        &self,
+        changes: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Self> {
+        let mut values = self.field_values();
+        values.extend(changes);
+        Self::from_sheet_data_param(values)
+    }
+}
+
+impl<T> SchemaRow for T where T: AstraSchema + FromSheetDataParam + ToSheetDataParam {}
+
+/// How a row differs from the sheet as it was loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowState {
+    Unchanged,
+    Modified,
+    Added,
+}
+
+/// Compare the row at `index` with its counterpart in the baseline.
+pub fn row_state<M, I>(model: &M, baseline: &M, index: usize) -> RowState
+where
+    M: RowModel<I>,
+    I: SchemaRow,
+{
+    match (model.row(index), model.baseline_row(baseline, index)) {
+        (Some(row), Some(original)) if row.field_values() != original.field_values() => {
+            RowState::Modified
+        }
+        (Some(_), None) => RowState::Added,
+        _ => RowState::Unchanged,
+    }
+}
+
+/// List the attributes of the row at `index` that differ from its counterpart in the baseline.
+pub fn changed_fields<M, I>(model: &M, baseline: &M, index: usize) -> Vec<&'static str>
+where
+    M: RowModel<I>,
+    I: SchemaRow,
+{
+    let (Some(row), Some(original)) = (model.row(index), model.baseline_row(baseline, index))
+    else {
+        return vec![];
+    };
+    let (values, original) = (row.field_values(), original.field_values());
+    I::FIELDS
+        .iter()
+        .filter(|field| values.get(field.key) != original.get(field.key))
+        .map(|field| field.key)
+        .collect()
+}
+
+/// Compare two cell values, numerically if both parse as numbers.
+pub fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        _ => a.cmp(b),
+    }
+}
@@ -12,7 +12,7 @@ use astra_types::{
     ChapterBook, DisposBook, ForgeEvolveData, ForgeExchangeData, ForgeImproveData, GameParam,
     GodBondLevelData, GodBook, GodData, GodLevelData, Item, ItemBook, Job, JobBook, ParamsBook,
     Person, PersonBook, RelianceBonusData, RelianceBook, RelianceData, RelianceExpData, ShopBook,
-    ShopInventory, Skill, SkillBook, Spawn, TerrainBook, TerrainData,
+    ShopInventory, Skill, SkillBook, Spawn, TerrainBook, TerrainCostData, TerrainData,
 };
 use egui::TextureHandle;
 use indexmap::IndexMap;
@@ -50,7 +50,7 @@ use crate::{
     TutorialDataSheet, VibrationDefineDataSheet, ViewItem,
 };
 
-use super::GroupViewItem;
+use super::{row_state, GroupViewItem, HistoryTracker, RowModel, RowState, SchemaRow};
 
 pub struct EditorState {
     pub message_db: MessageDbWrapper,
@@ -186,6 +186,7 @@ pub struct EditorState {
     pub tips: TipDataSheet,
     pub tutorials: TutorialDataSheet,
     pub terrain: TerrainDataSheet,
+    pub terrain_cost: TerrainCostDataSheet,
     pub vibration_data: VibrationDefineDataSheet,
 }
 
@@ -193,6 +194,10 @@ pub struct EditorState {
 pub trait SheetRetriever<B, S> {
     fn retrieve<'a>(&self, book: &'a B) -> &'a S;
     fn retrieve_mut<'a>(&self, book: &'a mut B) -> &'a mut S;
+
+    /// Approximate number of bytes used by a copy of the sheet, for limiting the undo history.
+    /// Only counts each row's inline size, so text in the rows makes the real figure higher.
+    fn approx_size(&self, sheet: &S) -> usize;
 }
 
 /// Utility for editing a sheet contained in some book and tracking changes to it.
@@ -200,6 +205,7 @@ pub struct SheetHandle<R, B, S> {
     book: OpenBook<B>,
     retriever: R,
     revision_number: Arc<AtomicUsize>,
+    history: Arc<HistoryTracker>,
     phantom: PhantomData<S>,
 }
 
@@ -212,6 +218,7 @@ where
             book: self.book.clone(),
             retriever: self.retriever.clone(),
             revision_number: self.revision_number.clone(),
+            history: self.history.clone(),
             phantom: PhantomData,
         }
     }
@@ -219,18 +226,42 @@ where
 
 impl<R, B, S> SheetHandle<R, B, S>
 where
-    R: SheetRetriever<B, S>,
+    R: SheetRetriever<B, S> + Clone + Send + Sync + 'static,
+    B: Send + Sync + 'static,
+    S: Clone + Send + Sync + 'static,
 {
     /// Create a new handle to a sheet from the given book and retriever.
     pub fn new(book: OpenBook<B>, retriever: R) -> Self {
+        let revision_number: Arc<AtomicUsize> = Default::default();
+        let history = {
+            let (capture_book, capture_retriever) = (book.clone(), retriever.clone());
+            let (apply_book, apply_retriever) = (book.clone(), retriever.clone());
+            let size_retriever = retriever.clone();
+            let revision_number = revision_number.clone();
+            HistoryTracker::new(
+                move || capture_book.read(|book| capture_retriever.retrieve(book).clone()),
+                move |sheet: &S| {
+                    apply_book.write(|book| *apply_retriever.retrieve_mut(book) = sheet.clone());
+                    apply_book.mark_dirty();
+                    revision_number.fetch_add(1, Ordering::Relaxed);
+                },
+                move |sheet: &S| size_retriever.approx_size(sheet),
+            )
+        };
         Self {
             book,
             retriever,
-            revision_number: Default::default(),
+            revision_number,
+            history,
             phantom: PhantomData,
         }
     }
+}
 
+impl<R, B, S> SheetHandle<R, B, S>
+where
+    R: SheetRetriever<B, S>,
+{
     /// Perform a read operation on the sheet.
     pub fn read<V>(&self, consumer: impl FnOnce(&S) -> V) -> V {
         self.book
@@ -239,10 +270,11 @@ where
 
     /// Perform a write operation on the sheet.
     /// The operation must return true if the sheet was modified.
+    /// Modifications are recorded in the [EditHistory](super::EditHistory) so they can be undone.
     pub fn write(&self, consumer: impl FnOnce(&mut S) -> bool) {
-        let mut changed = false;
-        self.book.write(|book| {
-            changed = consumer(self.retriever.retrieve_mut(book));
+        let changed = self.history.track(|| {
+            self.book
+                .write(|book| consumer(self.retriever.retrieve_mut(book)))
         });
         if changed {
             self.book.mark_dirty();
@@ -257,9 +289,61 @@ where
     }
 }
 
+impl<R, B, S> SheetHandle<R, B, S>
+where
+    R: SheetRetriever<B, S>,
+    S: Send + Sync + 'static,
+{
+    /// Retrieve the sheet as it was when the handle was created.
+    pub fn baseline(&self) -> Option<Arc<S>> {
+        self.history.baseline()
+    }
+
+    /// Compare the row at the given index with the loaded sheet.
+    pub fn row_state<I>(&self, index: usize) -> RowState
+    where
+        S: RowModel<I>,
+        I: SchemaRow,
+    {
+        match self.baseline() {
+            Some(baseline) => self.read(|data| row_state(data, &baseline, index)),
+            None => RowState::Unchanged,
+        }
+    }
+
+    /// Replace the row at the given index with its loaded version.
+    pub fn revert_row<I>(&self, index: usize)
+    where
+        S: RowModel<I>,
+        I: SchemaRow + Clone,
+    {
+        if let Some(baseline) = self.baseline() {
+            self.write(|data| {
+                let original = data.baseline_row(&baseline, index).cloned();
+                match (data.row_mut(index), original) {
+                    (Some(row), Some(original)) => {
+                        *row = original;
+                        true
+                    }
+                    _ => false,
+                }
+            });
+        }
+    }
+}
+
 #[macro_export]
 macro_rules! sheet_retriever {
-    ($name:ident, $book:ty, $sheet:ident, $con:ty) => {
+    ($name:ident, $book:ty, $sheet:ident, IndexMap<String, Vec<$row:ty>>) => {
+        $crate::sheet_retriever!(@impl $name, $book, $sheet, IndexMap<String, Vec<$row>>, $row);
+    };
+    ($name:ident, $book:ty, $sheet:ident, IndexMap<String, $row:ty>) => {
+        $crate::sheet_retriever!(@impl $name, $book, $sheet, IndexMap<String, $row>, $row);
+    };
+    ($name:ident, $book:ty, $sheet:ident, Vec<$row:ty>) => {
+        $crate::sheet_retriever!(@impl $name, $book, $sheet, Vec<$row>, $row);
+    };
+    (@impl $name:ident, $book:ty, $sheet:ident, $con:ty, $row:ty) => {
         paste::paste! {
             #[derive(Debug, Clone, Default)]
             pub struct [<$name SheetRetriever>];
@@ -272,6 +356,10 @@ macro_rules! sheet_retriever {
                 fn retrieve_mut<'a>(&self, book: &'a mut $book) -> &'a mut $con {
                     &mut book.$sheet.data
                 }
+
+                fn approx_size(&self, sheet: &$con) -> usize {
+                    $crate::RowModel::<$row>::rows(sheet).count() * std::mem::size_of::<$row>()
+                }
             }
 
             pub type [<$name Sheet>] = $crate::SheetHandle<[<$name SheetRetriever>], $book, $con>;
@@ -1036,6 +1124,8 @@ impl ViewItem for Spawn {
 
 sheet_retriever!(TerrainData, TerrainBook, terrain_data, IndexMap<String, TerrainData>);
 
+sheet_retriever!(TerrainCostData, TerrainBook, terrain_cost_data, Vec<TerrainCostData>);
+
 impl ViewItem for TerrainData {
     type Dependencies = EditorState;
 
@@ -1,52 +1,194 @@
-use egui::{Key, KeyboardShortcut, Modifiers};
-
-pub static ADD_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::A);
-
-pub static INSERT_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::I);
-
-pub static DUPLICATE_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::D);
-
-pub static DELETE_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::NONE, Key::Delete);
-
-pub static MOVE_UP_SHORTCUT: KeyboardShortcut =
-    KeyboardShortcut::new(Modifiers::COMMAND, Key::ArrowUp);
-
-pub static MOVE_DOWN_SHORTCUT: KeyboardShortcut =
-    KeyboardShortcut::new(Modifiers::COMMAND, Key::ArrowDown);
-
-pub static COPY_TO_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Q);
-
-pub static NEXT_TAB_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::T);
-
-pub static PREV_TAB_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(
-    Modifiers {
-        alt: false,
-        ctrl: false,
-        shift: true,
-        mac_cmd: false,
-        command: true,
-    },
-    Key::T,
-);
-
-pub static UP_ENTRY_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(
-    Modifiers {
-        alt: false,
-        ctrl: true,
-        shift: true,
-        mac_cmd: false,
-        command: true,
-    },
-    Key::ArrowUp,
-);
-
-pub static DOWN_ENTRY_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(
-    Modifiers {
-        alt: false,
-        ctrl: true,
-        shift: true,
-        mac_cmd: false,
-        command: true,
-    },
-    Key::ArrowDown,
-);
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use egui::{Event, InputState, Key, KeyboardShortcut, Modifiers};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+
+static ACTIVE_SHORTCUTS: OnceLock<RwLock<Shortcuts>> = OnceLock::new();
+
+const COMMAND_SHIFT: Modifiers = Modifiers {
+    alt: false,
+    ctrl: false,
+    shift: true,
+    mac_cmd: false,
+    command: true,
+};
+
+const COMMAND_CTRL_SHIFT: Modifiers = Modifiers {
+    alt: false,
+    ctrl: true,
+    shift: true,
+    mac_cmd: false,
+    command: true,
+};
+
+/// Something the user can trigger with a keyboard shortcut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ShortcutAction {
+    Add,
+    Insert,
+    Duplicate,
+    CopyTo,
+    Delete,
+    MoveUp,
+    MoveDown,
+    UpEntry,
+    DownEntry,
+    Undo,
+    Redo,
+    Save,
+    NextTab,
+    PrevTab,
+    Back,
+    Forward,
+    CommandPalette,
+    CopyXml,
+    PasteXml,
+}
+
+impl ShortcutAction {
+    pub const ALL: [ShortcutAction; 19] = [
+        ShortcutAction::Add,
+        ShortcutAction::Insert,
+        ShortcutAction::Duplicate,
+        ShortcutAction::CopyTo,
+        ShortcutAction::Delete,
+        ShortcutAction::MoveUp,
+        ShortcutAction::MoveDown,
+        ShortcutAction::UpEntry,
+        ShortcutAction::DownEntry,
+        ShortcutAction::Undo,
+        ShortcutAction::Redo,
+        ShortcutAction::Save,
+        ShortcutAction::NextTab,
+        ShortcutAction::PrevTab,
+        ShortcutAction::Back,
+        ShortcutAction::Forward,
+        ShortcutAction::CommandPalette,
+        ShortcutAction::CopyXml,
+        ShortcutAction::PasteXml,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ShortcutAction::Add => "Add Item",
+            ShortcutAction::Insert => "Insert Below",
+            ShortcutAction::Duplicate => "Duplicate",
+            ShortcutAction::CopyTo => "Copy To",
+            ShortcutAction::Delete => "Delete Item",
+            ShortcutAction::MoveUp => "Move Up",
+            ShortcutAction::MoveDown => "Move Down",
+            ShortcutAction::UpEntry => "Previous Entry",
+            ShortcutAction::DownEntry => "Next Entry",
+            ShortcutAction::Undo => "Undo",
+            ShortcutAction::Redo => "Redo",
+            ShortcutAction::Save => "Save",
+            ShortcutAction::NextTab => "Next Tab",
+            ShortcutAction::PrevTab => "Previous Tab",
+            ShortcutAction::Back => "Back",
+            ShortcutAction::Forward => "Forward",
+            ShortcutAction::CommandPalette => "Go To…",
+            ShortcutAction::CopyXml => "Copy as XML",
+            ShortcutAction::PasteXml => "Paste XML",
+        }
+    }
+
+    pub fn default_shortcut(&self) -> KeyboardShortcut {
+        match self {
+            ShortcutAction::Add => KeyboardShortcut::new(Modifiers::COMMAND, Key::A),
+            ShortcutAction::Insert => KeyboardShortcut::new(Modifiers::COMMAND, Key::I),
+            ShortcutAction::Duplicate => KeyboardShortcut::new(Modifiers::COMMAND, Key::D),
+            ShortcutAction::CopyTo => KeyboardShortcut::new(Modifiers::COMMAND, Key::Q),
+            ShortcutAction::Delete => KeyboardShortcut::new(Modifiers::NONE, Key::Delete),
+            ShortcutAction::MoveUp => KeyboardShortcut::new(Modifiers::COMMAND, Key::ArrowUp),
+            ShortcutAction::MoveDown => KeyboardShortcut::new(Modifiers::COMMAND, Key::ArrowDown),
+            ShortcutAction::UpEntry => KeyboardShortcut::new(COMMAND_CTRL_SHIFT, Key::ArrowUp),
+            ShortcutAction::DownEntry => KeyboardShortcut::new(COMMAND_CTRL_SHIFT, Key::ArrowDown),
+            ShortcutAction::Undo => KeyboardShortcut::new(Modifiers::COMMAND, Key::Z),
+            ShortcutAction::Redo => KeyboardShortcut::new(Modifiers::COMMAND, Key::Y),
+            ShortcutAction::Save => KeyboardShortcut::new(Modifiers::COMMAND, Key::S),
+            ShortcutAction::NextTab => KeyboardShortcut::new(Modifiers::COMMAND, Key::T),
+            ShortcutAction::PrevTab => KeyboardShortcut::new(COMMAND_SHIFT, Key::T),
+            ShortcutAction::Back => KeyboardShortcut::new(Modifiers::ALT, Key::ArrowLeft),
+            ShortcutAction::Forward => KeyboardShortcut::new(Modifiers::ALT, Key::ArrowRight),
+            ShortcutAction::CommandPalette => KeyboardShortcut::new(Modifiers::COMMAND, Key::P),
+            ShortcutAction::CopyXml => KeyboardShortcut::new(Modifiers::COMMAND, Key::C),
+            ShortcutAction::PasteXml => KeyboardShortcut::new(Modifiers::COMMAND, Key::V),
+        }
+    }
+
+    /// The shortcut currently bound to this action.
+    pub fn shortcut(&self) -> KeyboardShortcut {
+        match ACTIVE_SHORTCUTS.get() {
+            Some(shortcuts) => shortcuts.read().get(*self),
+            None => self.default_shortcut(),
+        }
+    }
+
+    /// Check if the shortcut was pressed, consuming the key press like
+    /// [InputState::consume_shortcut].
+    /// egui-winit sends Cmd+C, Cmd+X and Cmd+V as [Event::Copy], [Event::Cut] and
+    /// [Event::Paste] instead of key presses, so those events count for shortcuts bound to them.
+    /// Clipboard events are left in the input so the pasted text can still be read.
+    pub fn consume(&self, input: &mut InputState) -> bool {
+        let shortcut = self.shortcut();
+        if input.consume_shortcut(&shortcut) {
+            return true;
+        }
+        if !shortcut.modifiers.command || !input.modifiers.matches_logically(shortcut.modifiers) {
+            return false;
+        }
+        input.events.iter().any(|event| {
+            matches!(
+                (shortcut.logical_key, event),
+                (Key::C, Event::Copy) | (Key::X, Event::Cut) | (Key::V, Event::Paste(_))
+            )
+        })
+    }
+}
+
+/// Shortcut bindings. Only bindings that differ from the defaults are stored.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Shortcuts(BTreeMap<ShortcutAction, KeyboardShortcut>);
+
+impl Shortcuts {
+    pub fn get(&self, action: ShortcutAction) -> KeyboardShortcut {
+        self.0
+            .get(&action)
+            .copied()
+            .unwrap_or_else(|| action.default_shortcut())
+    }
+
+    pub fn set(&mut self, action: ShortcutAction, shortcut: KeyboardShortcut) {
+        if shortcut == action.default_shortcut() {
+            self.0.remove(&action);
+        } else {
+            self.0.insert(action, shortcut);
+        }
+    }
+
+    pub fn reset(&mut self, action: ShortcutAction) {
+        self.0.remove(&action);
+    }
+
+    pub fn is_default(&self, action: ShortcutAction) -> bool {
+        !self.0.contains_key(&action)
+    }
+
+    /// Find the other actions bound to the same shortcut as `action`.
+    pub fn conflicts(&self, action: ShortcutAction) -> Vec<ShortcutAction> {
+        let shortcut = self.get(action);
+        ShortcutAction::ALL
+            .into_iter()
+            .filter(|other| *other != action && self.get(*other) == shortcut)
+            .collect()
+    }
+
+    /// Make these the bindings returned by [ShortcutAction::shortcut].
+    pub fn apply(&self) {
+        let lock = ACTIVE_SHORTCUTS.get_or_init(|| RwLock::new(Shortcuts::default()));
+        *lock.write() = self.clone();
+    }
+}
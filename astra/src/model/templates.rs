@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+use anyhow::Result;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+
+use crate::SchemaRow;
+
+static ACTIVE_TEMPLATES: OnceLock<RwLock<RowTemplates>> = OnceLock::new();
+static TEMPLATES_CHANGED: AtomicBool = AtomicBool::new(false);
+
+/// The part of a key after its prefix, ex. `Foo` for `PID_Foo`.
+fn key_name(key: &str) -> &str {
+    key.split_once('_').map(|(_, name)| name).unwrap_or(key)
+}
+
+/// A saved starting point for new rows.
+/// `{key}` in a value is replaced by the new row's key and `{name}` by the key without its prefix.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RowTemplate {
+    pub name: String,
+    pub values: BTreeMap<String, String>,
+}
+
+impl RowTemplate {
+    /// Make a template from a row, replacing its key with placeholders.
+    /// Values that end with the key's name, like a message key `MPID_Foo` for `PID_Foo`, keep their prefix.
+    pub fn from_row<I: SchemaRow>(name: String, row: &I, key: &str) -> Self {
+        let key_name = key_name(key);
+        let values = row
+            .field_values()
+            .into_iter()
+            .map(|(field, value)| {
+                let value = if value == key {
+                    "{key}".to_string()
+                } else {
+                    match value.strip_suffix(key_name) {
+                        Some(prefix) if !key_name.is_empty() && prefix.ends_with('_') => {
+                            format!("{}{{name}}", prefix)
+                        }
+                        _ => value,
+                    }
+                };
+                (field, value)
+            })
+            .collect();
+        Self { name, values }
+    }
+
+    /// Build a row for `key` from this template.
+    pub fn build<I: SchemaRow + Default>(&self, key: &str) -> Result<I> {
+        let key_name = key_name(key);
+        I::default().with_field_values(self.values.iter().map(|(field, value)| {
+            let value = value.replace("{key}", key).replace("{name}", key_name);
+            (field.clone(), value)
+        }))
+    }
+}
+
+/// Row templates for a project, stored by the row type's schema name,
+/// since sheets don't have stable names of their own.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RowTemplates(BTreeMap<String, Vec<RowTemplate>>);
+
+impl RowTemplates {
+    /// Make these the templates offered when adding rows.
+    pub fn apply(&self) {
+        let lock = ACTIVE_TEMPLATES.get_or_init(|| RwLock::new(RowTemplates::default()));
+        *lock.write() = self.clone();
+        TEMPLATES_CHANGED.store(false, Ordering::Relaxed);
+    }
+
+    /// Retrieve the active templates if they were edited since the last call, so they can be saved with the project.
+    pub fn take_changes() -> Option<RowTemplates> {
+        if TEMPLATES_CHANGED.swap(false, Ordering::Relaxed) {
+            ACTIVE_TEMPLATES.get().map(|lock| lock.read().clone())
+        } else {
+            None
+        }
+    }
+
+    /// The active templates for rows of type `I`.
+    pub fn for_sheet<I: SchemaRow>() -> Vec<RowTemplate> {
+        ACTIVE_TEMPLATES
+            .get()
+            .and_then(|lock| lock.read().0.get(I::NAME).cloned())
+            .unwrap_or_default()
+    }
+
+    /// Add a template for rows of type `I`, replacing any template with the same name.
+    pub fn save<I: SchemaRow>(template: RowTemplate) {
+        Self::edit::<I>(|templates| {
+            match templates
+                .iter_mut()
+                .find(|existing| existing.name == template.name)
+            {
+                Some(existing) => *existing = template,
+                None => templates.push(template),
+            }
+        });
+    }
+
+    pub fn remove<I: SchemaRow>(name: &str) {
+        Self::edit::<I>(|templates| templates.retain(|template| template.name != name));
+    }
+
+    fn edit<I: SchemaRow>(func: impl FnOnce(&mut Vec<RowTemplate>)) {
+        let lock = ACTIVE_TEMPLATES.get_or_init(|| RwLock::new(RowTemplates::default()));
+        let mut active = lock.write();
+        let templates = active.0.entry(I::NAME.to_string()).or_default();
+        func(templates);
+        if templates.is_empty() {
+            active.0.remove(I::NAME);
+        }
+        TEMPLATES_CHANGED.store(true, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,286 @@
+use anyhow::{bail, Result};
+use astra_formats::{TerrainData, UString};
+use astra_types::Spawn;
+use indexmap::IndexMap;
+
+/// How a chapter's tiles are laid out in its terrain buffer.
+/// The buffer is a square grid, usually 32x32, no matter how big the playable area is.
+/// Buffers that aren't square are rejected since their row width can't be known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerrainLayout {
+    /// The number of tiles in each row of the buffer.
+    pub stride: usize,
+    pub rows: usize,
+}
+
+impl TerrainLayout {
+    /// Work out the layout of a terrain buffer and check that the playable area fits inside it.
+    pub fn of(terrain: &TerrainData) -> Result<Self> {
+        Self::of_buffer(terrain.terrains.len(), terrain.width, terrain.height)
+    }
+
+    /// [TerrainLayout::of] for a buffer of `len` tiles with a `width` x `height` playable area.
+    fn of_buffer(len: usize, width: i32, height: i32) -> Result<Self> {
+        let side = (len as f64).sqrt() as usize;
+        if side == 0 || side * side != len {
+            bail!(
+                "the terrain buffer has {} tiles, which isn't a square grid",
+                len
+            );
+        }
+        let layout = Self {
+            stride: side,
+            rows: side,
+        };
+        layout.check_size(width, height)?;
+        Ok(layout)
+    }
+
+    fn check_size(&self, width: i32, height: i32) -> Result<()> {
+        if width < 0 || height < 0 || width as usize > self.stride || height as usize > self.rows {
+            bail!(
+                "a {}x{} playable area doesn't fit in the {}x{} terrain buffer",
+                width,
+                height,
+                self.stride,
+                self.rows
+            );
+        }
+        Ok(())
+    }
+
+    /// The buffer index of the tile at `(x, y)`.
+    pub fn index(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.stride && y < self.rows).then_some(y * self.stride + x)
+    }
+
+    /// Resize the playable area and move its tiles by `shift`.
+    /// Tiles that end up in the playable area without coming from it are set to `fill`.
+    pub fn resize(
+        &self,
+        terrain: &mut TerrainData,
+        width: i32,
+        height: i32,
+        shift: (i32, i32),
+        fill: &str,
+    ) -> Result<()> {
+        terrain.terrains = self.resize_tiles(
+            &terrain.terrains,
+            (terrain.width, terrain.height),
+            (width, height),
+            shift,
+            fill,
+        )?;
+        terrain.width = width;
+        terrain.height = height;
+        Ok(())
+    }
+
+    /// [TerrainLayout::resize] for the buffer's tiles. Returns the resized buffer.
+    fn resize_tiles(
+        &self,
+        old_tiles: &[UString],
+        (old_width, old_height): (i32, i32),
+        (width, height): (i32, i32),
+        shift: (i32, i32),
+        fill: &str,
+    ) -> Result<Vec<UString>> {
+        self.check_size(width, height)?;
+        let mut tiles = old_tiles.to_vec();
+        for y in 0..self.rows {
+            for x in 0..self.stride {
+                let source = (x as i32 - shift.0, y as i32 - shift.1);
+                let in_old_area =
+                    (0..old_width).contains(&source.0) && (0..old_height).contains(&source.1);
+                let in_new_area = (x as i32) < width && (y as i32) < height;
+                let tile = if in_old_area {
+                    self.index(source.0 as usize, source.1 as usize)
+                        .and_then(|index| old_tiles.get(index).cloned())
+                } else if in_new_area {
+                    Some(UString(fill.to_string()))
+                } else {
+                    None
+                };
+                if let Some(tile) = tile {
+                    tiles[y * self.stride + x] = tile;
+                }
+            }
+        }
+        Ok(tiles)
+    }
+}
+
+/// Tiles copied from a rectangular region of a chapter's terrain so they can be stamped elsewhere.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerrainPattern {
+    pub width: usize,
+    pub height: usize,
+    /// Row by row from the bottom like the terrain buffer.
+    tiles: Vec<String>,
+}
+
+impl TerrainPattern {
+    /// Copy the region between two `(x, y)` corners.
+    pub fn copy(
+        terrain: &TerrainData,
+        layout: TerrainLayout,
+        a: (usize, usize),
+        b: (usize, usize),
+    ) -> Self {
+        let (min_x, max_x) = (a.0.min(b.0), a.0.max(b.0));
+        let (min_y, max_y) = (a.1.min(b.1), a.1.max(b.1));
+        let tiles = (min_y..=max_y)
+            .flat_map(|y| (min_x..=max_x).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                layout
+                    .index(x, y)
+                    .and_then(|index| terrain.terrains.get(index))
+                    .map(|tid| tid.to_string())
+                    .unwrap_or_default()
+            })
+            .collect();
+        Self {
+            width: max_x - min_x + 1,
+            height: max_y - min_y + 1,
+            tiles,
+        }
+    }
+
+    /// The pattern's tiles with their `(x, y)` positions when its bottom left corner is at `at`.
+    pub fn tiles_at(&self, at: (usize, usize)) -> impl Iterator<Item = ((usize, usize), &str)> {
+        self.tiles
+            .iter()
+            .enumerate()
+            .map(move |(i, tid)| ((at.0 + i % self.width, at.1 + i / self.width), tid.as_str()))
+    }
+}
+
+/// Replace every `from` tile in the playable area with `to`. Returns the number of tiles replaced.
+pub fn replace_tiles(terrain: &mut TerrainData, from: &str, to: &str) -> Result<usize> {
+    let layout = TerrainLayout::of(terrain)?;
+    let mut replaced = 0;
+    for y in 0..terrain.height as usize {
+        for x in 0..terrain.width as usize {
+            if let Some(index) = layout.index(x, y) {
+                if terrain.terrains[index].as_str() == from {
+                    terrain.terrains[index] = UString(to.to_string());
+                    replaced += 1;
+                }
+            }
+        }
+    }
+    Ok(replaced)
+}
+
+/// The tiles on a straight line between two `(x, y)` positions, including both ends.
+pub fn line_tiles(a: (usize, usize), b: (usize, usize)) -> Vec<(usize, usize)> {
+    // Bresenham's line algorithm.
+    let (mut x, mut y) = (a.0 as i64, a.1 as i64);
+    let (end_x, end_y) = (b.0 as i64, b.1 as i64);
+    let (dx, dy) = ((end_x - x).abs(), -(end_y - y).abs());
+    let (step_x, step_y) = ((end_x - x).signum(), (end_y - y).signum());
+    let mut error = dx + dy;
+    let mut tiles = vec![(x as usize, y as usize)];
+    while (x, y) != (end_x, end_y) {
+        let doubled = 2 * error;
+        if doubled >= dy {
+            error += dy;
+            x += step_x;
+        }
+        if doubled <= dx {
+            error += dx;
+            y += step_y;
+        }
+        tiles.push((x as usize, y as usize));
+    }
+    tiles
+}
+
+/// Move every spawn's dispos and appear coordinates by `shift`.
+pub fn shift_spawns(dispos: &mut IndexMap<String, Vec<Spawn>>, shift: (i32, i32)) {
+    let shifted =
+        |value: i8, by: i32| (value as i32 + by).clamp(i8::MIN as i32, i8::MAX as i32) as i8;
+    for spawn in dispos.values_mut().flatten() {
+        spawn.dispos_x = shifted(spawn.dispos_x, shift.0);
+        spawn.dispos_y = shifted(spawn.dispos_y, shift.1);
+        spawn.appear_x = shifted(spawn.appear_x, shift.0);
+        spawn.appear_y = shifted(spawn.appear_y, shift.1);
+    }
+}
+
+/// Count the spawns placed outside a `width` x `height` playable area.
+pub fn spawns_outside(dispos: &IndexMap<String, Vec<Spawn>>, width: i32, height: i32) -> usize {
+    dispos
+        .values()
+        .flatten()
+        .filter(|spawn| {
+            !(0..width).contains(&(spawn.dispos_x as i32))
+                || !(0..height).contains(&(spawn.dispos_y as i32))
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer(tiles: &[&str]) -> Vec<UString> {
+        tiles.iter().map(|tid| UString(tid.to_string())).collect()
+    }
+
+    #[test]
+    fn layout_of_square_buffers() {
+        let layout = TerrainLayout::of_buffer(9, 2, 1).unwrap();
+        assert_eq!(layout, TerrainLayout { stride: 3, rows: 3 });
+        assert_eq!(layout.index(2, 1), Some(5));
+        assert_eq!(layout.index(3, 0), None);
+
+        assert!(TerrainLayout::of_buffer(0, 0, 0).is_err());
+        // The row width of a buffer that isn't square can't be known.
+        assert!(TerrainLayout::of_buffer(12, 2, 2).is_err());
+        assert!(TerrainLayout::of_buffer(9, 4, 1).is_err());
+        assert!(TerrainLayout::of_buffer(9, -1, 1).is_err());
+    }
+
+    #[test]
+    fn resize_shifts_and_fills() {
+        let layout = TerrainLayout::of_buffer(9, 2, 2).unwrap();
+        #[rustfmt::skip]
+        let tiles = buffer(&[
+            "a", "b", "x",
+            "c", "d", "x",
+            "x", "x", "x",
+        ]);
+        let tiles = layout
+            .resize_tiles(&tiles, (2, 2), (3, 3), (1, 0), "f")
+            .unwrap();
+        #[rustfmt::skip]
+        assert_eq!(tiles, buffer(&[
+            "f", "a", "b",
+            "f", "c", "d",
+            "f", "f", "f",
+        ]));
+
+        // Shifted tiles are kept outside the playable area, the rest are left alone.
+        let tiles = layout
+            .resize_tiles(&tiles, (3, 3), (1, 1), (-1, 0), "g")
+            .unwrap();
+        #[rustfmt::skip]
+        assert_eq!(tiles, buffer(&[
+            "a", "b", "b",
+            "c", "d", "d",
+            "f", "f", "f",
+        ]));
+        assert!(layout
+            .resize_tiles(&tiles, (3, 3), (4, 1), (0, 0), "g")
+            .is_err());
+    }
+
+    #[test]
+    fn line_tiles_include_both_ends() {
+        assert_eq!(line_tiles((1, 1), (1, 1)), [(1, 1)]);
+        assert_eq!(line_tiles((0, 0), (3, 0)), [(0, 0), (1, 0), (2, 0), (3, 0)]);
+        assert_eq!(line_tiles((2, 2), (0, 0)), [(2, 2), (1, 1), (0, 0)]);
+        assert_eq!(line_tiles((0, 0), (1, 3)), [(0, 0), (0, 1), (1, 2), (1, 3)]);
+    }
+}
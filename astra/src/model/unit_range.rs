@@ -0,0 +1,384 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use astra_types::{Item, Spawn, TerrainCostData, TerrainData};
+use indexmap::IndexMap;
+
+use crate::{EditorState, TerrainLayout};
+
+/// Item kinds that can attack. Rods are left out since they only reach allies.
+fn is_weapon(kind: i8) -> bool {
+    matches!(kind, 1..=6 | 8 | 9)
+}
+
+/// How far a unit can move and attack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeProfile {
+    pub move_type: i8,
+    pub mov: u8,
+    /// The minimum and maximum attack range of the unit's weapons, if it has any.
+    pub attack_range: Option<(u8, u8)>,
+}
+
+impl RangeProfile {
+    /// Build the profile for a spawn from its class and items.
+    /// Spawns without a class use the class of their character.
+    pub fn of(spawn: &Spawn, state: &EditorState) -> Option<Self> {
+        let jid = if spawn.jid.is_empty() {
+            state
+                .person
+                .read(|data| data.get(&spawn.pid).map(|person| person.jid.clone()))?
+        } else {
+            spawn.jid.clone()
+        };
+        let (move_type, mov) = state
+            .job
+            .read(|data| data.get(&jid).map(|job| (job.move_type, job.base_move)))?;
+        let attack_range = state.item.read(|data| {
+            attack_range(
+                [
+                    &spawn.item_1_iid,
+                    &spawn.item_2_iid,
+                    &spawn.item_3_iid,
+                    &spawn.item_4_iid,
+                    &spawn.item_5_iid,
+                    &spawn.item_6_iid,
+                ]
+                .into_iter()
+                .filter_map(|iid| data.get(iid)),
+            )
+        });
+        Some(Self {
+            move_type,
+            mov,
+            attack_range,
+        })
+    }
+}
+
+/// The combined range of the weapons among `items`.
+fn attack_range<'a>(items: impl IntoIterator<Item = &'a Item>) -> Option<(u8, u8)> {
+    items
+        .into_iter()
+        .filter(|item| is_weapon(item.kind) && item.range_o > 0)
+        .map(|item| (item.range_i.max(1), item.range_o))
+        .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)))
+}
+
+/// Move costs for every tile kind, looked up by the tile's cost name.
+pub struct MoveCosts<'a> {
+    tiles: &'a IndexMap<String, TerrainData>,
+    costs: HashMap<&'a str, &'a TerrainCostData>,
+}
+
+impl<'a> MoveCosts<'a> {
+    pub fn new(tiles: &'a IndexMap<String, TerrainData>, costs: &'a [TerrainCostData]) -> Self {
+        Self {
+            tiles,
+            costs: costs
+                .iter()
+                .map(|cost| (cost.name.as_str(), cost))
+                .collect(),
+        }
+    }
+
+    /// The cost for `move_type` to enter a tile, or [None] if it can't.
+    /// Tiles without a cost table entry fall back to their `@MoveCost` and `@FlyCost` values.
+    pub fn cost(&self, tid: &str, move_type: i8) -> Option<u8> {
+        let tile = self.tiles.get(tid)?;
+        let cost = match self.costs.get(tile.cost_name.as_str()) {
+            Some(cost) => match move_type {
+                0 => cost.none,
+                1 => cost.foot,
+                2 => cost.horse,
+                3 => cost.fly,
+                4 => cost.dragon,
+                5 => cost.pad,
+                _ => cost.foot,
+            },
+            None if move_type == 3 => tile.fly_cost,
+            None => tile.move_cost,
+        };
+        (cost > 0).then_some(cost)
+    }
+}
+
+/// The tiles a unit can reach and attack from its position, as `(x, y)` coordinates.
+/// Other units don't block movement.
+#[derive(Debug, Clone, Default)]
+pub struct UnitRange {
+    pub reachable: HashSet<(usize, usize)>,
+    pub attackable: HashSet<(usize, usize)>,
+}
+
+impl UnitRange {
+    pub fn compute(
+        terrain: &astra_formats::TerrainData,
+        costs: &MoveCosts<'_>,
+        profile: &RangeProfile,
+        start: (usize, usize),
+    ) -> Self {
+        let Ok(layout) = TerrainLayout::of(terrain) else {
+            return Self::default();
+        };
+        let size = (
+            terrain.width.max(0) as usize,
+            terrain.height.max(0) as usize,
+        );
+        Self::on_grid(size, profile, start, |(x, y)| {
+            layout
+                .index(x, y)
+                .and_then(|index| terrain.terrains.get(index))
+                .and_then(|tid| costs.cost(tid.as_str(), profile.move_type))
+        })
+    }
+
+    /// Compute the range on a `width` by `height` grid where `cost` is the cost to enter a tile,
+    /// or [None] if it can't be entered.
+    fn on_grid(
+        (width, height): (usize, usize),
+        profile: &RangeProfile,
+        start: (usize, usize),
+        cost: impl Fn((usize, usize)) -> Option<u8>,
+    ) -> Self {
+        let in_bounds = |(x, y): (usize, usize)| x < width && y < height;
+        if !in_bounds(start) {
+            return Self::default();
+        }
+
+        // Dijkstra over the grid, starting with the unit's own tile for free.
+        let mut best: HashMap<(usize, usize), u32> = HashMap::from([(start, 0)]);
+        let mut queue = BinaryHeap::from([Reverse((0, start))]);
+        while let Some(Reverse((spent, (x, y)))) = queue.pop() {
+            if best.get(&(x, y)).is_some_and(|best| spent > *best) {
+                continue;
+            }
+            let neighbors = [
+                x.checked_sub(1).map(|x| (x, y)),
+                Some((x + 1, y)),
+                y.checked_sub(1).map(|y| (x, y)),
+                Some((x, y + 1)),
+            ];
+            for next in neighbors.into_iter().flatten().filter(|p| in_bounds(*p)) {
+                let Some(cost) = cost(next) else {
+                    continue;
+                };
+                let total = spent + cost as u32;
+                if total <= profile.mov as u32 && best.get(&next).is_none_or(|b| total < *b) {
+                    best.insert(next, total);
+                    queue.push(Reverse((total, next)));
+                }
+            }
+        }
+        let reachable: HashSet<_> = best.into_keys().collect();
+
+        let mut attackable = HashSet::new();
+        if let Some((min, max)) = profile.attack_range {
+            let (min, max) = (min as i32, max as i32);
+            for (x, y) in &reachable {
+                for dy in -max..=max {
+                    let reach = max - dy.abs();
+                    for dx in -reach..=reach {
+                        if dx.abs() + dy.abs() < min {
+                            continue;
+                        }
+                        let target = (*x as i32 + dx, *y as i32 + dy);
+                        if target.0 >= 0 && target.1 >= 0 {
+                            let target = (target.0 as usize, target.1 as usize);
+                            if in_bounds(target) {
+                                attackable.insert(target);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Self {
+            reachable,
+            attackable,
+        }
+    }
+}
+
+/// The enemies that can attack a tile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Threat {
+    pub count: usize,
+    pub max_level: u8,
+}
+
+/// Every tile enemies can attack, for spotting chokepoints and empty regions.
+#[derive(Debug, Clone, Default)]
+pub struct ThreatMap {
+    pub tiles: HashMap<(usize, usize), Threat>,
+    /// The highest count on any tile, for scaling the heatmap.
+    pub max_count: usize,
+}
+
+impl ThreatMap {
+    /// Combine the attack ranges of units given as their position, profile and level.
+    pub fn compute(
+        terrain: &astra_formats::TerrainData,
+        costs: &MoveCosts<'_>,
+        units: impl IntoIterator<Item = ((usize, usize), RangeProfile, u8)>,
+    ) -> Self {
+        let mut map = Self::default();
+        for (position, profile, level) in units {
+            if profile.attack_range.is_none() {
+                continue;
+            }
+            let range = UnitRange::compute(terrain, costs, &profile, position);
+            for tile in range.attackable {
+                let threat = map.tiles.entry(tile).or_default();
+                threat.count += 1;
+                threat.max_level = threat.max_level.max(level);
+                map.max_count = map.max_count.max(threat.count);
+            }
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(mov: u8, attack_range: Option<(u8, u8)>) -> RangeProfile {
+        RangeProfile {
+            move_type: 1,
+            mov,
+            attack_range,
+        }
+    }
+
+    /// A grid of entry costs given row by row, with 0 for impassable tiles.
+    fn grid_range(rows: &[&[u8]], profile: &RangeProfile, start: (usize, usize)) -> UnitRange {
+        UnitRange::on_grid((rows[0].len(), rows.len()), profile, start, |(x, y)| {
+            Some(rows[y][x]).filter(|cost| *cost > 0)
+        })
+    }
+
+    fn tiles(range: &HashSet<(usize, usize)>) -> Vec<(usize, usize)> {
+        let mut tiles: Vec<_> = range.iter().copied().collect();
+        tiles.sort();
+        tiles
+    }
+
+    #[test]
+    fn move_costs_fall_back_to_tile_costs() {
+        let tile = |cost_name: &str, move_cost, fly_cost| TerrainData {
+            cost_name: cost_name.to_string(),
+            move_cost,
+            fly_cost,
+            ..Default::default()
+        };
+        let tiles = IndexMap::from([
+            ("TID_Plain".to_string(), tile("Plain", 0, 0)),
+            ("TID_Wall".to_string(), tile("Wall", 1, 1)),
+            ("TID_Cliff".to_string(), tile("", 0, 2)),
+        ]);
+        let costs = [
+            TerrainCostData {
+                name: "Plain".to_string(),
+                foot: 1,
+                horse: 2,
+                fly: 1,
+                ..Default::default()
+            },
+            TerrainCostData {
+                name: "Wall".to_string(),
+                ..Default::default()
+            },
+        ];
+        let costs = MoveCosts::new(&tiles, &costs);
+
+        assert_eq!(costs.cost("TID_Plain", 1), Some(1));
+        assert_eq!(costs.cost("TID_Plain", 2), Some(2));
+        // Unknown move types move like infantry.
+        assert_eq!(costs.cost("TID_Plain", 9), Some(1));
+        // A 0 cost in the table blocks the tile, even if the tile has its own cost.
+        assert_eq!(costs.cost("TID_Wall", 1), None);
+        // Tiles missing from the table use their own costs, with the fly cost for fliers.
+        assert_eq!(costs.cost("TID_Cliff", 1), None);
+        assert_eq!(costs.cost("TID_Cliff", 3), Some(2));
+        assert_eq!(costs.cost("TID_Missing", 1), None);
+    }
+
+    #[test]
+    fn movement_stops_at_the_cost_limit() {
+        let range = grid_range(&[&[1, 1, 2, 1, 1]], &profile(3, None), (0, 0));
+        assert_eq!(tiles(&range.reachable), [(0, 0), (1, 0), (2, 0)]);
+        assert!(range.attackable.is_empty());
+    }
+
+    #[test]
+    fn movement_takes_the_cheapest_path() {
+        #[rustfmt::skip]
+        let rows: &[&[u8]] = &[
+            &[1, 5, 1],
+            &[1, 1, 1],
+        ];
+        let range = grid_range(rows, &profile(4, None), (0, 0));
+        assert_eq!(
+            tiles(&range.reachable),
+            [(0, 0), (0, 1), (1, 1), (2, 0), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn impassable_tiles_block_movement() {
+        let range = grid_range(&[&[1, 0, 1]], &profile(5, None), (0, 0));
+        assert_eq!(tiles(&range.reachable), [(0, 0)]);
+        // The start is reachable even if the unit couldn't enter its own tile.
+        let range = grid_range(&[&[0, 1]], &profile(5, None), (0, 0));
+        assert_eq!(tiles(&range.reachable), [(0, 0), (1, 0)]);
+        let outside = grid_range(&[&[1]], &profile(5, None), (1, 0));
+        assert!(outside.reachable.is_empty());
+    }
+
+    #[test]
+    fn attacks_cover_min_to_max_range() {
+        let row: &[u8] = &[0; 5];
+        let rows = [row; 5];
+        let range_of =
+            |attack_range, start| grid_range(&rows, &profile(5, Some(attack_range)), start);
+
+        let bow = range_of((2, 2), (2, 2));
+        assert_eq!(bow.attackable.len(), 8);
+        assert!(bow.attackable.contains(&(2, 0)));
+        assert!(bow.attackable.contains(&(3, 3)));
+        assert!(!bow.attackable.contains(&(2, 1)));
+        assert!(!bow.attackable.contains(&(2, 2)));
+
+        let javelin = range_of((1, 2), (2, 2));
+        assert_eq!(javelin.attackable.len(), 12);
+        assert!(!javelin.attackable.contains(&(2, 2)));
+
+        // Targets outside the map are left out.
+        let corner = range_of((1, 1), (0, 0));
+        assert_eq!(tiles(&corner.attackable), [(0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn attack_range_combines_weapons() {
+        let item = |kind, range_i, range_o| Item {
+            kind,
+            range_i,
+            range_o,
+            ..Default::default()
+        };
+        let sword = item(1, 1, 1);
+        let bow = item(4, 2, 3);
+        let rod = item(7, 1, 10);
+        let dagger = item(9, 0, 2);
+        assert_eq!(attack_range([&sword]), Some((1, 1)));
+        assert_eq!(attack_range([&sword, &bow]), Some((1, 3)));
+        // Rods only reach allies, so they don't count.
+        assert_eq!(attack_range([&rod]), None);
+        assert_eq!(attack_range([&bow, &rod]), Some((2, 3)));
+        // A minimum range of 0 still can't attack the unit's own tile.
+        assert_eq!(attack_range([&dagger]), Some((1, 2)));
+        assert_eq!(attack_range([]), None);
+    }
+}
@@ -1,5 +1,6 @@
 use std::sync::mpsc::Receiver;
 use std::sync::Arc;
+use std::time::Duration;
 
 use astra_core::error::Result;
 use astra_core::{Astra, AstraProject};
@@ -19,24 +20,41 @@ struct LoadedData {
 #[derive(Default)]
 pub struct LoadProjectState {
     receiver: Option<Receiver<Result<LoadedData>>>,
+    recoverable: Option<LoadedData>,
     error: Option<String>,
 }
 
+fn autosave_age(astra: &Astra) -> Option<Duration> {
+    astra
+        .autosave_time()
+        .map(|time| time.elapsed().unwrap_or_default())
+}
+
+fn finish_loading(data: LoadedData) -> AppState {
+    AppState::Main(Box::new(MainState::new(
+        data.astra,
+        data.message_db,
+        data.texture_cache,
+    )))
+}
+
 pub fn project_loader(
     state: &mut LoadProjectState,
     config: &AppConfig,
     next_state: &mut Option<AppState>,
     ctx: &egui::Context,
 ) {
-    if let (Some(receiver), None) = (&mut state.receiver, &state.error) {
+    if state.recoverable.is_some() {
+        // Waiting on the user to decide what to do with the autosave.
+    } else if let (Some(receiver), None) = (&mut state.receiver, &state.error) {
         if let Ok(load_result) = receiver.try_recv() {
             match load_result {
                 Ok(data) => {
-                    *next_state = Some(AppState::Main(Box::new(MainState::new(
-                        data.astra,
-                        data.message_db,
-                        data.texture_cache,
-                    ))));
+                    if autosave_age(&data.astra.read()).is_some() {
+                        state.recoverable = Some(data);
+                    } else {
+                        *next_state = Some(finish_loading(data));
+                    }
                 }
                 Err(err) => {
                     state.error = Some(format!("{:?}", err));
@@ -45,6 +63,7 @@ pub fn project_loader(
         }
     } else if state.error.is_none() {
         let project = config.get_active_project().unwrap(); // TODO
+        project.row_templates.apply();
         let project: AstraProject = project.clone().into();
         let (sender, receiver) = std::sync::mpsc::channel();
         let ctx = ctx.clone();
@@ -87,10 +106,58 @@ pub fn project_loader(
                 }
             });
         });
+        let recovery_modal = Modal::new(ctx, "load_project_recovery_modal");
+        recovery_modal.show(|ui| {
+            recovery_modal.title(ui, "Recover Unsaved Changes?");
+            let minutes = state
+                .recoverable
+                .as_ref()
+                .and_then(|data| autosave_age(&data.astra.read()))
+                .unwrap_or_default()
+                .as_secs()
+                / 60;
+            recovery_modal.frame(ui, |ui| {
+                recovery_modal.body(
+                    ui,
+                    format!(
+                        "Astra found changes autosaved {} minute(s) ago that were never saved. \
+                        Recover them?",
+                        minutes
+                    ),
+                );
+            });
+            recovery_modal.buttons(ui, |ui| {
+                if recovery_modal.button(ui, "Discard").clicked() {
+                    if let Some(data) = state.recoverable.take() {
+                        let result = data.astra.read().discard_autosave();
+                        match result {
+                            Ok(_) => *next_state = Some(finish_loading(data)),
+                            Err(err) => state.error = Some(format!("{:?}", err)),
+                        }
+                    }
+                }
+                if recovery_modal.suggested_button(ui, "Recover").clicked() {
+                    if let Some(mut data) = state.recoverable.take() {
+                        let result = data.astra.write().recover_autosave();
+                        match result {
+                            Ok(_) => {
+                                // The message DB copies messages on creation, so rebuild it.
+                                data.message_db = MessageDb::new(data.astra.clone());
+                                *next_state = Some(finish_loading(data));
+                            }
+                            Err(err) => state.error = Some(format!("{:?}", err)),
+                        }
+                    }
+                }
+            });
+        });
         match state.error.as_deref() {
             Some(_) => {
                 modal.open();
             }
+            None if state.recoverable.is_some() => {
+                recovery_modal.open();
+            }
             None => {
                 if ui.button("Cancel").clicked() {
                     *next_state = Some(AppState::SelectProject);
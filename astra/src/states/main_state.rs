@@ -1,25 +1,31 @@
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::sync::mpsc::{Receiver, TryRecvError};
 use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 
-use egui::Style;
+use egui::{Button, Checkbox, PointerButton, Style, ViewportCommand};
+use egui_modal::Modal;
 use egui_notify::Toasts;
 use parking_lot::{Mutex, RwLock};
+use tracing::error;
 
+use astra_core::error::Result;
 use astra_core::{Astra, RomSource};
 
 use crate::widgets::{about_modal, config_editor_modal};
 use crate::{
     AccessoryEditor, AchieveEditor, AiEditor, AnimSetEditor, AnimalEditor, AppConfig, AppState,
     ArenaEditor, AssetTableEditor, CalculatorEditor, ChapterEditor, ChartEditor,
-    CobaltConfigEditor, CookEditor, DragonRideEditor, EditorState, EffectEditor, EncountEditor,
-    FishingFishEditor, ForgeEditor, FriendListEditor, GameParamEditor, GodDataSheetRetriever,
-    GodEditor, HubAreaEditor, ItemEditor, JobEditor, KillBonusEditor, LaterTalkEditor,
-    MapEditorEditor, MascotEditor, MessageDb, MessageDbWrapper, MiscEditor, MovieEditor,
-    MuscleExerciseDataEditor, MusicEditor, PersonEditor, PhotographSpotEditor, ProfileCardEditor,
-    RelayEditor, RelianceEditor, RingEditor, SaveScreen, ScriptManager, SheetHandle, ShopEditor,
-    SkillEditor, TerrainDataEditor, TextDataEditor, TextureCache, Theme, TitleEditor,
-    TutorialEditor, NEXT_TAB_SHORTCUT, PREV_TAB_SHORTCUT,
+    CobaltConfigEditor, CommandPalette, CookEditor, DragonRideEditor, EditHistory, EditorState,
+    EffectEditor, EncountEditor, FishingFishEditor, ForgeEditor, FriendListEditor, GameParamEditor,
+    GodDataSheetRetriever, GodEditor, HubAreaEditor, ItemEditor, JobEditor, KillBonusEditor,
+    LaterTalkEditor, Location, MapEditorEditor, MascotEditor, MessageDb, MessageDbWrapper,
+    MiscEditor, MovieEditor, MuscleExerciseDataEditor, MusicEditor, NavigationHistory,
+    PaletteTarget, PersonEditor, PhotographSpotEditor, ProfileCardEditor, RelayEditor,
+    RelianceEditor, RingEditor, RowModel, RowTemplates, SaveScreen, ScriptManager, SheetHandle,
+    ShopEditor, ShortcutAction, SkillEditor, SplitView, TableEditor, TerrainDataEditor,
+    TextDataEditor, TextureCache, Theme, TitleEditor, TutorialEditor,
 };
 
 static TRANSITION: OnceLock<Mutex<Option<Transition>>> = OnceLock::new();
@@ -100,6 +106,7 @@ pub enum Screens {
     Scripts,
     Shop,
     Skill,
+    Table,
     Terrain,
     Text,
     Title,
@@ -148,12 +155,13 @@ impl Screens {
             35 => Some(Screens::Scripts),
             36 => Some(Screens::Shop),
             37 => Some(Screens::Skill),
-            38 => Some(Screens::Terrain),
-            39 => Some(Screens::Text),
-            40 => Some(Screens::Title),
-            41 => Some(Screens::Tutorial),
-            42 => Some(Screens::DragonRide),
-            43 => Some(Screens::CobaltConfig),
+            38 => Some(Screens::Table),
+            39 => Some(Screens::Terrain),
+            40 => Some(Screens::Text),
+            41 => Some(Screens::Title),
+            42 => Some(Screens::Tutorial),
+            43 => Some(Screens::DragonRide),
+            44 => Some(Screens::CobaltConfig),
             _ => None,
         }
     }
@@ -199,30 +207,98 @@ impl Screens {
             Screens::Scripts => Some(35),
             Screens::Shop => Some(36),
             Screens::Skill => Some(37),
-            Screens::Terrain => Some(38),
-            Screens::Text => Some(39),
-            Screens::Title => Some(40),
-            Screens::Tutorial => Some(41),
-            Screens::DragonRide => Some(42),
-            Screens::CobaltConfig => Some(43),
+            Screens::Table => Some(38),
+            Screens::Terrain => Some(39),
+            Screens::Text => Some(40),
+            Screens::Title => Some(41),
+            Screens::Tutorial => Some(42),
+            Screens::DragonRide => Some(43),
+            Screens::CobaltConfig => Some(44),
+        }
+    }
+
+    /// The name shown on the screen's tab.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Screens::Accessory => "Accessory",
+            Screens::Achieve => "Achieve",
+            Screens::Ai => "AI",
+            Screens::AnimSet => "Anim Set",
+            Screens::Animal => "Animal",
+            Screens::Arena => "Arena",
+            Screens::AssetTable => "Asset Table",
+            Screens::Calculator => "Calculator",
+            Screens::Chapter => "Chapters",
+            Screens::Person => "Characters",
+            Screens::Chart => "Chart",
+            Screens::Job => "Classes",
+            Screens::Cook => "Cook",
+            Screens::Effect => "Effect",
+            Screens::Encount => "Encount",
+            Screens::MuscleExercise => "Exercise",
+            Screens::Fishing => "Fishing",
+            Screens::Forge => "Forge",
+            Screens::FriendList => "Friend List",
+            Screens::God => "God",
+            Screens::Hub => "Hub",
+            Screens::Item => "Items",
+            Screens::KillBonus => "Kill Bonus",
+            Screens::MapEditor => "Map Editor",
+            Screens::Mascot => "Mascot",
+            Screens::Misc => "Misc.",
+            Screens::Movie => "Movie",
+            Screens::Music => "Music",
+            Screens::Param => "Param",
+            Screens::Photograph => "Photograph",
+            Screens::LaterTalk => "Post Battle",
+            Screens::ProfileCard => "Profile",
+            Screens::Relay => "Relay",
+            Screens::Reliance => "Reliance",
+            Screens::Ring => "Ring",
+            Screens::Scripts => "Scripts",
+            Screens::Shop => "Shop",
+            Screens::Skill => "Skills",
+            Screens::Table => "Table",
+            Screens::Terrain => "Terrain",
+            Screens::Text => "Text",
+            Screens::Title => "Title",
+            Screens::Tutorial => "Tutorial",
+            Screens::DragonRide => "Wyvern Ride",
+            Screens::CobaltConfig => "Cobalt Config",
+            Screens::Save => "Save",
         }
     }
 
     pub fn next_tab(&self) -> Option<Self> {
         self.get_tab_index()
-            .and_then(|index| Self::from_tab_index(if index + 1 < 44 { index + 1 } else { 0 }))
+            .and_then(|index| Self::from_tab_index(if index + 1 < 45 { index + 1 } else { 0 }))
     }
 
     pub fn prev_tab(&self) -> Option<Self> {
         self.get_tab_index()
-            .and_then(|index| Self::from_tab_index(if index > 0 { index - 1 } else { 43 }))
+            .and_then(|index| Self::from_tab_index(if index > 0 { index - 1 } else { 44 }))
     }
 }
 
+/// What to do once the user has dealt with unsaved changes.
+#[derive(Debug, Clone, Copy)]
+enum CloseAction {
+    CloseProject,
+    Exit,
+}
+
 pub struct MainState {
     editor_state: EditorState,
     active_screen: Screens,
     toasts: Toasts,
+    close_action: Option<CloseAction>,
+    save_before_close: bool,
+    allow_exit: bool,
+    last_autosave: Instant,
+    autosave_receiver: Option<Receiver<Result<()>>>,
+    split_view: SplitView,
+    navigation: NavigationHistory,
+    command_palette: CommandPalette,
 
     accessory_editor: AccessoryEditor,
     achieve_editor: AchieveEditor,
@@ -265,6 +341,7 @@ pub struct MainState {
     script_manager: ScriptManager,
     shop_editor: ShopEditor,
     skill_editor: SkillEditor,
+    table_editor: TableEditor,
     terrain_editor: TerrainDataEditor,
     text_data_editor: TextDataEditor,
     title_editor: TitleEditor,
@@ -277,6 +354,8 @@ impl MainState {
         message_db: MessageDb,
         texture_cache: TextureCache,
     ) -> Self {
+        // History from a previous project can't be applied to this one.
+        EditHistory::clear();
         let astra_tmp = astra.clone();
         let state = EditorState {
             accessory: SheetHandle::new(astra.read().get_item_book(), Default::default()),
@@ -602,6 +681,7 @@ impl MainState {
             tips: SheetHandle::new(astra.read().get_tutorial_book(), Default::default()),
             tutorials: SheetHandle::new(astra.read().get_tutorial_book(), Default::default()),
             terrain: SheetHandle::new(astra.read().get_terrain_book(), Default::default()),
+            terrain_cost: SheetHandle::new(astra.read().get_terrain_book(), Default::default()),
             vibration_data: SheetHandle::new(astra.read().get_vibration_book(), Default::default()),
 
             spawns: Default::default(),
@@ -649,6 +729,7 @@ impl MainState {
             ring_editor: RingEditor::new(&state),
             shop_editor: ShopEditor::new(&state),
             skill_editor: SkillEditor::new(&state),
+            table_editor: TableEditor::new(&state),
             terrain_editor: TerrainDataEditor::new(&state),
             text_data_editor: TextDataEditor::new(&state),
             title_editor: TitleEditor::new(&state),
@@ -658,6 +739,168 @@ impl MainState {
             script_manager: ScriptManager::new(astra),
             active_screen: Screens::Person,
             toasts: Toasts::default(),
+            close_action: None,
+            save_before_close: false,
+            allow_exit: false,
+            last_autosave: Instant::now(),
+            autosave_receiver: None,
+            split_view: SplitView::default(),
+            navigation: NavigationHistory::default(),
+            command_palette: CommandPalette::default(),
+        }
+    }
+
+    /// Close now if nothing is unsaved. Otherwise, ask the user what to do with their changes.
+    fn request_close(
+        &mut self,
+        action: CloseAction,
+        next_state: &mut Option<AppState>,
+        ctx: &egui::Context,
+        modal: &Modal,
+    ) {
+        if self.editor_state.astra.read().is_dirty() {
+            self.close_action = Some(action);
+            modal.open();
+        } else {
+            self.close(action, next_state, ctx);
+        }
+    }
+
+    fn close(
+        &mut self,
+        action: CloseAction,
+        next_state: &mut Option<AppState>,
+        ctx: &egui::Context,
+    ) {
+        match action {
+            CloseAction::CloseProject => *next_state = Some(AppState::SelectProject),
+            CloseAction::Exit => {
+                self.allow_exit = true;
+                ctx.send_viewport_cmd(ViewportCommand::Close);
+            }
+        }
+    }
+
+    /// Periodically write unsaved changes to the project's autosave folder on a background thread.
+    fn autosave(&mut self, config: &AppConfig, ctx: &egui::Context) {
+        if let Some(receiver) = &self.autosave_receiver {
+            match receiver.try_recv() {
+                Ok(Err(err)) => {
+                    error!("Autosave failed: {:?}", err);
+                    self.toasts.error("Autosave failed");
+                    self.autosave_receiver = None;
+                }
+                Ok(Ok(_)) | Err(TryRecvError::Disconnected) => self.autosave_receiver = None,
+                Err(TryRecvError::Empty) => return,
+            }
+        }
+        if !config.autosave_enabled || matches!(self.active_screen, Screens::Save) {
+            return;
+        }
+        let interval = Duration::from_secs(config.autosave_interval_minutes.max(1) as u64 * 60);
+        let elapsed = self.last_autosave.elapsed();
+        if elapsed < interval {
+            ctx.request_repaint_after(interval - elapsed);
+            return;
+        }
+        self.last_autosave = Instant::now();
+        let astra = self.editor_state.astra.clone();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        self.autosave_receiver = Some(receiver);
+        std::thread::spawn(move || {
+            let _ = sender.send(astra.read().autosave());
+        });
+    }
+
+    /// The active screen and the key of its selected row, if it has one.
+    fn location(&self) -> Location {
+        macro_rules! selected_key {
+            ($editor:ident, $sheet:ident) => {
+                self.$editor
+                    .selection()
+                    .and_then(|index| self.editor_state.$sheet.read(|data| data.row_id(index)))
+            };
+        }
+        let key = match self.active_screen {
+            Screens::Accessory => selected_key!(accessory_editor, accessory),
+            Screens::AnimSet => selected_key!(anim_set_editor, anim_set),
+            Screens::Chapter => selected_key!(chapter_editor, chapter),
+            Screens::God => selected_key!(god_editor, god),
+            Screens::Item => selected_key!(item_editor, item),
+            Screens::Job => selected_key!(job_editor, job),
+            Screens::Person => selected_key!(person_editor, person),
+            Screens::Skill => selected_key!(skill_editor, skill),
+            Screens::Terrain => selected_key!(terrain_editor, terrain),
+            _ => None,
+        };
+        let spawn = match self.active_screen {
+            Screens::Chapter => self.chapter_editor.selected_spawn(),
+            _ => None,
+        };
+        Location {
+            screen: self.active_screen,
+            key,
+            spawn,
+        }
+    }
+
+    /// Run an explicit navigation, ex. from the command palette or a reference,
+    /// and record it in the navigation history.
+    fn navigate(&mut self, navigate: impl FnOnce(&mut Self)) {
+        let from = self.location();
+        navigate(self);
+        let to = self.location();
+        self.navigation.visit(from, to);
+    }
+
+    /// Show a location from the navigation history.
+    fn go_to(&mut self, location: Location) {
+        macro_rules! key_index {
+            ($sheet:ident) => {
+                location
+                    .key
+                    .as_deref()
+                    .and_then(|key| self.editor_state.$sheet.read(|data| data.row_index(key)))
+            };
+        }
+        let index = match location.screen {
+            Screens::Accessory => key_index!(accessory),
+            Screens::AnimSet => key_index!(anim_set),
+            Screens::Chapter => key_index!(chapter),
+            Screens::God => key_index!(god),
+            Screens::Item => key_index!(item),
+            Screens::Job => key_index!(job),
+            Screens::Person => key_index!(person),
+            Screens::Skill => key_index!(skill),
+            Screens::Terrain => key_index!(terrain),
+            _ => None,
+        };
+        self.open_screen(location.screen, index);
+        if location.spawn.is_some() {
+            self.chapter_editor.select_spawn(location.spawn);
+        }
+    }
+
+    /// Switch to a screen, selecting a row on it if an index is given.
+    fn open_screen(&mut self, screen: Screens, index: Option<usize>) {
+        if self.active_screen != screen {
+            self.on_leave_tab(self.active_screen);
+        }
+        match index {
+            Some(index) => Transition::new(screen, index).act(self),
+            None => self.active_screen = screen,
+        }
+    }
+
+    fn navigate_back(&mut self) {
+        if let Some(location) = self.navigation.back(self.location()) {
+            self.go_to(location);
+        }
+    }
+
+    fn navigate_forward(&mut self) {
+        if let Some(location) = self.navigation.forward(self.location()) {
+            self.go_to(location);
         }
     }
 
@@ -679,10 +922,67 @@ pub fn main_window(
     let about_modal = about_modal(ctx);
     let config_editor_modal = config_editor_modal(ctx, config);
 
+    let unsaved_changes_modal = Modal::new(ctx, "unsaved_changes_modal");
+    unsaved_changes_modal.show(|ui| {
+        unsaved_changes_modal.title(ui, "Unsaved Changes");
+        unsaved_changes_modal.frame(ui, |ui| {
+            unsaved_changes_modal.body(ui, "Save your changes before closing the project?");
+        });
+        unsaved_changes_modal.buttons(ui, |ui| {
+            if unsaved_changes_modal.button(ui, "Cancel").clicked() {
+                state.close_action = None;
+            }
+            if unsaved_changes_modal
+                .caution_button(ui, "Discard")
+                .clicked()
+            {
+                if let Err(err) = state.editor_state.astra.read().discard_autosave() {
+                    error!("Failed to delete autosave: {:?}", err);
+                }
+                if let Some(action) = state.close_action.take() {
+                    state.close(action, next_state, ctx);
+                }
+            }
+            if unsaved_changes_modal.suggested_button(ui, "Save").clicked() {
+                state.save_before_close = true;
+                state.save_screen.set_return_screen(state.active_screen);
+                state.active_screen = Screens::Save;
+            }
+        });
+    });
+
+    if ctx.input(|input| input.viewport().close_requested()) && !state.allow_exit {
+        ctx.send_viewport_cmd(ViewportCommand::CancelClose);
+        state.request_close(CloseAction::Exit, next_state, ctx, &unsaved_changes_modal);
+    }
+
+    // Finish closing once the save started from the unsaved changes prompt is done.
+    if state.save_before_close && !matches!(state.active_screen, Screens::Save) {
+        state.save_before_close = false;
+        match state.close_action.take() {
+            // If the project is still dirty, the save failed and the user already saw why.
+            Some(action) if !state.editor_state.astra.read().is_dirty() => {
+                state.close(action, next_state, ctx)
+            }
+            _ => {}
+        }
+    }
+
+    state.autosave(config, ctx);
+
+    if let Some(templates) = RowTemplates::take_changes() {
+        if let Some(project) = config
+            .active_project
+            .and_then(|index| config.projects.get_mut(index))
+        {
+            project.row_templates = templates;
+        }
+    }
+
     if let Some(lock) = TRANSITION.get() {
         let mut data = lock.lock();
         if let Some(transition) = &*data {
-            transition.act(state);
+            state.navigate(|state| transition.act(state));
         }
         *data = None;
     }
@@ -691,7 +991,13 @@ pub fn main_window(
         ui.set_enabled(!matches!(state.active_screen, Screens::Save));
         egui::menu::bar(ui, |ui| {
             ui.menu_button("File", |ui| {
-                if ui.button("Save").clicked() {
+                if ui
+                    .add(
+                        Button::new("Save")
+                            .shortcut_text(ctx.format_shortcut(&ShortcutAction::Save.shortcut())),
+                    )
+                    .clicked()
+                {
                     state.save_screen.set_return_screen(state.active_screen);
                     state.active_screen = Screens::Save;
                     ui.close_menu();
@@ -735,12 +1041,82 @@ pub fn main_window(
                 }
                 ui.separator();
                 if ui.button("Close").clicked() {
-                    // TODO: Prompt before closing?
-                    *next_state = Some(AppState::SelectProject);
+                    state.request_close(
+                        CloseAction::CloseProject,
+                        next_state,
+                        ctx,
+                        &unsaved_changes_modal,
+                    );
+                    ui.close_menu();
+                }
+            });
+            ui.menu_button("Edit", |ui| {
+                if ui
+                    .add_enabled(
+                        EditHistory::can_undo(),
+                        Button::new("Undo")
+                            .shortcut_text(ctx.format_shortcut(&ShortcutAction::Undo.shortcut())),
+                    )
+                    .clicked()
+                {
+                    EditHistory::undo();
+                    ui.close_menu();
+                }
+                if ui
+                    .add_enabled(
+                        EditHistory::can_redo(),
+                        Button::new("Redo")
+                            .shortcut_text(ctx.format_shortcut(&ShortcutAction::Redo.shortcut())),
+                    )
+                    .clicked()
+                {
+                    EditHistory::redo();
+                    ui.close_menu();
+                }
+            });
+            ui.menu_button("Go", |ui| {
+                if ui
+                    .add(Button::new("Go To…").shortcut_text(
+                        ctx.format_shortcut(&ShortcutAction::CommandPalette.shortcut()),
+                    ))
+                    .clicked()
+                {
+                    state.command_palette.open(&state.editor_state);
+                    ui.close_menu();
+                }
+                ui.separator();
+                if ui
+                    .add_enabled(
+                        state.navigation.can_go_back(),
+                        Button::new("Back")
+                            .shortcut_text(ctx.format_shortcut(&ShortcutAction::Back.shortcut())),
+                    )
+                    .clicked()
+                {
+                    state.navigate_back();
+                    ui.close_menu();
+                }
+                if ui
+                    .add_enabled(
+                        state.navigation.can_go_forward(),
+                        Button::new("Forward").shortcut_text(
+                            ctx.format_shortcut(&ShortcutAction::Forward.shortcut()),
+                        ),
+                    )
+                    .clicked()
+                {
+                    state.navigate_forward();
                     ui.close_menu();
                 }
             });
             ui.menu_button("View", |ui| {
+                let has_tabs = state.split_view.has_tabs();
+                ui.add_enabled(
+                    has_tabs,
+                    Checkbox::new(state.split_view.visible_mut(), "Split View"),
+                )
+                .on_hover_text("Show pinned rows next to the current editor");
+                ui.separator();
                 ui.menu_button("Theme", |ui| {
                     if ui
                         .selectable_label(matches!(config.theme, Theme::Egui), "egui")
@@ -787,63 +1163,19 @@ pub fn main_window(
             });
         });
         ui.separator();
-        let prev = state.active_screen;
+        let mut clicked = None;
         ui.horizontal_wrapped(|ui| {
-            ui.selectable_value(&mut state.active_screen, Screens::Accessory, "Accessory");
-            ui.selectable_value(&mut state.active_screen, Screens::Achieve, "Achieve");
-            ui.selectable_value(&mut state.active_screen, Screens::Ai, "AI");
-            ui.selectable_value(&mut state.active_screen, Screens::AnimSet, "Anim Set");
-            ui.selectable_value(&mut state.active_screen, Screens::Animal, "Animal");
-            ui.selectable_value(&mut state.active_screen, Screens::Arena, "Arena");
-            ui.selectable_value(&mut state.active_screen, Screens::AssetTable, "Asset Table");
-            ui.selectable_value(&mut state.active_screen, Screens::Calculator, "Calculator");
-            ui.selectable_value(&mut state.active_screen, Screens::Chapter, "Chapters");
-            ui.selectable_value(&mut state.active_screen, Screens::Person, "Characters");
-            ui.selectable_value(&mut state.active_screen, Screens::Chart, "Chart");
-            ui.selectable_value(&mut state.active_screen, Screens::Job, "Classes");
-            ui.selectable_value(&mut state.active_screen, Screens::Cook, "Cook");
-            ui.selectable_value(&mut state.active_screen, Screens::Effect, "Effect");
-            ui.selectable_value(&mut state.active_screen, Screens::Encount, "Encount");
-            ui.selectable_value(
-                &mut state.active_screen,
-                Screens::MuscleExercise,
-                "Exercise",
-            );
-            ui.selectable_value(&mut state.active_screen, Screens::Fishing, "Fishing");
-            ui.selectable_value(&mut state.active_screen, Screens::Forge, "Forge");
-            ui.selectable_value(&mut state.active_screen, Screens::FriendList, "Friend List");
-            ui.selectable_value(&mut state.active_screen, Screens::God, "God");
-            ui.selectable_value(&mut state.active_screen, Screens::Hub, "Hub");
-            ui.selectable_value(&mut state.active_screen, Screens::Item, "Items");
-            ui.selectable_value(&mut state.active_screen, Screens::KillBonus, "Kill Bonus");
-            ui.selectable_value(&mut state.active_screen, Screens::MapEditor, "Map Editor");
-            ui.selectable_value(&mut state.active_screen, Screens::Mascot, "Mascot");
-            ui.selectable_value(&mut state.active_screen, Screens::Misc, "Misc.");
-            ui.selectable_value(&mut state.active_screen, Screens::Movie, "Movie");
-            ui.selectable_value(&mut state.active_screen, Screens::Music, "Music");
-            ui.selectable_value(&mut state.active_screen, Screens::Param, "Param");
-            ui.selectable_value(&mut state.active_screen, Screens::Photograph, "Photograph");
-            ui.selectable_value(&mut state.active_screen, Screens::LaterTalk, "Post Battle");
-            ui.selectable_value(&mut state.active_screen, Screens::ProfileCard, "Profile");
-            ui.selectable_value(&mut state.active_screen, Screens::Relay, "Relay");
-            ui.selectable_value(&mut state.active_screen, Screens::Reliance, "Reliance");
-            ui.selectable_value(&mut state.active_screen, Screens::Ring, "Ring");
-            ui.selectable_value(&mut state.active_screen, Screens::Scripts, "Scripts");
-            ui.selectable_value(&mut state.active_screen, Screens::Shop, "Shop");
-            ui.selectable_value(&mut state.active_screen, Screens::Skill, "Skills");
-            ui.selectable_value(&mut state.active_screen, Screens::Terrain, "Terrain");
-            ui.selectable_value(&mut state.active_screen, Screens::Text, "Text");
-            ui.selectable_value(&mut state.active_screen, Screens::Title, "Title");
-            ui.selectable_value(&mut state.active_screen, Screens::Tutorial, "Tutorial");
-            ui.selectable_value(&mut state.active_screen, Screens::DragonRide, "Wyvern Ride");
-            ui.selectable_value(
-                &mut state.active_screen,
-                Screens::CobaltConfig,
-                "Cobalt Config",
-            );
+            for screen in (0..45).filter_map(Screens::from_tab_index) {
+                if ui
+                    .selectable_label(state.active_screen == screen, screen.label())
+                    .clicked()
+                {
+                    clicked = Some(screen);
+                }
+            }
         });
-        if state.active_screen != prev {
-            state.on_leave_tab(prev);
+        if let Some(screen) = clicked {
+            state.navigate(|state| state.open_screen(screen, None));
         }
         match state.active_screen {
             Screens::Achieve => state.achieve_editor.tab_strip(ui),
@@ -875,30 +1207,99 @@ pub fn main_window(
         }
     });
 
-    ctx.input_mut(|input| {
-        if input.consume_shortcut(&PREV_TAB_SHORTCUT) {
-            if let Some(screen) = state.active_screen.prev_tab() {
-                state.active_screen = screen;
-            }
-        } else if input.consume_shortcut(&NEXT_TAB_SHORTCUT) {
-            if let Some(screen) = state.active_screen.next_tab() {
-                state.active_screen = screen;
-            }
+    let switch_tab = ctx.input_mut(|input| {
+        if input.consume_shortcut(&ShortcutAction::PrevTab.shortcut()) {
+            state.active_screen.prev_tab()
+        } else if input.consume_shortcut(&ShortcutAction::NextTab.shortcut()) {
+            state.active_screen.next_tab()
+        } else {
+            None
         }
     });
+    if let Some(screen) = switch_tab {
+        state.navigate(|state| state.open_screen(screen, None));
+    }
+
+    if !matches!(state.active_screen, Screens::Save)
+        && ctx.input_mut(|input| input.consume_shortcut(&ShortcutAction::Save.shortcut()))
+    {
+        state.save_screen.set_return_screen(state.active_screen);
+        state.active_screen = Screens::Save;
+    }
+
+    // Text fields have their own undo, so only handle the shortcuts when nothing is focused.
+    let no_widgets_focused = ctx.memory(|mem| mem.focus().is_none());
+    if no_widgets_focused && !matches!(state.active_screen, Screens::Save) {
+        if ctx.input_mut(|input| input.consume_shortcut(&ShortcutAction::Undo.shortcut())) {
+            EditHistory::undo();
+        } else if ctx.input_mut(|input| input.consume_shortcut(&ShortcutAction::Redo.shortcut())) {
+            EditHistory::redo();
+        }
+    }
+
+    if !matches!(state.active_screen, Screens::Save)
+        && ctx.input_mut(|input| input.consume_shortcut(&ShortcutAction::CommandPalette.shortcut()))
+    {
+        state.command_palette.open(&state.editor_state);
+    }
+    match state.command_palette.show(ctx) {
+        Some(PaletteTarget::Screen(screen)) => {
+            state.navigate(|state| state.open_screen(screen, None))
+        }
+        Some(PaletteTarget::Row(screen, index)) => {
+            state.navigate(|state| state.open_screen(screen, Some(index)))
+        }
+        None => {}
+    }
+
+    // The mouse's side buttons work like they do in a web browser.
+    if !matches!(state.active_screen, Screens::Save) {
+        let (back, forward) = ctx.input_mut(|input| {
+            (
+                input.pointer.button_pressed(PointerButton::Extra1)
+                    || (no_widgets_focused
+                        && input.consume_shortcut(&ShortcutAction::Back.shortcut())),
+                input.pointer.button_pressed(PointerButton::Extra2)
+                    || (no_widgets_focused
+                        && input.consume_shortcut(&ShortcutAction::Forward.shortcut())),
+            )
+        });
+        if back {
+            state.navigate_back();
+        } else if forward {
+            state.navigate_forward();
+        }
+    }
+
+    if !matches!(state.active_screen, Screens::Save) {
+        state.split_view.show(ctx, &state.editor_state);
+    }
 
     match &mut state.active_screen {
         Screens::Accessory => state.accessory_editor.show(ctx, &mut state.editor_state),
         Screens::Achieve => state.achieve_editor.show(ctx, &state.editor_state),
         Screens::Ai => state.ai_editor.show(ctx, &state.editor_state),
-        Screens::AnimSet => state.anim_set_editor.show(ctx),
+        Screens::AnimSet => state.anim_set_editor.show(ctx, &state.editor_state),
         Screens::Animal => state.animal_editor.show(ctx, &state.editor_state),
         Screens::Arena => state.arena_editor.show(ctx, &state.editor_state),
         Screens::AssetTable => state.asset_table_editor.show(ctx, &state.editor_state),
-        Screens::Calculator => state.calculator_editor.show(ctx),
-        Screens::Chapter => state
-            .chapter_editor
-            .show(ctx, &mut state.editor_state, config),
+        Screens::Calculator => state.calculator_editor.show(ctx, &state.editor_state),
+        Screens::Chapter => {
+            let spawn = state.chapter_editor.selected_spawn();
+            state
+                .chapter_editor
+                .show(ctx, &mut state.editor_state, config);
+            // Selecting a spawn counts as navigating so references from it can be retraced.
+            let selected = state.chapter_editor.selected_spawn();
+            if selected.is_some() && selected != spawn {
+                let to = state.location();
+                let from = Location {
+                    spawn,
+                    ..to.clone()
+                };
+                state.navigation.visit(from, to);
+            }
+        }
         Screens::Chart => state.chart_editor.show(ctx, &state.editor_state),
         Screens::Cook => state.cook_editor.show(ctx, &state.editor_state),
         Screens::DragonRide => state.dragon_ride_editor.show(ctx, &state.editor_state),
@@ -919,12 +1320,12 @@ pub fn main_window(
         Screens::Movie => state.movie_editor.show(ctx, &state.editor_state),
         Screens::MuscleExercise => state.muscle_exercise_editor.show(ctx, &state.editor_state),
         Screens::Music => state.music_editor.show(ctx, &state.editor_state),
-        Screens::Param => state.param_editor.show(ctx),
+        Screens::Param => state.param_editor.show(ctx, &state.editor_state),
         Screens::Person => state.person_editor.show(ctx, &mut state.editor_state),
         Screens::Photograph => state.photograph_editor.show(ctx, &state.editor_state),
         Screens::ProfileCard => state.profile_card_editor.show(ctx, &state.editor_state),
         Screens::Relay => state.relay_editor.show(ctx, &state.editor_state),
-        Screens::Reliance => state.reliance_editor.show(ctx),
+        Screens::Reliance => state.reliance_editor.show(ctx, &state.editor_state),
         Screens::Ring => state.ring_editor.show(ctx, &state.editor_state),
         Screens::Save => state
             .save_screen
@@ -932,6 +1333,7 @@ pub fn main_window(
         Screens::Scripts => state.script_manager.ui(ctx, config, &mut state.toasts),
         Screens::Shop => state.shop_editor.show(ctx, &mut state.editor_state),
         Screens::Skill => state.skill_editor.show(ctx, &mut state.editor_state),
+        Screens::Table => state.table_editor.show(ctx),
         Screens::Terrain => state.terrain_editor.show(ctx, &mut state.editor_state),
         Screens::Text => state
             .text_data_editor
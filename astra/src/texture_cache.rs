@@ -11,6 +11,7 @@ pub struct TextureCache {
     item_icon_cache: HashMap<String, TextureHandle>,
     skill_icon_cache: HashMap<String, TextureHandle>,
     unit_icon_cache: HashMap<String, TextureHandle>,
+    unit_icon_images: HashMap<String, DynamicImage>,
     versus_cache: HashMap<String, TextureHandle>,
     hub_icons_cache: HashMap<String, TextureHandle>,
     hub_cafe_icons_cache: HashMap<String, TextureHandle>,
@@ -23,6 +24,8 @@ pub struct TextureCache {
 
 impl TextureCache {
     pub fn new(ctx: egui::Context, astra: &mut Astra) -> Self {
+        // Unit icons are also drawn into exported map images, so keep their pixels around.
+        let unit_icon_images = astra.consume_sprite_atlas("units").unwrap_or_default();
         Self {
             system_cache: Self::build_cache(
                 &ctx,
@@ -51,9 +54,10 @@ impl TextureCache {
             ),
             unit_icon_cache: Self::build_cache(
                 &ctx,
-                astra.consume_sprite_atlas("units").unwrap_or_default(),
+                unit_icon_images.clone(),
                 TextureOptions::NEAREST,
             ),
+            unit_icon_images,
             versus_cache: Self::build_cache(
                 &ctx,
                 astra.consume_sprite_atlas("versus").unwrap_or_default(),
@@ -157,6 +161,16 @@ impl TextureCache {
         self.unit_icon_cache.get(&key).cloned()
     }
 
+    pub fn get_unit_image(
+        &self,
+        unit_icon_id: &str,
+        job_icon_id: &str,
+        weapon_icon_id: &str,
+    ) -> Option<&DynamicImage> {
+        let key = format!("{}_{}_{}", unit_icon_id, job_icon_id, weapon_icon_id);
+        self.unit_icon_images.get(&key)
+    }
+
     pub fn get_versus(&mut self, key: &str) -> Option<TextureHandle> {
         self.versus_cache.get(key).cloned()
     }
@@ -16,3 +16,13 @@ pub fn get_tile_color(tile: &TerrainData, config: &AppConfig) -> Color32 {
             )
         })
 }
+
+/// Mix `tint` into `base`. An `amount` of 0 keeps `base` and 1 gives `tint`.
+pub fn blend_color(base: Color32, tint: Color32, amount: f32) -> Color32 {
+    let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * amount).round() as u8;
+    Color32::from_rgb(
+        mix(base.r(), tint.r()),
+        mix(base.g(), tint.g()),
+        mix(base.b(), tint.b()),
+    )
+}
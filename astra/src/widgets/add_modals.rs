@@ -5,7 +5,7 @@ use egui_modal::Modal;
 use indexmap::IndexMap;
 
 use crate::model::{KeyedListModel, KeyedViewItem, ListModel, ViewItem};
-use crate::{model_drop_down, SheetHandle, SheetRetriever};
+use crate::{model_drop_down, RowTemplates, SchemaRow, SheetHandle, SheetRetriever};
 
 use super::indexed_model_drop_down;
 
@@ -91,7 +91,7 @@ where
             modal.buttons(ui, |ui| {
                 modal.button(ui, "Close");
                 if ui.add_enabled(valid, Button::new("Add")).clicked() {
-                    changed = command.act(model, std::mem::take(&mut self.id));
+                    changed = command.act(model, std::mem::take(&mut self.id), None);
                     modal.close();
                 }
             });
@@ -109,21 +109,22 @@ pub enum AddModalCommand {
 }
 
 impl AddModalCommand {
-    fn act<M, I>(&self, model: &mut M, key: String) -> bool
+    /// Add an item with the given key. New items start from `base` if it's set.
+    fn act<M, I>(&self, model: &mut M, key: String, base: Option<I>) -> bool
     where
         M: KeyedListModel<I>,
         I: KeyedViewItem + Default + Clone,
     {
         match self {
             Self::Add => {
-                let mut item = I::default();
+                let mut item = base.unwrap_or_default();
                 item.set_key(key);
                 model.add(item);
                 true
             }
             Self::Insert(index) => {
                 if *index <= model.len() {
-                    let mut item = I::default();
+                    let mut item = base.unwrap_or_default();
                     item.set_key(key);
                     model.insert(*index, item);
                     true
@@ -153,7 +154,7 @@ pub fn keyed_add_modal_content<M, I>(
 ) -> bool
 where
     M: KeyedListModel<I>,
-    I: KeyedViewItem + Default + Clone,
+    I: KeyedViewItem + SchemaRow + Default + Clone,
 {
     let id = Id::new(id_source).with("add_modal");
     if ui.input(|input| input.key_pressed(Key::Escape)) {
@@ -191,11 +192,63 @@ where
             mem.data.insert_persisted(id, false);
         });
     }
+
+    // Duplicates already have a starting point, so templates are only offered for new items.
+    let templates = match command {
+        AddModalCommand::Duplicate(_) => vec![],
+        _ => RowTemplates::for_sheet::<I>(),
+    };
+    let template_id = id.with("template");
+    let mut template_name = ui.memory_mut(|mem| {
+        mem.data
+            .get_persisted_mut_or_default::<Option<String>>(template_id)
+            .clone()
+            .filter(|name| templates.iter().any(|template| &template.name == name))
+    });
+    if !templates.is_empty() {
+        ui.horizontal(|ui| {
+            ui.label("Template");
+            ComboBox::from_id_source(template_id)
+                .selected_text(template_name.as_deref().unwrap_or("(Blank)"))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut template_name, None, "(Blank)");
+                    for template in &templates {
+                        ui.selectable_value(
+                            &mut template_name,
+                            Some(template.name.clone()),
+                            &template.name,
+                        );
+                    }
+                });
+            if let Some(name) = &template_name {
+                if ui
+                    .small_button("🗑")
+                    .on_hover_text("Delete this template")
+                    .clicked()
+                {
+                    RowTemplates::remove::<I>(name);
+                    template_name = None;
+                }
+            }
+        });
+    }
+    let base = template_name
+        .as_ref()
+        .and_then(|name| templates.iter().find(|template| &template.name == name))
+        .map(|template| template.build::<I>(&item_id));
+    if let Some(Err(err)) = &base {
+        ui.colored_label(
+            ui.visuals().error_fg_color,
+            format!("Template is out of date: {}", err),
+        );
+    }
+    let valid = valid && !matches!(base, Some(Err(_)));
+
     let is_submitting_input = valid && ui.input(|input| input.key_pressed(Key::Enter));
     modal.buttons(ui, |ui| {
         modal.button(ui, "Close");
         if ui.add_enabled(valid, Button::new("Add")).clicked() || is_submitting_input {
-            changed = command.act(model, item_id.clone());
+            changed = command.act(model, item_id.clone(), base.and_then(|base| base.ok()));
             ui.memory_mut(|mem| {
                 mem.data.insert_persisted(id, true);
             });
@@ -203,7 +256,10 @@ where
             item_id = String::new();
         }
     });
-    ui.memory_mut(|mem| mem.data.insert_persisted(id, item_id));
+    ui.memory_mut(|mem| {
+        mem.data.insert_persisted(id, item_id);
+        mem.data.insert_persisted(template_id, template_name);
+    });
     changed
 }
 
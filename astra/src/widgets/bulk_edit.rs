@@ -0,0 +1,218 @@
+use anyhow::{anyhow, Result};
+use egui::{Button, ComboBox, Grid, TextEdit, Ui};
+use egui_modal::Modal;
+
+use crate::{Expr, RowExpression, SchemaRow, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BulkEditOperation {
+    Set,
+    Add,
+    Multiply,
+    Expression,
+}
+
+impl BulkEditOperation {
+    fn label(&self) -> &'static str {
+        match self {
+            BulkEditOperation::Set => "Set",
+            BulkEditOperation::Add => "Add",
+            BulkEditOperation::Multiply => "Multiply",
+            BulkEditOperation::Expression => "Expression",
+        }
+    }
+}
+
+/// Form for applying a value or formula to many rows at once.
+/// The rows are chosen by the caller and the form only decides what happens to them.
+pub struct BulkEditor {
+    field: usize,
+    operation: BulkEditOperation,
+    value: String,
+    condition: String,
+    expression: String,
+    status: Option<Result<String, String>>,
+}
+
+impl BulkEditor {
+    pub fn new() -> Self {
+        Self {
+            field: 0,
+            operation: BulkEditOperation::Set,
+            value: String::new(),
+            condition: String::new(),
+            expression: String::new(),
+            status: None,
+        }
+    }
+
+    /// Forget the result of the last edit. Call this when opening the form for new rows.
+    pub fn reset_status(&mut self) {
+        self.status = None;
+    }
+
+    fn build<I: SchemaRow>(&self) -> Result<RowExpression> {
+        if self.operation == BulkEditOperation::Expression {
+            return RowExpression::parse(&self.expression, I::FIELDS);
+        }
+
+        let target = I::FIELDS
+            .iter()
+            .filter(|field| !field.id && !field.public_array)
+            .nth(self.field)
+            .ok_or_else(|| anyhow!("no field selected"))?;
+        let value = match self.operation {
+            BulkEditOperation::Set => Expr::Literal(Value::Text(self.value.clone())),
+            BulkEditOperation::Add => {
+                Expr::parse(&format!("{} + ({})", target.ident, self.value), I::FIELDS)?
+            }
+            BulkEditOperation::Multiply => {
+                Expr::parse(&format!("{} * ({})", target.ident, self.value), I::FIELDS)?
+            }
+            BulkEditOperation::Expression => unreachable!(),
+        };
+        let condition = if self.condition.trim().is_empty() {
+            None
+        } else {
+            Some(Expr::parse(&self.condition, I::FIELDS)?)
+        };
+        Ok(RowExpression::new(target, value, condition))
+    }
+
+    /// Draw the form inside a modal.
+    /// Returns the expression to apply when the user confirms.
+    pub fn show<I: SchemaRow>(
+        &mut self,
+        modal: &Modal,
+        ui: &mut Ui,
+        target_count: usize,
+    ) -> Option<RowExpression> {
+        let mut result = None;
+        modal.title(ui, "Bulk Edit");
+        modal.frame(ui, |ui| {
+            ui.label(format!("Applies to {} row(s).", target_count));
+            Grid::new("bulk_edit_grid").num_columns(2).show(ui, |ui| {
+                ui.label("Operation");
+                ComboBox::from_id_source("bulk_edit_operation")
+                    .selected_text(self.operation.label())
+                    .show_ui(ui, |ui| {
+                        for operation in [
+                            BulkEditOperation::Set,
+                            BulkEditOperation::Add,
+                            BulkEditOperation::Multiply,
+                            BulkEditOperation::Expression,
+                        ] {
+                            ui.selectable_value(&mut self.operation, operation, operation.label());
+                        }
+                    });
+                ui.end_row();
+
+                if self.operation == BulkEditOperation::Expression {
+                    ui.label("Expression");
+                    ui.add(
+                        TextEdit::singleline(&mut self.expression)
+                            .hint_text("base_str = base_str + 2 where rank == 1")
+                            .desired_width(300.),
+                    );
+                    ui.end_row();
+                } else {
+                    let fields: Vec<_> = I::FIELDS
+                        .iter()
+                        .filter(|field| !field.id && !field.public_array)
+                        .collect();
+                    ui.label("Field");
+                    ComboBox::from_id_source("bulk_edit_field")
+                        .selected_text(fields.get(self.field).map(|f| f.ident).unwrap_or_default())
+                        .show_ui(ui, |ui| {
+                            for (i, field) in fields.iter().enumerate() {
+                                ui.selectable_value(&mut self.field, i, field.ident)
+                                    .on_hover_text(format!("{} ({})", field.key, field.ty));
+                            }
+                        });
+                    ui.end_row();
+
+                    ui.label("Value");
+                    ui.add(TextEdit::singleline(&mut self.value).desired_width(300.));
+                    ui.end_row();
+
+                    ui.label("Where");
+                    ui.add(
+                        TextEdit::singleline(&mut self.condition)
+                            .hint_text("optional, ex. rank == 1")
+                            .desired_width(300.),
+                    );
+                    ui.end_row();
+                }
+            });
+
+            match &self.status {
+                Some(Ok(message)) => {
+                    ui.label(message);
+                }
+                Some(Err(message)) => {
+                    ui.colored_label(ui.visuals().error_fg_color, message);
+                }
+                None => {}
+            }
+        });
+        modal.buttons(ui, |ui| {
+            modal.button(ui, "Close");
+            if ui
+                .add_enabled(target_count > 0, Button::new("Apply"))
+                .clicked()
+            {
+                match self.build::<I>() {
+                    Ok(expression) => result = Some(expression),
+                    Err(err) => self.status = Some(Err(err.to_string())),
+                }
+            }
+        });
+        result
+    }
+
+    /// Apply an expression to every targeted row and record the outcome for display.
+    /// `row_mut` resolves a target to its row. Returns true if any row changed.
+    pub fn apply<M, T, I>(
+        &mut self,
+        expression: &RowExpression,
+        data: &mut M,
+        targets: impl IntoIterator<Item = T>,
+        row_mut: impl for<'a> Fn(&'a mut M, &T) -> Option<&'a mut I>,
+    ) -> bool
+    where
+        I: SchemaRow,
+    {
+        let mut updated = 0;
+        let mut errors = vec![];
+        for target in targets {
+            let Some(row) = row_mut(data, &target) else {
+                continue;
+            };
+            match expression.apply(row) {
+                Ok(Some(new_row)) => {
+                    *row = new_row;
+                    updated += 1;
+                }
+                Ok(None) => {}
+                Err(err) => errors.push(err.to_string()),
+            }
+        }
+        self.status = Some(if errors.is_empty() {
+            Ok(format!("Updated {} row(s).", updated))
+        } else {
+            Err(format!(
+                "Updated {} row(s). {} failed, ex. {}",
+                updated,
+                errors.len(),
+                errors[0]
+            ))
+        });
+        updated > 0
+    }
+}
+
+impl Default for BulkEditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,244 @@
+use egui::{Align2, Key, Label, RichText, ScrollArea, TextEdit, Window};
+use indexmap::IndexMap;
+
+use crate::{EditorState, Screens, SheetHandle, SheetRetriever, ViewItem};
+
+const MAX_RESULTS: usize = 50;
+
+/// Where the palette should take the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteTarget {
+    Screen(Screens),
+    Row(Screens, usize),
+}
+
+struct PaletteEntry {
+    text: String,
+    key: String,
+    category: &'static str,
+    target: PaletteTarget,
+}
+
+/// Score how well `query` matches `text` as a case insensitive subsequence. Higher is better.
+/// Runs of consecutive characters and matches at the start of words score extra.
+fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let mut query = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .peekable();
+    let mut score = 0;
+    let mut prev_char = None;
+    let mut prev_matched = false;
+    for c in text.chars().flat_map(char::to_lowercase) {
+        let Some(&target) = query.peek() else {
+            break;
+        };
+        if c == target {
+            score += 1;
+            if prev_matched {
+                score += 5;
+            }
+            if !prev_char.is_some_and(|prev: char| prev.is_alphanumeric()) {
+                score += 3;
+            }
+            query.next();
+            prev_matched = true;
+        } else {
+            prev_matched = false;
+        }
+        prev_char = Some(c);
+    }
+    query.peek().is_none().then_some(score)
+}
+
+fn keyed_entries<R, B, I, D>(
+    entries: &mut Vec<PaletteEntry>,
+    sheet: &SheetHandle<R, B, IndexMap<String, I>>,
+    dependencies: &D,
+    category: &'static str,
+    screen: Screens,
+) where
+    R: SheetRetriever<B, IndexMap<String, I>>,
+    I: ViewItem<Dependencies = D>,
+{
+    sheet.read(|data| {
+        for (index, (key, item)) in data.iter().enumerate() {
+            entries.push(PaletteEntry {
+                text: item.text(dependencies).into_owned(),
+                key: key.clone(),
+                category,
+                target: PaletteTarget::Row(screen, index),
+            });
+        }
+    });
+}
+
+/// Fuzzy search over every screen and the rows of every keyed sheet with its own editor.
+#[derive(Default)]
+pub struct CommandPalette {
+    open: bool,
+    query: String,
+    entries: Vec<PaletteEntry>,
+    results: Vec<usize>,
+    selected: usize,
+}
+
+impl CommandPalette {
+    /// Open the palette with a fresh copy of every entry.
+    pub fn open(&mut self, state: &EditorState) {
+        let mut entries: Vec<_> = (0..45)
+            .filter_map(Screens::from_tab_index)
+            .map(|screen| PaletteEntry {
+                text: screen.label().to_string(),
+                key: String::new(),
+                category: "Screen",
+                target: PaletteTarget::Screen(screen),
+            })
+            .collect();
+        keyed_entries(
+            &mut entries,
+            &state.person,
+            state,
+            "Character",
+            Screens::Person,
+        );
+        keyed_entries(&mut entries, &state.job, state, "Class", Screens::Job);
+        keyed_entries(&mut entries, &state.item, state, "Item", Screens::Item);
+        keyed_entries(&mut entries, &state.skill, state, "Skill", Screens::Skill);
+        keyed_entries(&mut entries, &state.god, state, "God", Screens::God);
+        keyed_entries(
+            &mut entries,
+            &state.chapter,
+            state,
+            "Chapter",
+            Screens::Chapter,
+        );
+        keyed_entries(
+            &mut entries,
+            &state.accessory,
+            state,
+            "Accessory",
+            Screens::Accessory,
+        );
+        keyed_entries(
+            &mut entries,
+            &state.anim_set,
+            &(),
+            "Anim Set",
+            Screens::AnimSet,
+        );
+        keyed_entries(
+            &mut entries,
+            &state.terrain,
+            state,
+            "Terrain",
+            Screens::Terrain,
+        );
+        self.entries = entries;
+        self.query.clear();
+        self.search();
+        self.open = true;
+    }
+
+    fn search(&mut self) {
+        let mut scored: Vec<_> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                let score = fuzzy_score(&self.query, &entry.text)
+                    .max(fuzzy_score(&self.query, &entry.key))?;
+                Some((score, entry.text.len(), i))
+            })
+            .collect();
+        // An empty query lists everything in order, starting with the screens.
+        if !self.query.trim().is_empty() {
+            // Shorter text wins ties since more of it was matched.
+            scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        }
+        self.results = scored
+            .into_iter()
+            .take(MAX_RESULTS)
+            .map(|(_, _, i)| i)
+            .collect();
+        self.selected = 0;
+    }
+
+    /// Show the palette if it's open. Returns the target if the user picked an entry.
+    pub fn show(&mut self, ctx: &egui::Context) -> Option<PaletteTarget> {
+        if !self.open {
+            return None;
+        }
+
+        let (escape, enter, up, down) = ctx.input_mut(|input| {
+            (
+                input.consume_key(Default::default(), Key::Escape),
+                input.consume_key(Default::default(), Key::Enter),
+                input.consume_key(Default::default(), Key::ArrowUp),
+                input.consume_key(Default::default(), Key::ArrowDown),
+            )
+        });
+        if escape {
+            self.open = false;
+            return None;
+        }
+        if up {
+            self.selected = self.selected.saturating_sub(1);
+        }
+        if down && self.selected + 1 < self.results.len() {
+            self.selected += 1;
+        }
+
+        let mut picked = enter.then_some(self.selected);
+        let response = Window::new("command_palette")
+            .title_bar(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_TOP, [0., 60.])
+            .fixed_size([500., 0.])
+            .show(ctx, |ui| {
+                let search = ui.add(
+                    TextEdit::singleline(&mut self.query)
+                        .hint_text("Go to a screen, character, class, item, skill…")
+                        .desired_width(f32::INFINITY),
+                );
+                search.request_focus();
+                if search.changed() {
+                    self.search();
+                }
+                ui.separator();
+                if self.results.is_empty() {
+                    ui.label("No matches.");
+                }
+                ScrollArea::vertical().max_height(400.).show(ui, |ui| {
+                    for (i, entry) in self.results.iter().map(|i| &self.entries[*i]).enumerate() {
+                        let selected = i == self.selected;
+                        let response = ui
+                            .horizontal(|ui| {
+                                let response = ui.selectable_label(selected, &entry.text);
+                                ui.add(Label::new(RichText::new(entry.category).weak()));
+                                response
+                            })
+                            .inner;
+                        if selected && (up || down) {
+                            response.scroll_to_me(None);
+                        }
+                        if response.clicked() {
+                            picked = Some(i);
+                        }
+                    }
+                });
+            });
+
+        if response.is_some_and(|response| response.response.clicked_elsewhere()) {
+            self.open = false;
+        }
+        let target = picked
+            .and_then(|i| self.results.get(i))
+            .map(|i| self.entries[*i].target);
+        if target.is_some() {
+            self.open = false;
+        }
+        target
+    }
+}
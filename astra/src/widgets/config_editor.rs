@@ -1,8 +1,8 @@
 use catppuccin_egui::{FRAPPE, LATTE, MACCHIATO, MOCHA};
-use egui::{Grid, Style, Ui};
+use egui::{Button, DragValue, Event, Grid, Id, Key, KeyboardShortcut, Modifiers, Style, Ui};
 use egui_modal::Modal;
 
-use crate::{folder_picker, AppConfig, Theme};
+use crate::{folder_picker, AppConfig, ShortcutAction, Shortcuts, Theme};
 
 pub fn config_editor_modal(ctx: &egui::Context, config: &mut AppConfig) -> Modal {
     let modal = Modal::new(ctx, "config_editor_modal");
@@ -66,5 +66,122 @@ pub fn config_editor(ui: &mut Ui, config: &mut AppConfig) {
         ui.label("Cobalt Path");
         ui.add(folder_picker(&mut config.cobalt_path));
         ui.end_row();
+
+        ui.label("Autosave");
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut config.autosave_enabled, "Every");
+            ui.add_enabled(
+                config.autosave_enabled,
+                DragValue::new(&mut config.autosave_interval_minutes).clamp_range(1..=60),
+            );
+            ui.label("minutes");
+        });
+        ui.end_row();
+    });
+
+    ui.separator();
+    ui.collapsing("Keyboard Shortcuts", |ui| {
+        if shortcut_editor(ui, &mut config.shortcuts) {
+            config.shortcuts.apply();
+        }
+    });
+}
+
+/// Store shortcuts using the platform independent command modifier so they work on every OS.
+fn normalize_modifiers(mut modifiers: Modifiers) -> Modifiers {
+    if modifiers.mac_cmd {
+        modifiers.mac_cmd = false;
+    } else if modifiers.command {
+        modifiers.ctrl = false;
+    }
+    modifiers
+}
+
+/// Edit the shortcut bindings. Click a binding, then press the new key combination.
+/// Returns true if any binding changed.
+fn shortcut_editor(ui: &mut Ui, shortcuts: &mut Shortcuts) -> bool {
+    let recording_id = Id::new("shortcut_editor_recording");
+    let mut recording: Option<ShortcutAction> = ui.data(|data| data.get_temp(recording_id));
+    let mut changed = false;
+
+    if let Some(action) = recording {
+        let pressed = ui.input_mut(|input| {
+            let pressed = input.events.iter().find_map(|event| match event {
+                Event::Key {
+                    key,
+                    pressed: true,
+                    modifiers,
+                    ..
+                } => Some((*key, *modifiers)),
+                _ => None,
+            });
+            if let Some((key, modifiers)) = pressed {
+                input.consume_key(modifiers, key);
+            }
+            pressed
+        });
+        match pressed {
+            Some((Key::Escape, _)) => recording = None,
+            Some((key, modifiers)) => {
+                let shortcut = KeyboardShortcut::new(normalize_modifiers(modifiers), key);
+                shortcuts.set(action, shortcut);
+                recording = None;
+                changed = true;
+            }
+            None => {}
+        }
+    }
+
+    Grid::new("shortcut_editor")
+        .num_columns(3)
+        .striped(true)
+        .show(ui, |ui| {
+            for action in ShortcutAction::ALL {
+                ui.label(action.label());
+                let text = if recording == Some(action) {
+                    "Press a shortcut…".to_string()
+                } else {
+                    ui.ctx().format_shortcut(&shortcuts.get(action))
+                };
+                if ui
+                    .selectable_label(recording == Some(action), text)
+                    .on_hover_text("Click, then press the new shortcut. Escape cancels.")
+                    .clicked()
+                {
+                    recording = Some(action);
+                }
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(!shortcuts.is_default(action), Button::new("Reset"))
+                        .clicked()
+                    {
+                        shortcuts.reset(action);
+                        changed = true;
+                    }
+                    let conflicts: Vec<_> = shortcuts
+                        .conflicts(action)
+                        .into_iter()
+                        .map(|other| other.label())
+                        .collect();
+                    if !conflicts.is_empty() {
+                        ui.colored_label(
+                            ui.visuals().error_fg_color,
+                            format!("Also used by {}", conflicts.join(", ")),
+                        );
+                    }
+                });
+                ui.end_row();
+            }
+        });
+    if ui.button("Reset All").clicked() {
+        *shortcuts = Shortcuts::default();
+        recording = None;
+        changed = true;
+    }
+
+    ui.data_mut(|data| match recording {
+        Some(action) => data.insert_temp(recording_id, action),
+        None => data.remove::<ShortcutAction>(recording_id),
     });
+    changed
 }
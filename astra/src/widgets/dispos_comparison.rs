@@ -0,0 +1,223 @@
+use astra_formats::TerrainData;
+use astra_types::Spawn;
+use egui::{vec2, Align2, Color32, FontId, Image, Rect, ScrollArea, Sense, Stroke, Ui};
+use indexmap::IndexMap;
+
+use crate::editors::Difficulty;
+use crate::util::get_tile_color;
+use crate::widgets::{in_difficulty, spawn_level};
+use crate::{
+    spawn_position, AppConfig, CoordinateKind, DecorationKind, EditorState, SpawnRef,
+    TerrainLayout, ViewItem,
+};
+
+const TILE_SIZE: f32 = 24.;
+const PARTIAL_TINT: Color32 = Color32::from_rgb(255, 150, 30);
+const LEVEL_UP_COLOR: Color32 = Color32::from_rgb(255, 90, 90);
+const LEVEL_DOWN_COLOR: Color32 = Color32::from_rgb(90, 220, 120);
+
+const DIFFICULTIES: [(Difficulty, &str); 3] = [
+    (Difficulty::Normal, "Normal"),
+    (Difficulty::Hard, "Hard"),
+    (Difficulty::Lunatic, "Lunatic"),
+];
+
+pub struct DisposComparisonResult {
+    pub hovered_spawn: Option<String>,
+}
+
+fn on_every_difficulty(spawn: &Spawn) -> bool {
+    DIFFICULTIES
+        .iter()
+        .all(|(difficulty, _)| in_difficulty(spawn, *difficulty))
+}
+
+/// How the spawn's level changes from the previous difficulty, if it's on both.
+fn level_delta(spawn: &Spawn, difficulty_index: usize) -> Option<i32> {
+    let previous = DIFFICULTIES.get(difficulty_index.checked_sub(1)?)?.0;
+    let current = DIFFICULTIES[difficulty_index].0;
+    (in_difficulty(spawn, previous) && in_difficulty(spawn, current))
+        .then(|| spawn_level(spawn, current) as i32 - spawn_level(spawn, previous) as i32)
+}
+
+fn spawn_summary(spawn: &Spawn, state: &EditorState) -> String {
+    let levels: Vec<_> = DIFFICULTIES
+        .iter()
+        .map(|(difficulty, label)| {
+            if in_difficulty(spawn, *difficulty) {
+                format!("{}: Lv {}", label, spawn_level(spawn, *difficulty))
+            } else {
+                format!("{}: —", label)
+            }
+        })
+        .collect();
+    format!("{}\n{}", spawn.text(state), levels.join(" | "))
+}
+
+/// Show the chapter's spawns on Normal, Hard and Lunatic as mini maps next to each other.
+/// Spawns that aren't on every difficulty are outlined, and the number on a spawn is
+/// its level change from the previous difficulty.
+pub fn dispos_comparison(
+    ui: &mut Ui,
+    terrain: &TerrainData,
+    state: &EditorState,
+    dispos: &IndexMap<String, Vec<Spawn>>,
+    selected_spawn: &mut Option<SpawnRef>,
+    coordinate_kind: CoordinateKind,
+    config: &AppConfig,
+) -> DisposComparisonResult {
+    let layout = match TerrainLayout::of(terrain) {
+        Ok(layout) => layout,
+        Err(err) => {
+            ui.centered_and_justified(|ui| {
+                ui.colored_label(ui.visuals().error_fg_color, format!("Bad terrain: {}", err));
+            });
+            return DisposComparisonResult {
+                hovered_spawn: None,
+            };
+        }
+    };
+    let (width, height) = (
+        terrain.width.max(0) as usize,
+        terrain.height.max(0) as usize,
+    );
+    let tile_colors: Vec<_> = state.terrain.read(|data| {
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                layout
+                    .index(x, y)
+                    .and_then(|index| terrain.terrains.get(index))
+                    .and_then(|tid| data.get(tid.as_str()))
+                    .map(|tile| get_tile_color(tile, config))
+                    .unwrap_or(Color32::from_gray(0))
+            })
+            .collect()
+    });
+    let spawns: Vec<(SpawnRef, &Spawn, (usize, usize))> = dispos
+        .iter()
+        .flat_map(|(group, spawns)| {
+            spawns
+                .iter()
+                .enumerate()
+                .map(move |(index, spawn)| ((group.clone(), index), spawn))
+        })
+        .filter_map(|(spawn_ref, spawn)| {
+            let (x, y) = spawn_position(spawn, coordinate_kind);
+            ((0..width as i32).contains(&x) && (0..height as i32).contains(&y)).then_some((
+                spawn_ref,
+                spawn,
+                (x as usize, y as usize),
+            ))
+        })
+        .collect();
+
+    let mut hovered_spawn = None;
+    ScrollArea::both()
+        .id_source("dispos_comparison_scroll")
+        .show(ui, |ui| {
+            ui.horizontal_top(|ui| {
+                for (difficulty_index, (difficulty, label)) in DIFFICULTIES.iter().enumerate() {
+                    let shown: Vec<_> = spawns
+                        .iter()
+                        .filter(|(_, spawn, _)| in_difficulty(spawn, *difficulty))
+                        .collect();
+                    ui.vertical(|ui| {
+                        ui.heading(*label);
+                        let partial = shown
+                            .iter()
+                            .filter(|(_, spawn, _)| !on_every_difficulty(spawn))
+                            .count();
+                        let average = if shown.is_empty() {
+                            0.
+                        } else {
+                            shown
+                                .iter()
+                                .map(|(_, spawn, _)| spawn_level(spawn, *difficulty) as f32)
+                                .sum::<f32>()
+                                / shown.len() as f32
+                        };
+                        ui.label(format!(
+                            "{} spawns, average Lv {:.1}, {} not on every difficulty",
+                            shown.len(),
+                            average,
+                            partial
+                        ));
+
+                        let (rect, response) = ui.allocate_exact_size(
+                            vec2(width as f32, height as f32) * TILE_SIZE,
+                            Sense::click(),
+                        );
+                        let tile_rect = |(x, y): (usize, usize)| {
+                            Rect::from_min_size(
+                                rect.min
+                                    + vec2(x as f32, (height - 1 - y) as f32) * TILE_SIZE,
+                                vec2(TILE_SIZE, TILE_SIZE),
+                            )
+                        };
+                        let painter = ui.painter_at(rect);
+                        for (i, color) in tile_colors.iter().enumerate() {
+                            let tile = tile_rect((i % width, i / width));
+                            painter.rect_filled(tile.shrink(0.5), 0., *color);
+                        }
+                        for (spawn_ref, spawn, position) in &shown {
+                            let tile = tile_rect(*position);
+                            if let Some((sprite, _)) =
+                                spawn.decoration(state, DecorationKind::Other("spawn_grid"))
+                            {
+                                Image::new(&sprite).paint_at(ui, tile);
+                            }
+                            if Some(spawn_ref) == selected_spawn.as_ref() {
+                                painter.rect_stroke(
+                                    tile,
+                                    0.,
+                                    ui.visuals().widgets.active.fg_stroke,
+                                );
+                            } else if !on_every_difficulty(spawn) {
+                                painter.rect_stroke(tile, 0., Stroke::new(2., PARTIAL_TINT));
+                            }
+                            match level_delta(spawn, difficulty_index) {
+                                Some(delta) if delta != 0 => {
+                                    painter.text(
+                                        tile.right_bottom(),
+                                        Align2::RIGHT_BOTTOM,
+                                        format!("{:+}", delta),
+                                        FontId::proportional(10.),
+                                        if delta > 0 {
+                                            LEVEL_UP_COLOR
+                                        } else {
+                                            LEVEL_DOWN_COLOR
+                                        },
+                                    );
+                                }
+                                _ => {}
+                            }
+                        }
+
+                        let pointer_spawn = response.hover_pos().and_then(|pos| {
+                            let x = ((pos.x - rect.min.x) / TILE_SIZE) as usize;
+                            let row = ((pos.y - rect.min.y) / TILE_SIZE) as usize;
+                            let y = height.checked_sub(row + 1)?;
+                            shown
+                                .iter()
+                                .rev()
+                                .find(|(_, _, position)| *position == (x, y))
+                        });
+                        if let Some((spawn_ref, spawn, _)) = pointer_spawn {
+                            let summary = spawn_summary(spawn, state);
+                            if response.clicked() || response.secondary_clicked() {
+                                *selected_spawn = Some(spawn_ref.clone());
+                            }
+                            response.on_hover_text(summary.clone());
+                            hovered_spawn = Some(summary.replace('\n', " - "));
+                        }
+                    });
+                    ui.add_space(8.);
+                }
+            });
+            ui.label(
+                "Outlined spawns aren't on every difficulty. Numbers are level changes from the previous difficulty.",
+            );
+        });
+    DisposComparisonResult { hovered_spawn }
+}
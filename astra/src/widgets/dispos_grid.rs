@@ -1,15 +1,41 @@
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 use astra_formats::TerrainData;
 use astra_types::Spawn;
-use egui::{Button, Color32, Grid, ScrollArea, TextureHandle, Ui, Vec2};
+use egui::{
+    Button, Color32, Grid, Key, PointerButton, ScrollArea, Stroke, TextureHandle, Ui, Vec2,
+};
 use indexmap::IndexMap;
 use itertools::Itertools;
 
-use crate::editors::Difficulty;
-use crate::util::get_tile_color;
-use crate::{AppConfig, CoordinateKind, DecorationKind, EditorState, ViewItem};
+use crate::editors::{Difficulty, DisposOverlay};
+use crate::util::{blend_color, get_tile_color};
+use crate::{
+    move_spawns, set_spawn_position, spawn_position, AppConfig, CoordinateKind, DecorationKind,
+    EditorState, Formation, MoveCosts, RangeProfile, SpawnRef, TerrainLayout, ThreatMap, UnitRange,
+    ViewItem,
+};
+
+const MOVE_TINT: Color32 = Color32::from_rgb(40, 90, 230);
+const ATTACK_TINT: Color32 = Color32::from_rgb(220, 40, 40);
+
+const GROUP_COLORS: &[Color32] = &[
+    Color32::from_rgb(255, 200, 40),
+    Color32::from_rgb(60, 220, 255),
+    Color32::from_rgb(255, 90, 200),
+    Color32::from_rgb(120, 255, 90),
+    Color32::from_rgb(255, 140, 60),
+    Color32::from_rgb(170, 130, 255),
+    Color32::from_rgb(255, 255, 255),
+    Color32::from_rgb(40, 160, 120),
+];
+
+/// The color used for a dispos group's paths, by the group's index in the dispos.
+pub fn group_color(group_index: usize) -> Color32 {
+    GROUP_COLORS[group_index % GROUP_COLORS.len()]
+}
 
 struct SpawnData<'a> {
     group: &'a str,
@@ -21,28 +47,207 @@ pub struct DisposGridResult {
     pub changed: bool,
     pub hovered_tile: Option<String>,
     pub hovered_spawn: Option<String>,
+    pub hovered_threat: Option<String>,
 }
 
-fn get_position(spawn: &Spawn, coordinate_kind: CoordinateKind) -> (usize, usize) {
-    match coordinate_kind {
-        CoordinateKind::Dispos => (spawn.dispos_x as usize, spawn.dispos_y as usize),
-        CoordinateKind::Appear => (spawn.appear_x as usize, spawn.appear_y as usize),
-    }
+/// Selection and pointer state the dispos grid keeps between frames.
+#[derive(Default)]
+pub struct DisposGridState {
+    /// Spawns selected along with the primary selection so they can be moved together.
+    /// Only used while it contains the primary selection.
+    pub selection: Vec<SpawnRef>,
+    /// A formation to place with the next left click.
+    pub pending_paste: Option<Formation>,
+    /// The button held down over the grid and the tile it was pressed on.
+    press: Option<(PointerButton, (usize, usize))>,
+    range_cache: Option<(OverlayKey, Option<Arc<UnitRange>>)>,
+    threat_cache: Option<(OverlayKey, Arc<ThreatMap>)>,
 }
 
-fn set_position(spawn: &mut Spawn, coordinate_kind: CoordinateKind, row: usize, col: usize) {
-    match coordinate_kind {
-        CoordinateKind::Dispos => {
-            spawn.dispos_x = col as i8;
-            spawn.dispos_y = row as i8;
+impl DisposGridState {
+    /// The spawns that grid actions apply to.
+    pub fn selected(&self, primary: &Option<SpawnRef>) -> Vec<SpawnRef> {
+        match primary {
+            Some(primary) if self.selection.contains(primary) => self.selection.clone(),
+            Some(primary) => vec![primary.clone()],
+            None => vec![],
         }
-        CoordinateKind::Appear => {
-            spawn.appear_x = col as i8;
-            spawn.appear_y = row as i8;
+    }
+}
+
+/// Names and revision numbers of the dispos and terrain shown in [dispos_grid], for caching overlays.
+/// Revision numbers start over for every file, so the names tell chapters apart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverlaySource {
+    pub dispos: String,
+    pub dispos_revision: usize,
+    pub terrain: String,
+    pub terrain_revision: usize,
+}
+
+/// Everything an overlay is computed from. Overlays are only recomputed when this changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct OverlayKey {
+    source: OverlaySource,
+    /// Revisions of the person, job, item, tile and move cost sheets.
+    sheet_revisions: [usize; 5],
+    coordinate_kind: CoordinateKind,
+    difficulty: Difficulty,
+    spawn: Option<SpawnRef>,
+}
+
+impl OverlayKey {
+    fn new(
+        source: OverlaySource,
+        state: &EditorState,
+        coordinate_kind: CoordinateKind,
+        difficulty: Difficulty,
+        spawn: Option<SpawnRef>,
+    ) -> Self {
+        Self {
+            source,
+            sheet_revisions: [
+                state.person.revision_number(),
+                state.job.revision_number(),
+                state.item.revision_number(),
+                state.terrain.revision_number(),
+                state.terrain_cost.revision_number(),
+            ],
+            coordinate_kind,
+            difficulty,
+            spawn,
         }
     }
 }
 
+/// Reuse the cached value if it was computed from the same data.
+fn cached<T: Clone>(
+    cache: &mut Option<(OverlayKey, T)>,
+    key: OverlayKey,
+    compute: impl FnOnce() -> T,
+) -> T {
+    match cache {
+        Some((cached_key, value)) if *cached_key == key => value.clone(),
+        _ => cache.insert((key, compute())).1.clone(),
+    }
+}
+
+fn get_position(spawn: &Spawn, coordinate_kind: CoordinateKind) -> (usize, usize) {
+    let (x, y) = spawn_position(spawn, coordinate_kind);
+    (x as usize, y as usize)
+}
+
+pub fn in_difficulty(spawn: &Spawn, difficulty: Difficulty) -> bool {
+    match difficulty {
+        Difficulty::All => true,
+        Difficulty::Normal => spawn.flag & 1 != 0,
+        Difficulty::Hard => spawn.flag & 2 != 0,
+        Difficulty::Lunatic => spawn.flag & 4 != 0,
+    }
+}
+
+/// The spawn's level on a difficulty. All difficulties use the highest one.
+pub fn spawn_level(spawn: &Spawn, difficulty: Difficulty) -> u8 {
+    match difficulty {
+        Difficulty::All => spawn.level_n.max(spawn.level_h).max(spawn.level_l),
+        Difficulty::Normal => spawn.level_n,
+        Difficulty::Hard => spawn.level_h,
+        Difficulty::Lunatic => spawn.level_l,
+    }
+}
+
+fn spawn_range(
+    terrain: &TerrainData,
+    state: &EditorState,
+    spawn: &Spawn,
+    coordinate_kind: CoordinateKind,
+) -> Option<UnitRange> {
+    let profile = RangeProfile::of(spawn, state)?;
+    Some(state.terrain_cost.read(|costs| {
+        state.terrain.read(|tiles| {
+            UnitRange::compute(
+                terrain,
+                &MoveCosts::new(tiles, costs),
+                &profile,
+                get_position(spawn, coordinate_kind),
+            )
+        })
+    }))
+}
+
+fn enemy_threat(
+    terrain: &TerrainData,
+    state: &EditorState,
+    dispos: &IndexMap<String, Vec<Spawn>>,
+    coordinate_kind: CoordinateKind,
+    difficulty: Difficulty,
+) -> ThreatMap {
+    let units: Vec<_> = dispos
+        .values()
+        .flatten()
+        .filter(|spawn| spawn.force == 1 && in_difficulty(spawn, difficulty))
+        .filter_map(|spawn| {
+            Some((
+                get_position(spawn, coordinate_kind),
+                RangeProfile::of(spawn, state)?,
+                spawn_level(spawn, difficulty),
+            ))
+        })
+        .collect();
+    state.terrain_cost.read(|costs| {
+        state
+            .terrain
+            .read(|tiles| ThreatMap::compute(terrain, &MoveCosts::new(tiles, costs), units))
+    })
+}
+
+/// A spawn that appears somewhere other than where it's placed, such as a reinforcement.
+struct SpawnPath {
+    spawn: SpawnRef,
+    group_index: usize,
+    appear: (usize, usize),
+    dispos: (usize, usize),
+}
+
+fn spawn_paths(dispos: &IndexMap<String, Vec<Spawn>>, difficulty: Difficulty) -> Vec<SpawnPath> {
+    dispos
+        .iter()
+        .enumerate()
+        .flat_map(|(group_index, (group, spawns))| {
+            spawns
+                .iter()
+                .enumerate()
+                .filter(|(_, spawn)| in_difficulty(spawn, difficulty))
+                .map(move |(index, spawn)| SpawnPath {
+                    spawn: (group.clone(), index),
+                    group_index,
+                    appear: get_position(spawn, CoordinateKind::Appear),
+                    dispos: get_position(spawn, CoordinateKind::Dispos),
+                })
+        })
+        .filter(|path| path.appear != path.dispos)
+        .collect()
+}
+
+/// The path end on a tile, preferring the primary selection when several share it.
+fn path_end<'a>(
+    paths: &'a [SpawnPath],
+    tile: (usize, usize),
+    primary: &Option<SpawnRef>,
+) -> Option<(&'a SpawnRef, CoordinateKind)> {
+    let ends = paths.iter().flat_map(|path| {
+        [
+            (path.appear == tile).then_some((&path.spawn, CoordinateKind::Appear)),
+            (path.dispos == tile).then_some((&path.spawn, CoordinateKind::Dispos)),
+        ]
+        .into_iter()
+        .flatten()
+    });
+    ends.clone()
+        .find(|(spawn, _)| Some(*spawn) == primary.as_ref())
+        .or_else(|| ends.last())
+}
+
 struct SpawnDataMap<'a> {
     spawns_by_position: HashMap<(usize, usize), Vec<SpawnData<'a>>>,
 }
@@ -60,12 +265,7 @@ impl<'a> SpawnDataMap<'a> {
                     group
                         .iter_mut()
                         .enumerate()
-                        .filter(|(_, spawn)| match difficulty {
-                            Difficulty::All => true,
-                            Difficulty::Normal => spawn.flag & 1 != 0,
-                            Difficulty::Hard => spawn.flag & 2 != 0,
-                            Difficulty::Lunatic => spawn.flag & 4 != 0,
-                        })
+                        .filter(|(_, spawn)| in_difficulty(spawn, difficulty))
                         .map(|(index, spawn)| {
                             let position = get_position(spawn, coordinate_kind);
                             (
@@ -100,6 +300,50 @@ impl<'a> SpawnDataMap<'a> {
             .get(&(col, row))
             .and_then(|group| group.last())
     }
+
+    /// Every spawn shown in the rectangle between two tiles.
+    pub fn spawns_between(&self, a: (usize, usize), b: (usize, usize)) -> Vec<SpawnRef> {
+        let (x_range, y_range) = (a.0.min(b.0)..=a.0.max(b.0), a.1.min(b.1)..=a.1.max(b.1));
+        self.spawns_by_position
+            .iter()
+            .filter(|((x, y), _)| x_range.contains(x) && y_range.contains(y))
+            .flat_map(|(_, spawns)| spawns)
+            .map(|spawn_data| (spawn_data.group.to_string(), spawn_data.index))
+            .sorted()
+            .collect()
+    }
+}
+
+/// Apply a selection click or rubber band to the grid state and primary selection.
+fn select_spawns(
+    grid_state: &mut DisposGridState,
+    selected_spawn: &mut Option<SpawnRef>,
+    spawns: Vec<SpawnRef>,
+    toggle: bool,
+) {
+    if toggle {
+        if grid_state.selection.is_empty() {
+            grid_state.selection.extend(selected_spawn.clone());
+        }
+        for spawn in spawns {
+            match grid_state.selection.iter().position(|s| *s == spawn) {
+                Some(index) => {
+                    grid_state.selection.remove(index);
+                }
+                None => grid_state.selection.push(spawn),
+            }
+        }
+    } else {
+        grid_state.selection = spawns;
+    }
+    if !selected_spawn
+        .as_ref()
+        .is_some_and(|primary| grid_state.selection.contains(primary))
+    {
+        if let Some(first) = grid_state.selection.first() {
+            *selected_spawn = Some(first.clone());
+        }
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -108,44 +352,130 @@ pub fn dispos_grid(
     terrain: &TerrainData,
     state: &EditorState,
     dispos: &mut IndexMap<String, Vec<Spawn>>,
-    selected_spawn: &mut Option<(String, usize)>,
+    source: OverlaySource,
+    selected_spawn: &mut Option<SpawnRef>,
+    grid_state: &mut DisposGridState,
     coordinate_kind: CoordinateKind,
     difficulty: Difficulty,
+    overlay: DisposOverlay,
     config: &AppConfig,
 ) -> DisposGridResult {
-    let selected_spawn_position = selected_spawn
+    let layout = match TerrainLayout::of(terrain) {
+        Ok(layout) => layout,
+        Err(err) => {
+            ui.centered_and_justified(|ui| {
+                ui.colored_label(ui.visuals().error_fg_color, format!("Bad terrain: {}", err));
+            });
+            return DisposGridResult {
+                changed: false,
+                hovered_tile: None,
+                hovered_spawn: None,
+                hovered_threat: None,
+            };
+        }
+    };
+    let selected = selected_spawn
         .as_ref()
-        .and_then(|(group, index)| dispos.get(group).and_then(|group| group.get(*index)))
-        .map(|spawn| get_position(spawn, coordinate_kind));
+        .and_then(|(group, index)| dispos.get(group).and_then(|group| group.get(*index)));
+    let selected_positions: HashSet<_> = grid_state
+        .selected(selected_spawn)
+        .iter()
+        .filter_map(|(group, index)| dispos.get(group).and_then(|group| group.get(*index)))
+        .map(|spawn| get_position(spawn, coordinate_kind))
+        .collect();
+    let primary_position = selected.map(|spawn| get_position(spawn, coordinate_kind));
+    let paths = match overlay {
+        DisposOverlay::AppearPaths => spawn_paths(dispos, difficulty),
+        _ => vec![],
+    };
+    let range = match (overlay, selected) {
+        (DisposOverlay::SelectedRange, Some(spawn)) => {
+            let key = OverlayKey::new(
+                source.clone(),
+                state,
+                coordinate_kind,
+                difficulty,
+                selected_spawn.clone(),
+            );
+            cached(&mut grid_state.range_cache, key, || {
+                spawn_range(terrain, state, spawn, coordinate_kind).map(Arc::new)
+            })
+        }
+        _ => None,
+    };
+    let threat = match overlay {
+        DisposOverlay::EnemyThreat => {
+            let key = OverlayKey::new(source, state, coordinate_kind, difficulty, None);
+            Some(cached(&mut grid_state.threat_cache, key, || {
+                Arc::new(enemy_threat(
+                    terrain,
+                    state,
+                    dispos,
+                    coordinate_kind,
+                    difficulty,
+                ))
+            }))
+        }
+        _ => None,
+    };
     let spawn_data = SpawnDataMap::new(dispos, coordinate_kind, difficulty);
     let mut changed = false;
-    let mut move_pos = None;
+    let mut tile_rects = vec![];
+    let mut grid_clip = ui.clip_rect();
+    let mut pressed_tile = None;
     let mut hovered_tile = None;
     let mut hovered_spawn = None;
+    let mut hovered_threat = None;
     ScrollArea::both()
         .id_source("spawn_grid_scroll")
         .show(ui, |ui| {
             ui.spacing_mut().button_padding = Vec2::ZERO;
             ui.spacing_mut().item_spacing = Vec2::new(1., 1.);
+            grid_clip = ui.clip_rect();
             Grid::new("chapter_spawn_grid").show(ui, |ui| {
                 state.terrain.read(|data| {
                     for row in (0..(terrain.height as usize)).rev() {
                         for col in 0..(terrain.width as usize) {
                             let sprite = spawn_data.get_sprite(state, row, col);
-                            let (tile_name, fill) = terrain
-                                .terrains
-                                .get(row * 32 + col)
+                            let (tile_name, fill) = layout
+                                .index(col, row)
+                                .and_then(|index| terrain.terrains.get(index))
                                 .and_then(|tid| data.get(tid.as_str()))
                                 .map(|tile| (tile.text(state), get_tile_color(tile, config)))
                                 .unwrap_or_else(|| (Cow::Borrowed("???"), Color32::from_gray(0)));
+                            let fill = match &range {
+                                Some(range) if range.reachable.contains(&(col, row)) => {
+                                    blend_color(fill, MOVE_TINT, 0.5)
+                                }
+                                Some(range) if range.attackable.contains(&(col, row)) => {
+                                    blend_color(fill, ATTACK_TINT, 0.5)
+                                }
+                                _ => fill,
+                            };
+                            let tile_threat = threat.as_ref().and_then(|threat| {
+                                threat
+                                    .tiles
+                                    .get(&(col, row))
+                                    .map(|tile| (tile, threat.max_count))
+                            });
+                            let fill = match tile_threat {
+                                Some((tile, max_count)) => blend_color(
+                                    fill,
+                                    ATTACK_TINT,
+                                    0.2 + 0.6 * tile.count as f32 / max_count as f32,
+                                ),
+                                None => fill,
+                            };
 
                             // Put these in a container to please egui's grid.
                             let mut button = Button::new("").rounding(0.).fill(fill);
-                            if Some((col, row)) == selected_spawn_position {
+                            if selected_positions.contains(&(col, row)) {
                                 button = button.stroke(ui.visuals().widgets.active.fg_stroke)
                             }
                             ui.vertical(|ui| {
                                 let response = ui.add_sized([48., 48.], button);
+                                tile_rects
+                                    .push(((col, row), response.rect.intersect(ui.clip_rect())));
                                 if let Some((sprite, _)) = sprite {
                                     ui.allocate_ui_at_rect(response.rect, |ui| {
                                         ui.image(&sprite);
@@ -153,18 +483,22 @@ pub fn dispos_grid(
                                 }
                                 if response.hovered() {
                                     hovered_tile = Some(tile_name.into_owned());
+                                    if let Some((tile, _)) = tile_threat {
+                                        hovered_threat = Some(format!(
+                                            "{} enemies (up to Lv {})",
+                                            tile.count, tile.max_level
+                                        ));
+                                    }
                                     if let Some(spawn_data) = spawn_data.get_spawn(row, col) {
                                         hovered_spawn =
                                             Some(spawn_data.spawn.text(state).into_owned());
                                     }
-                                }
-                                if response.clicked() {
-                                    move_pos = Some((row, col));
-                                } else if response.secondary_clicked() {
-                                    if let Some(spawn_data) = spawn_data.get_spawn(row, col) {
-                                        *selected_spawn =
-                                            Some((spawn_data.group.to_string(), spawn_data.index));
-                                    }
+                                    pressed_tile = ui.input(|input| {
+                                        [PointerButton::Primary, PointerButton::Secondary]
+                                            .into_iter()
+                                            .find(|button| input.pointer.button_pressed(*button))
+                                            .map(|button| (button, (col, row)))
+                                    });
                                 }
                             });
                         }
@@ -173,19 +507,244 @@ pub fn dispos_grid(
                 });
             });
         });
-    if let (Some((row, col)), Some((group, index))) = (move_pos, selected_spawn) {
-        let spawn = dispos
-            .get_mut(group)
-            .and_then(|group| group.get_mut(*index));
-        if let Some(spawn) = spawn {
-            set_position(spawn, coordinate_kind, row, col);
+
+    // Tiles are found by position rather than hover so drags can end on a different tile.
+    let (pointer, released, shift, nudge, escape) = ui.input(|input| {
+        let nudge = [
+            (Key::ArrowLeft, (-1, 0)),
+            (Key::ArrowRight, (1, 0)),
+            (Key::ArrowUp, (0, 1)),
+            (Key::ArrowDown, (0, -1)),
+        ]
+        .into_iter()
+        .find(|(key, _)| input.key_pressed(*key))
+        .map(|(_, offset)| offset);
+        (
+            input.pointer.interact_pos(),
+            [PointerButton::Primary, PointerButton::Secondary]
+                .into_iter()
+                .find(|button| input.pointer.button_released(*button)),
+            input.modifiers.shift,
+            nudge,
+            input.key_pressed(Key::Escape),
+        )
+    });
+    let pointer_tile = pointer.and_then(|pointer| {
+        tile_rects
+            .iter()
+            .find(|(_, rect)| rect.contains(pointer))
+            .map(|(tile, _)| *tile)
+    });
+    if pressed_tile.is_some() {
+        grid_state.press = pressed_tile;
+    }
+    if let (Some((PointerButton::Secondary, start)), Some(end)) = (grid_state.press, pointer_tile) {
+        if start != end {
+            let rect_of = |tile| {
+                tile_rects
+                    .iter()
+                    .find(|(t, _)| *t == tile)
+                    .map(|(_, rect)| *rect)
+            };
+            if let (Some(a), Some(b)) = (rect_of(start), rect_of(end)) {
+                ui.painter().rect_stroke(
+                    a.union(b),
+                    0.,
+                    Stroke::new(2., ui.visuals().selection.stroke.color),
+                );
+            }
+        }
+    }
+
+    let tile_center = |tile| {
+        tile_rects
+            .iter()
+            .find(|(t, _)| *t == tile)
+            .map(|(_, rect)| rect.center())
+    };
+    if !paths.is_empty() {
+        // Follow the pointer with the end being dragged.
+        let dragged = match (grid_state.press, pointer_tile) {
+            (Some((PointerButton::Primary, start)), Some(end)) if start != end => {
+                path_end(&paths, start, selected_spawn).map(|(spawn, kind)| (spawn, kind, end))
+            }
+            _ => None,
+        };
+        let painter = ui.painter().with_clip_rect(grid_clip);
+        for path in &paths {
+            let (mut appear, mut dispos) = (path.appear, path.dispos);
+            match dragged {
+                Some((spawn, CoordinateKind::Appear, end)) if *spawn == path.spawn => appear = end,
+                Some((spawn, CoordinateKind::Dispos, end)) if *spawn == path.spawn => dispos = end,
+                _ => {}
+            }
+            if let (Some(from), Some(to)) = (tile_center(appear), tile_center(dispos)) {
+                let color = group_color(path.group_index);
+                painter.circle_filled(from, 5., color);
+                painter.arrow(from, to - from, Stroke::new(2.5, color));
+            }
+        }
+    }
+
+    let mut moves = vec![];
+    let mut path_moves = vec![];
+    let mut paste_at = None;
+    if let Some(released) = released {
+        if let (Some((button, start)), Some(end)) = (grid_state.press.take(), pointer_tile) {
+            if button != released {
+                // Another button was held down too. Ignore both.
+            } else if button == PointerButton::Secondary {
+                let spawns = if start == end {
+                    spawn_data
+                        .get_spawn(end.1, end.0)
+                        .map(|spawn_data| (spawn_data.group.to_string(), spawn_data.index))
+                        .into_iter()
+                        .collect()
+                } else {
+                    spawn_data.spawns_between(start, end)
+                };
+                if !spawns.is_empty() || !shift {
+                    select_spawns(grid_state, selected_spawn, spawns, shift);
+                }
+            } else if start == end && grid_state.pending_paste.is_some() {
+                paste_at = Some(end);
+            } else if start == end {
+                // Move the selection so the primary spawn lands on the clicked tile.
+                if let Some((x, y)) = primary_position {
+                    moves.push((end.0 as i32 - x as i32, end.1 as i32 - y as i32));
+                }
+            } else if let Some((spawn, kind)) = path_end(&paths, start, selected_spawn) {
+                path_moves.push((spawn.clone(), kind, end));
+            } else if selected_positions.contains(&start) {
+                moves.push((end.0 as i32 - start.0 as i32, end.1 as i32 - start.1 as i32));
+            }
+        }
+    }
+    // Leave the arrow keys to text fields and other widgets when they have focus.
+    if let Some(offset) = nudge.filter(|_| ui.memory(|memory| memory.focus().is_none())) {
+        moves.push(offset);
+    }
+    if escape {
+        grid_state.pending_paste = None;
+    }
+
+    let selection = grid_state.selected(selected_spawn);
+    for offset in moves {
+        changed |= move_spawns(
+            dispos,
+            &selection,
+            coordinate_kind,
+            offset,
+            terrain.width,
+            terrain.height,
+        );
+    }
+    for ((group, index), kind, (x, y)) in path_moves {
+        if let Some(spawn) = dispos
+            .get_mut(&group)
+            .and_then(|group| group.get_mut(index))
+        {
+            set_spawn_position(spawn, kind, (x as i32, y as i32));
             changed = true;
         }
     }
+    if let (Some(at), Some(formation)) = (paste_at, grid_state.pending_paste.take()) {
+        let at = (at.0 as i32, at.1 as i32);
+        match formation.paste(dispos, coordinate_kind, at, terrain.width, terrain.height) {
+            Ok(pasted) => {
+                *selected_spawn = pasted.first().cloned();
+                grid_state.selection = pasted;
+                changed = true;
+            }
+            // Keep waiting for a tile the formation fits on.
+            Err(_) => grid_state.pending_paste = Some(formation),
+        }
+    }
 
     DisposGridResult {
         changed,
         hovered_tile,
         hovered_spawn,
+        hovered_threat,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Threat;
+
+    use super::*;
+
+    fn key(dispos: &str, terrain: &str, spawn: Option<SpawnRef>) -> OverlayKey {
+        OverlayKey {
+            source: OverlaySource {
+                dispos: dispos.to_string(),
+                dispos_revision: 0,
+                terrain: terrain.to_string(),
+                terrain_revision: 0,
+            },
+            sheet_revisions: [0; 5],
+            coordinate_kind: CoordinateKind::Dispos,
+            difficulty: Difficulty::All,
+            spawn,
+        }
+    }
+
+    #[test]
+    fn range_cache_tells_files_apart() {
+        let mut state = DisposGridState::default();
+        let spawn = Some(("Enemy".to_string(), 0));
+        let range = |reachable: &[(usize, usize)]| {
+            Some(Arc::new(UnitRange {
+                reachable: reachable.iter().copied().collect(),
+                attackable: HashSet::new(),
+            }))
+        };
+        let mut compute = |key, reachable| {
+            cached(&mut state.range_cache, key, || range(reachable))
+                .unwrap()
+                .reachable
+                .clone()
+        };
+
+        let main = compute(key("c001", "c001", spawn.clone()), &[(0, 0)]);
+        assert_eq!(main, HashSet::from([(0, 0)]));
+        // Same revisions and spawn, but the encount dispos of the chapter.
+        let encount = compute(key("c001e", "c001", spawn.clone()), &[(1, 1)]);
+        assert_eq!(encount, HashSet::from([(1, 1)]));
+        // Another chapter whose files are also unedited.
+        let other = compute(key("c002", "c002", spawn.clone()), &[(2, 2)]);
+        assert_eq!(other, HashSet::from([(2, 2)]));
+        // Unchanged inputs reuse the cached range.
+        let reused = compute(key("c002", "c002", spawn), &[(3, 3)]);
+        assert_eq!(reused, HashSet::from([(2, 2)]));
+    }
+
+    #[test]
+    fn threat_cache_tells_files_apart() {
+        let mut state = DisposGridState::default();
+        let map = |tile: (usize, usize)| {
+            let threat = Threat {
+                count: 1,
+                max_level: 1,
+            };
+            Arc::new(ThreatMap {
+                tiles: HashMap::from([(tile, threat)]),
+                max_count: 1,
+            })
+        };
+        let mut compute = |key, tile| {
+            cached(&mut state.threat_cache, key, || map(tile))
+                .tiles
+                .keys()
+                .copied()
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(compute(key("c001", "c001", None), (0, 0)), [(0, 0)]);
+        assert_eq!(compute(key("c001e", "c001", None), (1, 1)), [(1, 1)]);
+        // A dispos shown on another terrain, ex. after the chapter's terrain field changed.
+        assert_eq!(compute(key("c001e", "c002", None), (2, 2)), [(2, 2)]);
+        assert_eq!(compute(key("c001e", "c002", None), (3, 3)), [(2, 2)]);
     }
 }
@@ -1,3 +1,4 @@
+use std::collections::{BTreeSet, HashMap};
 use std::marker::PhantomData;
 
 use egui::panel::Side;
@@ -6,13 +7,20 @@ use egui_modal::Modal;
 
 use crate::model::{SheetHandle, SheetRetriever};
 use crate::{
-    blank_slate, list_view, AddModalRenderer, FilterProxyBuilder, ListModel, ViewItem,
-    ADD_SHORTCUT, COPY_TO_SHORTCUT, DELETE_SHORTCUT, DUPLICATE_SHORTCUT, INSERT_SHORTCUT,
-    MOVE_DOWN_SHORTCUT, MOVE_UP_SHORTCUT,
+    blank_slate, list_view, pasted_text, pin_tab, row_state, rows_to_xml, AddModalRenderer,
+    BaselineRow, BulkEditor, EditorState, FilterProxyBuilder, ListModel, PasteRowsForm, RowModel,
+    RowState, RowTab, RowTemplate, RowTemplates, SchemaRow, ShortcutAction, ViewItem, FILTER_HELP,
 };
 
 use super::{list_select_modal, AddModalCommand};
 
+/// The form an editor shows for one of its rows.
+/// Rows pinned to the split view are edited with the same form.
+pub trait RowForm {
+    /// Show the form. Returns true if the row was modified.
+    fn form(ui: &mut Ui, state: &EditorState, row: &mut Self) -> bool;
+}
+
 pub struct ListEditorContent<M, I, D> {
     phantom: PhantomData<I>,
     id_source: &'static str,
@@ -22,12 +30,19 @@ pub struct ListEditorContent<M, I, D> {
     prev_model_revision: Option<usize>,
     add_command: Option<AddModalCommand>,
     copy_index: Option<usize>,
+    marked: BTreeSet<usize>,
+    bulk_editor: BulkEditor,
+    bulk_targets: Vec<usize>,
+    paste_form: PasteRowsForm,
+    template_name: String,
+    row_states: HashMap<usize, RowState>,
+    selection_baseline: Option<BaselineRow>,
 }
 
 impl<M, I, D> ListEditorContent<M, I, D>
 where
-    M: ListModel<I>,
-    I: ViewItem<Dependencies = D> + Default + Clone,
+    M: ListModel<I> + RowModel<I> + Send + Sync + 'static,
+    I: ViewItem<Dependencies = D> + SchemaRow + RowForm + Default + Clone,
 {
     pub fn new(id_source: &'static str) -> Self {
         Self {
@@ -37,6 +52,13 @@ where
             prev_model_revision: None,
             add_command: None,
             copy_index: None,
+            marked: BTreeSet::new(),
+            bulk_editor: BulkEditor::new(),
+            bulk_targets: vec![],
+            paste_form: PasteRowsForm::new(),
+            template_name: String::new(),
+            row_states: HashMap::new(),
+            selection_baseline: None,
             filter_proxy: FilterProxyBuilder::new(),
             phantom: Default::default(),
         }
@@ -66,7 +88,9 @@ where
         model: &SheetHandle<R, B, M>,
         dependencies: &D,
     ) where
-        R: SheetRetriever<B, M>,
+        R: SheetRetriever<B, M> + Clone + Send + 'static,
+        B: Send + Sync + 'static,
+        I: Send + Sync + 'static,
     {
         // TODO: Fix out of bounds selection
         let add_modal = Modal::new(ctx, self.id_source);
@@ -105,6 +129,66 @@ where
             });
         }
 
+        let bulk_modal = Modal::new(ctx, format!("{}_bulk_edit_modal", self.id_source));
+        bulk_modal.show(|ui| {
+            if let Some(expression) =
+                self.bulk_editor
+                    .show::<I>(&bulk_modal, ui, self.bulk_targets.len())
+            {
+                model.write(|data| {
+                    self.bulk_editor.apply(
+                        &expression,
+                        data,
+                        self.bulk_targets.iter().copied(),
+                        |data, index| data.item_mut(*index),
+                    )
+                });
+            }
+        });
+
+        let paste_modal = Modal::new(ctx, format!("{}_paste_modal", self.id_source));
+        paste_modal.show(|ui| {
+            if let Some(rows) =
+                self.paste_form
+                    .show::<I>(&paste_modal, ui, Some("Matching keys:"), |_| {})
+            {
+                let index = self.selection.map(|index| index + 1).unwrap_or(usize::MAX);
+                model.write(|data| self.paste_form.paste_list(data, index, &rows) > 0);
+                self.marked.clear();
+            }
+        });
+
+        let template_modal = Modal::new(ctx, format!("{}_template_modal", self.id_source));
+        template_modal.show(|ui| {
+            template_modal.title(ui, "Save as Template");
+            let exists = RowTemplates::for_sheet::<I>()
+                .iter()
+                .any(|template| template.name == self.template_name);
+            template_modal.frame(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Name");
+                    ui.text_edit_singleline(&mut self.template_name);
+                });
+                if exists {
+                    ui.label("This will replace the template with the same name.");
+                }
+                ui.label(
+                    "The item's ID becomes {key} and values ending with its name, \
+                    like MPID_Foo for PID_Foo, become {name}.",
+                );
+            });
+            template_modal.buttons(ui, |ui| {
+                template_modal.button(ui, "Close");
+                if ui
+                    .add_enabled(!self.template_name.is_empty(), Button::new("Save"))
+                    .clicked()
+                {
+                    self.save_template(model);
+                    template_modal.close();
+                }
+            });
+        });
+
         SidePanel::new(Side::Left, Id::new(self.id_source).with("side_panel"))
             .default_width(300.)
             .show(ctx, |ui| {
@@ -139,6 +223,52 @@ where
                             copy_modal.open();
                             ui.close_menu();
                         }
+                        if ui
+                            .add_enabled(
+                                has_selection || !self.marked.is_empty(),
+                                Button::new("📋 Copy as XML"),
+                            )
+                            .on_hover_text("Copy the selected or marked items to the clipboard")
+                            .clicked()
+                        {
+                            self.copy_xml(ctx, model);
+                            ui.close_menu();
+                        }
+                        if ui
+                            .button("📋 Paste XML…")
+                            .on_hover_text("Paste items below the selection")
+                            .clicked()
+                        {
+                            self.paste_form.open(&paste_modal, None);
+                            ui.close_menu();
+                        }
+                        let has_key = model.read(|data| {
+                            self.selection
+                                .and_then(|index| data.item(index))
+                                .and_then(|item| data.key_of(item))
+                                .is_some()
+                        });
+                        if ui
+                            .add_enabled(has_key, Button::new("💾 Save as Template…"))
+                            .on_hover_text("Offer the item as a starting point when adding items")
+                            .clicked()
+                        {
+                            self.template_name.clear();
+                            template_modal.open();
+                            ui.close_menu();
+                        }
+                        if ui.button("✏ Bulk Edit…").clicked() {
+                            self.bulk_edit(model, dependencies, &bulk_modal);
+                            ui.close_menu();
+                        }
+                        if ui
+                            .add_enabled(has_selection, Button::new("📌 Pin to Split View"))
+                            .on_hover_text("Keep the item open next to other editors")
+                            .clicked()
+                        {
+                            self.pin_item(model, dependencies);
+                            ui.close_menu();
+                        }
                         ui.separator();
                         if ui
                             .add_enabled(has_selection, Button::new("⏶ Move Up"))
@@ -155,6 +285,18 @@ where
                             ui.close_menu();
                         }
                         ui.separator();
+                        let modified = self.selection.map(|index| model.row_state(index))
+                            == Some(RowState::Modified);
+                        if ui
+                            .add_enabled(modified, Button::new("↺ Revert to Loaded"))
+                            .on_hover_text("Restore the item as it was when the project was loaded")
+                            .clicked()
+                        {
+                            if let Some(index) = self.selection {
+                                model.revert_row(index);
+                            }
+                            ui.close_menu();
+                        }
                         if ui
                             .add_enabled(has_selection, Button::new("❎ Delete Item"))
                             .clicked()
@@ -164,10 +306,18 @@ where
                         }
                     });
                     self.filter_proxy.with_filter_expr(|filter| {
-                        ui.add(TextEdit::singleline(filter).desired_width(f32::INFINITY))
-                            .changed()
+                        ui.add(
+                            TextEdit::singleline(filter)
+                                .hint_text("Search, or filter like level>10")
+                                .desired_width(f32::INFINITY),
+                        )
+                        .on_hover_text(FILTER_HELP)
+                        .changed()
                     });
                 });
+                if let Some(error) = self.filter_proxy.query_error() {
+                    ui.colored_label(ui.visuals().error_fg_color, error);
+                }
 
                 let changed = if let Some(revision) = self.prev_model_revision {
                     if revision < model.revision_number() {
@@ -181,6 +331,10 @@ where
                     true
                 };
 
+                if changed {
+                    self.row_states.clear();
+                }
+                let baseline = model.baseline();
                 model.read(|data| {
                     // TODO: Find a better way to make sure the model is initialized.
                     let _ = self.filter_proxy.model(changed, data, dependencies);
@@ -188,43 +342,150 @@ where
                     let mut proxy_index = self
                         .selection
                         .and_then(|source_index| self.filter_proxy.proxy_index(source_index));
+                    let mut marked = self
+                        .marked
+                        .iter()
+                        .filter_map(|source_index| self.filter_proxy.proxy_index(*source_index))
+                        .collect();
+                    let proxy = self.filter_proxy.model(changed, data, dependencies);
+                    let row_states = &mut self.row_states;
+                    let mut modified = |proxy_index: usize| {
+                        let (Some(baseline), Some(index)) =
+                            (&baseline, proxy.row_to_index(proxy_index))
+                        else {
+                            return false;
+                        };
+                        let state = row_states
+                            .entry(index)
+                            .or_insert_with(|| row_state(data, baseline, index));
+                        *state != RowState::Unchanged
+                    };
                     ui.add(list_view(
-                        &self.filter_proxy.model(changed, data, dependencies),
+                        &proxy,
                         dependencies,
                         &mut proxy_index,
+                        &mut marked,
+                        &mut modified,
                     ));
                     self.selection = proxy_index
                         .and_then(|proxy_index| self.filter_proxy.source_index(proxy_index, data));
+                    self.marked = marked
+                        .into_iter()
+                        .filter_map(|proxy_index| self.filter_proxy.source_index(proxy_index, data))
+                        .collect();
                 });
 
                 let no_widgets_focused = ctx.memory(|mem| mem.focus().is_none());
                 if no_widgets_focused {
-                    if ui.input_mut(|input| input.consume_shortcut(&ADD_SHORTCUT)) {
+                    if ui.input_mut(|input| input.consume_shortcut(&ShortcutAction::Add.shortcut()))
+                    {
                         self.add_item(model, &add_modal);
                     }
-                    if ui.input_mut(|input| input.consume_shortcut(&INSERT_SHORTCUT)) {
+                    if ui.input_mut(|input| {
+                        input.consume_shortcut(&ShortcutAction::Insert.shortcut())
+                    }) {
                         self.insert_item(model, &add_modal);
                     }
-                    if ui.input_mut(|input| input.consume_shortcut(&DUPLICATE_SHORTCUT)) {
+                    if ui.input_mut(|input| {
+                        input.consume_shortcut(&ShortcutAction::Duplicate.shortcut())
+                    }) {
                         self.duplicate_item(model, &add_modal);
                     }
-                    if ui.input_mut(|input| input.consume_shortcut(&COPY_TO_SHORTCUT)) {
+                    if ui.input_mut(|input| {
+                        input.consume_shortcut(&ShortcutAction::CopyTo.shortcut())
+                    }) {
                         self.copy_index = self.selection;
                         copy_modal.open();
                     }
-                    if ui.input_mut(|input| input.consume_shortcut(&MOVE_UP_SHORTCUT)) {
+                    if ui.input_mut(|input| ShortcutAction::CopyXml.consume(input)) {
+                        self.copy_xml(ctx, model);
+                    }
+                    if ui.input_mut(|input| ShortcutAction::PasteXml.consume(input)) {
+                        self.paste_form.open(&paste_modal, pasted_text(ctx));
+                    }
+                    if ui.input_mut(|input| {
+                        input.consume_shortcut(&ShortcutAction::MoveUp.shortcut())
+                    }) {
                         self.move_item_up(model);
                     }
-                    if ui.input_mut(|input| input.consume_shortcut(&MOVE_DOWN_SHORTCUT)) {
+                    if ui.input_mut(|input| {
+                        input.consume_shortcut(&ShortcutAction::MoveDown.shortcut())
+                    }) {
                         self.move_item_down(model);
                     }
-                    if ui.input_mut(|input| input.consume_shortcut(&DELETE_SHORTCUT)) {
+                    if ui.input_mut(|input| {
+                        input.consume_shortcut(&ShortcutAction::Delete.shortcut())
+                    }) {
                         self.delete_item(model);
                     }
                 }
+
+                self.selection_baseline =
+                    self.selection.zip(baseline).and_then(|(index, baseline)| {
+                        model
+                            .read(|data| data.baseline_row(&baseline, index).cloned())
+                            .map(BaselineRow::new)
+                    });
             });
     }
 
+    /// Open the selected item in a tab of the split view.
+    pub fn pin_item<R, B>(&self, model: &SheetHandle<R, B, M>, dependencies: &D)
+    where
+        R: SheetRetriever<B, M> + Clone + Send + 'static,
+        B: Send + Sync + 'static,
+        I: Send + Sync + 'static,
+    {
+        let Some(index) = self.selection else {
+            return;
+        };
+        let title = model.read(|data| {
+            data.item(index)
+                .map(|item| item.text(dependencies).into_owned())
+        });
+        if let Some(tab) = title
+            .and_then(|title| RowTab::<_, _, M, I>::new(title, model.clone(), index, I::screen()))
+        {
+            pin_tab(tab);
+        }
+    }
+
+    /// Copy the marked items, or the selected item if none are marked, to the clipboard as XML.
+    pub fn copy_xml<R, B>(&self, ctx: &egui::Context, model: &SheetHandle<R, B, M>)
+    where
+        R: SheetRetriever<B, M>,
+    {
+        let indices: Vec<_> = if self.marked.is_empty() {
+            self.selection.into_iter().collect()
+        } else {
+            self.marked.iter().copied().collect()
+        };
+        if indices.is_empty() {
+            return;
+        }
+        let xml = model.read(|data| rows_to_xml(indices.iter().filter_map(|i| data.item(*i))));
+        ctx.output_mut(|output| output.copied_text = xml);
+    }
+
+    /// Save the selected item as a template for new items, replacing its key with placeholders.
+    pub fn save_template<R, B>(&self, model: &SheetHandle<R, B, M>)
+    where
+        R: SheetRetriever<B, M>,
+    {
+        let template = model.read(|data| {
+            let item = self.selection.and_then(|index| data.item(index))?;
+            let key = data.key_of(item)?;
+            Some(RowTemplate::from_row(
+                self.template_name.clone(),
+                item,
+                &key,
+            ))
+        });
+        if let Some(template) = template {
+            RowTemplates::save::<I>(template);
+        }
+    }
+
     pub fn add_item<R, B>(&mut self, model: &SheetHandle<R, B, M>, add_modal: &Modal)
     where
         R: SheetRetriever<B, M>,
@@ -278,6 +539,26 @@ where
         }
     }
 
+    /// Open the bulk edit form for the marked rows.
+    /// If no rows are marked, every row matching the current filter is edited instead.
+    pub fn bulk_edit<R, B>(&mut self, model: &SheetHandle<R, B, M>, dependencies: &D, modal: &Modal)
+    where
+        R: SheetRetriever<B, M>,
+    {
+        self.bulk_targets = if self.marked.is_empty() {
+            model.read(|data| {
+                let proxy = self.filter_proxy.model(false, data, dependencies);
+                (0..proxy.len())
+                    .filter_map(|proxy_index| self.filter_proxy.source_index(proxy_index, data))
+                    .collect()
+            })
+        } else {
+            self.marked.iter().copied().collect()
+        };
+        self.bulk_editor.reset_status();
+        modal.open();
+    }
+
     pub fn move_item_up<R, B>(&mut self, model: &SheetHandle<R, B, M>)
     where
         R: SheetRetriever<B, M>,
@@ -287,6 +568,7 @@ where
                 if selection > 0 && selection < data.len() {
                     data.swap_items(selection, selection - 1);
                     self.selection = Some(selection - 1);
+                    self.marked.clear();
                     self.filter_proxy.request_refresh();
                     return true;
                 }
@@ -304,6 +586,7 @@ where
                 if selection < data.len() - 1 {
                     data.swap_items(selection, selection + 1);
                     self.selection = Some(selection + 1);
+                    self.marked.clear();
                     self.filter_proxy.request_refresh();
                     return true;
                 }
@@ -322,27 +605,44 @@ where
                 if selection >= data.len() {
                     self.selection = None;
                 }
+                self.marked.clear();
                 return true;
             }
             false
         });
     }
 
-    pub fn content(
+    /// Show the selected item's [RowForm]. The item is edited on a copy and only written
+    /// back to the sheet when the form reports a change.
+    pub fn content<R, B>(
         &mut self,
         ctx: &egui::Context,
-        model: &mut M,
-        add_content: impl FnOnce(&mut Ui, &mut I) -> bool,
-    ) -> bool {
-        let item = self.selection.and_then(|index| model.item_mut(index));
-        CentralPanel::default()
-            .show(ctx, |ui| match item {
-                Some(item) => add_content(ui, item),
-                None => {
-                    blank_slate(ui);
-                    false
+        model: &SheetHandle<R, B, M>,
+        state: &EditorState,
+    ) where
+        R: SheetRetriever<B, M>,
+    {
+        let item = self.selection.and_then(|index| {
+            model
+                .read(|data| data.item(index).cloned())
+                .map(|item| (index, item))
+        });
+        CentralPanel::default().show(ctx, |ui| match item {
+            Some((index, mut item)) => {
+                let changed = BaselineRow::show(self.selection_baseline.as_ref(), ui, |ui| {
+                    I::form(ui, state, &mut item)
+                });
+                if changed {
+                    model.write(|data| match data.item_mut(index) {
+                        Some(row) => {
+                            *row = item;
+                            true
+                        }
+                        None => false,
+                    });
                 }
-            })
-            .inner
+            }
+            None => blank_slate(ui),
+        });
     }
 }
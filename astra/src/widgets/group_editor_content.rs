@@ -1,12 +1,16 @@
+use std::collections::{BTreeSet, HashMap};
+
 use egui::collapsing_header::CollapsingState;
-use egui::{Button, CentralPanel, Id, ScrollArea, SidePanel, TextEdit, Ui};
+use egui::{Button, CentralPanel, Checkbox, Id, ScrollArea, SidePanel, TextEdit, Ui};
 use egui_modal::Modal;
 use indexmap::IndexMap;
+use itertools::Itertools;
 
 use crate::model::{DecorationKind, GroupViewItem};
 use crate::{
-    blank_slate, SheetHandle, SheetRetriever, ViewItem, ADD_SHORTCUT, COPY_TO_SHORTCUT,
-    DELETE_SHORTCUT, DUPLICATE_SHORTCUT, INSERT_SHORTCUT, MOVE_DOWN_SHORTCUT, MOVE_UP_SHORTCUT,
+    blank_slate, groups_to_xml, modified_text, pasted_text, pin_tab, BaselineRow, BulkEditor,
+    EditorState, FilterQuery, PasteRowsForm, RowForm, RowState, RowTab, SchemaRow, SheetHandle,
+    SheetRetriever, ShortcutAction, ViewItem, FILTER_HELP,
 };
 
 use super::{group_add_modal_content, group_copy_modal_content, optional_image, GroupModalCommand};
@@ -135,12 +139,31 @@ impl GroupEntryCommand {
     }
 }
 
+/// Groups and entries matching the search. Rebuilt when the search or the sheet changes.
+struct GroupFilter {
+    search: String,
+    revision: usize,
+    visible: IndexMap<String, Vec<usize>>,
+    error: Option<String>,
+}
+
 pub struct GroupEditorContent {
     selection: Option<(String, usize)>,
     search: String,
+    filter: Option<GroupFilter>,
     modal_command: Option<GroupModalCommand>,
     copy_source: Option<(String, usize)>,
+    pin_source: Option<(String, usize)>,
+    xml_copy_source: Option<(String, Option<usize>)>,
     id_source: &'static str,
+    marked: BTreeSet<(String, usize)>,
+    bulk_editor: BulkEditor,
+    bulk_targets: Vec<(String, usize)>,
+    paste_form: PasteRowsForm,
+    paste_into_selection: bool,
+    row_states: HashMap<(String, usize), RowState>,
+    row_states_revision: Option<usize>,
+    selection_baseline: Option<BaselineRow>,
 }
 
 impl GroupEditorContent {
@@ -150,10 +173,25 @@ impl GroupEditorContent {
             selection: None,
             modal_command: None,
             copy_source: None,
+            pin_source: None,
+            xml_copy_source: None,
             search: String::new(),
+            filter: None,
+            marked: BTreeSet::new(),
+            bulk_editor: BulkEditor::new(),
+            bulk_targets: vec![],
+            paste_form: PasteRowsForm::new(),
+            paste_into_selection: false,
+            row_states: HashMap::new(),
+            row_states_revision: None,
+            selection_baseline: None,
         }
     }
 
+    pub fn selection(&self) -> Option<&(String, usize)> {
+        self.selection.as_ref()
+    }
+
     pub fn selection_mut(&mut self) -> &mut Option<(String, usize)> {
         &mut self.selection
     }
@@ -165,8 +203,16 @@ impl GroupEditorContent {
         dependencies: &D,
     ) where
         Group<I>: GroupViewItem<Dependencies = D>,
-        R: SheetRetriever<B, Group<I>>,
-        I: ViewItem<Dependencies = D> + Default + Clone,
+        R: SheetRetriever<B, Group<I>> + Clone + Send + 'static,
+        B: Send + Sync + 'static,
+        I: ViewItem<Dependencies = D>
+            + SchemaRow
+            + RowForm
+            + Default
+            + Clone
+            + Send
+            + Sync
+            + 'static,
     {
         let modal = Modal::new(ctx, self.id_source);
         if let Some(modal_command) = self.modal_command {
@@ -194,6 +240,48 @@ impl GroupEditorContent {
             });
         }
 
+        let bulk_modal = Modal::new(ctx, format!("{}_bulk_edit_modal", self.id_source));
+        bulk_modal.show(|ui| {
+            if let Some(expression) =
+                self.bulk_editor
+                    .show::<I>(&bulk_modal, ui, self.bulk_targets.len())
+            {
+                model.write(|data| {
+                    self.bulk_editor.apply(
+                        &expression,
+                        data,
+                        self.bulk_targets.iter(),
+                        |data, (group, index)| {
+                            data.get_mut(group).and_then(|group| group.get_mut(*index))
+                        },
+                    )
+                });
+            }
+        });
+
+        let paste_modal = Modal::new(ctx, format!("{}_paste_modal", self.id_source));
+        paste_modal.show(|ui| {
+            let has_selection = self.selection.is_some();
+            let into_selection = &mut self.paste_into_selection;
+            let rows =
+                self.paste_form
+                    .show::<I>(&paste_modal, ui, Some("Existing groups:"), |ui| {
+                        ui.add_enabled(
+                            has_selection,
+                            Checkbox::new(into_selection, "Paste after the selected entry"),
+                        );
+                    });
+            if let Some(rows) = rows {
+                let target = self
+                    .selection
+                    .as_ref()
+                    .filter(|_| self.paste_into_selection)
+                    .map(|(group, index)| (group.as_str(), *index));
+                model.write(|data| self.paste_form.paste_groups(data, target, &rows) > 0);
+                self.marked.clear();
+            }
+        });
+
         SidePanel::left(Id::new(self.id_source).with("left_panel"))
             .default_width(300.)
             .show(ctx, |ui| {
@@ -205,22 +293,54 @@ impl GroupEditorContent {
                         self.modal_command = Some(GroupModalCommand::Add);
                         modal.open();
                     }
-                    ui.add(TextEdit::singleline(&mut self.search).desired_width(f32::INFINITY));
+                    if ui
+                        .add(Button::new("✏").min_size([30., 0.].into()))
+                        .on_hover_text("Bulk edit the marked entries, or every entry shown")
+                        .clicked()
+                    {
+                        self.bulk_edit(model, dependencies, &bulk_modal);
+                    }
+                    if ui
+                        .add(Button::new("📋").min_size([30., 0.].into()))
+                        .on_hover_text("Paste groups or entries copied as XML")
+                        .clicked()
+                    {
+                        self.paste_form.open(&paste_modal, None);
+                    }
+                    ui.add(
+                        TextEdit::singleline(&mut self.search)
+                            .hint_text("Search, or filter like level>10")
+                            .desired_width(f32::INFINITY),
+                    )
+                    .on_hover_text(FILTER_HELP);
                 });
+                self.refresh_filter(model, dependencies);
+                if let Some(error) = self.filter.as_ref().and_then(|f| f.error.as_deref()) {
+                    ui.colored_label(ui.visuals().error_fg_color, error);
+                }
 
                 let mut group_command = None;
                 let mut group_entry_command = None;
+                if self.row_states_revision != Some(model.revision_number()) {
+                    self.row_states.clear();
+                    self.row_states_revision = Some(model.revision_number());
+                }
+                let baseline = model.baseline();
 
-                let search = self.search.to_lowercase();
                 ScrollArea::both()
                     .auto_shrink([false, false])
                     .show(ui, |ui| {
                         model.read(|data| {
                             for (i, (group, items)) in data.iter().enumerate() {
-                                let name = <Group<I> as GroupViewItem>::text(group, dependencies);
-                                if !name.to_lowercase().contains(&search) {
+                                let Some(entries) = self
+                                    .filter
+                                    .as_ref()
+                                    .and_then(|filter| filter.visible.get(group))
+                                    .cloned()
+                                else {
                                     continue;
-                                }
+                                };
+                                let name = <Group<I> as GroupViewItem>::text(group, dependencies);
                                 let id =
                                     ui.make_persistent_id(format!("{}_{}", &self.id_source, group));
                                 CollapsingState::load_with_default_open(ctx, id, false)
@@ -244,12 +364,19 @@ impl GroupEditorContent {
                                         }
                                     })
                                     .body(|ui| {
+                                        let entries = self.mark_modified(
+                                            baseline.as_deref(),
+                                            group,
+                                            items,
+                                            &entries,
+                                        );
                                         let command = self.group_list(
                                             &copy_modal,
                                             ui,
                                             dependencies,
                                             group,
                                             items,
+                                            &entries,
                                         );
                                         if group_entry_command.is_none() {
                                             group_entry_command = command;
@@ -260,6 +387,11 @@ impl GroupEditorContent {
                     });
 
                 let no_widgets_focused = ctx.memory(|mem| mem.focus().is_none());
+                if no_widgets_focused
+                    && ui.input_mut(|input| ShortcutAction::PasteXml.consume(input))
+                {
+                    self.paste_form.open(&paste_modal, pasted_text(ctx));
+                }
                 if no_widgets_focused && group_entry_command.is_none() {
                     if let Some((group, index)) = self.selection.clone() {
                         group_entry_command =
@@ -267,6 +399,16 @@ impl GroupEditorContent {
                     }
                 }
 
+                if let Some((group, index)) = self.pin_source.take() {
+                    Self::pin_entry(model, dependencies, &group, index);
+                }
+                if let Some((group, index)) = self.xml_copy_source.take() {
+                    self.copy_xml(ctx, model, &group, index);
+                }
+
+                if group_command.is_some() || group_entry_command.is_some() {
+                    self.marked.clear();
+                }
                 model.write(|data| {
                     let mut changed = false;
                     if let Some(command) = group_command {
@@ -293,9 +435,186 @@ impl GroupEditorContent {
                         }
                     })
                 });
+                self.selection_baseline = self.selection.as_ref().zip(model.baseline()).and_then(
+                    |((group, index), baseline)| {
+                        baseline
+                            .get(group)?
+                            .get(*index)
+                            .cloned()
+                            .map(BaselineRow::new)
+                    },
+                );
             });
     }
 
+    /// Pair each of the visible `entries` of `group` with whether it differs from the loaded sheet.
+    fn mark_modified<I: SchemaRow>(
+        &mut self,
+        baseline: Option<&Group<I>>,
+        group: &str,
+        items: &[I],
+        entries: &[usize],
+    ) -> Vec<(usize, bool)> {
+        let Some(baseline) = baseline else {
+            return entries.iter().map(|index| (*index, false)).collect();
+        };
+        entries
+            .iter()
+            .map(|index| {
+                let state = self
+                    .row_states
+                    .entry((group.to_string(), *index))
+                    .or_insert_with(|| {
+                        let original = baseline.get(group).and_then(|group| group.get(*index));
+                        match (items.get(*index), original) {
+                            (Some(entry), Some(original))
+                                if entry.field_values() != original.field_values() =>
+                            {
+                                RowState::Modified
+                            }
+                            (Some(_), None) => RowState::Added,
+                            _ => RowState::Unchanged,
+                        }
+                    });
+                (*index, *state != RowState::Unchanged)
+            })
+            .collect()
+    }
+
+    /// Rebuild the visible groups and entries if the search or the sheet changed.
+    /// Text terms in the search match group names and field terms match entries.
+    /// See [FilterQuery] for the syntax.
+    fn refresh_filter<R, B, I, D>(&mut self, model: &SheetHandle<R, B, Group<I>>, dependencies: &D)
+    where
+        Group<I>: GroupViewItem<Dependencies = D>,
+        R: SheetRetriever<B, Group<I>>,
+        I: SchemaRow,
+    {
+        let revision = model.revision_number();
+        if let Some(filter) = &self.filter {
+            if filter.search == self.search && filter.revision == revision {
+                return;
+            }
+        }
+        let (query, error) = match FilterQuery::parse(&self.search, I::FIELDS) {
+            Ok(query) => (query, None),
+            Err(err) => (FilterQuery::text(&self.search), Some(err.to_string())),
+        };
+        let visible = model.read(|data| {
+            data.iter()
+                .filter(|(group, _)| {
+                    query.matches_text(&<Group<I> as GroupViewItem>::text(group, dependencies))
+                })
+                .filter_map(|(group, items)| {
+                    let entries = (0..items.len())
+                        .filter(|index| query.matches_fields(&items[*index]))
+                        .collect_vec();
+                    (!query.has_field_terms() || !entries.is_empty())
+                        .then(|| (group.clone(), entries))
+                })
+                .collect()
+        });
+        self.filter = Some(GroupFilter {
+            search: self.search.clone(),
+            revision,
+            visible,
+            error,
+        });
+    }
+
+    /// Open the bulk edit form for the marked entries.
+    /// If no entries are marked, every entry matching the search is edited instead.
+    fn bulk_edit<R, B, I, D>(
+        &mut self,
+        model: &SheetHandle<R, B, Group<I>>,
+        dependencies: &D,
+        modal: &Modal,
+    ) where
+        Group<I>: GroupViewItem<Dependencies = D>,
+        R: SheetRetriever<B, Group<I>>,
+        I: SchemaRow,
+    {
+        self.bulk_targets = if self.marked.is_empty() {
+            self.refresh_filter(model, dependencies);
+            self.filter
+                .iter()
+                .flat_map(|filter| &filter.visible)
+                .flat_map(|(group, entries)| entries.iter().map(|i| (group.clone(), *i)))
+                .collect()
+        } else {
+            self.marked.iter().cloned().collect()
+        };
+        self.bulk_editor.reset_status();
+        modal.open();
+    }
+
+    /// Copy a whole group to the clipboard as XML if `index` is [None].
+    /// Otherwise copy the marked entries, or the entry at `index` if none are marked.
+    fn copy_xml<R, B, I>(
+        &self,
+        ctx: &egui::Context,
+        model: &SheetHandle<R, B, Group<I>>,
+        group: &str,
+        index: Option<usize>,
+    ) where
+        R: SheetRetriever<B, Group<I>>,
+        I: SchemaRow,
+    {
+        let xml = model.read(|data| match index {
+            None => groups_to_xml(
+                data.get_key_value(group)
+                    .map(|(name, entries)| (name.as_str(), entries.iter())),
+            ),
+            Some(index) => {
+                let mut targets = self.marked.clone();
+                if targets.is_empty() {
+                    targets.insert((group.to_string(), index));
+                }
+                groups_to_xml(data.iter().filter_map(|(name, entries)| {
+                    let copied = targets
+                        .iter()
+                        .filter(|(group, _)| group == name)
+                        .filter_map(|(_, index)| entries.get(*index))
+                        .collect_vec();
+                    (!copied.is_empty()).then_some((name.as_str(), copied))
+                }))
+            }
+        });
+        if !xml.is_empty() {
+            ctx.output_mut(|output| output.copied_text = xml);
+        }
+    }
+
+    /// Open an entry in a tab of the split view.
+    fn pin_entry<R, B, I, D>(
+        model: &SheetHandle<R, B, Group<I>>,
+        dependencies: &D,
+        group: &str,
+        index: usize,
+    ) where
+        Group<I>: GroupViewItem<Dependencies = D>,
+        R: SheetRetriever<B, Group<I>> + Clone + Send + 'static,
+        B: Send + Sync + 'static,
+        I: ViewItem<Dependencies = D> + SchemaRow + RowForm + Clone + Send + Sync + 'static,
+    {
+        let found = model.read(|data| {
+            let group_index = data.get_index_of(group)?;
+            let entry = data[group_index].get(index)?;
+            let title = format!(
+                "{} / {}",
+                <Group<I> as GroupViewItem>::text(group, dependencies),
+                entry.text(dependencies)
+            );
+            let offset: usize = data.values().take(group_index).map(|g| g.len()).sum();
+            Some((title, offset + index))
+        });
+        if let Some(tab) = found.and_then(|(title, row)| {
+            RowTab::<_, _, _, I>::new(title, model.clone(), row, I::screen())
+        }) {
+            pin_tab(tab);
+        }
+    }
+
     fn group_list<I>(
         &mut self,
         copy_modal: &Modal,
@@ -303,14 +622,18 @@ impl GroupEditorContent {
         dependencies: &I::Dependencies,
         group: &str,
         items: &[I],
+        entries: &[(usize, bool)],
     ) -> Option<GroupEntryCommand>
     where
         I: ViewItem + Default + Clone,
     {
         let mut command = None;
-        for (i, entry) in items.iter().enumerate() {
-            let selected =
-                self.selection.as_ref().map(|(g, i)| (g.as_str(), *i)) == Some((group, i));
+        for (i, entry, modified) in entries.iter().filter_map(|(index, modified)| {
+            items.get(*index).map(|entry| (*index, entry, *modified))
+        }) {
+            let selected = self.selection.as_ref().map(|(g, i)| (g.as_str(), *i))
+                == Some((group, i))
+                || self.marked.contains(&(group.to_owned(), i));
             ui.horizontal(|ui| {
                 if I::decorated(DecorationKind::List) {
                     ui.add(optional_image(
@@ -318,10 +641,22 @@ impl GroupEditorContent {
                         [0., 0.],
                     ));
                 }
+                let text = entry.text(dependencies);
                 if ui
-                    .selectable_label(selected, entry.text(dependencies))
+                    .selectable_label(selected, modified_text(ui, &text, modified))
                     .clicked()
                 {
+                    if ui.input(|input| input.modifiers.command) {
+                        if self.marked.is_empty() {
+                            self.marked.extend(self.selection.clone());
+                        }
+                        let entry = (group.to_owned(), i);
+                        if !self.marked.remove(&entry) {
+                            self.marked.insert(entry);
+                        }
+                    } else {
+                        self.marked.clear();
+                    }
                     self.selection = Some((group.to_owned(), i));
                 }
                 if command.is_none() {
@@ -361,6 +696,10 @@ impl GroupEditorContent {
                 modal.open();
                 ui.close_menu();
             }
+            if ui.button("📋 Copy as XML").clicked() {
+                self.xml_copy_source = Some((group.to_string(), None));
+                ui.close_menu();
+            }
             ui.separator();
             if ui.button("⏶ Move Up").clicked() {
                 command_kind = Some(GroupCommandKind::MoveUp);
@@ -410,6 +749,14 @@ impl GroupEditorContent {
                 copy_modal.open();
                 ui.close_menu();
             }
+            if ui.button("📋 Copy as XML").clicked() {
+                self.xml_copy_source = Some((group.to_string(), Some(index)));
+                ui.close_menu();
+            }
+            if ui.button("📌 Pin to Split View").clicked() {
+                self.pin_source = Some((group.to_string(), index));
+                ui.close_menu();
+            }
             ui.separator();
             if ui.button("⏶ Move Up").clicked() {
                 command_kind = Some(GroupEntryCommandKind::MoveUp);
@@ -442,25 +789,28 @@ impl GroupEditorContent {
         let mut command_kind = None;
         let mut open_modal = false;
         ui.input_mut(|input| {
-            if input.consume_shortcut(&ADD_SHORTCUT) {
+            if input.consume_shortcut(&ShortcutAction::Add.shortcut()) {
                 command_kind = Some(GroupEntryCommandKind::Add);
             }
-            if input.consume_shortcut(&INSERT_SHORTCUT) {
+            if input.consume_shortcut(&ShortcutAction::Insert.shortcut()) {
                 command_kind = Some(GroupEntryCommandKind::Insert);
             }
-            if input.consume_shortcut(&DUPLICATE_SHORTCUT) {
+            if input.consume_shortcut(&ShortcutAction::Duplicate.shortcut()) {
                 command_kind = Some(GroupEntryCommandKind::Duplicate);
             }
-            if input.consume_shortcut(&COPY_TO_SHORTCUT) {
+            if input.consume_shortcut(&ShortcutAction::CopyTo.shortcut()) {
                 open_modal = true;
             }
-            if input.consume_shortcut(&MOVE_UP_SHORTCUT) {
+            if ShortcutAction::CopyXml.consume(input) {
+                self.xml_copy_source = Some((group.clone(), Some(index)));
+            }
+            if input.consume_shortcut(&ShortcutAction::MoveUp.shortcut()) {
                 command_kind = Some(GroupEntryCommandKind::MoveUp);
             }
-            if input.consume_shortcut(&MOVE_DOWN_SHORTCUT) {
+            if input.consume_shortcut(&ShortcutAction::MoveDown.shortcut()) {
                 command_kind = Some(GroupEntryCommandKind::MoveDown);
             }
-            if input.consume_shortcut(&DELETE_SHORTCUT) {
+            if input.consume_shortcut(&ShortcutAction::Delete.shortcut()) {
                 command_kind = Some(GroupEntryCommandKind::Remove);
             }
         });
@@ -471,41 +821,81 @@ impl GroupEditorContent {
         command_kind.map(|kind| GroupEntryCommand { group, index, kind })
     }
 
-    pub fn content<I>(
-        &self,
-        ctx: &egui::Context,
-        model: &mut IndexMap<String, Vec<I>>,
-        add_content: impl FnOnce(&mut Ui, &mut I) -> bool,
-    ) -> bool {
-        let item = self.selection.as_ref().and_then(|(group, index)| {
-            model.get_mut(group).and_then(|group| group.get_mut(*index))
-        });
-        CentralPanel::default()
-            .show(ctx, |ui| match item {
-                Some(item) => add_content(ui, item),
-                None => {
-                    blank_slate(ui);
+    /// Copy the selected entry so it can be edited outside the sheet's lock.
+    fn selected_entry<R, B, I>(&self, model: &SheetHandle<R, B, Group<I>>) -> Option<I>
+    where
+        R: SheetRetriever<B, Group<I>>,
+        I: Clone,
+    {
+        let (group, index) = self.selection.as_ref()?;
+        model.read(|data| data.get(group)?.get(*index).cloned())
+    }
+
+    /// Write an entry edited by [GroupEditorContent::content] back to the sheet.
+    fn write_entry<R, B, I>(&self, model: &SheetHandle<R, B, Group<I>>, entry: I)
+    where
+        R: SheetRetriever<B, Group<I>>,
+    {
+        if let Some((group, index)) = &self.selection {
+            model.write(|data| {
+                let row = data.get_mut(group).and_then(|rows| rows.get_mut(*index));
+                if let Some(row) = row {
+                    *row = entry;
+                    true
+                } else {
                     false
                 }
-            })
-            .inner
+            });
+        }
     }
 
-    pub fn right_panel<I>(
+    /// Show the selected entry's [RowForm]. The entry is edited on a copy and only written
+    /// back to the sheet when the form reports a change.
+    pub fn content<R, B, I>(
         &self,
         ctx: &egui::Context,
-        model: &mut IndexMap<String, Vec<I>>,
-        add_content: impl FnOnce(&mut Ui, &mut I) -> bool,
-    ) -> bool {
-        let item = self.selection.as_ref().and_then(|(group, index)| {
-            model.get_mut(group).and_then(|group| group.get_mut(*index))
+        model: &SheetHandle<R, B, Group<I>>,
+        state: &EditorState,
+    ) where
+        R: SheetRetriever<B, Group<I>>,
+        I: RowForm + Clone,
+    {
+        let item = self.selected_entry(model);
+        CentralPanel::default().show(ctx, |ui| match item {
+            Some(mut item) => {
+                let changed = BaselineRow::show(self.selection_baseline.as_ref(), ui, |ui| {
+                    I::form(ui, state, &mut item)
+                });
+                if changed {
+                    self.write_entry(model, item);
+                }
+            }
+            None => blank_slate(ui),
         });
-        if let Some(item) = item {
-            SidePanel::right(Id::new(self.id_source).with("right_panel"))
-                .show(ctx, |ui| add_content(ui, item))
-                .inner
-        } else {
-            false
+    }
+
+    /// Like [GroupEditorContent::content], but shown in a panel on the right.
+    pub fn right_panel<R, B, I>(
+        &self,
+        ctx: &egui::Context,
+        model: &SheetHandle<R, B, Group<I>>,
+        state: &EditorState,
+    ) where
+        R: SheetRetriever<B, Group<I>>,
+        I: RowForm + Clone,
+    {
+        let Some(mut item) = self.selected_entry(model) else {
+            return;
+        };
+        let changed = SidePanel::right(Id::new(self.id_source).with("right_panel"))
+            .show(ctx, |ui| {
+                BaselineRow::show(self.selection_baseline.as_ref(), ui, |ui| {
+                    I::form(ui, state, &mut item)
+                })
+            })
+            .inner;
+        if changed {
+            self.write_entry(model, item);
         }
     }
 }
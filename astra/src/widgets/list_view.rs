@@ -1,6 +1,8 @@
-use egui::{Image, Response, ScrollArea, Sense, Ui, Widget};
+use std::collections::BTreeSet;
 
-use crate::{DecorationKind, ListModel, ViewItem, DOWN_ENTRY_SHORTCUT, UP_ENTRY_SHORTCUT};
+use egui::{Image, Response, RichText, ScrollArea, Sense, Ui, Widget, WidgetText};
+
+use crate::{DecorationKind, ListModel, ShortcutAction, ViewItem};
 
 fn item_number_ui(ui: &mut Ui, index: usize, max_indent: usize) {
     let label = (index + 1).to_string();
@@ -11,12 +13,22 @@ fn item_number_ui(ui: &mut Ui, index: usize, max_indent: usize) {
     ));
 }
 
+/// Label text for an item or field, highlighted if it differs from the loaded data.
+pub fn modified_text(ui: &Ui, text: &str, modified: bool) -> WidgetText {
+    if modified {
+        RichText::new(text).color(ui.visuals().warn_fg_color).into()
+    } else {
+        text.into()
+    }
+}
+
 fn list_item_ui<M, I, D>(
     ui: &mut Ui,
     model: &M,
     dependencies: &D,
     index: usize,
     selected: bool,
+    modified: bool,
     max_indent: usize,
 ) -> Response
 where
@@ -33,7 +45,7 @@ where
         } else {
             ui.label("");
         }
-        ui.selectable_label(selected, text)
+        ui.selectable_label(selected, modified_text(ui, &text, modified))
     } else {
         // Out of bounds - fill with empty space.
         ui.label("");
@@ -42,11 +54,39 @@ where
     }
 }
 
+/// Update the selection after a click. Holding command toggles the row in the marked set
+/// and holding shift marks every row between the current selection and the clicked one.
+fn select_item(
+    ui: &Ui,
+    index: usize,
+    selected_index: &mut Option<usize>,
+    marked: &mut BTreeSet<usize>,
+) {
+    let modifiers = ui.input(|input| input.modifiers);
+    if modifiers.command {
+        if marked.is_empty() {
+            marked.extend(*selected_index);
+        }
+        if !marked.remove(&index) {
+            marked.insert(index);
+        }
+    } else if modifiers.shift {
+        let anchor = selected_index.unwrap_or(index);
+        marked.clear();
+        marked.extend(anchor.min(index)..=anchor.max(index));
+    } else {
+        marked.clear();
+    }
+    *selected_index = Some(index);
+}
+
 fn list_view_ui<M, I, D>(
     ui: &mut Ui,
     model: &M,
     dependencies: &D,
     selected_index: &mut Option<usize>,
+    marked: &mut BTreeSet<usize>,
+    modified: &mut dyn FnMut(usize) -> bool,
 ) -> Response
 where
     M: ListModel<I>,
@@ -55,6 +95,7 @@ where
     if model.len() == 0 && selected_index.is_some() {
         *selected_index = None;
     }
+    marked.retain(|index| *index < model.len());
 
     let max_indent = (model.len() + 1).to_string().len();
     let mut changed = false;
@@ -72,29 +113,31 @@ where
                                 model,
                                 dependencies,
                                 index,
-                                Some(index) == *selected_index,
+                                Some(index) == *selected_index || marked.contains(&index),
+                                modified(index),
                                 max_indent,
                             )
                         })
                         .inner;
                     if response.clicked() {
-                        *selected_index = Some(index);
+                        select_item(ui, index, selected_index, marked);
                         changed = true;
                     }
                 } else {
                     ui.horizontal(|ui| {
                         item_number_ui(ui, model.row_to_index(index).unwrap_or(0), max_indent);
+                        let text = model
+                            .item(index)
+                            .map(|item| item.text(dependencies))
+                            .unwrap_or_default();
                         if ui
                             .selectable_label(
-                                Some(index) == *selected_index,
-                                model
-                                    .item(index)
-                                    .map(|item| item.text(dependencies))
-                                    .unwrap_or_default(),
+                                Some(index) == *selected_index || marked.contains(&index),
+                                modified_text(ui, &text, modified(index)),
                             )
                             .clicked()
                         {
-                            *selected_index = Some(index);
+                            select_item(ui, index, selected_index, marked);
                             changed = true;
                         }
                     });
@@ -103,7 +146,7 @@ where
         },
     );
 
-    if ui.input_mut(|input| input.consume_shortcut(&UP_ENTRY_SHORTCUT)) {
+    if ui.input_mut(|input| input.consume_shortcut(&ShortcutAction::UpEntry.shortcut())) {
         if let Some(index) = *selected_index {
             if index > 0 {
                 *selected_index = Some(index - 1);
@@ -111,7 +154,7 @@ where
         }
         changed = true;
     }
-    if ui.input_mut(|input| input.consume_shortcut(&DOWN_ENTRY_SHORTCUT)) {
+    if ui.input_mut(|input| input.consume_shortcut(&ShortcutAction::DownEntry.shortcut())) {
         *selected_index = match *selected_index {
             Some(index) => (index < model.len() - 1)
                 .then_some(index + 1)
@@ -132,14 +175,19 @@ where
     response
 }
 
+/// A scrolling list of items with a single focused selection.
+/// Rows can also be marked with command/shift click for operations on many items at once.
+/// Items for which `modified` returns true are highlighted.
 pub fn list_view<'a, M, I, D>(
     model: &'a M,
     dependencies: &'a D,
     selected_index: &'a mut Option<usize>,
+    marked: &'a mut BTreeSet<usize>,
+    modified: &'a mut dyn FnMut(usize) -> bool,
 ) -> impl Widget + 'a
 where
     M: ListModel<I>,
     I: ViewItem<Dependencies = D>,
 {
-    move |ui: &mut Ui| list_view_ui(ui, model, dependencies, selected_index)
+    move |ui: &mut Ui| list_view_ui(ui, model, dependencies, selected_index, marked, modified)
 }
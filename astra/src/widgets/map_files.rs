@@ -0,0 +1,121 @@
+use std::path::PathBuf;
+
+use egui::Ui;
+use egui_modal::Modal;
+use rfd::FileDialog;
+
+/// A file operation the user picked in a [MapFilesForm].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MapFileAction {
+    ExportPng(PathBuf),
+    ExportTmx(PathBuf),
+    ImportTmx {
+        path: PathBuf,
+        terrain: bool,
+        spawns: bool,
+    },
+}
+
+/// Form for exporting a chapter's map as a printable image or a Tiled map, and importing Tiled maps.
+pub struct MapFilesForm {
+    file_stem: String,
+    import_terrain: bool,
+    import_spawns: bool,
+    status: Option<Result<String, String>>,
+}
+
+impl MapFilesForm {
+    pub fn new() -> Self {
+        Self {
+            file_stem: String::new(),
+            import_terrain: true,
+            import_spawns: true,
+            status: None,
+        }
+    }
+
+    /// Open the form, suggesting file names based on `file_stem`.
+    pub fn open(&mut self, modal: &Modal, file_stem: &str) {
+        self.file_stem = file_stem.to_string();
+        self.status = None;
+        modal.open();
+    }
+
+    /// Report how the last action went.
+    pub fn set_status(&mut self, status: Result<String, String>) {
+        self.status = Some(status);
+    }
+
+    /// Draw the form inside a modal. Returns the action once the user has picked a file for it.
+    pub fn show(&mut self, modal: &Modal, ui: &mut Ui) -> Option<MapFileAction> {
+        let mut result = None;
+        modal.title(ui, "Map Files");
+        modal.frame(ui, |ui| {
+            ui.heading("Export");
+            ui.horizontal(|ui| {
+                if ui
+                    .button("Image (PNG)…")
+                    .on_hover_text("A printable sheet with tile colors and unit icons")
+                    .clicked()
+                {
+                    result = FileDialog::new()
+                        .add_filter("PNG Image", &["png"])
+                        .set_file_name(&format!("{}.png", self.file_stem))
+                        .save_file()
+                        .map(MapFileAction::ExportPng);
+                }
+                if ui
+                    .button("Tiled Map (TMX)…")
+                    .on_hover_text("The tileset image is saved next to the map")
+                    .clicked()
+                {
+                    result = FileDialog::new()
+                        .add_filter("Tiled Map", &["tmx"])
+                        .set_file_name(&format!("{}.tmx", self.file_stem))
+                        .save_file()
+                        .map(MapFileAction::ExportTmx);
+                }
+            });
+            ui.separator();
+            ui.heading("Import");
+            ui.checkbox(&mut self.import_terrain, "Replace Terrain");
+            ui.checkbox(&mut self.import_spawns, "Replace Spawns")
+                .on_hover_text("Replaces the dispos currently shown with the map's spawn objects");
+            if ui
+                .add_enabled(
+                    self.import_terrain || self.import_spawns,
+                    egui::Button::new("Tiled Map (TMX)…"),
+                )
+                .clicked()
+            {
+                result = FileDialog::new()
+                    .add_filter("Tiled Map", &["tmx"])
+                    .pick_file()
+                    .map(|path| MapFileAction::ImportTmx {
+                        path,
+                        terrain: self.import_terrain,
+                        spawns: self.import_spawns,
+                    });
+            }
+            match &self.status {
+                Some(Ok(message)) => {
+                    ui.label(message);
+                }
+                Some(Err(message)) => {
+                    ui.colored_label(ui.visuals().error_fg_color, message);
+                }
+                None => {}
+            }
+        });
+        modal.buttons(ui, |ui| {
+            modal.button(ui, "Close");
+        });
+        result
+    }
+}
+
+impl Default for MapFilesForm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,10 +1,13 @@
 mod about_modal;
 mod add_modals;
 mod bit_grid;
+mod bulk_edit;
 mod color_picker;
+mod command_palette;
 mod common;
 mod config_editor;
 mod defaults;
+mod dispos_comparison;
 mod dispos_grid;
 mod drop_down;
 mod editable_list;
@@ -13,20 +16,29 @@ mod enum_combo_box;
 mod group_editor_content;
 mod id_field;
 mod list_view;
+mod map_files;
 mod msbt_editor;
 mod msbt_field;
+mod paste_rows;
 mod project_setup;
 mod property_grid;
+mod sheet_table;
+mod split_view;
 mod stats;
 mod terrain_grid;
+mod terrain_replace;
+mod terrain_resize;
 
 pub use about_modal::*;
 pub use add_modals::*;
 pub use bit_grid::*;
+pub use bulk_edit::*;
 pub use color_picker::*;
+pub use command_palette::*;
 pub use common::*;
 pub use config_editor::*;
 pub use defaults::*;
+pub use dispos_comparison::*;
 pub use dispos_grid::*;
 pub use drop_down::*;
 pub use editable_list::*;
@@ -35,9 +47,15 @@ pub use enum_combo_box::*;
 pub use group_editor_content::*;
 pub use id_field::*;
 pub use list_view::*;
+pub use map_files::*;
 pub use msbt_editor::*;
 pub use msbt_field::*;
+pub use paste_rows::*;
 pub use project_setup::*;
 pub use property_grid::*;
+pub use sheet_table::*;
+pub use split_view::*;
 pub use stats::*;
 pub use terrain_grid::*;
+pub use terrain_replace::*;
+pub use terrain_resize::*;
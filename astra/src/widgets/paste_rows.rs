@@ -0,0 +1,256 @@
+use egui::{Button, Event, TextEdit, Ui};
+use egui_modal::Modal;
+use indexmap::IndexMap;
+use itertools::Itertools;
+
+use crate::{parse_copied_rows, unique_key, CopiedRow, KeyCollision, ListModel, SchemaRow};
+
+/// Find text pasted from the system clipboard this frame.
+/// Only arrives when the platform's paste shortcut was pressed.
+pub fn pasted_text(ctx: &egui::Context) -> Option<String> {
+    ctx.input(|input| {
+        input.events.iter().find_map(|event| match event {
+            Event::Paste(text) => Some(text.clone()),
+            _ => None,
+        })
+    })
+}
+
+/// Form for pasting rows copied as XML, from this project or another one.
+pub struct PasteRowsForm {
+    text: String,
+    collision: KeyCollision,
+    keep_unknown: bool,
+    status: Option<Result<String, String>>,
+}
+
+impl PasteRowsForm {
+    pub fn new() -> Self {
+        Self {
+            text: String::new(),
+            collision: KeyCollision::default(),
+            keep_unknown: false,
+            status: None,
+        }
+    }
+
+    /// Open the form. `clipboard` replaces the text from the last paste if it's available.
+    pub fn open(&mut self, modal: &Modal, clipboard: Option<String>) {
+        if let Some(text) = clipboard {
+            self.text = text;
+        }
+        self.keep_unknown = false;
+        self.status = None;
+        modal.open();
+    }
+
+    /// Draw the form inside a modal. `collision_label` describes what the collision setting
+    /// applies to. It's hidden if [None]. `options` can add extra controls above the buttons.
+    /// Returns the rows to paste when the user confirms.
+    pub fn show<I: SchemaRow + Default>(
+        &mut self,
+        modal: &Modal,
+        ui: &mut Ui,
+        collision_label: Option<&str>,
+        options: impl FnOnce(&mut Ui),
+    ) -> Option<Vec<CopiedRow>> {
+        let mut result = None;
+        let parsed = parse_copied_rows(&self.text);
+        let unknown_keys = parsed
+            .iter()
+            .flatten()
+            .flat_map(|row| row.unknown_keys::<I>())
+            .unique()
+            .join(", ");
+        // Check every row so rows from the wrong sheet are caught before pasting.
+        let parsed = parsed.and_then(|rows| {
+            for row in &rows {
+                row.to_row::<I>(self.keep_unknown)?;
+            }
+            Ok(rows)
+        });
+        modal.title(ui, "Paste XML");
+        modal.frame(ui, |ui| {
+            ui.label("Paste rows copied with \"Copy as XML\" or taken from a book's XML.");
+            ui.add(
+                TextEdit::multiline(&mut self.text)
+                    .code_editor()
+                    .desired_rows(10)
+                    .desired_width(500.),
+            );
+            match &parsed {
+                Ok(rows) => ui.label(format!("{} row(s) ready to paste.", rows.len())),
+                Err(err) => ui.colored_label(ui.visuals().error_fg_color, err.to_string()),
+            };
+            if !unknown_keys.is_empty() {
+                ui.checkbox(
+                    &mut self.keep_unknown,
+                    "Paste fields this sheet doesn't have",
+                )
+                .on_hover_text(format!(
+                    "{}\nThey're kept like unknown attributes in a book.",
+                    unknown_keys
+                ));
+            }
+            if let Some(label) = collision_label {
+                ui.horizontal(|ui| {
+                    ui.label(label);
+                    for collision in [
+                        KeyCollision::Rename,
+                        KeyCollision::Overwrite,
+                        KeyCollision::Skip,
+                    ] {
+                        ui.radio_value(&mut self.collision, collision, collision.label());
+                    }
+                });
+            }
+            options(ui);
+
+            match &self.status {
+                Some(Ok(message)) => {
+                    ui.label(message);
+                }
+                Some(Err(message)) => {
+                    ui.colored_label(ui.visuals().error_fg_color, message);
+                }
+                None => {}
+            }
+        });
+        modal.buttons(ui, |ui| {
+            modal.button(ui, "Close");
+            if ui
+                .add_enabled(parsed.is_ok(), Button::new("Paste"))
+                .clicked()
+            {
+                result = parsed.ok();
+            }
+        });
+        result
+    }
+
+    /// Insert rows into a list starting at `index`, resolving key collisions with the chosen setting.
+    /// Returns the number of rows that were inserted or replaced.
+    pub fn paste_list<M, I>(&mut self, data: &mut M, index: usize, rows: &[CopiedRow]) -> usize
+    where
+        M: ListModel<I>,
+        I: SchemaRow + Default,
+    {
+        let mut index = index.min(data.len());
+        let (mut pasted, mut replaced, mut skipped) = (0, 0, 0);
+        let mut errors = vec![];
+        for row in rows {
+            let mut item = match row.to_row::<I>(self.keep_unknown) {
+                Ok(item) => item,
+                Err(err) => {
+                    errors.push(err.to_string());
+                    continue;
+                }
+            };
+            if let Some(existing) = data.key_collision(&item) {
+                match self.collision {
+                    KeyCollision::Overwrite => {
+                        if let Some(target) = data.item_mut(existing) {
+                            *target = item;
+                            replaced += 1;
+                        }
+                        continue;
+                    }
+                    KeyCollision::Skip => {
+                        skipped += 1;
+                        continue;
+                    }
+                    KeyCollision::Rename => data.make_key_unique(&mut item),
+                }
+            }
+            data.insert(index, item);
+            index += 1;
+            pasted += 1;
+        }
+        self.set_status(pasted, replaced, skipped, &errors);
+        pasted + replaced
+    }
+
+    /// Add rows to a grouped sheet. Rows go into the group they were copied from, unless
+    /// `target` is set. Then every row is inserted into the target group after the given entry.
+    /// Copied groups that already exist are resolved with the chosen setting.
+    /// Returns the number of rows that were added.
+    pub fn paste_groups<I>(
+        &mut self,
+        data: &mut IndexMap<String, Vec<I>>,
+        target: Option<(&str, usize)>,
+        rows: &[CopiedRow],
+    ) -> usize
+    where
+        I: SchemaRow + Default,
+    {
+        let mut groups: IndexMap<String, Vec<I>> = IndexMap::new();
+        let mut errors = vec![];
+        for row in rows {
+            let group = match (target, row.group.as_deref()) {
+                (Some((group, _)), _) | (None, Some(group)) => group.to_string(),
+                (None, None) => {
+                    errors.push("select an entry to paste rows without a group".to_string());
+                    continue;
+                }
+            };
+            match row.to_row::<I>(self.keep_unknown) {
+                Ok(item) => groups.entry(group).or_default().push(item),
+                Err(err) => errors.push(err.to_string()),
+            }
+        }
+
+        let (mut pasted, mut replaced, mut skipped) = (0, 0, 0);
+        for (group, items) in groups {
+            if let Some((_, index)) = target {
+                if let Some(entries) = data.get_mut(&group) {
+                    let index = (index + 1).min(entries.len());
+                    pasted += items.len();
+                    entries.splice(index..index, items);
+                }
+                continue;
+            }
+            if !data.contains_key(&group) {
+                pasted += items.len();
+                data.insert(group, items);
+                continue;
+            }
+            match self.collision {
+                KeyCollision::Overwrite => {
+                    replaced += items.len();
+                    data.insert(group, items);
+                }
+                KeyCollision::Skip => skipped += items.len(),
+                KeyCollision::Rename => {
+                    pasted += items.len();
+                    let key = unique_key(&group, |key| data.contains_key(key));
+                    data.insert(key, items);
+                }
+            }
+        }
+        self.set_status(pasted, replaced, skipped, &errors);
+        pasted + replaced
+    }
+
+    fn set_status(&mut self, pasted: usize, replaced: usize, skipped: usize, errors: &[String]) {
+        let message = format!(
+            "Pasted {} row(s), replaced {}, skipped {}.",
+            pasted, replaced, skipped
+        );
+        self.status = Some(if errors.is_empty() {
+            Ok(message)
+        } else {
+            Err(format!(
+                "{} {} failed, ex. {}",
+                message,
+                errors.len(),
+                errors[0]
+            ))
+        });
+    }
+}
+
+impl Default for PasteRowsForm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
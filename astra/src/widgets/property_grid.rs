@@ -1,16 +1,67 @@
+use std::any::Any;
 use std::collections::HashSet;
+use std::rc::Rc;
+use std::sync::Arc;
 
 use egui::{Grid, Id, Label, Response, ScrollArea, TextEdit, Ui};
 
-use crate::raised_heading;
+use crate::{modified_text, raised_heading};
 
 use super::defaults::DefaultWidget;
 
+/// The loaded version of the row being edited.
+/// Property grids shown inside [BaselineRow::show] highlight the fields that differ from it.
+#[derive(Clone)]
+pub struct BaselineRow(Arc<dyn Any + Send + Sync>);
+
+impl BaselineRow {
+    pub fn new<T: Send + Sync + 'static>(row: T) -> Self {
+        Self(Arc::new(row))
+    }
+
+    fn id() -> Id {
+        Id::new("property_grid_baseline_row")
+    }
+
+    /// Run `add_contents` with `baseline` as the loaded version of the row it edits.
+    pub fn show<R>(
+        baseline: Option<&BaselineRow>,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> R {
+        let set = |ui: &mut Ui, baseline: Option<BaselineRow>| {
+            ui.data_mut(|data| match baseline {
+                Some(baseline) => data.insert_temp(Self::id(), baseline),
+                None => data.remove::<BaselineRow>(Self::id()),
+            })
+        };
+        let previous = ui.data(|data| data.get_temp::<BaselineRow>(Self::id()));
+        set(ui, baseline.cloned());
+        let result = add_contents(ui);
+        set(ui, previous);
+        result
+    }
+
+    fn current<D: Clone + 'static>(ui: &Ui) -> Option<D> {
+        ui.data(|data| data.get_temp::<BaselineRow>(Self::id()))
+            .and_then(|baseline| baseline.0.downcast_ref::<D>().cloned())
+    }
+}
+
+type AddContents<'a, D> = Box<dyn Fn(&mut Ui, &mut D) -> Response + 'a>;
+type FieldChanged<'a, D> = Box<dyn Fn(&mut D, &mut D) -> bool + 'a>;
+
+struct PropertyGridField<'a, D> {
+    label: &'a str,
+    add_contents: AddContents<'a, D>,
+    changed: Option<FieldChanged<'a, D>>,
+}
+
 struct PropertyGridSection<'a, D> {
     name: &'a str,
     num_columns: usize,
     labels: HashSet<&'a str>,
-    fields: Vec<(&'a str, Box<dyn Fn(&mut Ui, &mut D) -> Response + 'a>)>,
+    fields: Vec<PropertyGridField<'a, D>>,
 }
 
 impl<'a, D> PropertyGridSection<'a, D> {
@@ -27,9 +78,14 @@ impl<'a, D> PropertyGridSection<'a, D> {
         &mut self,
         label: &'a str,
         add_contents: impl Fn(&mut Ui, &mut D) -> Response + 'a,
+        changed: Option<FieldChanged<'a, D>>,
     ) {
         self.labels.insert(label);
-        self.fields.push((label, Box::new(add_contents)));
+        self.fields.push(PropertyGridField {
+            label,
+            add_contents: Box::new(add_contents),
+            changed,
+        });
     }
 
     pub fn visible(&self, filter: &str) -> bool {
@@ -40,7 +96,13 @@ impl<'a, D> PropertyGridSection<'a, D> {
                 .any(|label| label.to_lowercase().contains(&filter.to_lowercase()))
     }
 
-    pub fn show(&self, ui: &mut Ui, data: &mut D, filter: &str) -> Response {
+    pub fn show(
+        &self,
+        ui: &mut Ui,
+        data: &mut D,
+        baseline: Option<&mut D>,
+        filter: &str,
+    ) -> Response {
         if !self.name.is_empty() {
             ui.add(raised_heading(self.name));
         }
@@ -49,12 +111,19 @@ impl<'a, D> PropertyGridSection<'a, D> {
             .num_columns(self.num_columns * 2)
             .show(ui, |ui| {
                 let mut fields_in_row = 0;
-                for (label, add_contents) in &self.fields {
-                    if label.to_lowercase().contains(&filter.to_lowercase()) {
+                let mut baseline = baseline;
+                for field in &self.fields {
+                    if field.label.to_lowercase().contains(&filter.to_lowercase()) {
+                        let modified = match (&field.changed, baseline.as_deref_mut()) {
+                            (Some(changed), Some(baseline)) => changed(data, baseline),
+                            _ => false,
+                        };
                         ui.vertical(|ui| {
-                            ui.add(Label::new(*label).wrap(false));
+                            ui.add(
+                                Label::new(modified_text(ui, field.label, modified)).wrap(false),
+                            );
                         });
-                        if add_contents(ui, data).changed() {
+                        if (field.add_contents)(ui, data).changed() {
                             changed = true;
                         }
                         fields_in_row += 1;
@@ -110,7 +179,7 @@ impl<'a, D> PropertyGrid<'a, D> {
         add_contents: impl Fn(&mut Ui, &mut D) -> Response + 'a,
     ) -> Self {
         if let Some(section) = self.sections.last_mut() {
-            section.field(label, add_contents);
+            section.field(label, add_contents, None);
         }
         self
     }
@@ -126,17 +195,29 @@ impl<'a, D> PropertyGrid<'a, D> {
         retrieve_field: impl Fn(&mut D) -> &mut F + 'a,
     ) -> Self
     where
-        F: DefaultWidget,
+        F: DefaultWidget + PartialEq,
     {
+        let retrieve_field = Rc::new(retrieve_field);
         if let Some(section) = self.sections.last_mut() {
-            section.field(label, move |ui: &mut Ui, data: &mut D| {
-                retrieve_field(data).default_widget(ui)
-            })
+            let retrieve_baseline = retrieve_field.clone();
+            section.field(
+                label,
+                move |ui: &mut Ui, data: &mut D| retrieve_field(data).default_widget(ui),
+                Some(Box::new(move |data: &mut D, baseline: &mut D| {
+                    retrieve_baseline(data) != retrieve_baseline(baseline)
+                })),
+            )
         }
         self
     }
 
-    pub fn show(&mut self, ui: &mut Ui) -> Response {
+    /// Draw the grid. Inside [BaselineRow::show], fields that differ from the loaded row
+    /// are highlighted. Only fields added with [PropertyGrid::default_field] can be compared.
+    pub fn show(&mut self, ui: &mut Ui) -> Response
+    where
+        D: Clone + 'static,
+    {
+        let mut baseline = BaselineRow::current::<D>(ui);
         let mut filter: String = ui.memory_mut(|mem| {
             std::mem::take(mem.data.get_persisted_mut_or_default::<String>(self.id))
         });
@@ -153,7 +234,9 @@ impl<'a, D> PropertyGrid<'a, D> {
                     ui.separator();
                     for section in &self.sections {
                         if section.visible(&filter)
-                            && section.show(ui, self.data, &filter).changed()
+                            && section
+                                .show(ui, self.data, baseline.as_mut(), &filter)
+                                .changed()
                         {
                             changed = true;
                         }
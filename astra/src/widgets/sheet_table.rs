@@ -0,0 +1,359 @@
+use std::marker::PhantomData;
+
+use egui::{Event, Id, Key, Label, RichText, ScrollArea, Sense, TextEdit, Ui};
+use egui_extras::{Column, TableBuilder};
+use indexmap::IndexMap;
+
+use crate::model::{SheetHandle, SheetRetriever};
+use crate::{changed_fields, compare_cells, RowModel, SchemaRow};
+
+struct CellEdit {
+    row: usize,
+    column: usize,
+    buffer: String,
+    request_focus: bool,
+}
+
+/// Spreadsheet-style view of every row in a sheet with one column per field.
+/// Supports sorting by column, filtering, inline editing, and pasting tab separated cells.
+pub struct SheetTable<I> {
+    phantom: PhantomData<I>,
+    id_source: &'static str,
+    filter: String,
+    sort: Option<(usize, bool)>,
+    selection: Option<(usize, usize)>,
+    edit: Option<CellEdit>,
+    values: Vec<IndexMap<String, String>>,
+    changed: Vec<Vec<&'static str>>,
+    view: Vec<usize>,
+    requires_refresh: bool,
+    prev_model_revision: Option<usize>,
+    error: Option<String>,
+}
+
+impl<I> SheetTable<I>
+where
+    I: SchemaRow,
+{
+    pub fn new(id_source: &'static str) -> Self {
+        Self {
+            phantom: PhantomData,
+            id_source,
+            filter: String::new(),
+            sort: None,
+            selection: None,
+            edit: None,
+            values: vec![],
+            changed: vec![],
+            view: vec![],
+            requires_refresh: true,
+            prev_model_revision: None,
+            error: None,
+        }
+    }
+
+    pub fn show<R, B, M>(&mut self, ui: &mut Ui, model: &SheetHandle<R, B, M>)
+    where
+        R: SheetRetriever<B, M>,
+        M: RowModel<I> + Send + Sync + 'static,
+    {
+        if self.prev_model_revision != Some(model.revision_number()) {
+            self.prev_model_revision = Some(model.revision_number());
+            let baseline = model.baseline();
+            (self.values, self.changed) = model.read(|data| {
+                let values: Vec<_> = data.rows().map(|row| row.field_values()).collect();
+                let changed = (0..values.len())
+                    .map(|row| match &baseline {
+                        Some(baseline) => changed_fields(data, baseline, row),
+                        None => vec![],
+                    })
+                    .collect();
+                (values, changed)
+            });
+            self.requires_refresh = true;
+        }
+        if self.requires_refresh {
+            self.refresh_view();
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("Filter");
+            if ui
+                .add(TextEdit::singleline(&mut self.filter).desired_width(300.))
+                .changed()
+            {
+                self.requires_refresh = true;
+            }
+            ui.label(format!("{} / {} rows", self.view.len(), self.values.len()));
+            if let Some(error) = &self.error {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
+        });
+        ui.separator();
+
+        let mut changes = vec![];
+        self.handle_input(ui, &mut changes);
+
+        let row_height = ui.spacing().interact_size.y;
+        ui.push_id(Id::new(self.id_source).with("sheet_table"), |ui| {
+            ScrollArea::horizontal().show(ui, |ui| {
+                TableBuilder::new(ui)
+                    .striped(true)
+                    .auto_shrink([false, false])
+                    .column(Column::auto().at_least(40.))
+                    .columns(
+                        Column::initial(120.)
+                            .at_least(40.)
+                            .clip(true)
+                            .resizable(true),
+                        I::FIELDS.len(),
+                    )
+                    .header(row_height, |mut header| {
+                        header.col(|ui| {
+                            ui.strong("#");
+                        });
+                        for (column, field) in I::FIELDS.iter().enumerate() {
+                            header.col(|ui| {
+                                let text = match self.sort {
+                                    Some((c, false)) if c == column => format!("{} ⏶", field.ident),
+                                    Some((c, true)) if c == column => format!("{} ⏷", field.ident),
+                                    _ => field.ident.to_string(),
+                                };
+                                let response = ui
+                                    .add(
+                                        Label::new(RichText::new(text).strong())
+                                            .sense(Sense::click()),
+                                    )
+                                    .on_hover_text(format!("{} ({})", field.key, field.ty));
+                                if response.clicked() {
+                                    self.sort = match self.sort {
+                                        Some((c, false)) if c == column => Some((column, true)),
+                                        Some((c, true)) if c == column => None,
+                                        _ => Some((column, false)),
+                                    };
+                                    self.requires_refresh = true;
+                                }
+                            });
+                        }
+                    })
+                    .body(|body| {
+                        body.rows(row_height, self.view.len(), |mut table_row| {
+                            let row = self.view[table_row.index()];
+                            table_row.col(|ui| {
+                                ui.weak((row + 1).to_string());
+                            });
+                            for (column, field) in I::FIELDS.iter().enumerate() {
+                                table_row.col(|ui| {
+                                    if let Some(edit) = self
+                                        .edit
+                                        .as_mut()
+                                        .filter(|e| e.row == row && e.column == column)
+                                    {
+                                        let response = ui.add(
+                                            TextEdit::singleline(&mut edit.buffer)
+                                                .desired_width(f32::INFINITY),
+                                        );
+                                        if edit.request_focus {
+                                            response.request_focus();
+                                            edit.request_focus = false;
+                                        } else if response.lost_focus() {
+                                            if !ui.input(|input| input.key_pressed(Key::Escape)) {
+                                                changes.push((row, column, edit.buffer.clone()));
+                                            }
+                                            self.edit = None;
+                                        }
+                                        return;
+                                    }
+
+                                    let value = self.values[row]
+                                        .get(field.key)
+                                        .map(|v| v.as_str())
+                                        .unwrap_or_default();
+                                    let selected = self.selection == Some((row, column));
+                                    let mut text = RichText::new(value);
+                                    if field.id || field.public_array {
+                                        text = text.weak();
+                                    }
+                                    if self.changed[row].contains(&field.key) {
+                                        text = text.color(ui.visuals().warn_fg_color);
+                                    }
+                                    let response = ui.selectable_label(selected, text);
+                                    if response.clicked() {
+                                        self.selection = Some((row, column));
+                                    }
+                                    if response.double_clicked() {
+                                        self.begin_edit(row, column);
+                                    }
+                                });
+                            }
+                        });
+                    });
+            });
+        });
+
+        if !changes.is_empty() {
+            self.apply_changes(model, changes);
+        }
+    }
+
+    fn refresh_view(&mut self) {
+        let filter = self.filter.to_lowercase();
+        self.view = (0..self.values.len())
+            .filter(|row| {
+                filter.is_empty()
+                    || self.values[*row]
+                        .values()
+                        .any(|value| value.to_lowercase().contains(&filter))
+            })
+            .collect();
+        if let Some((column, descending)) = self.sort {
+            let key = I::FIELDS[column].key;
+            self.view.sort_by(|a, b| {
+                let a = self.values[*a]
+                    .get(key)
+                    .map(|v| v.as_str())
+                    .unwrap_or_default();
+                let b = self.values[*b]
+                    .get(key)
+                    .map(|v| v.as_str())
+                    .unwrap_or_default();
+                let ordering = compare_cells(a, b);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+        self.requires_refresh = false;
+    }
+
+    fn begin_edit(&mut self, row: usize, column: usize) {
+        let field = &I::FIELDS[column];
+        if field.id || field.public_array {
+            self.error = Some(format!(
+                "'{}' identifies the row and can't be edited here",
+                field.ident
+            ));
+            return;
+        }
+        self.selection = Some((row, column));
+        self.edit = Some(CellEdit {
+            row,
+            column,
+            buffer: self.values[row].get(field.key).cloned().unwrap_or_default(),
+            request_focus: true,
+        });
+    }
+
+    fn handle_input(&mut self, ui: &mut Ui, changes: &mut Vec<(usize, usize, String)>) {
+        let no_widgets_focused = ui.memory(|mem| mem.focus().is_none());
+        if self.edit.is_some() || !no_widgets_focused {
+            return;
+        }
+        let Some((row, column)) = self.selection else {
+            return;
+        };
+        let Some(position) = self.view.iter().position(|r| *r == row) else {
+            return;
+        };
+
+        let (events, up, down, left, right, edit) = ui.input(|input| {
+            (
+                input.events.clone(),
+                input.key_pressed(Key::ArrowUp),
+                input.key_pressed(Key::ArrowDown),
+                input.key_pressed(Key::ArrowLeft),
+                input.key_pressed(Key::ArrowRight),
+                input.key_pressed(Key::Enter) || input.key_pressed(Key::F2),
+            )
+        });
+        for event in events {
+            match event {
+                Event::Copy => {
+                    let value = self.values[row]
+                        .get(I::FIELDS[column].key)
+                        .cloned()
+                        .unwrap_or_default();
+                    ui.output_mut(|out| out.copied_text = value);
+                }
+                Event::Paste(text) => self.paste(&text, position, column, changes),
+                _ => {}
+            }
+        }
+
+        if edit {
+            self.begin_edit(row, column);
+        } else if up && position > 0 {
+            self.selection = Some((self.view[position - 1], column));
+        } else if down && position + 1 < self.view.len() {
+            self.selection = Some((self.view[position + 1], column));
+        } else if left && column > 0 {
+            self.selection = Some((row, column - 1));
+        } else if right && column + 1 < I::FIELDS.len() {
+            self.selection = Some((row, column + 1));
+        }
+    }
+
+    /// Spread tab separated text across the table starting at the selected cell.
+    /// Cells that fall outside the visible rows or on read-only columns are skipped.
+    fn paste(
+        &mut self,
+        text: &str,
+        position: usize,
+        column: usize,
+        changes: &mut Vec<(usize, usize, String)>,
+    ) {
+        for (i, line) in text.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let Some(row) = self.view.get(position + i).copied() else {
+                break;
+            };
+            for (j, value) in line.trim_end_matches('\r').split('\t').enumerate() {
+                let Some(field) = I::FIELDS.get(column + j) else {
+                    break;
+                };
+                if !field.id && !field.public_array {
+                    changes.push((row, column + j, value.to_string()));
+                }
+            }
+        }
+    }
+
+    fn apply_changes<R, B, M>(
+        &mut self,
+        model: &SheetHandle<R, B, M>,
+        changes: Vec<(usize, usize, String)>,
+    ) where
+        R: SheetRetriever<B, M>,
+        M: RowModel<I>,
+    {
+        let mut by_row: IndexMap<usize, Vec<(String, String)>> = IndexMap::new();
+        for (row, column, value) in changes {
+            let key = I::FIELDS[column].key;
+            if self.values[row].get(key) != Some(&value) {
+                by_row
+                    .entry(row)
+                    .or_default()
+                    .push((key.to_string(), value));
+            }
+        }
+
+        let mut errors = vec![];
+        model.write(|data| {
+            let mut changed = false;
+            for (row, values) in by_row {
+                if let Some(item) = data.row_mut(row) {
+                    match item.with_field_values(values) {
+                        Ok(new_item) => {
+                            *item = new_item;
+                            changed = true;
+                        }
+                        Err(err) => errors.push(format!("row {}: {}", row + 1, err)),
+                    }
+                }
+            }
+            changed
+        });
+        self.error = (!errors.is_empty()).then(|| errors.join("; "));
+    }
+}
@@ -0,0 +1,223 @@
+use std::marker::PhantomData;
+use std::sync::OnceLock;
+
+use egui::{Button, ScrollArea, SidePanel, Ui};
+use parking_lot::Mutex;
+
+use crate::{
+    queue_transition, BaselineRow, EditorState, RowForm, RowModel, SchemaRow, Screens, SheetHandle,
+    SheetRetriever, SheetTable, Transition,
+};
+
+static PINNED_TABS: OnceLock<Mutex<Vec<Box<dyn SplitViewTab>>>> = OnceLock::new();
+
+/// Queue a tab to be opened in the split view on the next frame.
+pub fn pin_tab(tab: impl SplitViewTab + 'static) {
+    let lock = PINNED_TABS.get_or_init(|| Mutex::new(vec![]));
+    lock.lock().push(Box::new(tab));
+}
+
+/// Content that can be pinned next to the active editor.
+pub trait SplitViewTab: Send {
+    fn title(&self) -> &str;
+
+    /// The screen and index to open when jumping to the pinned content.
+    fn location(&self) -> Option<(Screens, usize)> {
+        None
+    }
+
+    fn show(&mut self, ui: &mut Ui, state: &EditorState);
+}
+
+/// A single row, edited through the same [RowForm] as in its editor.
+pub struct RowTab<R, B, M, I> {
+    title: String,
+    sheet: SheetHandle<R, B, M>,
+    row_id: String,
+    screen: Option<Screens>,
+    phantom: PhantomData<fn() -> I>,
+}
+
+impl<R, B, M, I> RowTab<R, B, M, I>
+where
+    R: SheetRetriever<B, M>,
+    M: RowModel<I>,
+{
+    /// Pin the row at `index`. Returns [None] if the index is out of bounds.
+    pub fn new(
+        title: impl Into<String>,
+        sheet: SheetHandle<R, B, M>,
+        index: usize,
+        screen: Option<Screens>,
+    ) -> Option<Self> {
+        let row_id = sheet.read(|data| data.row_id(index))?;
+        Some(Self {
+            title: title.into(),
+            sheet,
+            row_id,
+            screen,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<R, B, M, I> SplitViewTab for RowTab<R, B, M, I>
+where
+    R: SheetRetriever<B, M> + Send,
+    B: Send + Sync,
+    M: RowModel<I> + Send + Sync + 'static,
+    I: RowForm + Clone + Send + Sync + 'static,
+{
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn location(&self) -> Option<(Screens, usize)> {
+        let index = self.sheet.read(|data| data.row_index(&self.row_id))?;
+        self.screen.map(|screen| (screen, index))
+    }
+
+    fn show(&mut self, ui: &mut Ui, state: &EditorState) {
+        let baseline = self.sheet.baseline();
+        let found = self.sheet.read(|data| {
+            let index = data.row_index(&self.row_id)?;
+            let row = data.row(index)?.clone();
+            let original = baseline
+                .as_ref()
+                .and_then(|baseline| data.baseline_row(baseline, index).cloned())
+                .map(BaselineRow::new);
+            Some((index, row, original))
+        });
+        let Some((index, mut row, original)) = found else {
+            ui.label("This row no longer exists.");
+            return;
+        };
+        let changed = ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                BaselineRow::show(original.as_ref(), ui, |ui| I::form(ui, state, &mut row))
+            })
+            .inner;
+        if changed {
+            self.sheet.write(|data| match data.row_mut(index) {
+                Some(target) => {
+                    *target = row;
+                    true
+                }
+                None => false,
+            });
+        }
+    }
+}
+
+/// Every row in a sheet, shown as a table.
+pub struct SheetTab<R, B, M, I> {
+    title: String,
+    sheet: SheetHandle<R, B, M>,
+    table: SheetTable<I>,
+}
+
+impl<R, B, M, I> SheetTab<R, B, M, I>
+where
+    I: SchemaRow,
+{
+    pub fn new(title: impl Into<String>, sheet: SheetHandle<R, B, M>) -> Self {
+        Self {
+            title: title.into(),
+            sheet,
+            table: SheetTable::new("split_view_sheet_table"),
+        }
+    }
+}
+
+impl<R, B, M, I> SplitViewTab for SheetTab<R, B, M, I>
+where
+    R: SheetRetriever<B, M> + Send,
+    B: Send + Sync,
+    M: RowModel<I> + Send + Sync + 'static,
+    I: SchemaRow + Send,
+{
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn show(&mut self, ui: &mut Ui, _: &EditorState) {
+        self.table.show(ui, &self.sheet);
+    }
+}
+
+/// Pinned rows and sheets shown as tabs in a panel beside the active editor.
+#[derive(Default)]
+pub struct SplitView {
+    tabs: Vec<Box<dyn SplitViewTab>>,
+    active: usize,
+    visible: bool,
+}
+
+impl SplitView {
+    pub fn has_tabs(&self) -> bool {
+        !self.tabs.is_empty()
+    }
+
+    pub fn visible_mut(&mut self) -> &mut bool {
+        &mut self.visible
+    }
+
+    /// Show the split view if it's open. Must be called before the active editor's panels.
+    pub fn show(&mut self, ctx: &egui::Context, state: &EditorState) {
+        if let Some(lock) = PINNED_TABS.get() {
+            let pinned = std::mem::take(&mut *lock.lock());
+            if !pinned.is_empty() {
+                self.active = self.tabs.len() + pinned.len() - 1;
+                self.tabs.extend(pinned);
+                self.visible = true;
+            }
+        }
+        if !self.visible || self.tabs.is_empty() {
+            return;
+        }
+
+        SidePanel::right("split_view_panel")
+            .default_width(400.)
+            .show(ctx, |ui| {
+                let mut closed = None;
+                ui.horizontal_wrapped(|ui| {
+                    for (i, tab) in self.tabs.iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            ui.spacing_mut().item_spacing.x = 0.;
+                            ui.selectable_value(&mut self.active, i, tab.title());
+                            if ui.small_button("x").on_hover_text("Unpin").clicked() {
+                                closed = Some(i);
+                            }
+                        });
+                    }
+                });
+                if let Some(index) = closed {
+                    self.tabs.remove(index);
+                    if self.active > index || self.active >= self.tabs.len() {
+                        self.active = self.active.saturating_sub(1);
+                    }
+                }
+                let Some(tab) = self.tabs.get_mut(self.active) else {
+                    return;
+                };
+                ui.separator();
+                let location = tab.location();
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(location.is_some(), Button::new("Go To"))
+                        .clicked()
+                    {
+                        if let Some((screen, index)) = location {
+                            queue_transition(Transition::new(screen, index));
+                        }
+                    }
+                    if ui.button("Hide").clicked() {
+                        self.visible = false;
+                    }
+                });
+                ui.separator();
+                ui.push_id(("split_view_tab", self.active), |ui| tab.show(ui, state));
+            });
+    }
+}
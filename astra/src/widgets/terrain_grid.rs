@@ -6,7 +6,7 @@ use egui::{Button, Color32, Grid, ScrollArea, Sense, Ui, Vec2};
 
 use crate::model::ViewItem;
 use crate::util::get_tile_color;
-use crate::{AppConfig, EditorState, ListModel};
+use crate::{line_tiles, AppConfig, EditorState, ListModel, TerrainLayout, TerrainPattern};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum TerrainBrush {
@@ -14,6 +14,35 @@ pub enum TerrainBrush {
     Stamp,
     Fill,
     Box,
+    Line,
+    /// Pick the clicked tile as the brush's tile.
+    Eyedropper,
+    /// Drag to copy a region, click to stamp it.
+    Pattern,
+}
+
+impl TerrainBrush {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            TerrainBrush::Stamp => "🖊",
+            TerrainBrush::Fill => "💧",
+            TerrainBrush::Box => "⬜",
+            TerrainBrush::Line => "📏",
+            TerrainBrush::Eyedropper => "💉",
+            TerrainBrush::Pattern => "📋",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            TerrainBrush::Stamp => "Stamp",
+            TerrainBrush::Fill => "Fill",
+            TerrainBrush::Box => "Box (drag)",
+            TerrainBrush::Line => "Line (drag)",
+            TerrainBrush::Eyedropper => "Eyedropper",
+            TerrainBrush::Pattern => "Pattern (drag to copy a region, click to stamp it)",
+        }
+    }
 }
 
 pub struct TerrainGridResult {
@@ -29,8 +58,23 @@ pub fn terrain_grid(
     state: &EditorState,
     config: &AppConfig,
     brush: TerrainBrush,
+    pattern: &mut Option<TerrainPattern>,
 ) -> TerrainGridResult {
+    let layout = match TerrainLayout::of(terrain) {
+        Ok(layout) => layout,
+        Err(err) => {
+            ui.centered_and_justified(|ui| {
+                ui.colored_label(ui.visuals().error_fg_color, format!("Bad terrain: {}", err));
+            });
+            return TerrainGridResult {
+                changed: false,
+                hovered_tile: None,
+                selected_tile: None,
+            };
+        }
+    };
     let mut changed = vec![];
+    let mut stamp_at = None;
     let mut hovered_tile = None;
     let mut selected_tile = None;
     let mut drag_origin = None;
@@ -44,16 +88,19 @@ pub fn terrain_grid(
                 state.terrain.read(|data| {
                     for row in (0..(terrain.height as usize)).rev() {
                         for col in 0..(terrain.width as usize) {
-                            let tid = terrain.terrains.get(row * 32 + col);
+                            let tid = layout
+                                .index(col, row)
+                                .and_then(|index| terrain.terrains.get(index));
                             let (tile_name, fill) = tid
                                 .and_then(|tid| data.get(tid.as_str()))
                                 .map(|tile| (tile.text(state), get_tile_color(tile, config)))
                                 .unwrap_or_else(|| (Cow::Borrowed("???"), Color32::from_gray(0)));
 
-                            let sense = if let TerrainBrush::Box = brush {
-                                Sense::click_and_drag()
-                            } else {
-                                Sense::click()
+                            let sense = match brush {
+                                TerrainBrush::Box | TerrainBrush::Line | TerrainBrush::Pattern => {
+                                    Sense::click_and_drag()
+                                }
+                                _ => Sense::click(),
                             };
 
                             let response = ui.add_sized(
@@ -67,11 +114,16 @@ pub fn terrain_grid(
                                         TerrainBrush::Fill => get_bucket_fill_tiles(
                                             &mut changed,
                                             &terrain.terrains,
+                                            layout,
                                             tid.as_str(),
                                             row,
                                             col,
                                         ),
-                                        TerrainBrush::Box => {}
+                                        TerrainBrush::Eyedropper => {
+                                            selected_tile = Some(tid.to_string())
+                                        }
+                                        TerrainBrush::Pattern => stamp_at = Some((col, row)),
+                                        TerrainBrush::Box | TerrainBrush::Line => {}
                                     }
                                 }
                             }
@@ -102,31 +154,70 @@ pub fn terrain_grid(
             .and_then(|index| data.item(index))
             .map(|tile| tile.tid.clone())
     });
+    let drag = drag_origin.zip(drag_end);
+    if let (TerrainBrush::Pattern, Some(((start_row, start_col), (end_row, end_col)))) =
+        (brush, drag)
+    {
+        *pattern = Some(TerrainPattern::copy(
+            terrain,
+            layout,
+            (start_col, start_row),
+            (end_col, end_row),
+        ));
+    }
+    let mut stamped = false;
+    let mut painted = false;
+    if let (Some(at), Some(pattern)) = (stamp_at, pattern.as_ref()) {
+        for ((x, y), tid) in pattern.tiles_at(at) {
+            // Parts of the pattern past the playable area are cut off.
+            if x >= terrain.width as usize || y >= terrain.height as usize || tid.is_empty() {
+                continue;
+            }
+            if let Some(index) = layout.index(x, y) {
+                if terrain.terrains[index].as_str() != tid {
+                    terrain.terrains[index] = UString(tid.to_string());
+                    stamped = true;
+                }
+            }
+        }
+    }
     if let Some(tid) = new_tid {
-        if let (Some((drag_start_row, drag_start_col)), Some((drag_end_row, drag_end_col))) =
-            (drag_origin, drag_end)
-        {
-            let min_row = drag_start_row.min(drag_end_row);
-            let max_row = drag_start_row.max(drag_end_row);
-            let min_col = drag_start_col.min(drag_end_col);
-            let max_col = drag_start_col.max(drag_end_col);
-            for row in min_row..=max_row {
-                for col in min_col..=max_col {
-                    changed.push((row, col));
+        if let Some(((drag_start_row, drag_start_col), (drag_end_row, drag_end_col))) = drag {
+            match brush {
+                TerrainBrush::Box => {
+                    let min_row = drag_start_row.min(drag_end_row);
+                    let max_row = drag_start_row.max(drag_end_row);
+                    let min_col = drag_start_col.min(drag_end_col);
+                    let max_col = drag_start_col.max(drag_end_col);
+                    for row in min_row..=max_row {
+                        for col in min_col..=max_col {
+                            changed.push((row, col));
+                        }
+                    }
                 }
+                TerrainBrush::Line => changed.extend(
+                    line_tiles(
+                        (drag_start_col, drag_start_row),
+                        (drag_end_col, drag_end_row),
+                    )
+                    .into_iter()
+                    .map(|(col, row)| (row, col)),
+                ),
+                _ => {}
             }
         }
-        if !changed.is_empty() {
-            for (row, col) in &changed {
-                let index = row * 32 + col;
-                if index < terrain.terrains.len() {
+        // Painting over tiles that already have the TID isn't a change.
+        for (row, col) in &changed {
+            if let Some(index) = layout.index(*col, *row) {
+                if terrain.terrains[index].as_str() != tid {
                     terrain.terrains[index] = UString(tid.clone());
+                    painted = true;
                 }
             }
         }
     }
     TerrainGridResult {
-        changed: changed.is_empty(),
+        changed: painted || stamped,
         hovered_tile,
         selected_tile,
     }
@@ -135,6 +226,7 @@ pub fn terrain_grid(
 fn get_bucket_fill_tiles(
     output: &mut Vec<(usize, usize)>,
     tiles: &[UString],
+    layout: TerrainLayout,
     target_tid: &str,
     row: usize,
     col: usize,
@@ -143,13 +235,14 @@ fn get_bucket_fill_tiles(
     // We don't do that because of the borrow checker, so we use a set to track checked coordinates
     // and exit recursion when the coordinate has already been checked.
     let mut checked = HashSet::new();
-    get_bucket_fill_tiles_recursive(&mut checked, output, tiles, target_tid, row, col);
+    get_bucket_fill_tiles_recursive(&mut checked, output, tiles, layout, target_tid, row, col);
 }
 
 fn get_bucket_fill_tiles_recursive(
     checked: &mut HashSet<(usize, usize)>,
     output: &mut Vec<(usize, usize)>,
     tiles: &[UString],
+    layout: TerrainLayout,
     target_tid: &str,
     row: usize,
     col: usize,
@@ -162,15 +255,15 @@ fn get_bucket_fill_tiles_recursive(
     checked.insert((row, col));
 
     // Base case: out of bounds
-    if let Some(tid) = tiles.get(row * 32 + col) {
+    if let Some(tid) = layout.index(col, row).and_then(|index| tiles.get(index)) {
         // Base case: different tile
         if tid.as_str() == target_tid {
             // General case: note the tile and recurse to adjacent tiles
             output.push((row, col));
-            get_bucket_fill_tiles_recursive(checked, output, tiles, target_tid, row.saturating_sub(1), col);
-            get_bucket_fill_tiles_recursive(checked, output, tiles, target_tid, row + 1, col);
-            get_bucket_fill_tiles_recursive(checked, output, tiles, target_tid, row, col + 1);
-            get_bucket_fill_tiles_recursive(checked, output, tiles, target_tid, row, col.saturating_sub(1));
+            get_bucket_fill_tiles_recursive(checked, output, tiles, layout, target_tid, row.saturating_sub(1), col);
+            get_bucket_fill_tiles_recursive(checked, output, tiles, layout, target_tid, row + 1, col);
+            get_bucket_fill_tiles_recursive(checked, output, tiles, layout, target_tid, row, col + 1);
+            get_bucket_fill_tiles_recursive(checked, output, tiles, layout, target_tid, row, col.saturating_sub(1));
         }
     }
 }
@@ -0,0 +1,88 @@
+use egui::{Button, Grid, Ui};
+use egui_modal::Modal;
+
+use crate::{model_drop_down, EditorState};
+
+/// Form for replacing every tile of one kind with another.
+pub struct TerrainReplaceForm {
+    from: String,
+    to: String,
+    status: Option<Result<String, String>>,
+}
+
+impl TerrainReplaceForm {
+    pub fn new() -> Self {
+        Self {
+            from: String::new(),
+            to: String::new(),
+            status: None,
+        }
+    }
+
+    /// Open the form, replacing `from` if given.
+    pub fn open(&mut self, modal: &Modal, from: Option<String>) {
+        if let Some(from) = from {
+            self.from = from;
+        }
+        self.status = None;
+        modal.open();
+    }
+
+    /// Report how the last replace went.
+    pub fn set_status(&mut self, status: Result<String, String>) {
+        self.status = Some(status);
+    }
+
+    /// Draw the form inside a modal. Returns the TIDs to replace when the user confirms.
+    pub fn show(
+        &mut self,
+        modal: &Modal,
+        ui: &mut Ui,
+        state: &EditorState,
+    ) -> Option<(String, String)> {
+        let mut result = None;
+        modal.title(ui, "Replace Tiles");
+        modal.frame(ui, |ui| {
+            Grid::new("terrain_replace_grid")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    state.terrain.read(|data| {
+                        ui.label("Replace");
+                        ui.add(model_drop_down(data, state, &mut self.from));
+                        ui.end_row();
+                        ui.label("With");
+                        ui.add(model_drop_down(data, state, &mut self.to));
+                        ui.end_row();
+                    });
+                });
+            match &self.status {
+                Some(Ok(message)) => {
+                    ui.label(message);
+                }
+                Some(Err(message)) => {
+                    ui.colored_label(ui.visuals().error_fg_color, message);
+                }
+                None => {}
+            }
+        });
+        modal.buttons(ui, |ui| {
+            modal.button(ui, "Close");
+            if ui
+                .add_enabled(
+                    !self.from.is_empty() && !self.to.is_empty() && self.from != self.to,
+                    Button::new("Replace All"),
+                )
+                .clicked()
+            {
+                result = Some((self.from.clone(), self.to.clone()));
+            }
+        });
+        result
+    }
+}
+
+impl Default for TerrainReplaceForm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
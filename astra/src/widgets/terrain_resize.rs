@@ -0,0 +1,150 @@
+use astra_formats::TerrainData;
+use egui::{Button, DragValue, Grid, Ui};
+use egui_modal::Modal;
+
+use crate::{model_drop_down, EditorState, TerrainLayout};
+
+/// A resize the user confirmed in a [TerrainResizeForm].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerrainResize {
+    pub width: i32,
+    pub height: i32,
+    pub shift: (i32, i32),
+    pub fill: String,
+    pub move_spawns: bool,
+}
+
+/// Form for growing, shrinking or shifting a chapter's playable area.
+pub struct TerrainResizeForm {
+    layout: Option<TerrainLayout>,
+    width: i32,
+    height: i32,
+    shift_x: i32,
+    shift_y: i32,
+    fill: String,
+    move_spawns: bool,
+    status: Option<Result<String, String>>,
+}
+
+impl TerrainResizeForm {
+    pub fn new() -> Self {
+        Self {
+            layout: None,
+            width: 0,
+            height: 0,
+            shift_x: 0,
+            shift_y: 0,
+            fill: String::new(),
+            move_spawns: true,
+            status: None,
+        }
+    }
+
+    /// Open the form, starting from the terrain's current size.
+    pub fn open(&mut self, modal: &Modal, terrain: &TerrainData) {
+        self.layout = TerrainLayout::of(terrain).ok();
+        self.width = terrain.width;
+        self.height = terrain.height;
+        self.shift_x = 0;
+        self.shift_y = 0;
+        if self.fill.is_empty() {
+            if let Some(tile) = terrain.terrains.first() {
+                self.fill = tile.to_string();
+            }
+        }
+        self.status = None;
+        modal.open();
+    }
+
+    /// Report how applying the last resize went.
+    pub fn set_status(&mut self, status: Result<String, String>) {
+        self.status = Some(status);
+    }
+
+    /// Draw the form inside a modal. Returns the resize when the user confirms.
+    pub fn show(
+        &mut self,
+        modal: &Modal,
+        ui: &mut Ui,
+        state: &EditorState,
+    ) -> Option<TerrainResize> {
+        let mut result = None;
+        modal.title(ui, "Resize Terrain");
+        modal.frame(ui, |ui| {
+            let Some(layout) = self.layout else {
+                ui.colored_label(
+                    ui.visuals().error_fg_color,
+                    "The terrain buffer doesn't match its playable area, so it can't be resized.",
+                );
+                return;
+            };
+            let (stride, rows) = (layout.stride as i32, layout.rows as i32);
+            Grid::new("terrain_resize_grid")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Playable Width");
+                    ui.add(DragValue::new(&mut self.width).clamp_range(1..=stride));
+                    ui.end_row();
+                    ui.label("Playable Height");
+                    ui.add(DragValue::new(&mut self.height).clamp_range(1..=rows));
+                    ui.end_row();
+                    ui.label("Shift X");
+                    ui.add(DragValue::new(&mut self.shift_x).clamp_range(-stride..=stride));
+                    ui.end_row();
+                    ui.label("Shift Y");
+                    ui.add(DragValue::new(&mut self.shift_y).clamp_range(-rows..=rows));
+                    ui.end_row();
+                    ui.label("New Tiles");
+                    state
+                        .terrain
+                        .read(|data| ui.add(model_drop_down(data, state, &mut self.fill)));
+                    ui.end_row();
+                });
+            ui.checkbox(
+                &mut self.move_spawns,
+                "Shift spawns in the chapter's dispos",
+            );
+            ui.label(format!(
+                "The terrain buffer is {}x{}. Tiles shifted past its edge are lost.",
+                stride, rows
+            ));
+            match &self.status {
+                Some(Ok(message)) => {
+                    ui.label(message);
+                }
+                Some(Err(message)) => {
+                    ui.colored_label(ui.visuals().error_fg_color, message);
+                }
+                None => {}
+            }
+        });
+        modal.buttons(ui, |ui| {
+            modal.button(ui, "Close");
+            if ui
+                .add_enabled(
+                    self.layout.is_some() && !self.fill.is_empty(),
+                    Button::new("Resize"),
+                )
+                .clicked()
+            {
+                result = Some(TerrainResize {
+                    width: self.width,
+                    height: self.height,
+                    shift: (self.shift_x, self.shift_y),
+                    fill: self.fill.clone(),
+                    move_spawns: self.move_spawns,
+                });
+                // The tiles have moved, so another click shouldn't shift them again.
+                self.shift_x = 0;
+                self.shift_y = 0;
+            }
+        });
+        result
+    }
+}
+
+impl Default for TerrainResizeForm {
+    fn default() -> Self {
+        Self::new()
+    }
+}